@@ -1,24 +1,307 @@
+use crate::scan_checkpoint::{self, ScanCheckpoint};
 use crate::{config::Config, ledger_dispatch, utils};
 use ethers::prelude::*;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use tokio::sync::mpsc;
+use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
 
-#[derive(Debug, Clone)]
+/// On-chain activity class of a scanned address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AddressActivity {
+    /// Zero balance, zero nonce and no code - never used
+    #[default]
+    Fresh,
+    /// Zero balance but has sent transactions before
+    UsedEmpty,
+    /// Holds a native balance
+    Funded,
+    /// Has code at the address (contract or EIP-7702 delegated account)
+    HasCode,
+}
+
+impl AddressActivity {
+    /// Classify an address from its balance, nonce and whether it has code.
+    /// Code takes precedence, since a delegated account must never be treated as a plain EOA.
+    pub fn classify(balance: U256, nonce: u64, has_code: bool) -> Self {
+        if has_code {
+            AddressActivity::HasCode
+        } else if !balance.is_zero() {
+            AddressActivity::Funded
+        } else if nonce > 0 {
+            AddressActivity::UsedEmpty
+        } else {
+            AddressActivity::Fresh
+        }
+    }
+
+    /// Only fresh addresses count towards the consecutive-empty gap limit
+    pub fn is_fresh(&self) -> bool {
+        matches!(self, AddressActivity::Fresh)
+    }
+
+    /// Label used for the CSV `Status` column
+    pub fn label(&self) -> &'static str {
+        match self {
+            AddressActivity::Fresh => "Fresh",
+            AddressActivity::UsedEmpty => "Used",
+            AddressActivity::Funded => "Funded",
+            AddressActivity::HasCode => "Has Code",
+        }
+    }
+
+    /// Parse a CSV `Status` value. The legacy "Empty" status maps to `Fresh`.
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label.trim().to_lowercase().as_str() {
+            "fresh" | "empty" => Some(AddressActivity::Fresh),
+            "used" => Some(AddressActivity::UsedEmpty),
+            "funded" => Some(AddressActivity::Funded),
+            "has code" | "contract" => Some(AddressActivity::HasCode),
+            _ => None,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            AddressActivity::Fresh => "⚪",
+            AddressActivity::UsedEmpty => "🟡",
+            AddressActivity::Funded => "🟢",
+            AddressActivity::HasCode => "🔵",
+        }
+    }
+
+    pub fn color(&self) -> (u8, u8, u8) {
+        match self {
+            AddressActivity::Fresh => (128, 128, 128),
+            AddressActivity::UsedEmpty => (200, 170, 90),
+            AddressActivity::Funded => (100, 200, 150),
+            AddressActivity::HasCode => (100, 149, 237),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BalanceScanRecord {
     pub index: u32,
     pub address: Address,
     pub balance: U256,
+    pub nonce: u64,
+    pub activity: AddressActivity,
     pub derivation_path: String,
 }
 
-#[derive(Debug, Clone)]
+/// Fetch balance, nonce and code for a derived address and build its scan record
+async fn fetch_scan_record(
+    provider: &Provider<Http>,
+    config: &Config,
+    index: u32,
+    address: Address,
+    block: Option<BlockId>,
+) -> Result<BalanceScanRecord> {
+    let (balance, nonce, code) = tokio::try_join!(
+        provider.get_balance(address, block),
+        provider.get_transaction_count(address, block),
+        provider.get_code(address, block),
+    )?;
+    let nonce = nonce.as_u64();
+
+    Ok(BalanceScanRecord {
+        index,
+        address,
+        balance,
+        nonce,
+        activity: AddressActivity::classify(balance, nonce, !code.is_empty()),
+        derivation_path: config.get_derivation_path(index),
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BalanceScanResult {
     pub records: Vec<BalanceScanRecord>,
     pub empty_addresses: Vec<(u32, Address)>,
     pub last_scanned_index: u32,
     pub met_target: bool,
     pub cancelled: bool,
+    /// Historical snapshot the balances were read at (`None` = latest block)
+    pub snapshot: Option<BalanceSnapshot>,
+}
+
+/// Point in chain history to read balances at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SnapshotTarget {
+    #[default]
+    Latest,
+    /// A specific block number
+    Block(u64),
+    /// A unix timestamp (seconds), resolved to the last block at or before it
+    Timestamp(u64),
+}
+
+impl SnapshotTarget {
+    /// Parse user input: blank for latest, digits for a block number, or a UTC
+    /// date/time (`YYYY-MM-DD`, `YYYY-MM-DD HH:MM[:SS]` or RFC 3339).
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        if input.is_empty() || input.eq_ignore_ascii_case("latest") {
+            return Ok(SnapshotTarget::Latest);
+        }
+        if let Ok(block) = input.trim_start_matches('#').parse::<u64>() {
+            return Ok(SnapshotTarget::Block(block));
+        }
+
+        let datetime = if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(input) {
+            dt.with_timezone(&chrono::Utc)
+        } else if let Some(dt) = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+            .iter()
+            .find_map(|fmt| chrono::NaiveDateTime::parse_from_str(input, fmt).ok())
+        {
+            dt.and_utc()
+        } else if let Ok(date) = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+            date.and_hms_opt(0, 0, 0)
+                .ok_or_else(|| anyhow!("Invalid date: {}", input))?
+                .and_utc()
+        } else {
+            return Err(anyhow!(
+                "Invalid snapshot '{}'. Use a block number or a UTC date like 2024-01-31 or 2024-01-31 12:00",
+                input
+            ));
+        };
+
+        let timestamp = u64::try_from(datetime.timestamp())
+            .map_err(|_| anyhow!("Snapshot time must be after 1970-01-01"))?;
+        Ok(SnapshotTarget::Timestamp(timestamp))
+    }
+}
+
+/// A resolved historical snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceSnapshot {
+    pub block_number: u64,
+    pub block_timestamp: u64,
+    /// Original timestamp request, when the block was resolved from a time
+    pub requested_timestamp: Option<u64>,
+}
+
+impl BalanceSnapshot {
+    pub fn block_id(&self) -> BlockId {
+        BlockId::Number(BlockNumber::Number(self.block_number.into()))
+    }
+
+    /// Human readable description, used in the UI, logs and CSV headers
+    pub fn describe(&self) -> String {
+        let block_time = format_utc_timestamp(self.block_timestamp);
+        match self.requested_timestamp {
+            Some(requested) => format!(
+                "block {} ({}), resolved from {}",
+                self.block_number,
+                block_time,
+                format_utc_timestamp(requested)
+            ),
+            None => format!("block {} ({})", self.block_number, block_time),
+        }
+    }
+}
+
+fn format_utc_timestamp(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Resolve a snapshot target to a concrete block, verifying the RPC can serve historical state.
+/// Returns `None` for `SnapshotTarget::Latest` without any RPC call.
+pub async fn resolve_snapshot(
+    provider: &Provider<Http>,
+    target: SnapshotTarget,
+) -> Result<Option<BalanceSnapshot>> {
+    if target == SnapshotTarget::Latest {
+        return Ok(None);
+    }
+    let latest = provider.get_block_number().await?.as_u64();
+
+    let (block_number, requested_timestamp) = match target {
+        SnapshotTarget::Latest => return Ok(None),
+        SnapshotTarget::Block(block) => {
+            if block > latest {
+                return Err(anyhow!(
+                    "Block {} is in the future (latest block is {})",
+                    block,
+                    latest
+                ));
+            }
+            (block, None)
+        }
+        SnapshotTarget::Timestamp(timestamp) => {
+            let block = find_block_at_timestamp(latest, timestamp, |number| {
+                block_timestamp(provider, number)
+            })
+            .await?;
+            (block, Some(timestamp))
+        }
+    };
+
+    let snapshot = BalanceSnapshot {
+        block_number,
+        block_timestamp: block_timestamp(provider, block_number).await?,
+        requested_timestamp,
+    };
+    ensure_historical_state(provider, &snapshot).await?;
+    Ok(Some(snapshot))
+}
+
+async fn block_timestamp(provider: &Provider<Http>, number: u64) -> Result<u64> {
+    let block = provider
+        .get_block(number)
+        .await?
+        .ok_or_else(|| anyhow!("Block {} not found", number))?;
+    Ok(block.timestamp.as_u64())
+}
+
+/// Binary search over block headers for the last block with `timestamp <= target`
+async fn find_block_at_timestamp<F, Fut>(latest: u64, target: u64, mut timestamp_of: F) -> Result<u64>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: std::future::Future<Output = Result<u64>>,
+{
+    if timestamp_of(0).await? > target {
+        return Err(anyhow!(
+            "{} is before the chain's genesis block",
+            format_utc_timestamp(target)
+        ));
+    }
+    if timestamp_of(latest).await? < target {
+        return Err(anyhow!(
+            "{} is after the latest block ({})",
+            format_utc_timestamp(target),
+            latest
+        ));
+    }
+
+    let (mut low, mut high) = (0u64, latest);
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if timestamp_of(mid).await? <= target {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    Ok(low)
+}
+
+/// Non-archive nodes prune old state, so probe it once up front instead of failing mid-scan
+async fn ensure_historical_state(provider: &Provider<Http>, snapshot: &BalanceSnapshot) -> Result<()> {
+    provider
+        .get_balance(Address::zero(), Some(snapshot.block_id()))
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            anyhow!(
+                "RPC cannot serve state at block {} - historical balances need an archive node ({})",
+                snapshot.block_number,
+                e
+            )
+        })
 }
 
 impl BalanceScanResult {
@@ -26,18 +309,22 @@ impl BalanceScanResult {
         if self.cancelled {
             return "Scan cancelled.".to_string();
         }
-        if self.met_target {
+        let summary = if self.met_target {
             format!(
-                "Found {} consecutive empty addresses (target met). Scanned up to index {}.",
+                "Found {} consecutive fresh addresses (target met). Scanned up to index {}.",
                 self.empty_addresses.len(),
                 self.last_scanned_index
             )
         } else {
             format!(
-                "Found {} consecutive empty addresses. Scanned up to index {}.",
+                "Found {} consecutive fresh addresses. Scanned up to index {}.",
                 self.empty_addresses.len(),
                 self.last_scanned_index
             )
+        };
+        match &self.snapshot {
+            Some(snapshot) => format!("{} Balances at {}.", summary, snapshot.describe()),
+            None => summary,
         }
     }
 
@@ -60,9 +347,12 @@ pub async fn scan_consecutive_empty(
     config: &Config,
     empty_target: u32,
     start_index: u32,
+    snapshot_target: SnapshotTarget,
     use_native_ledger: bool,
 ) -> Result<BalanceScanResult> {
     let provider = config.get_provider().await?;
+    let snapshot = resolve_snapshot(&provider, snapshot_target).await?;
+    let block = snapshot.map(|s| s.block_id());
     let mut consecutive_empty = 0;
     let mut index = start_index;
     let mut last_scanned_index = start_index.saturating_sub(1);
@@ -73,16 +363,12 @@ pub async fn scan_consecutive_empty(
     while consecutive_empty < empty_target {
         match ledger_dispatch::get_ledger_address_with_retry_config(use_native_ledger, config.chain_id, index, Some(config)).await {
             Ok(addr) => {
-                let balance = provider.get_balance(addr, None).await?;
-                records.push(BalanceScanRecord {
-                    index,
-                    address: addr,
-                    balance,
-                    derivation_path: config.get_derivation_path(index),
-                });
+                let record = fetch_scan_record(&provider, config, index, addr, block).await?;
+                let fresh = record.activity.is_fresh();
+                records.push(record);
                 last_scanned_index = index;
 
-                if balance.is_zero() {
+                if fresh {
                     consecutive_empty += 1;
                     empty_sequence.push((index, addr));
                 } else {
@@ -104,6 +390,7 @@ pub async fn scan_consecutive_empty(
         last_scanned_index,
         met_target: consecutive_empty >= empty_target,
         cancelled,
+        snapshot,
     })
 }
 
@@ -116,25 +403,59 @@ pub struct FundedAddressScan {
 /// Progress update for streaming scan
 #[derive(Debug, Clone)]
 pub enum ScanProgress {
+    SnapshotResolved(BalanceSnapshot),
     AddressFound(BalanceScanRecord),
     Completed(BalanceScanResult),
 }
 
-/// Scan addresses with real-time progress updates and cancellation support
+/// Scan addresses with real-time progress updates and cancellation support.
+/// Progress is checkpointed to disk so an interrupted scan can be resumed with
+/// [`resume_consecutive_empty_streaming`].
 pub async fn scan_consecutive_empty_streaming(
     config: Config,
     empty_target: u32,
     start_index: u32,
+    snapshot_target: SnapshotTarget,
     progress_sender: mpsc::UnboundedSender<ScanProgress>,
-    mut cancel_receiver: oneshot::Receiver<()>,
+    cancel_receiver: oneshot::Receiver<()>,
     use_native_ledger: bool,
 ) -> Result<()> {
     let provider = config.get_provider().await?;
-    let mut consecutive_empty = 0;
-    let mut index = start_index;
-    let mut last_scanned_index = start_index.saturating_sub(1);
-    let mut empty_sequence: Vec<(u32, Address)> = Vec::new();
-    let mut records: Vec<BalanceScanRecord> = Vec::new();
+    // Resolve the snapshot before touching the Ledger so archive errors surface immediately
+    let snapshot = resolve_snapshot(&provider, snapshot_target).await?;
+    let checkpoint = ScanCheckpoint::new(&config, empty_target, start_index, snapshot);
+    run_consecutive_empty_scan(&provider, &config, checkpoint, progress_sender, cancel_receiver, use_native_ledger).await
+}
+
+/// Continue an interrupted scan from its checkpoint. Records already scanned are
+/// not re-sent; the caller shows them from the checkpoint.
+pub async fn resume_consecutive_empty_streaming(
+    config: Config,
+    checkpoint: ScanCheckpoint,
+    progress_sender: mpsc::UnboundedSender<ScanProgress>,
+    cancel_receiver: oneshot::Receiver<()>,
+    use_native_ledger: bool,
+) -> Result<()> {
+    if !checkpoint.matches_config(&config) {
+        return Err(anyhow!("Checkpoint was made on a different network or derivation settings"));
+    }
+    let provider = config.get_provider().await?;
+    run_consecutive_empty_scan(&provider, &config, checkpoint, progress_sender, cancel_receiver, use_native_ledger).await
+}
+
+async fn run_consecutive_empty_scan(
+    provider: &Provider<Http>,
+    config: &Config,
+    mut checkpoint: ScanCheckpoint,
+    progress_sender: mpsc::UnboundedSender<ScanProgress>,
+    mut cancel_receiver: oneshot::Receiver<()>,
+    use_native_ledger: bool,
+) -> Result<()> {
+    let block = checkpoint.snapshot.map(|s| s.block_id());
+    if let Some(snapshot) = checkpoint.snapshot {
+        let _ = progress_sender.send(ScanProgress::SnapshotResolved(snapshot));
+    }
+    let mut unsaved = 0;
     let mut cancelled = false;
 
     loop {
@@ -145,32 +466,30 @@ pub async fn scan_consecutive_empty_streaming(
         }
 
         // Check if we've met the target
-        if consecutive_empty >= empty_target {
+        if checkpoint.met_target() {
             break;
         }
 
-        match ledger_dispatch::get_ledger_address_with_retry_config(use_native_ledger, config.chain_id, index, Some(&config)).await {
+        let index = checkpoint.next_index;
+        match ledger_dispatch::get_ledger_address_with_retry_config(use_native_ledger, config.chain_id, index, Some(config)).await {
             Ok(addr) => {
-                let balance = provider.get_balance(addr, None).await?;
-                let record = BalanceScanRecord {
-                    index,
-                    address: addr,
-                    balance,
-                    derivation_path: config.get_derivation_path(index),
+                let record = match fetch_scan_record(provider, config, index, addr, block).await {
+                    Ok(record) => record,
+                    Err(e) => {
+                        // Keep what was scanned so far before giving up
+                        save_checkpoint_logged(&checkpoint);
+                        return Err(e);
+                    }
                 };
-                
+
                 // Send progress update
                 let _ = progress_sender.send(ScanProgress::AddressFound(record.clone()));
-                
-                records.push(record);
-                last_scanned_index = index;
 
-                if balance.is_zero() {
-                    consecutive_empty += 1;
-                    empty_sequence.push((index, addr));
-                } else {
-                    consecutive_empty = 0;
-                    empty_sequence.clear();
+                checkpoint.push_record(record);
+                unsaved += 1;
+                if unsaved >= scan_checkpoint::CHECKPOINT_INTERVAL {
+                    save_checkpoint_logged(&checkpoint);
+                    unsaved = 0;
                 }
             }
             Err(_) => {
@@ -178,22 +497,27 @@ pub async fn scan_consecutive_empty_streaming(
                 break;
             }
         }
-        index += 1;
+    }
+
+    if checkpoint.met_target() {
+        if let Err(e) = scan_checkpoint::clear_checkpoint(config.chain_id) {
+            tracing::warn!("Failed to clear scan checkpoint: {}", e);
+        }
+    } else {
+        save_checkpoint_logged(&checkpoint);
     }
 
     // Send final result
-    let result = BalanceScanResult {
-        records,
-        empty_addresses: empty_sequence,
-        last_scanned_index,
-        met_target: consecutive_empty >= empty_target,
-        cancelled,
-    };
-    
-    let _ = progress_sender.send(ScanProgress::Completed(result));
+    let _ = progress_sender.send(ScanProgress::Completed(checkpoint.to_result(cancelled)));
     Ok(())
 }
 
+fn save_checkpoint_logged(checkpoint: &ScanCheckpoint) {
+    if let Err(e) = scan_checkpoint::save_checkpoint(checkpoint) {
+        tracing::warn!("Failed to save scan checkpoint: {}", e);
+    }
+}
+
 /// Progress update for funded address scan
 #[derive(Debug, Clone)]
 pub enum FundedScanProgress {
@@ -201,13 +525,16 @@ pub enum FundedScanProgress {
     Completed(FundedAddressScan),
 }
 
+/// Scan for funded addresses, reading every balance at `snapshot_target`
 pub async fn scan_for_funded_addresses(
     config: &Config,
     start_index: u32,
     empty_streak_target: u32,
+    snapshot_target: SnapshotTarget,
     use_native_ledger: bool,
 ) -> Result<FundedAddressScan> {
     let provider = config.get_provider().await?;
+    let block = resolve_snapshot(&provider, snapshot_target).await?.map(|s| s.block_id());
     let mut funded = Vec::new();
     let mut empty = Vec::new();
     let mut consecutive_empty = 0;
@@ -217,19 +544,17 @@ pub async fn scan_for_funded_addresses(
     while index < scan_limit && consecutive_empty < empty_streak_target {
         match ledger_dispatch::get_ledger_address_with_retry_config(use_native_ledger, config.chain_id, index, Some(config)).await {
             Ok(addr) => {
-                let balance = provider.get_balance(addr, None).await?;
-                let record = BalanceScanRecord {
-                    index,
-                    address: addr,
-                    balance,
-                    derivation_path: config.get_derivation_path(index),
-                };
+                let record = fetch_scan_record(&provider, config, index, addr, block).await?;
 
-                if balance.is_zero() {
+                if record.activity.is_fresh() {
                     consecutive_empty += 1;
-                    empty.push(record);
                 } else {
                     consecutive_empty = 0;
+                }
+
+                if record.balance.is_zero() {
+                    empty.push(record);
+                } else {
                     funded.push(record);
                 }
             }
@@ -241,16 +566,19 @@ pub async fn scan_for_funded_addresses(
     Ok(FundedAddressScan { funded, empty })
 }
 
-/// Streaming version of funded address scan with real-time progress updates and cancellation support
+/// Streaming version of funded address scan with real-time progress updates and cancellation support.
+/// Every balance is read at `snapshot_target`, so a snapshot scan never mixes in latest state.
 pub async fn scan_for_funded_addresses_streaming(
     config: Config,
     start_index: u32,
     empty_streak_target: u32,
+    snapshot_target: SnapshotTarget,
     progress_sender: mpsc::UnboundedSender<FundedScanProgress>,
     mut cancel_receiver: oneshot::Receiver<()>,
     use_native_ledger: bool,
 ) -> Result<()> {
     let provider = config.get_provider().await?;
+    let block = resolve_snapshot(&provider, snapshot_target).await?.map(|s| s.block_id());
     let mut funded = Vec::new();
     let mut empty = Vec::new();
     let mut consecutive_empty = 0;
@@ -265,22 +593,20 @@ pub async fn scan_for_funded_addresses_streaming(
 
         match ledger_dispatch::get_ledger_address_with_retry_config(use_native_ledger, config.chain_id, index, Some(&config)).await {
             Ok(addr) => {
-                let balance = provider.get_balance(addr, None).await?;
-                let record = BalanceScanRecord {
-                    index,
-                    address: addr,
-                    balance,
-                    derivation_path: config.get_derivation_path(index),
-                };
+                let record = fetch_scan_record(&provider, &config, index, addr, block).await?;
 
                 // Send progress update for each address found
                 let _ = progress_sender.send(FundedScanProgress::AddressFound(record.clone()));
 
-                if balance.is_zero() {
+                if record.activity.is_fresh() {
                     consecutive_empty += 1;
-                    empty.push(record);
                 } else {
                     consecutive_empty = 0;
+                }
+
+                if record.balance.is_zero() {
+                    empty.push(record);
+                } else {
                     funded.push(record);
                 }
             }
@@ -297,3 +623,123 @@ pub async fn scan_for_funded_addresses_streaming(
     Ok(())
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== AddressActivity tests ====================
+
+    #[test]
+    fn test_classify_fresh() {
+        assert_eq!(AddressActivity::classify(U256::zero(), 0, false), AddressActivity::Fresh);
+    }
+
+    #[test]
+    fn test_classify_used_empty() {
+        assert_eq!(AddressActivity::classify(U256::zero(), 3, false), AddressActivity::UsedEmpty);
+    }
+
+    #[test]
+    fn test_classify_funded() {
+        assert_eq!(AddressActivity::classify(U256::from(1u64), 0, false), AddressActivity::Funded);
+        assert_eq!(AddressActivity::classify(U256::from(1u64), 5, false), AddressActivity::Funded);
+    }
+
+    #[test]
+    fn test_classify_code_takes_precedence() {
+        assert_eq!(AddressActivity::classify(U256::zero(), 0, true), AddressActivity::HasCode);
+        assert_eq!(AddressActivity::classify(U256::from(1u64), 1, true), AddressActivity::HasCode);
+    }
+
+    #[test]
+    fn test_only_fresh_counts_as_empty() {
+        assert!(AddressActivity::Fresh.is_fresh());
+        assert!(!AddressActivity::UsedEmpty.is_fresh());
+        assert!(!AddressActivity::Funded.is_fresh());
+        assert!(!AddressActivity::HasCode.is_fresh());
+    }
+
+    #[test]
+    fn test_label_roundtrip() {
+        for activity in [
+            AddressActivity::Fresh,
+            AddressActivity::UsedEmpty,
+            AddressActivity::Funded,
+            AddressActivity::HasCode,
+        ] {
+            assert_eq!(AddressActivity::from_label(activity.label()), Some(activity));
+        }
+    }
+
+    #[test]
+    fn test_from_label_legacy_empty() {
+        assert_eq!(AddressActivity::from_label("Empty"), Some(AddressActivity::Fresh));
+        assert_eq!(AddressActivity::from_label("bogus"), None);
+    }
+
+    // ==================== SnapshotTarget tests ====================
+
+    #[test]
+    fn test_snapshot_parse_latest() {
+        assert_eq!(SnapshotTarget::parse("").unwrap(), SnapshotTarget::Latest);
+        assert_eq!(SnapshotTarget::parse("  latest ").unwrap(), SnapshotTarget::Latest);
+    }
+
+    #[test]
+    fn test_snapshot_parse_block() {
+        assert_eq!(SnapshotTarget::parse("19000000").unwrap(), SnapshotTarget::Block(19_000_000));
+        assert_eq!(SnapshotTarget::parse("#42").unwrap(), SnapshotTarget::Block(42));
+    }
+
+    #[test]
+    fn test_snapshot_parse_dates() {
+        assert_eq!(SnapshotTarget::parse("2024-01-01").unwrap(), SnapshotTarget::Timestamp(1_704_067_200));
+        assert_eq!(SnapshotTarget::parse("2024-01-01 01:00").unwrap(), SnapshotTarget::Timestamp(1_704_070_800));
+        assert_eq!(SnapshotTarget::parse("2024-01-01 00:00:30").unwrap(), SnapshotTarget::Timestamp(1_704_067_230));
+        assert_eq!(
+            SnapshotTarget::parse("2024-01-01T02:00:00+01:00").unwrap(),
+            SnapshotTarget::Timestamp(1_704_070_800)
+        );
+    }
+
+    #[test]
+    fn test_snapshot_parse_invalid() {
+        assert!(SnapshotTarget::parse("yesterday").is_err());
+        assert!(SnapshotTarget::parse("2024-13-01").is_err());
+    }
+
+    // ==================== find_block_at_timestamp tests ====================
+
+    /// Blocks every 12 seconds starting at t=1000
+    async fn mock_timestamp(number: u64) -> Result<u64> {
+        Ok(1000 + number * 12)
+    }
+
+    #[tokio::test]
+    async fn test_resolve_latest_makes_no_rpc_call() {
+        // Nothing listens on this port: any request would fail
+        let provider = Provider::<Http>::try_from("http://127.0.0.1:1").unwrap();
+        assert_eq!(resolve_snapshot(&provider, SnapshotTarget::Latest).await.unwrap(), None);
+        assert!(resolve_snapshot(&provider, SnapshotTarget::Block(1)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_find_block_exact_match() {
+        assert_eq!(find_block_at_timestamp(100, 1000 + 50 * 12, mock_timestamp).await.unwrap(), 50);
+    }
+
+    #[tokio::test]
+    async fn test_find_block_between_blocks() {
+        // Picks the last block at or before the timestamp
+        assert_eq!(find_block_at_timestamp(100, 1000 + 50 * 12 + 5, mock_timestamp).await.unwrap(), 50);
+    }
+
+    #[tokio::test]
+    async fn test_find_block_bounds() {
+        assert_eq!(find_block_at_timestamp(100, 1000, mock_timestamp).await.unwrap(), 0);
+        assert_eq!(find_block_at_timestamp(100, 1000 + 100 * 12, mock_timestamp).await.unwrap(), 100);
+        assert!(find_block_at_timestamp(100, 999, mock_timestamp).await.is_err());
+        assert!(find_block_at_timestamp(100, 1000 + 101 * 12, mock_timestamp).await.is_err());
+    }
+}
@@ -0,0 +1,300 @@
+//! Background balance watcher.
+//! Polls watched addresses across networks and reports balance changes that
+//! exceed each address's threshold. Addresses are stored resolved, so polling
+//! never needs the Ledger.
+
+use crate::user_settings::{UserSettings, WatchedAddress};
+use crate::utils;
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Last known balance per (chain_id, address)
+pub type WatchBalances = HashMap<(u64, Address), U256>;
+
+/// A network the watcher polls, with its resolved RPC endpoint
+#[derive(Debug, Clone)]
+pub struct WatchNetwork {
+    pub chain_id: u64,
+    pub label: String,
+    pub native_token: String,
+    pub rpc_url: String,
+}
+
+impl WatchNetwork {
+    /// Resolve a chain ID against built-in and custom networks
+    pub fn resolve(chain_id: u64, settings: &UserSettings) -> Option<Self> {
+        let rpc_url = settings.rpc_url_for(chain_id)?;
+        if let Some(network) = crate::config::find_network_by_chain_id(chain_id) {
+            return Some(Self {
+                chain_id,
+                label: network.label.to_string(),
+                native_token: network.native_token.to_string(),
+                rpc_url,
+            });
+        }
+        settings.get_custom_network(chain_id).map(|network| Self {
+            chain_id,
+            label: network.label.clone(),
+            native_token: network.native_token.clone(),
+            rpc_url,
+        })
+    }
+}
+
+/// A single address/network pair to poll
+#[derive(Debug, Clone)]
+pub struct WatchTarget {
+    pub label: String,
+    pub address: Address,
+    pub network: WatchNetwork,
+    pub threshold: U256,
+}
+
+/// Build the poll targets for every watched address and network.
+/// Invalid addresses, thresholds or unknown networks are reported instead of polled.
+pub fn build_targets(settings: &UserSettings) -> (Vec<WatchTarget>, Vec<String>) {
+    let mut targets = Vec::new();
+    let mut errors = Vec::new();
+
+    for watched in &settings.watched_addresses {
+        let address = match watched.address.parse::<Address>() {
+            Ok(address) => address,
+            Err(_) => {
+                errors.push(format!("{}: invalid address {}", watched.label, watched.address));
+                continue;
+            }
+        };
+        let threshold = match parse_threshold(watched) {
+            Ok(threshold) => threshold,
+            Err(e) => {
+                errors.push(format!("{}: {}", watched.label, e));
+                continue;
+            }
+        };
+
+        for chain_id in &watched.chain_ids {
+            match WatchNetwork::resolve(*chain_id, settings) {
+                Some(network) => targets.push(WatchTarget {
+                    label: watched.label.clone(),
+                    address,
+                    network,
+                    threshold,
+                }),
+                None => errors.push(format!("{}: unknown network (chain ID {})", watched.label, chain_id)),
+            }
+        }
+    }
+
+    (targets, errors)
+}
+
+/// Parse a watched address's threshold (native units); empty means any change
+pub fn parse_threshold(watched: &WatchedAddress) -> Result<U256> {
+    let threshold = watched.threshold.trim();
+    if threshold.is_empty() {
+        return Ok(U256::zero());
+    }
+    utils::parse_eth_str_to_wei(threshold).map_err(|_| anyhow!("invalid threshold '{}'", threshold))
+}
+
+/// A balance change that crossed its threshold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceChange {
+    pub label: String,
+    pub address: Address,
+    pub chain_id: u64,
+    pub network_label: String,
+    pub native_token: String,
+    pub old_balance: U256,
+    pub new_balance: U256,
+}
+
+impl BalanceChange {
+    pub fn describe(&self) -> String {
+        let (sign, change) = if self.new_balance >= self.old_balance {
+            ("+", self.new_balance - self.old_balance)
+        } else {
+            ("-", self.old_balance - self.new_balance)
+        };
+        format!(
+            "{} on {}: {}{} {} (now {} {})",
+            self.label,
+            self.network_label,
+            sign,
+            utils::format_ether(change),
+            self.native_token,
+            utils::format_ether(self.new_balance),
+            self.native_token
+        )
+    }
+}
+
+/// Whether a balance change is large enough to report (a zero threshold reports any change)
+pub fn exceeds_threshold(old: U256, new: U256, threshold: U256) -> bool {
+    if old == new {
+        return false;
+    }
+    let change = if new > old { new - old } else { old - new };
+    change >= threshold
+}
+
+/// Result of one polling round
+#[derive(Debug, Default)]
+pub struct WatchPollResult {
+    /// Balances changes are measured against (merged into the caller's state)
+    pub baselines: WatchBalances,
+    /// Balances fetched this round
+    pub latest: WatchBalances,
+    /// Changes that crossed their threshold, compared to the previous round
+    pub changes: Vec<BalanceChange>,
+    /// Per-target RPC errors; a failing network does not stop the others
+    pub errors: Vec<String>,
+}
+
+/// Poll every target once. Targets without a previous balance only establish a baseline.
+pub async fn poll_once(targets: Vec<WatchTarget>, baselines: WatchBalances) -> WatchPollResult {
+    let mut result = WatchPollResult::default();
+    let mut providers: HashMap<u64, Arc<Provider<Http>>> = HashMap::new();
+
+    for target in targets {
+        let chain_id = target.network.chain_id;
+        let provider = match providers.get(&chain_id) {
+            Some(provider) => provider.clone(),
+            None => match Provider::<Http>::try_from(target.network.rpc_url.as_str()) {
+                Ok(provider) => {
+                    let provider = Arc::new(provider);
+                    providers.insert(chain_id, provider.clone());
+                    provider
+                }
+                Err(e) => {
+                    result.errors.push(format!("{}: invalid RPC URL ({})", target.network.label, e));
+                    continue;
+                }
+            },
+        };
+
+        let balance = match provider.get_balance(target.address, None).await {
+            Ok(balance) => balance,
+            Err(e) => {
+                result.errors.push(format!("{} on {}: {}", target.label, target.network.label, e));
+                continue;
+            }
+        };
+
+        let key = (chain_id, target.address);
+        result.latest.insert(key, balance);
+
+        // The baseline only moves when a change is reported, so small changes
+        // accumulate until they cross the threshold
+        let baseline = match baselines.get(&key) {
+            Some(old_balance) if exceeds_threshold(*old_balance, balance, target.threshold) => {
+                result.changes.push(BalanceChange {
+                    label: target.label.clone(),
+                    address: target.address,
+                    chain_id,
+                    network_label: target.network.label.clone(),
+                    native_token: target.network.native_token.clone(),
+                    old_balance: *old_balance,
+                    new_balance: balance,
+                });
+                balance
+            }
+            Some(old_balance) => *old_balance,
+            None => balance,
+        };
+        result.baselines.insert(key, baseline);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn watched(threshold: &str, chain_ids: Vec<u64>) -> WatchedAddress {
+        WatchedAddress {
+            label: "Test".to_string(),
+            address: "0x0000000000000000000000000000000000000001".to_string(),
+            chain_ids,
+            threshold: threshold.to_string(),
+            derivation_index: None,
+        }
+    }
+
+    // ==================== exceeds_threshold tests ====================
+
+    #[test]
+    fn test_no_change_never_reported() {
+        assert!(!exceeds_threshold(U256::from(5u64), U256::from(5u64), U256::zero()));
+    }
+
+    #[test]
+    fn test_zero_threshold_reports_any_change() {
+        assert!(exceeds_threshold(U256::from(5u64), U256::from(6u64), U256::zero()));
+        assert!(exceeds_threshold(U256::from(6u64), U256::from(5u64), U256::zero()));
+    }
+
+    #[test]
+    fn test_threshold_applies_both_directions() {
+        let threshold = U256::from(10u64);
+        assert!(!exceeds_threshold(U256::from(100u64), U256::from(109u64), threshold));
+        assert!(exceeds_threshold(U256::from(100u64), U256::from(110u64), threshold));
+        assert!(exceeds_threshold(U256::from(100u64), U256::from(80u64), threshold));
+    }
+
+    // ==================== parse_threshold tests ====================
+
+    #[test]
+    fn test_parse_threshold() {
+        assert_eq!(parse_threshold(&watched("", vec![1])).unwrap(), U256::zero());
+        assert_eq!(
+            parse_threshold(&watched("0.5", vec![1])).unwrap(),
+            U256::from(500_000_000_000_000_000u64)
+        );
+        assert!(parse_threshold(&watched("abc", vec![1])).is_err());
+    }
+
+    // ==================== build_targets tests ====================
+
+    #[test]
+    fn test_build_targets_per_network() {
+        let mut settings = UserSettings::default();
+        settings.watched_addresses.push(watched("", vec![1, 8453, 424242]));
+        let (targets, errors) = build_targets(&settings);
+
+        assert_eq!(targets.len(), 2);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("424242"));
+    }
+
+    #[test]
+    fn test_build_targets_invalid_address() {
+        let mut settings = UserSettings::default();
+        let mut bad = watched("", vec![1]);
+        bad.address = "0xnope".to_string();
+        settings.watched_addresses.push(bad);
+        let (targets, errors) = build_targets(&settings);
+
+        assert!(targets.is_empty());
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn test_balance_change_describe() {
+        let change = BalanceChange {
+            label: "Vault".to_string(),
+            address: Address::zero(),
+            chain_id: 1,
+            network_label: "Ethereum".to_string(),
+            native_token: "ETH".to_string(),
+            old_balance: U256::from(2_000_000_000_000_000_000u64),
+            new_balance: U256::from(1_500_000_000_000_000_000u64),
+        };
+        let text = change.describe();
+        assert!(text.starts_with("Vault on Ethereum: -0.5"));
+        assert!(text.contains("now 1.5"));
+    }
+}
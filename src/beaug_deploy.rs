@@ -0,0 +1,359 @@
+//! Deterministic deployment of the Beaug contract to a new chain.
+//! The contract is created through the deterministic CREATE2 deployer
+//! (`0x4e59b44847b379578588920cA78FbF26c0B4956C`, calldata = salt ++ creation bytecode),
+//! so it lands at `MAIN_BEAUG_ADDRESS` on every chain. The creation bytecode and salt are
+//! checked against that address before anything is signed, and the deployed runtime code
+//! hash is compared with the deployment on a reference network afterwards.
+//!
+//! The creation bytecode does not ship with the app (the contract sources and release
+//! artifacts are not part of this repository); it is loaded from a release artifact, and
+//! anything that would not land at `MAIN_BEAUG_ADDRESS` is refused.
+
+use crate::bulk_disperse;
+use crate::config::Config;
+use crate::disperse;
+use crate::fee_oracle::{self, FeeQuote};
+use crate::ledger_dispatch;
+use crate::operation_log;
+use crate::simulation;
+use crate::utils;
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use tracing::{info, warn};
+
+/// The deterministic deployment proxy, present at this address on most EVM chains
+pub const DETERMINISTIC_DEPLOYER_ADDRESS: &str = "0x4e59b44847b379578588920cA78FbF26c0B4956C";
+
+/// Chain whose Beaug deployment is the reference for the runtime code hash (Ethereum)
+pub const REFERENCE_CHAIN_ID: u64 = 1;
+
+/// Gas limit used when `eth_estimateGas` fails for the deployment
+const DEPLOY_FALLBACK_GAS_LIMIT: u64 = 3_000_000;
+
+/// Parsed [`DETERMINISTIC_DEPLOYER_ADDRESS`]
+pub fn deployer_address() -> Address {
+    DETERMINISTIC_DEPLOYER_ADDRESS
+        .parse()
+        .expect("DETERMINISTIC_DEPLOYER_ADDRESS constant is invalid - this is a programming error")
+}
+
+/// Creation bytecode from raw hex (with or without `0x`, whitespace ignored) or from a
+/// Foundry / Hardhat JSON artifact (`bytecode` as a string or `{ "object": "0x..." }`)
+pub fn parse_creation_bytecode(input: &str) -> Result<Bytes> {
+    let input = input.trim();
+    let hex_str = if input.starts_with('{') {
+        let artifact: serde_json::Value =
+            serde_json::from_str(input).map_err(|e| anyhow!("Invalid JSON artifact: {}", e))?;
+        match &artifact["bytecode"] {
+            serde_json::Value::String(hex) => hex.clone(),
+            serde_json::Value::Object(obj) => obj
+                .get("object")
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("Artifact bytecode has no \"object\" field"))?,
+            _ => return Err(anyhow!("Artifact has no \"bytecode\" field")),
+        }
+    } else {
+        input.to_string()
+    };
+
+    let cleaned: String = hex_str.chars().filter(|c| !c.is_whitespace()).collect();
+    let cleaned = cleaned.strip_prefix("0x").unwrap_or(&cleaned);
+    if cleaned.contains("__") {
+        return Err(anyhow!("Bytecode has unlinked library placeholders"));
+    }
+    let bytes = hex::decode(cleaned).map_err(|e| anyhow!("Invalid bytecode hex: {}", e))?;
+    if bytes.is_empty() {
+        return Err(anyhow!("Bytecode is empty"));
+    }
+    Ok(Bytes::from(bytes))
+}
+
+/// Parse a 32-byte CREATE2 salt (`0x` + 64 hex digits); blank means zero
+pub fn parse_salt(input: &str) -> Result<H256> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(H256::zero());
+    }
+    input
+        .parse()
+        .map_err(|_| anyhow!("Salt must be 0x followed by 64 hex digits"))
+}
+
+/// Address the deterministic deployer creates for `salt` and `init_code`
+pub fn predicted_address(salt: H256, init_code: &[u8]) -> Address {
+    ethers::utils::get_create2_address(deployer_address(), salt.as_bytes(), init_code)
+}
+
+/// Deployer calldata: the salt followed by the creation bytecode
+pub fn deploy_calldata(salt: H256, init_code: &[u8]) -> Vec<u8> {
+    let mut calldata = salt.as_bytes().to_vec();
+    calldata.extend_from_slice(init_code);
+    calldata
+}
+
+/// Fail unless `salt` and `init_code` land at `MAIN_BEAUG_ADDRESS`
+pub fn check_predicted_address(salt: H256, init_code: &[u8]) -> Result<Address> {
+    let predicted = predicted_address(salt, init_code);
+    let expected = disperse::main_beaug_address();
+    if predicted != expected {
+        return Err(anyhow!(
+            "This bytecode and salt deploy to {:?}, not the Beaug address {:?}. Use the exact Beaug release artifact and salt.",
+            predicted,
+            expected
+        ));
+    }
+    Ok(predicted)
+}
+
+/// Whether Beaug and the deployer have code on the provider's chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeployTargetStatus {
+    pub beaug_deployed: bool,
+    pub deployer_present: bool,
+}
+
+pub async fn fetch_deploy_target_status(provider: &Provider<Http>) -> Result<DeployTargetStatus> {
+    Ok(DeployTargetStatus {
+        beaug_deployed: !provider.get_code(disperse::main_beaug_address(), None).await?.is_empty(),
+        deployer_present: !provider.get_code(deployer_address(), None).await?.is_empty(),
+    })
+}
+
+/// keccak256 of the runtime code at `address`, `None` if it has no code
+pub async fn runtime_code_hash(provider: &Provider<Http>, address: Address) -> Result<Option<H256>> {
+    let code = provider.get_code(address, None).await?;
+    Ok((!code.is_empty()).then(|| H256::from(ethers::utils::keccak256(code.as_ref()))))
+}
+
+/// A prepared deployment, shown for review before it is signed
+#[derive(Debug, Clone)]
+pub struct DeployReview {
+    pub chain_id: u64,
+    pub init_code: Bytes,
+    pub salt: H256,
+    pub target: Address,
+    pub signer_index: u32,
+    pub signer_address: Address,
+    pub signer_path: String,
+    pub gas_limit: u64,
+    pub fees: FeeQuote,
+}
+
+impl DeployReview {
+    pub fn max_fee(&self) -> U256 {
+        self.fees.max_cost(self.gas_limit)
+    }
+
+    pub fn expected_fee(&self) -> U256 {
+        self.fees.expected_cost(self.gas_limit)
+    }
+}
+
+/// Check the bytecode, the deployer and the target, simulate and price the deployment.
+/// Nothing is signed.
+pub async fn prepare_deployment(
+    config: Config,
+    init_code: Bytes,
+    salt: H256,
+    source_idx: usize,
+    gas_speed: f32,
+    use_native_ledger: bool,
+) -> Result<DeployReview> {
+    let target = check_predicted_address(salt, &init_code)?;
+    let provider = config.get_provider().await?;
+
+    let status = fetch_deploy_target_status(&provider).await?;
+    if status.beaug_deployed {
+        return Err(anyhow!("Beaug is already deployed at {:?} on this network", target));
+    }
+    if !status.deployer_present {
+        return Err(anyhow!(
+            "The deterministic deployer {} is not on this network; it has to be deployed first with its presigned transaction",
+            DETERMINISTIC_DEPLOYER_ADDRESS
+        ));
+    }
+
+    let source = bulk_disperse::load_source_account(&config, &provider, Some(source_idx), use_native_ledger).await?;
+    let calldata = deploy_calldata(salt, &init_code);
+    if let Some(reason) = simulation::simulate_call(&provider, source.address, deployer_address(), calldata.clone(), U256::zero()).await? {
+        return Err(anyhow!("Simulation failed: {}", reason));
+    }
+
+    let tx: TypedTransaction = TransactionRequest::new()
+        .from(source.address)
+        .to(deployer_address())
+        .data(calldata)
+        .into();
+    let gas_limit = match provider.estimate_gas(&tx, None).await {
+        Ok(estimate) => disperse::apply_gas_margin(estimate, disperse::DEFAULT_GAS_MARGIN_PERCENT),
+        Err(e) => {
+            warn!("eth_estimateGas failed for the deployment, using {}: {}", DEPLOY_FALLBACK_GAS_LIMIT, e);
+            DEPLOY_FALLBACK_GAS_LIMIT
+        }
+    };
+    let fees = fee_oracle::quote_fees(&provider, config.chain_id, gas_speed).await?;
+    if source.balance < fees.max_cost(gas_limit) {
+        return Err(anyhow!(
+            "Balance too low for the deployment gas. Index {} has {} but needs ~{}.",
+            source.index,
+            utils::format_ether(source.balance),
+            utils::format_ether(fees.max_cost(gas_limit))
+        ));
+    }
+
+    Ok(DeployReview {
+        chain_id: config.chain_id,
+        init_code,
+        salt,
+        target,
+        signer_index: source.index,
+        signer_address: source.address,
+        signer_path: source.derivation_path,
+        gas_limit,
+        fees,
+    })
+}
+
+/// Result of a deployment and its code hash check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeployOutcome {
+    pub tx_hash: TxHash,
+    pub code_hash: H256,
+    /// Runtime code hash on the reference network, if it could be read
+    pub reference_code_hash: Option<H256>,
+}
+
+impl DeployOutcome {
+    /// `None` when the reference hash is unavailable
+    pub fn code_hash_matches(&self) -> Option<bool> {
+        self.reference_code_hash.map(|reference| reference == self.code_hash)
+    }
+}
+
+/// Sign and send a reviewed deployment, then verify the runtime code hash against the
+/// Beaug deployment read from `reference_rpc_url`
+pub async fn execute_deployment(
+    config: Config,
+    review: DeployReview,
+    reference_rpc_url: Option<String>,
+    use_native_ledger: bool,
+) -> Result<DeployOutcome> {
+    let provider = config.get_provider().await?;
+    if config.chain_id != review.chain_id {
+        return Err(anyhow!("The network changed since the deployment was reviewed"));
+    }
+    let nonce = provider.get_transaction_count(review.signer_address, None).await?.as_u64();
+
+    info!("Deploying Beaug to {:?} on chain {}", review.target, review.chain_id);
+    let tx_hash = ledger_dispatch::sign_and_send_contract_call(
+        use_native_ledger,
+        provider.clone(),
+        &config.rpc_url,
+        review.signer_index,
+        deployer_address(),
+        deploy_calldata(review.salt, &review.init_code),
+        U256::zero(),
+        review.gas_limit,
+        review.fees.fees,
+        nonce,
+        config.chain_id,
+        config.derivation_mode,
+        config.custom_account,
+        config.custom_address_index,
+        config.coin_type,
+    )
+    .await?;
+
+    let (block_number, gas_used) = bulk_disperse::wait_for_receipt(&provider, tx_hash).await?;
+    let code_hash = runtime_code_hash(&provider, review.target)
+        .await?
+        .ok_or_else(|| anyhow!("Deployment {:?} was mined but {:?} has no code", tx_hash, review.target))?;
+
+    let reference_code_hash = match reference_rpc_url {
+        Some(url) => match Provider::<Http>::try_from(url.as_str()) {
+            Ok(reference) => runtime_code_hash(&reference, review.target).await.unwrap_or_else(|e| {
+                warn!("Could not read the reference Beaug code: {}", e);
+                None
+            }),
+            Err(e) => {
+                warn!("Invalid reference RPC URL: {}", e);
+                None
+            }
+        },
+        None => None,
+    };
+    let outcome = DeployOutcome { tx_hash, code_hash, reference_code_hash };
+
+    let verification = match outcome.code_hash_matches() {
+        Some(true) => "matches the reference deployment".to_string(),
+        Some(false) => format!("does NOT match the reference deployment ({:?})", reference_code_hash.unwrap_or_default()),
+        None => "reference deployment unavailable".to_string(),
+    };
+    operation_log::append_log(
+        "Beaug Deploy",
+        config.chain_id,
+        format!(
+            "Deployed Beaug via CREATE2\nContract: {:?}\nDeployer: {}\nSalt: {:?}\nSigner: {} → {:?}\nRuntime code hash: {:?} ({})\nTx hash: {:?}\nBlock: {:?}\nGas used: {:?}",
+            review.target,
+            DETERMINISTIC_DEPLOYER_ADDRESS,
+            review.salt,
+            review.signer_path,
+            review.signer_address,
+            code_hash,
+            verification,
+            tx_hash,
+            block_number,
+            gas_used
+        ),
+    )?;
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Bytecode parsing tests ====================
+
+    #[test]
+    fn test_parse_creation_bytecode_formats() {
+        let expected = Bytes::from(vec![0x60, 0x80, 0x60, 0x40]);
+        assert_eq!(parse_creation_bytecode("0x60806040").unwrap(), expected);
+        assert_eq!(parse_creation_bytecode(" 6080\n6040 ").unwrap(), expected);
+        assert_eq!(parse_creation_bytecode(r#"{"bytecode": "0x60806040"}"#).unwrap(), expected);
+        assert_eq!(parse_creation_bytecode(r#"{"bytecode": {"object": "0x60806040"}}"#).unwrap(), expected);
+        assert!(parse_creation_bytecode("0x").is_err());
+        assert!(parse_creation_bytecode("0x6080__$lib$__").is_err());
+    }
+
+    #[test]
+    fn test_parse_salt() {
+        assert_eq!(parse_salt("").unwrap(), H256::zero());
+        assert_eq!(parse_salt(&format!("0x{}", "00".repeat(31) + "01")).unwrap(), H256::from_low_u64_be(1));
+        assert!(parse_salt("0x1234").is_err());
+    }
+
+    // ==================== CREATE2 tests ====================
+
+    #[test]
+    fn test_predicted_address_and_calldata() {
+        // keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12..]
+        let salt = H256::from_low_u64_be(1);
+        let init_code = [0x00u8];
+        let mut preimage = vec![0xff];
+        preimage.extend_from_slice(deployer_address().as_bytes());
+        preimage.extend_from_slice(salt.as_bytes());
+        preimage.extend_from_slice(&ethers::utils::keccak256(init_code));
+        let expected = Address::from_slice(&ethers::utils::keccak256(&preimage)[12..]);
+        assert_eq!(predicted_address(salt, &init_code), expected);
+        assert!(check_predicted_address(salt, &init_code).is_err());
+
+        let calldata = deploy_calldata(H256::from_low_u64_be(7), &[0xaa, 0xbb]);
+        assert_eq!(calldata.len(), 34);
+        assert_eq!(calldata[31], 7);
+        assert_eq!(&calldata[32..], &[0xaa, 0xbb]);
+    }
+}
@@ -2,41 +2,20 @@
 //! Uses the Beaug contract to distribute funds to multiple recipients in a single transaction.
 
 use crate::config::Config;
+use crate::dispersal_proof;
 use crate::disperse;
+use crate::disperse_protocol;
+use crate::erc20;
+use crate::fee_oracle;
 use crate::ledger_dispatch;
+use crate::payment_uri::PaymentAsset;
+use crate::recipient_input::{self, RecipientAmount};
 use crate::types::AccountInfo;
-use crate::{operation_log, utils};
+use crate::{operation_log, simulation, utils};
 use ethers::prelude::*;
-use ethers::abi::{Function, Token, Param, ParamType, StateMutability};
 use anyhow::{anyhow, Result};
 use tracing::info;
 
-/// Beaug contract function signature: beaugDisperse(address[] recipients, uint256[] amounts)
-/// This is the single unified disperse function - GUI calculates amounts for equal distribution
-#[allow(deprecated)]
-fn get_beaug_disperse_function() -> Function {
-    // function beaugDisperse(address[] calldata recipients, uint256[] calldata amounts) external payable
-    Function {
-        name: "beaugDisperse".to_string(),
-        inputs: vec![
-            Param {
-                name: "recipients".to_string(),
-                kind: ParamType::Array(Box::new(ParamType::Address)),
-                internal_type: None,
-            },
-            Param {
-                name: "amounts".to_string(),
-                kind: ParamType::Array(Box::new(ParamType::Uint(256))),
-                internal_type: None,
-            },
-        ],
-        outputs: vec![],
-        constant: None,
-        state_mutability: StateMutability::Payable,
-    }
-}
-
-
 /// Types of bulk disperse operations
 #[derive(Debug, Clone)]
 pub enum BulkDisperseType {
@@ -44,110 +23,225 @@ pub enum BulkDisperseType {
     Equal(Vec<Address>),
     /// Mixed distribution - addresses with specific amounts
     Mixed(Vec<(Address, U256)>),
+    /// Weighted distribution - percentages and weights (possibly with some fixed amounts)
+    /// resolved against the total amount
+    Weighted(Vec<(Address, RecipientAmount)>),
+}
+
+impl BulkDisperseType {
+    pub fn len(&self) -> usize {
+        match self {
+            BulkDisperseType::Equal(addresses) => addresses.len(),
+            BulkDisperseType::Mixed(recipients) => recipients.len(),
+            BulkDisperseType::Weighted(recipients) => recipients.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn addresses(&self) -> Vec<Address> {
+        match self {
+            BulkDisperseType::Equal(addresses) => addresses.clone(),
+            BulkDisperseType::Mixed(recipients) => recipients.iter().map(|(address, _)| *address).collect(),
+            BulkDisperseType::Weighted(recipients) => recipients.iter().map(|(address, _)| *address).collect(),
+        }
+    }
+
+    /// Distribution name used in previews and the operation log
+    pub fn label(&self) -> &'static str {
+        match self {
+            BulkDisperseType::Equal(_) => "Equal",
+            BulkDisperseType::Mixed(_) => "Mixed",
+            BulkDisperseType::Weighted(_) => "Weighted",
+        }
+    }
+
+    /// Amounts for a total of `amount_to_send`: an even split (rounded down), the specified
+    /// amounts, or the resolved shares (summing to exactly `amount_to_send`)
+    pub fn resolve(&self, amount_to_send: U256) -> Result<Vec<(Address, U256)>> {
+        match self {
+            BulkDisperseType::Equal(addresses) => {
+                if addresses.is_empty() {
+                    return Err(anyhow!("No recipients specified"));
+                }
+                let amount_per_recipient = amount_to_send / U256::from(addresses.len());
+                if amount_per_recipient.is_zero() {
+                    return Err(anyhow!("Amount per recipient would be zero"));
+                }
+                Ok(addresses.iter().map(|address| (*address, amount_per_recipient)).collect())
+            }
+            BulkDisperseType::Mixed(recipients) => Ok(recipients.clone()),
+            BulkDisperseType::Weighted(recipients) => recipient_input::resolve_amounts(recipients, amount_to_send),
+        }
+    }
 }
 
 /// Parse a string input that can be either:
 /// - Just addresses (for equal distribution)
-/// - Addresses with amounts (for mixed distribution)
-pub fn parse_bulk_disperse_input(input: &str) -> Result<BulkDisperseType> {
-    let lines: Vec<&str> = input.lines()
-        .filter(|line| !line.trim().is_empty())
-        .collect();
+/// - Addresses with amounts in the native currency (for mixed distribution), optionally
+///   with a `wei`, `gwei` or `ether` suffix
+/// - Addresses with percentages (`25%`) or weights (`w=3`) of the total (weighted distribution)
+///
+/// Addresses may also be ENS names, EIP-3770 `short:address` forms or EIP-681 payment
+/// requests; those naming another chain than `chain_id` are rejected.
+pub fn parse_bulk_disperse_input(input: &str, chain_id: u64) -> Result<BulkDisperseType> {
+    parse_bulk_disperse_input_with(input, chain_id, PaymentAsset::Native, recipient_input::parse_native_amount)
+}
+
+/// Parse recipient input for a token dispersal; amounts use the token's decimals
+pub fn parse_bulk_disperse_input_for_token(input: &str, chain_id: u64, token: &erc20::TokenInfo) -> Result<BulkDisperseType> {
+    parse_bulk_disperse_input_with(input, chain_id, PaymentAsset::Token(token.address), |amount| token.parse_amount(amount))
+}
 
-    if lines.is_empty() {
+fn parse_bulk_disperse_input_with<F>(input: &str, chain_id: u64, asset: PaymentAsset, parse_amount: F) -> Result<BulkDisperseType>
+where
+    F: Fn(&str) -> Result<U256>,
+{
+    let lines = recipient_input::parse_recipient_lines(input, chain_id, asset, parse_amount)?;
+    let Some(first) = lines.first() else {
         return Ok(BulkDisperseType::Equal(vec![]));
+    };
+
+    // The first line determines the format; every line must match it
+    if first.amount.is_none() {
+        if let Some(line) = lines.iter().find(|l| l.amount.is_some()) {
+            return Err(anyhow!(
+                "Line {}: Expected only address for equal distribution, got an amount",
+                line.line
+            ));
+        }
+        return Ok(BulkDisperseType::Equal(lines.into_iter().map(|l| l.address).collect()));
     }
 
-    // Check the first line to determine the format
-    let first_line = lines[0];
-    let parts: Vec<&str> = if first_line.contains(',') {
-        first_line.split(',').collect()
+    let mut recipients = Vec::with_capacity(lines.len());
+    for line in lines {
+        let amount = line.amount.ok_or_else(|| {
+            anyhow!("Line {}: Invalid format. Expected `address,amount` or `address amount`", line.line)
+        })?;
+        recipients.push((line.address, amount));
+    }
+    let fixed: Option<Vec<(Address, U256)>> = recipients
+        .iter()
+        .map(|(address, amount)| match amount {
+            RecipientAmount::Fixed(value) => Some((*address, *value)),
+            _ => None,
+        })
+        .collect();
+    Ok(match fixed {
+        Some(fixed) => BulkDisperseType::Mixed(fixed),
+        None => BulkDisperseType::Weighted(recipients),
+    })
+}
+
+/// Resolve the disperse contract: an explicit override, else the Beaug contract for the chain
+pub(crate) fn resolve_disperse_address(chain_id: u64, disperse_address_override: Option<String>) -> Result<Address> {
+    if let Some(addr_str) = disperse_address_override {
+        addr_str
+            .parse()
+            .map_err(|_| anyhow!("Invalid disperse contract address"))
     } else {
-        first_line.split_whitespace().collect()
-    };
+        disperse::get_disperse_address(chain_id)
+            .ok_or_else(|| anyhow!(
+                "No known Disperse contract for chain {}. Please deploy one or specify --disperse-contract",
+                chain_id
+            ))
+    }
+}
 
-    match parts.len() {
-        1 => {
-            // Address only format - parse all lines as addresses
-            let mut addresses = Vec::new();
-            for (line_num, line) in lines.iter().enumerate() {
-                let trimmed = line.trim();
-                let addr_parts: Vec<&str> = if trimmed.contains(',') {
-                    trimmed.split(',').collect()
-                } else {
-                    trimmed.split_whitespace().collect()
-                };
-
-                if addr_parts.len() != 1 {
-                    return Err(anyhow!(
-                        "Line {}: Expected only address for equal distribution, got {} parts",
-                        line_num + 1, addr_parts.len()
-                    ));
-                }
+/// Derive the source address from the Ledger and read its native balance and nonce
+pub(crate) async fn load_source_account(
+    config: &Config,
+    provider: &Provider<Http>,
+    source_idx_override: Option<usize>,
+    use_native_ledger: bool,
+) -> Result<AccountInfo> {
+    let Some(source_address_index) = source_idx_override else {
+        return Err(anyhow!("Source address must be specified"));
+    };
+    info!(
+        "Using specified source address index: {}",
+        source_address_index
+    );
 
-                let address: Address = addr_parts[0].trim().parse().map_err(|_| {
-                    anyhow!("Line {}: Invalid address format: {}", line_num + 1, addr_parts[0])
-                })?;
-                addresses.push(address);
-            }
-            Ok(BulkDisperseType::Equal(addresses))
-        }
-        2 => {
-            // Address and amount format - parse all lines as address,amount pairs
-            let mut recipients = Vec::new();
-            for (line_num, line) in lines.iter().enumerate() {
-                let parts: Vec<&str> = if line.contains(',') {
-                    line.split(',').collect()
-                } else {
-                    line.split_whitespace().collect()
-                };
-
-                if parts.len() != 2 {
-                    return Err(anyhow!(
-                        "Line {}: Invalid format. Expected `address,amount_in_eth` or `address amount_in_eth`",
-                        line_num + 1
-                    ));
-                }
+    let addr = ledger_dispatch::get_ledger_address_with_retry_config(
+        use_native_ledger,
+        config.chain_id,
+        source_address_index as u32,
+        Some(config)
+    ).await?;
+    let balance = provider.get_balance(addr, None).await?;
+    let nonce = provider.get_transaction_count(addr, None).await?.as_u64();
+
+    if balance.is_zero() {
+        return Err(anyhow!(
+            "Source address at index {} has zero balance.",
+            source_address_index
+        ));
+    }
 
-                let address: Address = parts[0].trim().parse().map_err(|_| {
-                    anyhow!("Line {}: Invalid address format: {}", line_num + 1, parts[0])
-                })?;
+    Ok(AccountInfo {
+        index: source_address_index as u32,
+        address: addr,
+        balance,
+        nonce,
+        derivation_path: config.get_derivation_path(source_address_index as u32),
+    })
+}
 
-                // Convert ETH string directly to Wei (avoids f64 precision issues)
-                let amount_wei = utils::parse_eth_str_to_wei(parts[1].trim()).map_err(|e| {
-                    anyhow!("Line {}: Invalid amount '{}': {}", line_num + 1, parts[1].trim(), e)
-                })?;
-                recipients.push((address, amount_wei));
+/// Poll for a transaction receipt, failing if the transaction reverted
+async fn wait_for_mined_receipt(provider: &Provider<Http>, tx_hash: TxHash) -> Result<TransactionReceipt> {
+    let max_attempts = 120;
+    for _ in 0..max_attempts {
+        if let Ok(Some(receipt)) = provider.get_transaction_receipt(tx_hash).await {
+            if receipt.status == Some(U64::zero()) {
+                return Err(anyhow!("Transaction {:?} reverted", tx_hash));
             }
-            Ok(BulkDisperseType::Mixed(recipients))
-        }
-        _ => {
-            Err(anyhow!(
-                "Invalid format. Expected either addresses only (one per line) or address,amount pairs"
-            ))
+            return Ok(receipt);
         }
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
     }
+    Err(anyhow!("Timeout waiting for transaction receipt"))
 }
 
-/// Execute bulk disperse using the Disperse contract (single transaction)
-/// 
-/// Tips are handled as regular recipients - if tip_amount and tip_recipient are provided,
-/// they are appended to the recipients/amounts arrays and included in the transaction.
-pub async fn bulk_disperse(
+/// Poll for a transaction receipt, returning (block number, gas used)
+pub(crate) async fn wait_for_receipt(provider: &Provider<Http>, tx_hash: TxHash) -> Result<(Option<u64>, u64)> {
+    let receipt = wait_for_mined_receipt(provider, tx_hash).await?;
+    Ok((
+        receipt.block_number.map(|n| n.as_u64()),
+        receipt.gas_used.map(|g| g.as_u64()).unwrap_or(0),
+    ))
+}
+
+/// Estimate the gas limit of a token disperse before signing, for the fee preview
+pub async fn estimate_token_disperse_gas(
     config: Config,
+    token: Address,
+    disperse_address: Address,
+    from: Address,
+    recipients: Vec<Address>,
+    amounts: Vec<U256>,
+    gas_margin_percent: u32,
+) -> Result<disperse::DisperseGasEstimate> {
+    let provider = config.get_provider().await?;
+    let protocol = disperse_protocol::resolve_token_disperse(&provider, disperse_address).await?;
+    let calldata = protocol.token_calldata(token, &recipients, &amounts)?;
+    Ok(disperse::estimate_disperse_gas(&provider, disperse_address, from, calldata, U256::zero(), recipients.len(), gas_margin_percent).await)
+}
+
+/// Expand a disperse request into (recipients, amounts, is_equal_distribution).
+/// For Beaug, we always use beaugDisperse(recipients, amounts):
+/// equal distribution divides `amount_to_send` by the recipient count, weighted distribution
+/// resolves its shares against `amount_to_send`, mixed uses the specified amounts, and a tip
+/// is appended as a regular recipient. `is_equal_distribution` is true unless the amounts
+/// were specified (mixed).
+pub(crate) fn disperse_recipients(
     disperse_type: BulkDisperseType,
     amount_to_send: U256,
-    disperse_address_override: Option<String>,
-    source_idx_override: Option<usize>,
     tip_amount: Option<U256>,
     tip_recipient: Option<Address>,
-    gas_speed: f32,
-    use_native_ledger: bool,
-) -> Result<()> {
-    let provider = config.get_provider().await?;
-    let chain_id = config.chain_id;
-    let operation_name = "Beaug Bulk Disperse";
-    
+) -> Result<(Vec<Address>, Vec<U256>, bool)> {
     // Tips are now handled as regular recipients (added to arrays below)
     // Validate and extract tip info early to avoid multiple unwraps later
     let tip = tip_amount.unwrap_or(U256::zero());
@@ -161,133 +255,163 @@ pub async fn bulk_disperse(
         None
     };
 
-    // Resolve disperse contract address
-    let disperse_address: Address = if let Some(addr_str) = disperse_address_override {
-        addr_str
-            .parse()
-            .map_err(|_| anyhow!("Invalid disperse contract address"))?
-    } else {
-        disperse::get_disperse_address(chain_id)
-            .ok_or_else(|| anyhow!(
-                "No known Disperse contract for chain {}. Please deploy one or specify --disperse-contract",
-                chain_id
-            ))?
-    };
-
-    info!("Using Disperse contract at {:?}", disperse_address);
-
-    // Get source address
-    let source = if let Some(source_address_index) = source_idx_override {
-        info!(
-            "Using specified source address index: {}",
-            source_address_index
-        );
-
-        let addr = ledger_dispatch::get_ledger_address_with_retry_config(
-            use_native_ledger,
-            chain_id, 
-            source_address_index as u32, 
-            Some(&config)
-        ).await?;
-        let balance = provider.get_balance(addr, None).await?;
-        let nonce = provider.get_transaction_count(addr, None).await?.as_u64();
-
-        if balance.is_zero() {
-            return Err(anyhow!(
-                "Source address at index {} has zero balance.",
-                source_address_index
-            ));
-        }
-
-        AccountInfo {
-            index: source_address_index as u32,
-            address: addr,
-            balance,
-            nonce,
-            derivation_path: config.get_derivation_path(source_address_index as u32),
-        }
-    } else {
-        return Err(anyhow!("Source address must be specified"));
-    };
-
     // Extract addresses and calculate amounts
     // For Beaug, we always use beaugDisperse(recipients, amounts)
-    // For equal distribution: GUI calculates equal amounts from amount_to_send / recipient_count
+    // For equal and weighted distribution: amounts are resolved from amount_to_send
     // For mixed distribution: use the specified amounts
     // Tips are appended as a regular recipient
-    let (mut recipient_addresses, mut amounts, is_equal_distribution) = match disperse_type {
-        BulkDisperseType::Equal(addresses) => {
-            if addresses.is_empty() {
-                return Err(anyhow!("No recipients specified"));
-            }
-            // Calculate equal amounts: amount_to_send / recipient_count
-            let amount_per_recipient = amount_to_send / U256::from(addresses.len());
-            if amount_per_recipient.is_zero() {
-                return Err(anyhow!("Amount per recipient would be zero"));
-            }
-            let calculated_amounts: Vec<U256> = vec![amount_per_recipient; addresses.len()];
-            info!(
-                "Equal distribution: {} ETH each to {} recipients",
-                utils::format_ether(amount_per_recipient),
-                addresses.len()
-            );
-            (addresses, calculated_amounts, true)
-        }
-        BulkDisperseType::Mixed(recipients) => {
-            if recipients.is_empty() {
-                return Err(anyhow!("No recipients specified"));
-            }
-            let (addresses, amounts): (Vec<Address>, Vec<U256>) = recipients.into_iter().unzip();
-            (addresses, amounts, false)
-        }
-    };
-    
+    if disperse_type.is_empty() {
+        return Err(anyhow!("No recipients specified"));
+    }
+    let is_equal_distribution = !matches!(disperse_type, BulkDisperseType::Mixed(_));
+    let (mut recipient_addresses, mut amounts): (Vec<Address>, Vec<U256>) =
+        disperse_type.resolve(amount_to_send)?.into_iter().unzip();
+    info!(
+        "{} distribution of {} ETH to {} recipients",
+        disperse_type.label(),
+        utils::format_ether(amounts.iter().fold(U256::zero(), |acc, x| acc + *x)),
+        recipient_addresses.len()
+    );
+
     // Append tip as a regular recipient if specified
     if let Some(tip_addr) = verified_tip_recipient {
         recipient_addresses.push(tip_addr);
         amounts.push(tip);
     }
     
+    Ok((recipient_addresses, amounts, is_equal_distribution))
+}
+
+/// Simulate a token disperse with `eth_call` before signing, for the GUI pre-flight check
+pub async fn simulate_token_disperse(
+    config: Config,
+    token: Address,
+    disperse_address: Address,
+    from: Address,
+    recipients: Vec<Address>,
+    amounts: Vec<U256>,
+) -> Result<Option<simulation::RevertReason>> {
+    let provider = config.get_provider().await?;
+    let protocol = disperse_protocol::resolve_token_disperse(&provider, disperse_address).await?;
+    let calldata = protocol.token_calldata(token, &recipients, &amounts)?;
+    simulation::simulate_call(&provider, from, disperse_address, calldata, U256::zero()).await
+}
+
+/// How a disperse transaction is priced and signed: the fee speed multiplier, the previewed
+/// gas limit if there is one, the safety margin added when the limit is estimated instead,
+/// and whether the source signs through the native Ledger path.
+#[derive(Debug, Clone, Copy)]
+pub struct DisperseSendOptions {
+    pub gas_speed: f32,
+    pub gas_limit_override: Option<u64>,
+    pub gas_margin_percent: u32,
+    /// Fees already planned for this transaction; signed as is instead of a fresh quote at `gas_speed`
+    pub fees_override: Option<fee_oracle::TxFees>,
+    pub use_native_ledger: bool,
+}
+
+impl DisperseSendOptions {
+    /// Resolve the fees: the planned ones, or a quote from the fee history at `gas_speed`
+    async fn resolve_fees(&self, provider: &Provider<Http>, chain_id: u64) -> Result<fee_oracle::TxFees> {
+        match self.fees_override {
+            Some(fees) => Ok(fees),
+            None => Ok(fee_oracle::quote_fees(provider, chain_id, self.gas_speed).await?.fees),
+        }
+    }
+
+    /// Resolve the gas limit: the previewed one, or `eth_estimateGas` plus the margin
+    async fn resolve_gas_limit(
+        &self,
+        provider: &Provider<Http>,
+        disperse_address: Address,
+        from: Address,
+        calldata: Vec<u8>,
+        value: U256,
+        recipient_count: usize,
+    ) -> u64 {
+        if let Some(gas_limit) = self.gas_limit_override {
+            return gas_limit;
+        }
+        let estimate = disperse::estimate_disperse_gas(
+            provider,
+            disperse_address,
+            from,
+            calldata,
+            value,
+            recipient_count,
+            self.gas_margin_percent,
+        )
+        .await;
+        info!("Gas limit: {}", estimate.describe());
+        estimate.gas_limit
+    }
+}
+
+/// Execute bulk disperse using the Disperse contract (single transaction)
+/// 
+/// Tips are handled as regular recipients - if tip_amount and tip_recipient are provided,
+/// they are appended to the recipients/amounts arrays and included in the transaction.
+///
+/// Returns the hash of the mined transaction.
+pub async fn bulk_disperse(
+    config: Config,
+    disperse_type: BulkDisperseType,
+    amount_to_send: U256,
+    disperse_address_override: Option<String>,
+    source_idx_override: Option<usize>,
+    tip_amount: Option<U256>,
+    tip_recipient: Option<Address>,
+    options: DisperseSendOptions,
+) -> Result<TxHash> {
+    let provider = config.get_provider().await?;
+    let chain_id = config.chain_id;
+    let operation_name = "Beaug Bulk Disperse";
+    
+    // Resolve disperse contract address
+    let (disperse_address, protocol) = disperse_protocol::resolve_native_disperse(
+        &provider,
+        resolve_disperse_address(chain_id, disperse_address_override)?,
+    )
+    .await?;
+    info!("Using {} Disperse contract at {:?}", protocol, disperse_address);
+
+    // Get source address
+    let source = load_source_account(&config, &provider, source_idx_override, options.use_native_ledger).await?;
+
+    // Extract addresses and amounts; tips are appended as a regular recipient
+    let tip = tip_amount.unwrap_or(U256::zero());
+    let distribution_type = disperse_type.label();
+    let (recipient_addresses, amounts, is_equal_distribution) =
+        disperse_recipients(disperse_type, amount_to_send, tip_amount, tip_recipient)?;
+    let verified_tip_recipient = if tip.is_zero() { None } else { tip_recipient };
+
     // Calculate total being distributed (includes tip)
     let total_to_distribute: U256 = amounts.iter().fold(U256::zero(), |acc, x| acc + *x);
     
     // Total value to send equals exactly the sum of amounts (contract requires exact match)
     let total_value_to_send = total_to_distribute;
 
-    // Get gas price and apply speed multiplier
-    let base_gas_price = provider.get_gas_price().await?;
-    let gas_price = base_gas_price * U256::from((gas_speed * 100.0) as u64) / U256::from(100u64);
-    info!(
-        "Base Gas Price: {} Gwei, With {:.1}x multiplier: {} Gwei",
-        ethers::utils::format_units(base_gas_price, "gwei")?,
-        gas_speed,
-        ethers::utils::format_units(gas_price, "gwei")?
-    );
+    // Price the call from the fee history at the chosen speed, unless the fees were planned
+    let fees = options.resolve_fees(&provider, chain_id).await?;
 
-    // Calculate gas limit based on number of recipients
-    // With scoring system, each first-time recipient requires:
-    // - Transfer: ~30k gas
-    // - hasReceivedFunds write: ~20k gas (SSTORE)
-    // - scoredAddresses.push: ~40k gas (array expansion + SSTORE)
-    // - scores write: ~20k gas (SSTORE)
-    // - Event emission: ~3k gas
-    // Base overhead: ~100k for function call, calldata, etc.
-    // Safe formula: 150k base + 120k per recipient (accounts for first-time scoring)
-    // Plus 10% safety buffer for network variability
-    let base_gas_limit = 150_000u64 + 120_000u64 * recipient_addresses.len() as u64;
-    let gas_limit = (base_gas_limit as f64 * 1.10) as u64; // 10% safety buffer
-    let max_gas_limit = 15_000_000u64; // Safety cap (block gas limit)
-    let gas_limit = gas_limit.min(max_gas_limit);
-    
-    // Calculate estimated gas cost
-    let estimated_gas_cost = gas_price * U256::from(gas_limit);
+    // Encode beaugDisperse, disperseEther or aggregate3Value for (recipients, amounts)
+    // Beaug and Multicall3 require exact msg.value == sum(amounts)
+    let calldata = protocol.native_calldata(&recipient_addresses, &amounts)?;
+
+    // Use the previewed gas limit, or estimate against the real calldata, value and sender
+    let gas_limit = options
+        .resolve_gas_limit(&provider, disperse_address, source.address, calldata.clone(), total_value_to_send, recipient_addresses.len())
+        .await;
+
+    // The balance has to cover the max fee, even though the expected fee is lower
+    let estimated_gas_cost = fees.max_fee_per_gas * U256::from(gas_limit);
     let total_needed = total_value_to_send + estimated_gas_cost;
 
     // Check that source has enough balance for total + gas
     if source.balance < total_needed {
         if !tip.is_zero() {
             return Err(anyhow!(
-                "Balance too low. Source has {} ETH but needs {} ETH (amount: {} + tip: {} + gas: ~{}).",
+                "Balance too low. Source has {} ETH but needs {} ETH (amount: {} + tip: {} + max gas fee: {}).",
                 utils::format_ether(source.balance),
                 utils::format_ether(total_needed),
                 utils::format_ether(amount_to_send),
@@ -296,7 +420,7 @@ pub async fn bulk_disperse(
             ));
         } else {
             return Err(anyhow!(
-                "Balance too low. Source has {} ETH but needs {} ETH (amount: {} + gas: ~{}).",
+                "Balance too low. Source has {} ETH but needs {} ETH (amount: {} + max gas fee: {}).",
                 utils::format_ether(source.balance),
                 utils::format_ether(total_needed),
                 utils::format_ether(amount_to_send),
@@ -323,24 +447,22 @@ pub async fn bulk_disperse(
         info!("Tip: {} ETH to {:?}", utils::format_ether(tip), tip_addr);
     }
 
-    // Encode function call - always use beaugDisperse(recipients, amounts)
-    // Contract requires exact msg.value == sum(amounts)
-    let func = get_beaug_disperse_function();
-    let recipient_tokens: Vec<Token> = recipient_addresses.iter().map(|a| Token::Address(*a)).collect();
-    let amount_tokens: Vec<Token> = amounts.iter().map(|a| Token::Uint(*a)).collect();
-    let calldata = func.encode_input(&[Token::Array(recipient_tokens), Token::Array(amount_tokens)])?;
+    // Simulate the exact call so a revert is caught before the Ledger prompt
+    if let Some(reason) = simulation::simulate_call(&provider, source.address, disperse_address, calldata.clone(), total_value_to_send).await? {
+        return Err(anyhow!("Simulation failed, transaction not sent: {}", reason));
+    }
 
     // Sign and send via the selected Ledger backend (include tip in the value sent)
     let tx_hash = ledger_dispatch::sign_and_send_contract_call(
-        use_native_ledger,
+        options.use_native_ledger,
         provider.clone(),
         &config.rpc_url,
         source.index,
         disperse_address,
-        calldata.to_vec(),
+        calldata,
         total_value_to_send,
         gas_limit,
-        gas_price,
+        fees,
         source.nonce,
         chain_id,
         config.derivation_mode,
@@ -351,25 +473,26 @@ pub async fn bulk_disperse(
     .await?;
 
     // Wait for receipt
-    let mut attempts = 0;
-    let max_attempts = 120;
-    let (block_number, gas_used) = loop {
-        if let Ok(Some(receipt)) = provider.get_transaction_receipt(tx_hash).await {
-            break (
-                receipt.block_number.map(|n| n.as_u64()),
-                receipt.gas_used.map(|g| g.as_u64()).unwrap_or(0),
-            );
-        }
-        attempts += 1;
-        if attempts >= max_attempts {
-            return Err(anyhow!("Timeout waiting for transaction receipt"));
-        }
-        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
-    };
+    let receipt = wait_for_mined_receipt(&provider, tx_hash).await?;
+    let block_number = receipt.block_number.map(|n| n.as_u64());
+    let gas_used = receipt.gas_used.map(|g| g.as_u64()).unwrap_or(0);
 
     info!("Bulk Disperse Complete! Tx: {:?}, Block: {:?}, Gas: {:?}", 
         tx_hash, block_number, gas_used);
 
+    // Proof of dispersal: dispersalId and recipientsHash from the BeaugDispersed event
+    let proof_info = match dispersal_proof::dispersals_in_receipt(&receipt).first() {
+        Some(record) => {
+            let check = if record.recipients_hash == dispersal_proof::recipients_hash(&recipient_addresses) {
+                "matches the recipient list"
+            } else {
+                "does NOT match the recipient list"
+            };
+            format!("\n{} ({})", record.log_lines(), check)
+        }
+        None => String::new(),
+    };
+
     // Always show amounts (they're calculated for equal distribution)
     let distribution_lines = recipient_addresses
         .iter()
@@ -380,8 +503,7 @@ pub async fn bulk_disperse(
         })
         .collect::<Vec<_>>()
         .join("\n");
-    
-    let distribution_type = if is_equal_distribution { "Equal" } else { "Mixed" };
+
 
     let tip_info = if let Some(tip_addr) = verified_tip_recipient {
         format!("\nTip: {} ETH to {:?}", utils::format_ether(tip), tip_addr)
@@ -393,7 +515,8 @@ pub async fn bulk_disperse(
         operation_name,
         chain_id,
         format!(
-            "Beaug disperse executed ({})\nSource: {} → {:?}\nBeaug contract: {:?}\nRecipients: {}\n{}\nTotal distributed: {} ETH{}\nTx hash: {:?}\nBlock: {:?}\nGas used: {:?}",
+            "{} disperse executed ({})\nSource: {} → {:?}\nContract: {:?}\nRecipients: {}\n{}\nTotal distributed: {} ETH{}\nTx hash: {:?}\nBlock: {:?}\nGas used: {:?}{}",
+            protocol,
             distribution_type,
             source.derivation_path,
             source.address,
@@ -404,6 +527,221 @@ pub async fn bulk_disperse(
             tip_info,
             tx_hash,
             block_number,
+            gas_used,
+            proof_info
+        ),
+    )?;
+
+    Ok(tx_hash)
+}
+
+
+/// Approve the disperse contract to pull `amount` of a token from the source address.
+/// Waits for the approval to be mined so the disperse can follow immediately.
+pub async fn approve_token_for_disperse(
+    config: Config,
+    token: erc20::TokenInfo,
+    amount: U256,
+    disperse_address_override: Option<String>,
+    source_idx_override: Option<usize>,
+    gas_speed: f32,
+    use_native_ledger: bool,
+) -> Result<TxHash> {
+    let provider = config.get_provider().await?;
+    let chain_id = config.chain_id;
+    let disperse_address = resolve_disperse_address(chain_id, disperse_address_override)?;
+    // No allowance for a contract that could never spend it
+    disperse_protocol::resolve_token_disperse(&provider, disperse_address).await?;
+    let source = load_source_account(&config, &provider, source_idx_override, use_native_ledger).await?;
+
+    let fee_quote = fee_oracle::quote_fees(&provider, chain_id, gas_speed).await?;
+    let gas_limit = erc20::APPROVE_GAS_LIMIT;
+    let gas_cost = fee_quote.max_cost(gas_limit);
+    if source.balance < gas_cost {
+        return Err(anyhow!(
+            "Balance too low for the approval gas. Source has {} but needs ~{}.",
+            utils::format_ether(source.balance),
+            utils::format_ether(gas_cost)
+        ));
+    }
+
+    info!(
+        "Approving {:?} to spend {} {} from {:?}",
+        disperse_address,
+        token.format_amount(amount),
+        token.symbol,
+        source.address
+    );
+
+    let tx_hash = ledger_dispatch::sign_and_send_contract_call(
+        use_native_ledger,
+        provider.clone(),
+        &config.rpc_url,
+        source.index,
+        token.address,
+        erc20::approve_calldata(disperse_address, amount),
+        U256::zero(),
+        gas_limit,
+        fee_quote.fees,
+        source.nonce,
+        chain_id,
+        config.derivation_mode,
+        config.custom_account,
+        config.custom_address_index,
+        config.coin_type,
+    )
+    .await?;
+
+    let (block_number, gas_used) = wait_for_receipt(&provider, tx_hash).await?;
+
+    operation_log::append_log(
+        "Beaug Token Approve",
+        chain_id,
+        format!(
+            "Token approval for disperse\nToken: {} ({:?})\nOwner: {} → {:?}\nSpender: {:?}\nAmount: {} {}\nTx hash: {:?}\nBlock: {:?}\nGas used: {:?}",
+            token.symbol,
+            token.address,
+            source.derivation_path,
+            source.address,
+            disperse_address,
+            token.format_amount(amount),
+            token.symbol,
+            tx_hash,
+            block_number,
+            gas_used
+        ),
+    )?;
+
+    Ok(tx_hash)
+}
+
+/// Execute an ERC-20 bulk disperse via `disperseToken(token, recipients, values)`.
+///
+/// The contract pulls the total from the source with `transferFrom`, so the allowance
+/// must already cover it (see [`approve_token_for_disperse`]). Gas is paid in the native currency.
+/// `disperse_address` is the contract the allowance was granted to (see [`resolve_disperse_address`]).
+pub async fn bulk_disperse_token(
+    config: Config,
+    token: erc20::TokenInfo,
+    disperse_type: BulkDisperseType,
+    amount_to_send: U256,
+    disperse_address: Address,
+    source_idx_override: Option<usize>,
+    options: DisperseSendOptions,
+) -> Result<()> {
+    let provider = config.get_provider().await?;
+    let chain_id = config.chain_id;
+    let protocol = disperse_protocol::resolve_token_disperse(&provider, disperse_address).await?;
+    let source = load_source_account(&config, &provider, source_idx_override, options.use_native_ledger).await?;
+
+    let distribution_type = disperse_type.label();
+    if disperse_type.is_empty() {
+        return Err(anyhow!("No recipients specified"));
+    }
+    let (recipient_addresses, amounts): (Vec<Address>, Vec<U256>) = disperse_type.resolve(amount_to_send)?.into_iter().unzip();
+    let total_to_distribute: U256 = amounts.iter().fold(U256::zero(), |acc, x| acc + *x);
+
+    // The token balance and allowance must both cover the total
+    let (token_balance, allowance) = tokio::try_join!(
+        erc20::balance_of(&provider, token.address, source.address),
+        erc20::allowance(&provider, token.address, source.address, disperse_address),
+    )?;
+    if token_balance < total_to_distribute {
+        return Err(anyhow!(
+            "Token balance too low. Source has {} {} but needs {} {}.",
+            token.format_amount(token_balance),
+            token.symbol,
+            token.format_amount(total_to_distribute),
+            token.symbol
+        ));
+    }
+    if allowance < total_to_distribute {
+        return Err(anyhow!(
+            "Allowance too low. The disperse contract may spend {} {} but needs {} {}. Approve it first.",
+            token.format_amount(allowance),
+            token.symbol,
+            token.format_amount(total_to_distribute),
+            token.symbol
+        ));
+    }
+
+    let calldata = protocol.token_calldata(token.address, &recipient_addresses, &amounts)?;
+
+    let fees = options.resolve_fees(&provider, chain_id).await?;
+    let gas_limit = options
+        .resolve_gas_limit(&provider, disperse_address, source.address, calldata.clone(), U256::zero(), recipient_addresses.len())
+        .await;
+    let estimated_gas_cost = fees.max_fee_per_gas * U256::from(gas_limit);
+    if source.balance < estimated_gas_cost {
+        return Err(anyhow!(
+            "Balance too low for gas. Source has {} but needs ~{} in the native currency.",
+            utils::format_ether(source.balance),
+            utils::format_ether(estimated_gas_cost)
+        ));
+    }
+
+    info!(
+        "Token Disperse: {} {} to {} recipients via {} {:?}",
+        token.format_amount(total_to_distribute),
+        token.symbol,
+        recipient_addresses.len(),
+        protocol,
+        disperse_address
+    );
+
+    // Simulate the exact call so a revert is caught before the Ledger prompt
+    if let Some(reason) = simulation::simulate_call(&provider, source.address, disperse_address, calldata.clone(), U256::zero()).await? {
+        return Err(anyhow!("Simulation failed, transaction not sent: {}", reason));
+    }
+
+    let tx_hash = ledger_dispatch::sign_and_send_contract_call(
+        options.use_native_ledger,
+        provider.clone(),
+        &config.rpc_url,
+        source.index,
+        disperse_address,
+        calldata,
+        U256::zero(),
+        gas_limit,
+        fees,
+        source.nonce,
+        chain_id,
+        config.derivation_mode,
+        config.custom_account,
+        config.custom_address_index,
+        config.coin_type,
+    )
+    .await?;
+
+    let (block_number, gas_used) = wait_for_receipt(&provider, tx_hash).await?;
+    info!("Token Disperse Complete! Tx: {:?}, Block: {:?}, Gas: {:?}", tx_hash, block_number, gas_used);
+
+    let distribution_lines = recipient_addresses
+        .iter()
+        .zip(amounts.iter())
+        .enumerate()
+        .map(|(i, (address, amount))| {
+            format!("{}. {:?} → {} {}", i + 1, address, token.format_amount(*amount), token.symbol)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    operation_log::append_log(
+        "Beaug Token Disperse",
+        chain_id,
+        format!(
+            "Token disperse executed ({})\nToken: {} ({:?})\nSource: {} → {:?}\nDisperse contract: {:?}\nRecipients: {}\n{}\nTotal distributed: {} {}\nTx hash: {:?}\nBlock: {:?}\nGas used: {:?}",
+            distribution_type,
+            token.symbol,
+            token.address,
+            source.derivation_path,
+            source.address,
+            disperse_address,
+            recipient_addresses.len(),
+            distribution_lines,
+            token.format_amount(total_to_distribute),
+            token.symbol,
+            tx_hash,
+            block_number,
             gas_used
         ),
     )?;
@@ -411,3 +749,51 @@ pub async fn bulk_disperse(
     Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usdc() -> erc20::TokenInfo {
+        erc20::TokenInfo {
+            address: Address::zero(),
+            symbol: "USDC".to_string(),
+            decimals: 6,
+        }
+    }
+
+    // ==================== parse_bulk_disperse_input tests ====================
+
+    #[test]
+    fn test_parse_equal_distribution() {
+        let input = "0x0000000000000000000000000000000000000001\n\n0x0000000000000000000000000000000000000002\n";
+        match parse_bulk_disperse_input(input, 1).unwrap() {
+            BulkDisperseType::Equal(addresses) => assert_eq!(addresses.len(), 2),
+            other => panic!("expected equal distribution, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_mixed_native_amounts() {
+        let input = "0x0000000000000000000000000000000000000001,0.5\n0x0000000000000000000000000000000000000002 1";
+        match parse_bulk_disperse_input(input, 1).unwrap() {
+            BulkDisperseType::Mixed(recipients) => {
+                assert_eq!(recipients[0].1, U256::exp10(17) * 5);
+                assert_eq!(recipients[1].1, U256::exp10(18));
+            }
+            other => panic!("expected mixed distribution, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_mixed_token_amounts_use_token_decimals() {
+        let input = "0x0000000000000000000000000000000000000001,2.5";
+        match parse_bulk_disperse_input_for_token(input, 1, &usdc()).unwrap() {
+            BulkDisperseType::Mixed(recipients) => assert_eq!(recipients[0].1, U256::from(2_500_000u64)),
+            other => panic!("expected mixed distribution, got {:?}", other),
+        }
+
+        let too_precise = "0x0000000000000000000000000000000000000001,0.0000001";
+        assert!(parse_bulk_disperse_input_for_token(too_precise, 1, &usdc()).is_err());
+    }
+
+}
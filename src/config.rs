@@ -111,6 +111,37 @@ pub fn get_address_explorer_url(chain_id: u64, address: &str) -> Option<String>
     get_block_explorer_url(chain_id).map(|base| format!("{}/address/{}", base, address))
 }
 
+/// EIP-3770 short names (from the chainid.network registry), canonical name first
+const CHAIN_SHORT_NAMES: &[(u64, &str)] = &[
+    (1, "eth"),
+    (11155111, "sep"),
+    (560048, "hoodi"),
+    (10, "oeth"),
+    (8453, "base"),
+    (137, "pol"),
+    (137, "matic"),
+    (59144, "linea"),
+    (100, "gno"),
+    (56, "bnb"),
+    (43114, "avax"),
+    (61, "etc"),
+    (369, "pls"),
+    (42220, "celo"),
+];
+
+/// EIP-3770 short name of a chain (e.g. `base`), for built-in networks
+pub fn get_chain_short_name(chain_id: u64) -> Option<&'static str> {
+    CHAIN_SHORT_NAMES.iter().find(|(id, _)| *id == chain_id).map(|(_, name)| *name)
+}
+
+/// Chain ID of an EIP-3770 short name (case-insensitive)
+pub fn find_chain_id_by_short_name(short_name: &str) -> Option<u64> {
+    CHAIN_SHORT_NAMES
+        .iter()
+        .find(|(_, name)| name.eq_ignore_ascii_case(short_name))
+        .map(|(id, _)| *id)
+}
+
 /// Default BIP-44 coin type for Ethereum (used for all EVM chains for compatibility)
 pub const DEFAULT_COIN_TYPE: u32 = 60;
 
@@ -365,6 +396,19 @@ mod tests {
         assert!(index.is_none());
     }
 
+    // ==================== EIP-3770 short name tests ====================
+
+    #[test]
+    fn test_chain_short_names() {
+        assert_eq!(get_chain_short_name(8453), Some("base"));
+        assert_eq!(get_chain_short_name(137), Some("pol"));
+        assert_eq!(find_chain_id_by_short_name("MATIC"), Some(137));
+        assert_eq!(find_chain_id_by_short_name("unknown"), None);
+        for network in NETWORKS {
+            assert!(get_chain_short_name(network.chain_id).is_some(), "no short name for {}", network.label);
+        }
+    }
+
     // ==================== is_builtin_chain_id tests ====================
 
     #[test]
@@ -0,0 +1,338 @@
+//! Owner administration of Beaug disperse contracts.
+//! Reads the owner, pending owner and stuck ETH balance of a contract, and prepares, reviews
+//! and signs the two-step ownership transfer (`transferOwnership` / `acceptOwnership`) and
+//! `withdrawStuckFunds` transactions on the Ledger.
+
+use crate::bulk_disperse;
+use crate::config::Config;
+use crate::disperse;
+use crate::fee_oracle::{self, FeeQuote};
+use crate::ledger_dispatch;
+use crate::operation_log;
+use crate::registry;
+use crate::simulation;
+use crate::utils;
+use anyhow::{anyhow, Result};
+use ethers::abi::{Abi, Token};
+use ethers::prelude::*;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use std::sync::OnceLock;
+use tracing::info;
+
+/// Ownership and recovery functions of the Beaug contract
+const ADMIN_ABI: &[&str] = &[
+    "function pendingOwner() view returns (address)",
+    "function transferOwnership(address newOwner)",
+    "function acceptOwnership()",
+    "function withdrawStuckFunds()",
+];
+
+/// Gas limit used when `eth_estimateGas` fails for an admin call
+const ADMIN_FALLBACK_GAS_LIMIT: u64 = 100_000;
+
+static ADMIN: OnceLock<Abi> = OnceLock::new();
+
+fn admin_abi() -> &'static Abi {
+    ADMIN.get_or_init(|| ethers::abi::parse_abi(ADMIN_ABI).expect("ADMIN_ABI is invalid - this is a programming error"))
+}
+
+fn encode_call(name: &str, args: &[Token]) -> Result<Vec<u8>> {
+    Ok(admin_abi().function(name)?.encode_input(args)?)
+}
+
+/// Pending owner of a two-step transfer, `None` if no transfer is in progress
+pub async fn fetch_pending_owner(provider: &Provider<Http>, contract: Address) -> Result<Option<Address>> {
+    let tx: TypedTransaction = TransactionRequest::new().to(contract).data(encode_call("pendingOwner", &[])?).into();
+    let output = provider.call(&tx, None).await?;
+    let pending = admin_abi()
+        .function("pendingOwner")?
+        .decode_output(&output)?
+        .into_iter()
+        .next()
+        .and_then(|t| t.into_address())
+        .ok_or_else(|| anyhow!("Unexpected pendingOwner() output from {:?}", contract))?;
+    Ok((!pending.is_zero()).then_some(pending))
+}
+
+/// Ownership state and recoverable balance of a contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminStatus {
+    pub contract: Address,
+    pub owner: Address,
+    pub pending_owner: Option<Address>,
+    /// ETH held by the contract (sent to it directly by mistake)
+    pub stuck_balance: U256,
+}
+
+impl AdminStatus {
+    /// Role of `address` on this contract
+    pub fn role_of(&self, address: Address) -> AdminRole {
+        if address == self.owner {
+            AdminRole::Owner
+        } else if Some(address) == self.pending_owner {
+            AdminRole::PendingOwner
+        } else {
+            AdminRole::None
+        }
+    }
+}
+
+/// What an address may do on the contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdminRole {
+    Owner,
+    PendingOwner,
+    None,
+}
+
+/// Read the owner, pending owner and ETH balance of `contract`
+pub async fn fetch_admin_status(provider: &Provider<Http>, contract: Address) -> Result<AdminStatus> {
+    if provider.get_code(contract, None).await?.is_empty() {
+        return Err(anyhow!("{:?} is not a contract on this network", contract));
+    }
+    Ok(AdminStatus {
+        contract,
+        owner: registry::fetch_owner(provider, contract).await?,
+        pending_owner: fetch_pending_owner(provider, contract).await?,
+        stuck_balance: provider.get_balance(contract, None).await?,
+    })
+}
+
+/// An owner/admin transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdminAction {
+    /// Step one of a transfer: nominate a new owner
+    TransferOwnership(Address),
+    /// Step two of a transfer: the pending owner accepts
+    AcceptOwnership,
+    /// Send the contract's ETH balance to the owner
+    WithdrawStuckFunds,
+}
+
+impl AdminAction {
+    pub fn calldata(&self) -> Result<Vec<u8>> {
+        match self {
+            AdminAction::TransferOwnership(new_owner) => encode_call("transferOwnership", &[Token::Address(*new_owner)]),
+            AdminAction::AcceptOwnership => encode_call("acceptOwnership", &[]),
+            AdminAction::WithdrawStuckFunds => encode_call("withdrawStuckFunds", &[]),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            AdminAction::TransferOwnership(new_owner) => format!("transferOwnership({:?})", new_owner),
+            AdminAction::AcceptOwnership => "acceptOwnership()".to_string(),
+            AdminAction::WithdrawStuckFunds => "withdrawStuckFunds()".to_string(),
+        }
+    }
+
+    /// Role the signer must have
+    pub fn required_role(&self) -> AdminRole {
+        match self {
+            AdminAction::AcceptOwnership => AdminRole::PendingOwner,
+            _ => AdminRole::Owner,
+        }
+    }
+
+    /// Check the action makes sense for the contract's current state
+    pub fn check(&self, status: &AdminStatus) -> Result<()> {
+        match self {
+            AdminAction::TransferOwnership(new_owner) if new_owner.is_zero() => {
+                Err(anyhow!("The new owner cannot be the zero address"))
+            }
+            AdminAction::TransferOwnership(new_owner) if *new_owner == status.owner => {
+                Err(anyhow!("{:?} is already the owner", new_owner))
+            }
+            AdminAction::AcceptOwnership if status.pending_owner.is_none() => {
+                Err(anyhow!("No ownership transfer is pending"))
+            }
+            AdminAction::WithdrawStuckFunds if status.stuck_balance.is_zero() => {
+                Err(anyhow!("The contract holds no ETH to withdraw"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A prepared admin transaction, shown for review before it is signed
+#[derive(Debug, Clone)]
+pub struct AdminReview {
+    pub action: AdminAction,
+    pub status: AdminStatus,
+    pub chain_id: u64,
+    pub signer_index: u32,
+    pub signer_address: Address,
+    pub signer_path: String,
+    pub gas_limit: u64,
+    pub fees: FeeQuote,
+}
+
+impl AdminReview {
+    pub fn max_fee(&self) -> U256 {
+        self.fees.max_cost(self.gas_limit)
+    }
+
+    pub fn expected_fee(&self) -> U256 {
+        self.fees.expected_cost(self.gas_limit)
+    }
+}
+
+/// Check the signer's role, simulate the call and price it. Nothing is signed.
+pub async fn prepare_admin_action(
+    config: Config,
+    contract: Address,
+    action: AdminAction,
+    source_idx: usize,
+    gas_speed: f32,
+    use_native_ledger: bool,
+) -> Result<AdminReview> {
+    let provider = config.get_provider().await?;
+    let status = fetch_admin_status(&provider, contract).await?;
+    action.check(&status)?;
+
+    let source = bulk_disperse::load_source_account(&config, &provider, Some(source_idx), use_native_ledger).await?;
+    if status.role_of(source.address) != action.required_role() {
+        let required = match action.required_role() {
+            AdminRole::PendingOwner => status.pending_owner.unwrap_or_default(),
+            _ => status.owner,
+        };
+        return Err(anyhow!(
+            "{} must be signed by {:?}; index {} is {:?}",
+            action.describe(),
+            required,
+            source.index,
+            source.address
+        ));
+    }
+
+    let calldata = action.calldata()?;
+    if let Some(reason) = simulation::simulate_call(&provider, source.address, contract, calldata.clone(), U256::zero()).await? {
+        return Err(anyhow!("Simulation failed: {}", reason));
+    }
+
+    let tx: TypedTransaction = TransactionRequest::new().from(source.address).to(contract).data(calldata).into();
+    let gas_limit = match provider.estimate_gas(&tx, None).await {
+        Ok(estimate) => disperse::apply_gas_margin(estimate, disperse::DEFAULT_GAS_MARGIN_PERCENT),
+        Err(_) => ADMIN_FALLBACK_GAS_LIMIT,
+    };
+    let fees = fee_oracle::quote_fees(&provider, config.chain_id, gas_speed).await?;
+    if source.balance < fees.max_cost(gas_limit) {
+        return Err(anyhow!(
+            "Balance too low for gas. Index {} has {} but needs ~{}.",
+            source.index,
+            utils::format_ether(source.balance),
+            utils::format_ether(fees.max_cost(gas_limit))
+        ));
+    }
+
+    Ok(AdminReview {
+        action,
+        status,
+        chain_id: config.chain_id,
+        signer_index: source.index,
+        signer_address: source.address,
+        signer_path: source.derivation_path,
+        gas_limit,
+        fees,
+    })
+}
+
+/// Sign and send a reviewed admin transaction, wait for it and log it
+pub async fn execute_admin_action(config: Config, review: AdminReview, use_native_ledger: bool) -> Result<TxHash> {
+    let provider = config.get_provider().await?;
+    if config.chain_id != review.chain_id {
+        return Err(anyhow!("The network changed since the transaction was reviewed"));
+    }
+    let nonce = provider.get_transaction_count(review.signer_address, None).await?.as_u64();
+
+    info!("Sending {} to {:?}", review.action.describe(), review.status.contract);
+    let tx_hash = ledger_dispatch::sign_and_send_contract_call(
+        use_native_ledger,
+        provider.clone(),
+        &config.rpc_url,
+        review.signer_index,
+        review.status.contract,
+        review.action.calldata()?,
+        U256::zero(),
+        review.gas_limit,
+        review.fees.fees,
+        nonce,
+        config.chain_id,
+        config.derivation_mode,
+        config.custom_account,
+        config.custom_address_index,
+        config.coin_type,
+    )
+    .await?;
+
+    let (block_number, gas_used) = bulk_disperse::wait_for_receipt(&provider, tx_hash).await?;
+
+    let withdrawn = match review.action {
+        AdminAction::WithdrawStuckFunds => format!("\nWithdrawn: {} {}", utils::format_ether(review.status.stuck_balance), config.native_token()),
+        _ => String::new(),
+    };
+    operation_log::append_log(
+        "Beaug Contract Admin",
+        config.chain_id,
+        format!(
+            "{}\nContract: {:?}\nSigner: {} → {:?}{}\nTx hash: {:?}\nBlock: {:?}\nGas used: {:?}",
+            review.action.describe(),
+            review.status.contract,
+            review.signer_path,
+            review.signer_address,
+            withdrawn,
+            tx_hash,
+            block_number,
+            gas_used
+        ),
+    )?;
+
+    Ok(tx_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status() -> AdminStatus {
+        AdminStatus {
+            contract: Address::from_low_u64_be(0xbeef),
+            owner: Address::from_low_u64_be(1),
+            pending_owner: None,
+            stuck_balance: U256::zero(),
+        }
+    }
+
+    // ==================== Admin action tests ====================
+
+    #[test]
+    fn test_admin_action_checks() {
+        let status = status();
+        assert!(AdminAction::TransferOwnership(Address::from_low_u64_be(2)).check(&status).is_ok());
+        assert!(AdminAction::TransferOwnership(Address::zero()).check(&status).is_err());
+        assert!(AdminAction::TransferOwnership(status.owner).check(&status).is_err());
+        assert!(AdminAction::AcceptOwnership.check(&status).is_err());
+        assert!(AdminAction::WithdrawStuckFunds.check(&status).is_err());
+    }
+
+    #[test]
+    fn test_role_of() {
+        let mut status = status();
+        status.pending_owner = Some(Address::from_low_u64_be(2));
+        assert_eq!(status.role_of(Address::from_low_u64_be(1)), AdminRole::Owner);
+        assert_eq!(status.role_of(Address::from_low_u64_be(2)), AdminRole::PendingOwner);
+        assert_eq!(status.role_of(Address::from_low_u64_be(3)), AdminRole::None);
+        assert_eq!(AdminAction::AcceptOwnership.required_role(), AdminRole::PendingOwner);
+    }
+
+    #[test]
+    fn test_admin_calldata_selectors() {
+        let new_owner = Address::from_low_u64_be(2);
+        let calldata = AdminAction::TransferOwnership(new_owner).calldata().unwrap();
+        assert_eq!(&calldata[..4], &ethers::utils::keccak256("transferOwnership(address)")[..4]);
+        assert_eq!(
+            AdminAction::WithdrawStuckFunds.calldata().unwrap(),
+            ethers::utils::keccak256("withdrawStuckFunds()")[..4].to_vec()
+        );
+    }
+}
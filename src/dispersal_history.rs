@@ -0,0 +1,226 @@
+//! On-chain dispersal history.
+//! Reads `BeaugDispersed` events for a set of sender addresses with `eth_getLogs`, paging
+//! through block ranges (and shrinking the page when the RPC rejects a range) so public RPC
+//! limits are respected. Every network the Beaug contract is deployed on is searched.
+
+use crate::balance_watcher::WatchNetwork;
+use crate::dispersal_proof::{self, DispersalRecord};
+use crate::user_settings::UserSettings;
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::task::JoinSet;
+use tracing::warn;
+
+/// Blocks per `eth_getLogs` request to start with
+pub const DEFAULT_PAGE_BLOCKS: u64 = 50_000;
+
+/// Smallest page before a failing range is reported as an error
+const MIN_PAGE_BLOCKS: u64 = 500;
+
+/// How far back to search when the contract's deployment block cannot be found
+/// (non-archive RPCs cannot answer `eth_getCode` at old blocks)
+pub const DEFAULT_LOOKBACK_BLOCKS: u64 = 1_000_000;
+
+/// Progress of one network's search
+#[derive(Debug, Clone)]
+pub struct HistoryProgress {
+    pub chain_id: u64,
+    pub scanned_to: u64,
+    pub from_block: u64,
+    pub to_block: u64,
+}
+
+/// Dispersals found on one network
+#[derive(Debug, Clone, Default)]
+pub struct NetworkDispersals {
+    pub chain_id: u64,
+    pub label: String,
+    pub native_token: String,
+    /// False if the contract has no code on this network
+    pub deployed: bool,
+    pub from_block: u64,
+    pub to_block: u64,
+    /// True if the search started at the contract's deployment block (complete history)
+    pub from_deployment: bool,
+    pub records: Vec<DispersalRecord>,
+    pub error: Option<String>,
+}
+
+/// Page that starts at `start`, at most `page_blocks` long and not past `to`
+pub fn page_end(start: u64, to: u64, page_blocks: u64) -> u64 {
+    start.saturating_add(page_blocks.max(1) - 1).min(to)
+}
+
+/// Topic for the indexed `sender` of `BeaugDispersed`
+pub fn sender_topic(sender: Address) -> H256 {
+    H256::from(sender)
+}
+
+/// Run `filter` over `from..=to` in pages. A rejected page is retried at half the size, down
+/// to [`MIN_PAGE_BLOCKS`]; after a success the page grows back towards `page_blocks`.
+pub async fn get_logs_paged(
+    provider: &Provider<Http>,
+    filter: &Filter,
+    from: u64,
+    to: u64,
+    page_blocks: u64,
+    mut on_page: impl FnMut(u64),
+) -> Result<Vec<Log>> {
+    let mut logs = Vec::new();
+    let mut page = page_blocks.max(MIN_PAGE_BLOCKS);
+    let mut start = from;
+
+    while start <= to {
+        let end = page_end(start, to, page);
+        let page_filter = filter.clone().from_block(start).to_block(end);
+        match provider.get_logs(&page_filter).await {
+            Ok(page_logs) => {
+                logs.extend(page_logs);
+                on_page(end);
+                start = end + 1;
+                page = (page * 2).min(page_blocks.max(MIN_PAGE_BLOCKS));
+            }
+            Err(e) if page > MIN_PAGE_BLOCKS => {
+                warn!("eth_getLogs rejected blocks {}-{} ({}), halving the page", start, end, e);
+                page = (page / 2).max(MIN_PAGE_BLOCKS);
+            }
+            Err(e) => return Err(anyhow!("eth_getLogs failed for blocks {}-{}: {}", start, end, e)),
+        }
+    }
+
+    Ok(logs)
+}
+
+/// First block at which `contract` has code, by binary search over `eth_getCode`.
+/// `None` if the node cannot serve historical state.
+pub async fn find_deployment_block(provider: &Provider<Http>, contract: Address, latest: u64) -> Option<u64> {
+    let (mut low, mut high) = (0u64, latest);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let code = provider.get_code(contract, Some(BlockId::from(mid))).await.ok()?;
+        if code.is_empty() {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    Some(low)
+}
+
+/// Every built-in and custom network with its configured RPC
+pub fn history_networks(settings: &UserSettings) -> Vec<WatchNetwork> {
+    crate::config::NETWORKS
+        .iter()
+        .map(|n| n.chain_id)
+        .chain(settings.custom_networks.iter().map(|n| n.chain_id))
+        .filter_map(|chain_id| WatchNetwork::resolve(chain_id, settings))
+        .collect()
+}
+
+/// Search one network for dispersals sent by `senders`
+pub async fn fetch_network_dispersals(
+    network: WatchNetwork,
+    contract: Address,
+    senders: Vec<Address>,
+    lookback_blocks: u64,
+    progress: Option<UnboundedSender<HistoryProgress>>,
+) -> NetworkDispersals {
+    let mut result = NetworkDispersals {
+        chain_id: network.chain_id,
+        label: network.label.clone(),
+        native_token: network.native_token.clone(),
+        ..Default::default()
+    };
+
+    let provider = match Provider::<Http>::try_from(network.rpc_url.as_str()) {
+        Ok(provider) => provider,
+        Err(e) => {
+            result.error = Some(format!("invalid RPC URL ({})", e));
+            return result;
+        }
+    };
+
+    let search = async {
+        let latest = provider.get_block_number().await?.as_u64();
+        if provider.get_code(contract, None).await?.is_empty() {
+            return Ok::<_, anyhow::Error>(false);
+        }
+        result.deployed = true;
+        result.to_block = latest;
+
+        match find_deployment_block(&provider, contract, latest).await {
+            Some(block) => {
+                result.from_block = block;
+                result.from_deployment = true;
+            }
+            None => result.from_block = latest.saturating_sub(lookback_blocks),
+        }
+
+        let topics: Vec<H256> = senders.iter().copied().map(sender_topic).collect();
+        let filter = Filter::new()
+            .address(contract)
+            .topic0(dispersal_proof::beaug_dispersed_topic())
+            .topic1(topics);
+        let (chain_id, from_block) = (result.chain_id, result.from_block);
+        let logs = get_logs_paged(&provider, &filter, from_block, latest, DEFAULT_PAGE_BLOCKS, |scanned_to| {
+            if let Some(sender) = &progress {
+                let _ = sender.send(HistoryProgress { chain_id, scanned_to, from_block, to_block: latest });
+            }
+        })
+        .await?;
+        result.records = logs.iter().filter_map(dispersal_proof::decode_dispersal_log).collect();
+        Ok(true)
+    };
+
+    if let Err(e) = search.await {
+        result.error = Some(e.to_string());
+    }
+    result
+}
+
+/// Search every network in parallel; networks without the contract come back with `deployed: false`
+pub async fn fetch_dispersal_history(
+    networks: Vec<WatchNetwork>,
+    contract: Address,
+    senders: Vec<Address>,
+    lookback_blocks: u64,
+    progress: Option<UnboundedSender<HistoryProgress>>,
+) -> Vec<NetworkDispersals> {
+    let mut tasks = JoinSet::new();
+    for network in networks {
+        tasks.spawn(fetch_network_dispersals(network, contract, senders.clone(), lookback_blocks, progress.clone()));
+    }
+
+    let mut results = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok(network) => results.push(network),
+            Err(e) => warn!("Dispersal history task failed: {}", e),
+        }
+    }
+    results.sort_by(|a, b| a.label.cmp(&b.label));
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Paging tests ====================
+
+    #[test]
+    fn test_page_end() {
+        assert_eq!(page_end(0, 1_000_000, 50_000), 49_999);
+        assert_eq!(page_end(990_000, 1_000_000, 50_000), 1_000_000);
+        assert_eq!(page_end(5, 5, 50_000), 5);
+    }
+
+    #[test]
+    fn test_sender_topic_is_left_padded() {
+        let sender: Address = "0xc2D167fd7CD0dC3E0Bd61C5206295C0560e66e31".parse().unwrap();
+        let topic = sender_topic(sender);
+        assert_eq!(&topic.as_bytes()[..12], &[0u8; 12]);
+        assert_eq!(&topic.as_bytes()[12..], sender.as_bytes());
+    }
+}
@@ -0,0 +1,274 @@
+//! Proof of dispersal using the `BeaugDispersed` event.
+//! Every Beaug dispersal emits a sequential `dispersalId` and the `recipientsHash`
+//! (`keccak256(abi.encodePacked(recipients))`). This module decodes the event from receipts
+//! and logs, and verifies a recipient list against it by recomputing the hash locally.
+
+use anyhow::{anyhow, Result};
+use ethers::abi::{Event, RawLog, Token};
+use ethers::prelude::*;
+use std::sync::OnceLock;
+
+/// Human-readable signature of the Beaug dispersal event
+pub const BEAUG_DISPERSED_EVENT: &str = "event BeaugDispersed(address indexed sender, uint256 indexed dispersalId, uint256 totalDistributed, uint256 recipientCount, bytes32 recipientsHash)";
+
+static BEAUG_DISPERSED: OnceLock<Event> = OnceLock::new();
+
+fn beaug_dispersed_event() -> &'static Event {
+    BEAUG_DISPERSED.get_or_init(|| {
+        ethers::abi::parse_abi(&[BEAUG_DISPERSED_EVENT])
+            .ok()
+            .and_then(|abi| abi.event("BeaugDispersed").ok().cloned())
+            .expect("BEAUG_DISPERSED_EVENT is invalid - this is a programming error")
+    })
+}
+
+/// topic0 of `BeaugDispersed`
+pub fn beaug_dispersed_topic() -> H256 {
+    beaug_dispersed_event().signature()
+}
+
+/// One decoded `BeaugDispersed` event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispersalRecord {
+    /// Contract that emitted the event
+    pub contract: Address,
+    pub sender: Address,
+    pub dispersal_id: U256,
+    pub total_distributed: U256,
+    pub recipient_count: U256,
+    pub recipients_hash: H256,
+    pub tx_hash: Option<TxHash>,
+    pub block_number: Option<u64>,
+}
+
+impl DispersalRecord {
+    /// Lines appended to the operation log entry of a dispersal
+    pub fn log_lines(&self) -> String {
+        format!(
+            "Dispersal ID: {}\nRecipients hash: {:?}",
+            self.dispersal_id, self.recipients_hash
+        )
+    }
+}
+
+/// Decode a `BeaugDispersed` log, or `None` if the log is another event
+pub fn decode_dispersal_log(log: &Log) -> Option<DispersalRecord> {
+    if log.topics.first() != Some(&beaug_dispersed_topic()) {
+        return None;
+    }
+    let parsed = beaug_dispersed_event()
+        .parse_log(RawLog {
+            topics: log.topics.clone(),
+            data: log.data.to_vec(),
+        })
+        .ok()?;
+
+    let param = |name: &str| parsed.params.iter().find(|p| p.name == name).map(|p| p.value.clone());
+    let uint = |name: &str| match param(name) {
+        Some(Token::Uint(value)) => Some(value),
+        _ => None,
+    };
+
+    Some(DispersalRecord {
+        contract: log.address,
+        sender: match param("sender")? {
+            Token::Address(address) => address,
+            _ => return None,
+        },
+        dispersal_id: uint("dispersalId")?,
+        total_distributed: uint("totalDistributed")?,
+        recipient_count: uint("recipientCount")?,
+        recipients_hash: match param("recipientsHash")? {
+            Token::FixedBytes(bytes) if bytes.len() == 32 => H256::from_slice(&bytes),
+            _ => return None,
+        },
+        tx_hash: log.transaction_hash,
+        block_number: log.block_number.map(|n| n.as_u64()),
+    })
+}
+
+/// All `BeaugDispersed` events in a receipt
+pub fn dispersals_in_receipt(receipt: &TransactionReceipt) -> Vec<DispersalRecord> {
+    receipt.logs.iter().filter_map(decode_dispersal_log).collect()
+}
+
+/// `keccak256(abi.encodePacked(recipients))`: each address padded to 32 bytes, as Solidity
+/// packs array elements
+pub fn recipients_hash(recipients: &[Address]) -> H256 {
+    let mut packed = Vec::with_capacity(recipients.len() * 32);
+    for recipient in recipients {
+        packed.extend_from_slice(&[0u8; 12]);
+        packed.extend_from_slice(recipient.as_bytes());
+    }
+    H256::from(ethers::utils::keccak256(packed))
+}
+
+/// How the dispersal to verify is identified
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DispersalLookup {
+    TxHash(TxHash),
+    DispersalId(U256),
+}
+
+/// Parse a transaction hash (0x + 64 hex digits) or a decimal dispersal id
+pub fn parse_dispersal_lookup(input: &str) -> Result<DispersalLookup> {
+    let input = input.trim();
+    if input.starts_with("0x") && input.len() == 66 {
+        return input
+            .parse()
+            .map(DispersalLookup::TxHash)
+            .map_err(|_| anyhow!("Invalid transaction hash"));
+    }
+    U256::from_dec_str(input)
+        .map(DispersalLookup::DispersalId)
+        .map_err(|_| anyhow!("Enter a transaction hash (0x...) or a numeric dispersal id"))
+}
+
+/// Find the dispersal by tx hash (from its receipt) or by id (from the contract's logs)
+pub async fn find_dispersal(
+    provider: &Provider<Http>,
+    contract: Address,
+    lookup: &DispersalLookup,
+) -> Result<DispersalRecord> {
+    match lookup {
+        DispersalLookup::TxHash(tx_hash) => {
+            let receipt = provider
+                .get_transaction_receipt(*tx_hash)
+                .await?
+                .ok_or_else(|| anyhow!("Transaction {:?} not found or not mined yet", tx_hash))?;
+            dispersals_in_receipt(&receipt)
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow!("Transaction {:?} emitted no BeaugDispersed event", tx_hash))
+        }
+        DispersalLookup::DispersalId(id) => {
+            let filter = Filter::new()
+                .address(contract)
+                .topic0(beaug_dispersed_topic())
+                .topic2(H256::from_uint(id));
+            let logs = match provider.get_logs(&filter.clone().from_block(0u64).to_block(BlockNumber::Latest)).await {
+                Ok(logs) => logs,
+                // The RPC limits the block range: page from the contract's deployment instead
+                Err(_) => {
+                    let latest = provider.get_block_number().await?.as_u64();
+                    let from = crate::dispersal_history::find_deployment_block(provider, contract, latest)
+                        .await
+                        .unwrap_or_else(|| latest.saturating_sub(crate::dispersal_history::DEFAULT_LOOKBACK_BLOCKS));
+                    crate::dispersal_history::get_logs_paged(
+                        provider,
+                        &filter,
+                        from,
+                        latest,
+                        crate::dispersal_history::DEFAULT_PAGE_BLOCKS,
+                        |_| {},
+                    )
+                    .await
+                    .map_err(|e| {
+                        anyhow!("Could not search logs for dispersal {} ({}); try the transaction hash instead", id, e)
+                    })?
+                }
+            };
+            logs.iter()
+                .find_map(decode_dispersal_log)
+                .ok_or_else(|| anyhow!("No dispersal with id {} on contract {:?}", id, contract))
+        }
+    }
+}
+
+/// Result of checking a recipient list against an on-chain dispersal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispersalVerification {
+    pub record: DispersalRecord,
+    pub computed_hash: H256,
+    pub provided_count: usize,
+}
+
+impl DispersalVerification {
+    pub fn hash_matches(&self) -> bool {
+        self.computed_hash == self.record.recipients_hash
+    }
+
+    pub fn count_matches(&self) -> bool {
+        U256::from(self.provided_count) == self.record.recipient_count
+    }
+}
+
+/// Look up a dispersal and check `recipients` (in the order they were sent) against its hash
+pub async fn verify_dispersal(
+    provider: &Provider<Http>,
+    contract: Address,
+    lookup: &DispersalLookup,
+    recipients: &[Address],
+) -> Result<DispersalVerification> {
+    let record = find_dispersal(provider, contract, lookup).await?;
+    Ok(DispersalVerification {
+        record,
+        computed_hash: recipients_hash(recipients),
+        provided_count: recipients.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dispersal_log(sender: Address, id: u64, recipients: &[Address]) -> Log {
+        Log {
+            address: Address::from_low_u64_be(0xbeef),
+            topics: vec![
+                beaug_dispersed_topic(),
+                H256::from(sender),
+                H256::from_low_u64_be(id),
+            ],
+            data: ethers::abi::encode(&[
+                Token::Uint(U256::from(3_000u64)),
+                Token::Uint(U256::from(recipients.len())),
+                Token::FixedBytes(recipients_hash(recipients).as_bytes().to_vec()),
+            ])
+            .into(),
+            ..Default::default()
+        }
+    }
+
+    // ==================== Event decoding tests ====================
+
+    #[test]
+    fn test_decode_dispersal_log() {
+        let sender = Address::from_low_u64_be(7);
+        let recipients = vec![Address::from_low_u64_be(1), Address::from_low_u64_be(2)];
+        let record = decode_dispersal_log(&dispersal_log(sender, 42, &recipients)).unwrap();
+
+        assert_eq!(record.sender, sender);
+        assert_eq!(record.dispersal_id, U256::from(42u64));
+        assert_eq!(record.total_distributed, U256::from(3_000u64));
+        assert_eq!(record.recipient_count, U256::from(2u64));
+        assert_eq!(record.recipients_hash, recipients_hash(&recipients));
+    }
+
+    #[test]
+    fn test_decode_ignores_other_events() {
+        let mut log = dispersal_log(Address::zero(), 1, &[]);
+        log.topics[0] = H256::zero();
+        assert!(decode_dispersal_log(&log).is_none());
+    }
+
+    // ==================== Recipients hash tests ====================
+
+    #[test]
+    fn test_recipients_hash_is_order_sensitive() {
+        let a = Address::from_low_u64_be(1);
+        let b = Address::from_low_u64_be(2);
+        assert_ne!(recipients_hash(&[a, b]), recipients_hash(&[b, a]));
+        // Each element is padded to 32 bytes, like abi.encodePacked(address[])
+        let padded = ethers::abi::encode(&[Token::Address(a), Token::Address(b)]);
+        assert_eq!(recipients_hash(&[a, b]), H256::from(ethers::utils::keccak256(padded)));
+    }
+
+    #[test]
+    fn test_parse_dispersal_lookup() {
+        let hash = format!("0x{}", "ab".repeat(32));
+        assert!(matches!(parse_dispersal_lookup(&hash).unwrap(), DispersalLookup::TxHash(_)));
+        assert_eq!(parse_dispersal_lookup(" 17 ").unwrap(), DispersalLookup::DispersalId(U256::from(17u64)));
+        assert!(parse_dispersal_lookup("0x1234").is_err());
+    }
+}
@@ -3,6 +3,8 @@ use ethers::abi::{Function, Param, ParamType, StateMutability, Token};
 use anyhow::Result;
 use std::sync::{Arc, OnceLock};
 use ethers::providers::{Http, Provider};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use crate::disperse_protocol::DisperseProtocol;
 
 /// The main Beaug contract address (deployed via CREATE2 for same address across all chains)
 pub const MAIN_BEAUG_ADDRESS: &str = "0xe7deB73d0661aA3732c971Ab3d583CFCa786e0d7";
@@ -14,7 +16,7 @@ pub const BEAUG_OWNER_ADDRESS: &str = "0xc2D167fd7CD0dC3E0Bd61C5206295C0560e66e3
 static MAIN_BEAUG_ADDRESS_PARSED: OnceLock<Address> = OnceLock::new();
 
 /// Get the parsed main Beaug address, parsing it once and caching
-fn main_beaug_address() -> Address {
+pub(crate) fn main_beaug_address() -> Address {
     *MAIN_BEAUG_ADDRESS_PARSED.get_or_init(|| {
         MAIN_BEAUG_ADDRESS.parse()
             .expect("MAIN_BEAUG_ADDRESS constant is invalid - this is a programming error")
@@ -34,21 +36,104 @@ pub fn get_beaug_registry_address(_chain_id: u64) -> Option<Address> {
 }
 
 /// Function selector for beaugDisperse(address[],uint256[])
-/// keccak256("beaugDisperse(address[],uint256[])") = 0x84a63544...
-pub const BEAUG_DISPERSE_SELECTOR: [u8; 4] = [0x84, 0xa6, 0x35, 0x44];
+/// keccak256("beaugDisperse(address[],uint256[])") = 0x4ee0cd65...
+pub const BEAUG_DISPERSE_SELECTOR: [u8; 4] = [0x4e, 0xe0, 0xcd, 0x65];
 
-/// Estimate gas for a disperse transaction
+/// Function selector for disperseToken(address,address[],uint256[])
+/// keccak256("disperseToken(address,address[],uint256[])") = 0xc73a2d60...
+pub const DISPERSE_TOKEN_SELECTOR: [u8; 4] = [0xc7, 0x3a, 0x2d, 0x60];
+
+/// Default headroom added on top of `eth_estimateGas`, in percent
+pub const DEFAULT_GAS_MARGIN_PERCENT: u32 = 20;
+
+/// Upper bound for any disperse gas limit (typical block gas limit)
+pub const MAX_DISPERSE_GAS_LIMIT: u64 = 15_000_000;
+
+/// Fallback gas limit when the node cannot estimate.
+/// Base gas: 150k for function call overhead
+/// Per recipient: 120k (transfer + first-time scoring storage writes + events)
+/// Safety buffer: 10%, capped at the block gas limit
+pub fn heuristic_disperse_gas_limit(recipient_count: usize) -> u64 {
+    let base_gas_limit = 150_000u64 + 120_000u64 * recipient_count as u64;
+    let with_buffer = (base_gas_limit as f64 * 1.10) as u64;
+    with_buffer.min(MAX_DISPERSE_GAS_LIMIT)
+}
+
+/// Add the safety margin to a node estimate, capped at the block gas limit
+pub fn apply_gas_margin(estimate: U256, margin_percent: u32) -> u64 {
+    let with_margin = estimate.saturating_mul(U256::from(100 + margin_percent as u64)) / U256::from(100u64);
+    if with_margin > U256::from(MAX_DISPERSE_GAS_LIMIT) {
+        MAX_DISPERSE_GAS_LIMIT
+    } else {
+        with_margin.as_u64()
+    }
+}
+
+/// Where a disperse gas limit came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GasEstimateSource {
+    /// `eth_estimateGas` plus the safety margin (in percent)
+    Node { margin_percent: u32 },
+    /// Estimation failed, the recipient-count heuristic was used
+    Heuristic { reason: String },
+}
+
+/// Gas limit for a disperse call, as previewed and as signed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisperseGasEstimate {
+    pub gas_limit: u64,
+    pub source: GasEstimateSource,
+}
+
+impl DisperseGasEstimate {
+    pub fn is_heuristic(&self) -> bool {
+        matches!(self.source, GasEstimateSource::Heuristic { .. })
+    }
+
+    pub fn describe(&self) -> String {
+        match &self.source {
+            GasEstimateSource::Node { margin_percent } => {
+                format!("{} gas (estimated +{}%)", self.gas_limit, margin_percent)
+            }
+            GasEstimateSource::Heuristic { reason } => {
+                format!("{} gas (heuristic, estimate failed: {})", self.gas_limit, reason)
+            }
+        }
+    }
+}
+
+/// Estimate gas for a disperse transaction with `eth_estimateGas` against the real calldata,
+/// value and sender, adding `margin_percent` of headroom. Falls back to
+/// [`heuristic_disperse_gas_limit`] only when the node cannot estimate.
 pub async fn estimate_disperse_gas(
-    _provider: Arc<Provider<Http>>,
-    _disperse_address: Address,
-    recipients: Vec<Address>,
-    _amounts: Vec<U256>,
-    _from: Address,
-) -> Result<u64> {
-    // Base gas + per recipient overhead
-    let base_gas = 100_000u64;
-    let per_recipient = 50_000u64;
-    Ok(base_gas + (per_recipient * recipients.len() as u64))
+    provider: &Provider<Http>,
+    disperse_address: Address,
+    from: Address,
+    calldata: Vec<u8>,
+    value: U256,
+    recipient_count: usize,
+    margin_percent: u32,
+) -> DisperseGasEstimate {
+    let tx: TypedTransaction = TransactionRequest::new()
+        .from(from)
+        .to(disperse_address)
+        .data(calldata)
+        .value(value)
+        .into();
+
+    match provider.estimate_gas(&tx, None).await {
+        Ok(estimate) => DisperseGasEstimate {
+            gas_limit: apply_gas_margin(estimate, margin_percent),
+            source: GasEstimateSource::Node { margin_percent },
+        },
+        Err(e) => {
+            tracing::warn!("eth_estimateGas failed, using heuristic gas limit: {}", e);
+            DisperseGasEstimate {
+                gas_limit: heuristic_disperse_gas_limit(recipient_count),
+                source: GasEstimateSource::Heuristic { reason: e.to_string() },
+            }
+        }
+    }
 }
 
 /// Contract validation result
@@ -62,6 +147,11 @@ pub enum ContractValidationStatus {
     RegisteredButIncompatible,
     /// Contract has compatible function signature but is not registered
     CompatibleButUnregistered,
+    /// Contract implements the classic disperse.app interface (`disperseEther` / `disperseToken`)
+    DisperseAppCompatible,
+    /// Native sends go through Multicall3 `aggregate3Value` (Beaug is not deployed on this chain,
+    /// or the address is Multicall3 itself)
+    Multicall3Fallback,
     /// Contract is neither registered nor has compatible signature
     Unknown,
     /// Validation is in progress
@@ -77,6 +167,8 @@ impl ContractValidationStatus {
             ContractValidationStatus::RegisteredAndCompatible => "Verified Beaug Contract",
             ContractValidationStatus::RegisteredButIncompatible => "Registered (signature mismatch)",
             ContractValidationStatus::CompatibleButUnregistered => "Compatible (unregistered)",
+            ContractValidationStatus::DisperseAppCompatible => "disperse.app Contract",
+            ContractValidationStatus::Multicall3Fallback => "Multicall3 (native only)",
             ContractValidationStatus::Unknown => "Unknown contract",
             ContractValidationStatus::Checking => "Checking...",
             ContractValidationStatus::Error(_) => "Validation error",
@@ -89,8 +181,22 @@ impl ContractValidationStatus {
             ContractValidationStatus::MainBeaugRegistry
                 | ContractValidationStatus::RegisteredAndCompatible
                 | ContractValidationStatus::CompatibleButUnregistered
+                | ContractValidationStatus::DisperseAppCompatible
+                | ContractValidationStatus::Multicall3Fallback
         )
     }
+
+    /// Disperse interface to drive the contract with, if it implements one
+    pub fn protocol(&self) -> Option<DisperseProtocol> {
+        match self {
+            ContractValidationStatus::MainBeaugRegistry
+            | ContractValidationStatus::RegisteredAndCompatible
+            | ContractValidationStatus::CompatibleButUnregistered => Some(DisperseProtocol::Beaug),
+            ContractValidationStatus::DisperseAppCompatible => Some(DisperseProtocol::DisperseApp),
+            ContractValidationStatus::Multicall3Fallback => Some(DisperseProtocol::Multicall3),
+            _ => None,
+        }
+    }
 }
 
 /// Check if a contract is registered with the main Beaug registry
@@ -159,16 +265,26 @@ pub async fn check_contract_has_beaug_disperse(
     Ok(false)
 }
 
-/// Validate a contract address for Beaug compatibility
+/// Validate a contract address for Beaug (or disperse.app) compatibility
 pub async fn validate_contract(
     provider: Arc<Provider<Http>>,
     chain_id: u64,
     contract_address: Address,
 ) -> ContractValidationStatus {
-    // Check if this IS the main Beaug contract by comparing addresses directly
-    // This works even if the contract isn't deployed on the current network yet
+    // Check if this IS the main Beaug contract by comparing addresses directly.
+    // If it has no code on this network, native sends fall back to Multicall3 when available;
+    // an RPC error keeps the main-contract status
     if contract_address == main_beaug_address() {
-        return ContractValidationStatus::MainBeaugRegistry;
+        return match provider.get_code(contract_address, None).await {
+            Ok(code) if code.is_empty() => match crate::disperse_protocol::multicall3_deployed(&provider).await {
+                Ok(true) => ContractValidationStatus::Multicall3Fallback,
+                Ok(false) => ContractValidationStatus::Error(
+                    "Beaug is not deployed on this network and Multicall3 is not available".to_string(),
+                ),
+                Err(_) => ContractValidationStatus::MainBeaugRegistry,
+            },
+            _ => ContractValidationStatus::MainBeaugRegistry,
+        };
     }
     
     // For other contracts, check if they have code
@@ -181,12 +297,8 @@ pub async fn validate_contract(
         return ContractValidationStatus::Error("Address is not a contract".to_string());
     }
     
-    // Check for function signature
-    let has_beaug_disperse = {
-        let selector = &BEAUG_DISPERSE_SELECTOR;
-        let code_bytes = code.as_ref();
-        code_bytes.windows(4).any(|w| w == selector)
-    };
+    // Check which disperse interface the bytecode implements
+    let protocol = crate::disperse_protocol::detect_protocol(code.as_ref());
     
     // Check registration status (if we have a registry for this chain)
     let is_registered = if let Some(registry) = get_beaug_registry_address(chain_id) {
@@ -198,10 +310,57 @@ pub async fn validate_contract(
         false // No registry for this chain
     };
     
-    match (is_registered, has_beaug_disperse) {
-        (true, true) => ContractValidationStatus::RegisteredAndCompatible,
-        (true, false) => ContractValidationStatus::RegisteredButIncompatible,
-        (false, true) => ContractValidationStatus::CompatibleButUnregistered,
-        (false, false) => ContractValidationStatus::Unknown,
+    match (is_registered, protocol) {
+        (true, Some(DisperseProtocol::Beaug)) => ContractValidationStatus::RegisteredAndCompatible,
+        (_, Some(DisperseProtocol::DisperseApp)) => ContractValidationStatus::DisperseAppCompatible,
+        (true, None) => ContractValidationStatus::RegisteredButIncompatible,
+        (false, Some(DisperseProtocol::Beaug)) => ContractValidationStatus::CompatibleButUnregistered,
+        (_, Some(DisperseProtocol::Multicall3)) => ContractValidationStatus::Multicall3Fallback,
+        (false, None) => ContractValidationStatus::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Selector tests ====================
+
+    #[test]
+    fn test_beaug_disperse_selector() {
+        let hash = ethers::utils::keccak256("beaugDisperse(address[],uint256[])");
+        assert_eq!(hash[..4], BEAUG_DISPERSE_SELECTOR);
+    }
+
+    // ==================== Gas limit tests ====================
+
+    #[test]
+    fn test_apply_gas_margin() {
+        assert_eq!(apply_gas_margin(U256::from(100_000u64), 20), 120_000);
+        assert_eq!(apply_gas_margin(U256::from(100_000u64), 0), 100_000);
+        assert_eq!(apply_gas_margin(U256::from(20_000_000u64), 20), MAX_DISPERSE_GAS_LIMIT);
+    }
+
+    #[test]
+    fn test_heuristic_gas_limit() {
+        assert_eq!(heuristic_disperse_gas_limit(1), 297_000);
+        assert_eq!(heuristic_disperse_gas_limit(500), MAX_DISPERSE_GAS_LIMIT);
+    }
+
+    #[test]
+    fn test_gas_estimate_describe() {
+        let estimated = DisperseGasEstimate {
+            gas_limit: 120_000,
+            source: GasEstimateSource::Node { margin_percent: 20 },
+        };
+        assert!(!estimated.is_heuristic());
+        assert_eq!(estimated.describe(), "120000 gas (estimated +20%)");
+
+        let fallback = DisperseGasEstimate {
+            gas_limit: 297_000,
+            source: GasEstimateSource::Heuristic { reason: "execution reverted".to_string() },
+        };
+        assert!(fallback.is_heuristic());
+        assert!(fallback.describe().contains("execution reverted"));
     }
 }
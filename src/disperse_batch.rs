@@ -0,0 +1,445 @@
+//! Automatic chunking of oversized disperse lists.
+//! When one disperse call would not fit in a block, the recipients are split into
+//! batches sized from a gas estimate and the block gas limit, and each batch becomes one
+//! queued transaction signed with consecutive nonces.
+
+use crate::bulk_disperse::{self, BulkDisperseType};
+use crate::config::Config;
+use crate::disperse::{self, DisperseGasEstimate, MAX_DISPERSE_GAS_LIMIT};
+use crate::disperse_protocol::{self, DisperseProtocol};
+use crate::ledger_transaction_manager::{LedgerTransactionManager, PendingTransaction, TransactionManagerConfig};
+use crate::{fee_oracle, simulation, utils};
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Share of the latest block gas limit a single batch may use, in percent
+const BLOCK_GAS_SHARE_PERCENT: u64 = 50;
+
+/// Number of recipients estimated to measure the per-recipient gas cost
+const SAMPLE_SIZE: usize = 20;
+
+/// How many times the batch size is halved when a batch still estimates over the cap
+const MAX_RESIZE_ATTEMPTS: usize = 4;
+
+/// How a disperse list will be sent: one transaction, or several batches of `batch_size`
+/// recipients (the last one may be smaller), each with its own gas limit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispersePlan {
+    pub batch_size: usize,
+    pub batches: Vec<DisperseGasEstimate>,
+    /// [`list_hash`] of the recipients and amounts the plan was estimated for, if known
+    pub list_hash: Option<H256>,
+}
+
+impl DispersePlan {
+    /// A plan that sends all `recipient_count` recipients in one transaction
+    pub fn single(estimate: DisperseGasEstimate, recipient_count: usize) -> Self {
+        Self {
+            batch_size: recipient_count.max(1),
+            batches: vec![estimate],
+            list_hash: None,
+        }
+    }
+
+    /// Tie the plan to the recipients and amounts it was estimated for
+    pub fn for_list(mut self, recipients: &[Address], amounts: &[U256]) -> Self {
+        self.list_hash = Some(list_hash(recipients, amounts));
+        self
+    }
+
+    pub fn batch_count(&self) -> usize {
+        self.batches.len()
+    }
+
+    pub fn is_batched(&self) -> bool {
+        self.batches.len() > 1
+    }
+
+    /// Sum of the gas limits of every batch
+    pub fn total_gas_limit(&self) -> u64 {
+        self.batches.iter().map(|b| b.gas_limit).sum()
+    }
+
+    /// True if any batch fell back to the recipient-count heuristic
+    pub fn uses_heuristic(&self) -> bool {
+        self.batches.iter().any(|b| b.is_heuristic())
+    }
+
+    /// The single estimate, or the first heuristic one if any batch used the fallback
+    pub fn representative(&self) -> &DisperseGasEstimate {
+        self.batches
+            .iter()
+            .find(|b| b.is_heuristic())
+            .unwrap_or(&self.batches[0])
+    }
+}
+
+/// keccak256 of the ABI-encoded recipients and amounts, to tell whether a plan is stale
+pub fn list_hash(recipients: &[Address], amounts: &[U256]) -> H256 {
+    let encoded = ethers::abi::encode(&[
+        ethers::abi::Token::Array(recipients.iter().map(|a| ethers::abi::Token::Address(*a)).collect()),
+        ethers::abi::Token::Array(amounts.iter().map(|a| ethers::abi::Token::Uint(*a)).collect()),
+    ]);
+    H256::from(ethers::utils::keccak256(encoded))
+}
+
+/// Recipients per batch so a batch stays under `max_batch_gas`
+pub fn batch_size_for(per_recipient_gas: u64, max_batch_gas: u64) -> usize {
+    if per_recipient_gas == 0 {
+        return usize::MAX;
+    }
+    ((max_batch_gas / per_recipient_gas) as usize).max(1)
+}
+
+/// Split recipients and amounts into consecutive batches of at most `batch_size`
+pub fn chunk_recipients(
+    recipients: &[Address],
+    amounts: &[U256],
+    batch_size: usize,
+) -> Vec<(Vec<Address>, Vec<U256>)> {
+    let batch_size = batch_size.max(1);
+    recipients
+        .chunks(batch_size)
+        .zip(amounts.chunks(batch_size))
+        .map(|(r, a)| (r.to_vec(), a.to_vec()))
+        .collect()
+}
+
+/// Gas budget for one batch: a share of the latest block gas limit, capped at
+/// [`MAX_DISPERSE_GAS_LIMIT`]
+async fn max_batch_gas(provider: &Provider<Http>) -> u64 {
+    match provider.get_block(BlockNumber::Latest).await {
+        Ok(Some(block)) => {
+            let share = block.gas_limit * U256::from(BLOCK_GAS_SHARE_PERCENT) / U256::from(100u64);
+            if share > U256::from(MAX_DISPERSE_GAS_LIMIT) {
+                MAX_DISPERSE_GAS_LIMIT
+            } else {
+                share.as_u64()
+            }
+        }
+        _ => {
+            warn!("Could not read the block gas limit, using {} per batch", MAX_DISPERSE_GAS_LIMIT);
+            MAX_DISPERSE_GAS_LIMIT
+        }
+    }
+}
+
+fn sum_amounts(amounts: &[U256]) -> U256 {
+    amounts.iter().fold(U256::zero(), |acc, x| acc + *x)
+}
+
+async fn estimate_batch(
+    provider: &Provider<Http>,
+    protocol: DisperseProtocol,
+    disperse_address: Address,
+    from: Address,
+    recipients: &[Address],
+    amounts: &[U256],
+    gas_margin_percent: u32,
+) -> Result<DisperseGasEstimate> {
+    let calldata = protocol.native_calldata(recipients, amounts)?;
+    Ok(disperse::estimate_disperse_gas(
+        provider,
+        disperse_address,
+        from,
+        calldata,
+        sum_amounts(amounts),
+        recipients.len(),
+        gas_margin_percent,
+    )
+    .await)
+}
+
+/// Plan a native disperse before signing. The full list is estimated first; if it does not
+/// fit in one batch, a sample of recipients sizes the batches and every batch is estimated.
+/// The GUI passes the plan back into [`prepare_disperse_batches`] so the previewed limits are signed.
+pub async fn plan_native_disperse(
+    config: Config,
+    disperse_address: Address,
+    from: Address,
+    recipients: Vec<Address>,
+    amounts: Vec<U256>,
+    gas_margin_percent: u32,
+) -> Result<DispersePlan> {
+    if recipients.is_empty() {
+        return Err(anyhow!("No recipients specified"));
+    }
+    let provider = config.get_provider().await?;
+    let (disperse_address, protocol) = disperse_protocol::resolve_native_disperse(&provider, disperse_address).await?;
+    let max_gas = max_batch_gas(&provider).await;
+
+    let full = estimate_batch(&provider, protocol, disperse_address, from, &recipients, &amounts, gas_margin_percent).await?;
+    if full.gas_limit <= max_gas && full.gas_limit < MAX_DISPERSE_GAS_LIMIT {
+        return Ok(DispersePlan::single(full, recipients.len()).for_list(&recipients, &amounts));
+    }
+
+    // Measure the per-recipient cost on a sample; the base cost is folded in, which errs small
+    let sample_len = recipients.len().min(SAMPLE_SIZE);
+    let sample = estimate_batch(
+        &provider,
+        protocol,
+        disperse_address,
+        from,
+        &recipients[..sample_len],
+        &amounts[..sample_len],
+        gas_margin_percent,
+    )
+    .await?;
+    let mut batch_size = batch_size_for(sample.gas_limit / sample_len as u64, max_gas);
+
+    for _ in 0..MAX_RESIZE_ATTEMPTS {
+        let mut batches = Vec::new();
+        for (batch_recipients, batch_amounts) in chunk_recipients(&recipients, &amounts, batch_size) {
+            batches.push(
+                estimate_batch(&provider, protocol, disperse_address, from, &batch_recipients, &batch_amounts, gas_margin_percent)
+                    .await?,
+            );
+        }
+
+        let oversized = batches
+            .iter()
+            .any(|b| b.gas_limit > max_gas || b.gas_limit >= MAX_DISPERSE_GAS_LIMIT);
+        if !oversized || batch_size == 1 {
+            info!(
+                "Disperse of {} recipients planned as {} batches of up to {} (max {} gas per batch)",
+                recipients.len(),
+                batches.len(),
+                batch_size,
+                max_gas
+            );
+            return Ok(DispersePlan { batch_size, batches, list_hash: None }.for_list(&recipients, &amounts));
+        }
+        batch_size = (batch_size / 2).max(1);
+    }
+
+    Err(anyhow!(
+        "Could not size disperse batches under {} gas; try fewer recipients",
+        max_gas
+    ))
+}
+
+/// Simulate each batch of a native disperse with `eth_call`, returning the first readable
+/// failure (prefixed with its batch when there are several)
+pub async fn simulate_native_disperse(
+    config: Config,
+    disperse_address: Address,
+    from: Address,
+    recipients: Vec<Address>,
+    amounts: Vec<U256>,
+    batch_size: usize,
+) -> Result<Option<String>> {
+    let provider = config.get_provider().await?;
+    let (disperse_address, protocol) = disperse_protocol::resolve_native_disperse(&provider, disperse_address).await?;
+    let chunks = chunk_recipients(&recipients, &amounts, batch_size);
+    let batch_count = chunks.len();
+    for (idx, (batch_recipients, batch_amounts)) in chunks.into_iter().enumerate() {
+        let calldata = protocol.native_calldata(&batch_recipients, &batch_amounts)?;
+        let value = sum_amounts(&batch_amounts);
+        if let Some(reason) = simulation::simulate_call(&provider, from, disperse_address, calldata, value).await? {
+            return Ok(Some(if batch_count > 1 {
+                format!("batch {}/{} {}", idx + 1, batch_count, reason)
+            } else {
+                reason.to_string()
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// A batched disperse to prepare: the same inputs as a single disperse, plus the previewed plan
+#[derive(Debug, Clone)]
+pub struct DisperseBatchRequest {
+    pub disperse_type: BulkDisperseType,
+    pub amount_to_send: U256,
+    pub disperse_address_override: Option<String>,
+    pub source_idx_override: Option<usize>,
+    pub tip_amount: Option<U256>,
+    pub tip_recipient: Option<Address>,
+    pub plan: DispersePlan,
+}
+
+/// Prepare one queued disperse transaction per batch of the request's plan, without executing them.
+/// Tips are appended as a regular recipient, so they land in the last batch.
+/// Returns the transactions and the transaction manager that assigns consecutive nonces.
+pub async fn prepare_disperse_batches(
+    config: Config,
+    request: DisperseBatchRequest,
+    gas_speed: f32,
+    use_native_ledger: bool,
+) -> Result<(Vec<(PendingTransaction, String, String)>, Arc<LedgerTransactionManager>)> {
+    let DisperseBatchRequest {
+        disperse_type,
+        amount_to_send,
+        disperse_address_override,
+        source_idx_override,
+        tip_amount,
+        tip_recipient,
+        plan,
+    } = request;
+    let provider = config.get_provider().await?;
+    let chain_id = config.chain_id;
+    let operation_name = "Beaug Bulk Disperse";
+
+    let (recipients, amounts, is_equal_distribution) =
+        bulk_disperse::disperse_recipients(disperse_type, amount_to_send, tip_amount, tip_recipient)?;
+    let total_to_distribute = sum_amounts(&amounts);
+    if !is_equal_distribution && amount_to_send < total_to_distribute {
+        return Err(anyhow!(
+            "Amount to send ({}) is less than the sum of specified amounts ({}).",
+            utils::format_ether(amount_to_send),
+            utils::format_ether(total_to_distribute)
+        ));
+    }
+
+    // Same count of batches is not enough: changed amounts or recipients need a new plan
+    let chunks = chunk_recipients(&recipients, &amounts, plan.batch_size);
+    if plan.list_hash != Some(list_hash(&recipients, &amounts)) || chunks.len() != plan.batch_count() {
+        return Err(anyhow!("Recipients or amounts changed since the gas preview. Re-estimate and try again."));
+    }
+
+    let (disperse_address, protocol) = disperse_protocol::resolve_native_disperse(
+        &provider,
+        bulk_disperse::resolve_disperse_address(chain_id, disperse_address_override)?,
+    )
+    .await?;
+    let source = bulk_disperse::load_source_account(&config, &provider, source_idx_override, use_native_ledger).await?;
+
+    let fee_quote = fee_oracle::quote_fees(&provider, chain_id, gas_speed).await?;
+
+    // Every batch must be able to pay its max fee
+    let total_gas_cost = fee_quote.max_cost(plan.total_gas_limit());
+    let total_needed = total_to_distribute + total_gas_cost;
+    if source.balance < total_needed {
+        return Err(anyhow!(
+            "Balance too low. Source has {} ETH but needs {} ETH (amount: {} + max gas fee for {} batches: {}).",
+            utils::format_ether(source.balance),
+            utils::format_ether(total_needed),
+            utils::format_ether(total_to_distribute),
+            plan.batch_count(),
+            utils::format_ether(total_gas_cost)
+        ));
+    }
+
+    let manager_config = TransactionManagerConfig {
+        inter_transaction_delay_ms: 3000,
+        max_retries: 2,
+        retry_delay_ms: 2000,
+        wait_for_confirmation: true,
+        confirmation_timeout_secs: 90,
+        derivation_mode: config.derivation_mode,
+        custom_account: config.custom_account,
+        custom_address_index: config.custom_address_index,
+        coin_type: config.coin_type,
+        use_native_ledger,
+    };
+
+    let manager = Arc::new(LedgerTransactionManager::new(
+        provider.clone(),
+        manager_config,
+        chain_id,
+        source.address,
+        source.index,
+        config.rpc_url.clone(),
+    )
+    .await?);
+
+    // Initialize manager (fetches current nonce)
+    manager.initialize().await?;
+
+    let batch_count = chunks.len();
+    let mut tx_list = Vec::with_capacity(batch_count);
+    for (idx, ((batch_recipients, batch_amounts), estimate)) in chunks.into_iter().zip(plan.batches).enumerate() {
+        let value = sum_amounts(&batch_amounts);
+        let tx = PendingTransaction {
+            to: disperse_address,
+            value,
+            data: protocol.native_calldata(&batch_recipients, &batch_amounts)?,
+            gas_limit: estimate.gas_limit,
+            fees: fee_quote.fees,
+            operation_name: format!("{}_batch_{}", operation_name, idx + 1),
+        };
+        let description = format!(
+            "Disperse batch {}/{}: {} ETH to {} recipients (fee ~{}, max {})",
+            idx + 1,
+            batch_count,
+            utils::format_ether(value),
+            batch_recipients.len(),
+            utils::format_ether(fee_quote.expected_cost(estimate.gas_limit)),
+            utils::format_ether(fee_quote.max_cost(estimate.gas_limit))
+        );
+        let dest_label = format!(
+            "Batch {}/{} ({} recipients) → {:?}",
+            idx + 1,
+            batch_count,
+            batch_recipients.len(),
+            disperse_address
+        );
+        tx_list.push((tx, description, dest_label));
+    }
+
+    // Simulate every batch so a revert is caught before the first Ledger prompt
+    simulation::simulate_pending_transactions(&provider, source.address, &tx_list).await?;
+
+    info!(
+        "Prepared {} disperse batches from {:?} (Index {}) to contract {:?}",
+        batch_count, source.address, source.index, disperse_address
+    );
+
+    Ok((tx_list, manager))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Batch sizing tests ====================
+
+    #[test]
+    fn test_batch_size_for() {
+        assert_eq!(batch_size_for(50_000, 15_000_000), 300);
+        assert_eq!(batch_size_for(20_000_000, 15_000_000), 1);
+        assert_eq!(batch_size_for(0, 15_000_000), usize::MAX);
+    }
+
+    #[test]
+    fn test_chunk_recipients_keeps_order_and_remainder() {
+        let recipients: Vec<Address> = (1..=5u64).map(Address::from_low_u64_be).collect();
+        let amounts: Vec<U256> = (1..=5u64).map(U256::from).collect();
+
+        let chunks = chunk_recipients(&recipients, &amounts, 2);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].0, recipients[..2].to_vec());
+        assert_eq!(chunks[2].0, vec![recipients[4]]);
+        assert_eq!(chunks[2].1, vec![U256::from(5u64)]);
+    }
+
+    #[test]
+    fn test_plan_totals() {
+        let estimate = |gas_limit| DisperseGasEstimate {
+            gas_limit,
+            source: disperse::GasEstimateSource::Node { margin_percent: 20 },
+        };
+        let plan = DispersePlan { batch_size: 100, batches: vec![estimate(4_000_000), estimate(1_000_000)], list_hash: None };
+        assert!(plan.is_batched());
+        assert_eq!(plan.total_gas_limit(), 5_000_000);
+        assert!(!plan.uses_heuristic());
+        assert!(!DispersePlan::single(estimate(21_000), 3).is_batched());
+    }
+
+    #[test]
+    fn test_list_hash_detects_changed_amounts() {
+        let recipients: Vec<Address> = (1..=3u64).map(Address::from_low_u64_be).collect();
+        let amounts: Vec<U256> = (1..=3u64).map(U256::from).collect();
+        let hash = list_hash(&recipients, &amounts);
+        assert_eq!(hash, list_hash(&recipients, &amounts));
+
+        let mut changed = amounts.clone();
+        changed[1] = U256::from(7u64);
+        assert_ne!(hash, list_hash(&recipients, &changed));
+        let mut swapped = recipients.clone();
+        swapped.swap(0, 2);
+        assert_ne!(hash, list_hash(&swapped, &amounts));
+    }
+}
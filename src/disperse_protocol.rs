@@ -0,0 +1,383 @@
+//! Disperse contract interfaces.
+//! Bulk Disperse can drive two contract ABIs: Beaug (`beaugDisperse`, which also emits the
+//! `BeaugDispersed` proof event) and the classic disperse.app contract (`disperseEther`).
+//! The interface a contract implements is detected from the function selectors in its
+//! bytecode. ERC-20s are sent with disperse.app's `disperseToken(token, recipients, values)`,
+//! and only to contracts whose bytecode contains that selector.
+//!
+//! Where the Beaug contract is not deployed, native sends fall back to Multicall3's
+//! `aggregate3Value`, which is deployed at the same address on most EVM chains.
+
+use crate::disperse::{self, BEAUG_DISPERSE_SELECTOR, DISPERSE_TOKEN_SELECTOR};
+use anyhow::{anyhow, Result};
+use ethers::abi::{Function, Param, ParamType, StateMutability, Token};
+use ethers::prelude::*;
+use std::fmt;
+
+/// Function selector for disperseEther(address[],uint256[]) (disperse.app)
+/// keccak256("disperseEther(address[],uint256[])") = 0xe63d38ed...
+pub const DISPERSE_ETHER_SELECTOR: [u8; 4] = [0xe6, 0x3d, 0x38, 0xed];
+
+/// Multicall3, deployed at this address on most EVM chains
+pub const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
+/// Function selector for aggregate3Value((address,bool,uint256,bytes)[]) (Multicall3)
+/// keccak256("aggregate3Value((address,bool,uint256,bytes)[])") = 0x174dea71...
+pub const AGGREGATE3_VALUE_SELECTOR: [u8; 4] = [0x17, 0x4d, 0xea, 0x71];
+
+/// Parsed [`MULTICALL3_ADDRESS`]
+pub fn multicall3_address() -> Address {
+    MULTICALL3_ADDRESS
+        .parse()
+        .expect("MULTICALL3_ADDRESS constant is invalid - this is a programming error")
+}
+
+/// Multicall3: aggregate3Value(Call3Value[] calls) with Call3Value = (target, allowFailure, value, callData).
+/// Reverts unless msg.value equals the sum of the call values.
+#[allow(deprecated)]
+fn get_aggregate3_value_function() -> Function {
+    Function {
+        name: "aggregate3Value".to_string(),
+        inputs: vec![Param {
+            name: "calls".to_string(),
+            kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
+                ParamType::Address,
+                ParamType::Bool,
+                ParamType::Uint(256),
+                ParamType::Bytes,
+            ]))),
+            internal_type: None,
+        }],
+        outputs: vec![Param {
+            name: "returnData".to_string(),
+            kind: ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Bool, ParamType::Bytes]))),
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: StateMutability::Payable,
+    }
+}
+
+/// disperse.app native function: disperseEther(address[] recipients, uint256[] values).
+/// Any value left over is refunded to the sender.
+#[allow(deprecated)]
+fn get_disperse_ether_function() -> Function {
+    Function {
+        name: "disperseEther".to_string(),
+        inputs: vec![
+            Param {
+                name: "recipients".to_string(),
+                kind: ParamType::Array(Box::new(ParamType::Address)),
+                internal_type: None,
+            },
+            Param {
+                name: "values".to_string(),
+                kind: ParamType::Array(Box::new(ParamType::Uint(256))),
+                internal_type: None,
+            },
+        ],
+        outputs: vec![],
+        constant: None,
+        state_mutability: StateMutability::Payable,
+    }
+}
+
+/// Beaug contract function signature: beaugDisperse(address[] recipients, uint256[] amounts)
+/// This is the single unified disperse function - GUI calculates amounts for equal distribution
+#[allow(deprecated)]
+fn get_beaug_disperse_function() -> Function {
+    // function beaugDisperse(address[] calldata recipients, uint256[] calldata amounts) external payable
+    Function {
+        name: "beaugDisperse".to_string(),
+        inputs: vec![
+            Param {
+                name: "recipients".to_string(),
+                kind: ParamType::Array(Box::new(ParamType::Address)),
+                internal_type: None,
+            },
+            Param {
+                name: "amounts".to_string(),
+                kind: ParamType::Array(Box::new(ParamType::Uint(256))),
+                internal_type: None,
+            },
+        ],
+        outputs: vec![],
+        constant: None,
+        state_mutability: StateMutability::Payable,
+    }
+}
+
+/// Token-disperse function signature: disperseToken(address token, address[] recipients, uint256[] values)
+/// disperse.app's ERC-20 function: the contract pulls the total with transferFrom, so it needs an allowance first
+#[allow(deprecated)]
+fn get_disperse_token_function() -> Function {
+    Function {
+        name: "disperseToken".to_string(),
+        inputs: vec![
+            Param {
+                name: "token".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "recipients".to_string(),
+                kind: ParamType::Array(Box::new(ParamType::Address)),
+                internal_type: None,
+            },
+            Param {
+                name: "values".to_string(),
+                kind: ParamType::Array(Box::new(ParamType::Uint(256))),
+                internal_type: None,
+            },
+        ],
+        outputs: vec![],
+        constant: None,
+        state_mutability: StateMutability::NonPayable,
+    }
+}
+
+/// Which disperse ABI a contract implements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisperseProtocol {
+    /// Beaug: `beaugDisperse(recipients, amounts)` with the `BeaugDispersed` event
+    Beaug,
+    /// disperse.app: `disperseEther(recipients, values)`, no events
+    DisperseApp,
+    /// Multicall3: one `aggregate3Value` call per recipient, native currency only
+    Multicall3,
+}
+
+impl fmt::Display for DisperseProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DisperseProtocol::Beaug => write!(f, "Beaug"),
+            DisperseProtocol::DisperseApp => write!(f, "disperse.app"),
+            DisperseProtocol::Multicall3 => write!(f, "Multicall3"),
+        }
+    }
+}
+
+impl DisperseProtocol {
+    /// Name of the native disperse function
+    pub fn native_function_name(&self) -> &'static str {
+        match self {
+            DisperseProtocol::Beaug => "beaugDisperse",
+            DisperseProtocol::DisperseApp => "disperseEther",
+            DisperseProtocol::Multicall3 => "aggregate3Value",
+        }
+    }
+
+    /// Calldata sending `amounts[i]` of the native currency to `recipients[i]`; the
+    /// transaction value must be the sum of `amounts`
+    pub fn native_calldata(&self, recipients: &[Address], amounts: &[U256]) -> Result<Vec<u8>> {
+        match self {
+            DisperseProtocol::Beaug | DisperseProtocol::DisperseApp => {
+                let function = if *self == DisperseProtocol::Beaug {
+                    get_beaug_disperse_function()
+                } else {
+                    get_disperse_ether_function()
+                };
+                let recipient_tokens: Vec<Token> = recipients.iter().map(|a| Token::Address(*a)).collect();
+                let amount_tokens: Vec<Token> = amounts.iter().map(|a| Token::Uint(*a)).collect();
+                Ok(function.encode_input(&[Token::Array(recipient_tokens), Token::Array(amount_tokens)])?)
+            }
+            DisperseProtocol::Multicall3 => {
+                if recipients.len() != amounts.len() {
+                    return Err(anyhow!("{} recipients but {} amounts", recipients.len(), amounts.len()));
+                }
+                // Plain value transfers: empty calldata, and any failed transfer reverts the batch
+                let calls: Vec<Token> = recipients
+                    .iter()
+                    .zip(amounts)
+                    .map(|(recipient, amount)| {
+                        Token::Tuple(vec![
+                            Token::Address(*recipient),
+                            Token::Bool(false),
+                            Token::Uint(*amount),
+                            Token::Bytes(Vec::new()),
+                        ])
+                    })
+                    .collect();
+                Ok(get_aggregate3_value_function().encode_input(&[Token::Array(calls)])?)
+            }
+        }
+    }
+
+    /// Calldata for an ERC-20 disperse with `disperseToken`; check the contract has it with
+    /// [`resolve_token_disperse`]
+    pub fn token_calldata(&self, token: Address, recipients: &[Address], amounts: &[U256]) -> Result<Vec<u8>> {
+        match self {
+            DisperseProtocol::Multicall3 => Err(anyhow!("Multicall3 can only send the native currency")),
+            _ => {
+                let recipient_tokens: Vec<Token> = recipients.iter().map(|a| Token::Address(*a)).collect();
+                let amount_tokens: Vec<Token> = amounts.iter().map(|a| Token::Uint(*a)).collect();
+                Ok(get_disperse_token_function().encode_input(&[
+                    Token::Address(token),
+                    Token::Array(recipient_tokens),
+                    Token::Array(amount_tokens),
+                ])?)
+            }
+        }
+    }
+
+    /// True if dispersals emit `BeaugDispersed` (dispersal id and recipients hash)
+    pub fn emits_dispersal_proof(&self) -> bool {
+        matches!(self, DisperseProtocol::Beaug)
+    }
+}
+
+/// Interface implemented by `code`, checked in order Beaug, disperse.app, Multicall3
+pub fn detect_protocol(code: &[u8]) -> Option<DisperseProtocol> {
+    if code.windows(4).any(|w| w == BEAUG_DISPERSE_SELECTOR) {
+        Some(DisperseProtocol::Beaug)
+    } else if code.windows(4).any(|w| w == DISPERSE_ETHER_SELECTOR) {
+        Some(DisperseProtocol::DisperseApp)
+    } else if code.windows(4).any(|w| w == AGGREGATE3_VALUE_SELECTOR) {
+        Some(DisperseProtocol::Multicall3)
+    } else {
+        None
+    }
+}
+
+/// True if Multicall3 has code on the provider's chain
+pub async fn multicall3_deployed(provider: &Provider<Http>) -> Result<bool> {
+    Ok(!provider.get_code(multicall3_address(), None).await?.is_empty())
+}
+
+/// Contract and interface for a native disperse to `contract`. If `contract` is the main
+/// Beaug address but Beaug has no code on this chain, Multicall3 is used instead when deployed.
+pub async fn resolve_native_disperse(provider: &Provider<Http>, contract: Address) -> Result<(Address, DisperseProtocol)> {
+    let code = provider.get_code(contract, None).await?;
+    if code.is_empty() {
+        if contract == disperse::main_beaug_address() && multicall3_deployed(provider).await? {
+            tracing::info!("Beaug is not deployed on this chain, falling back to Multicall3 aggregate3Value");
+            return Ok((multicall3_address(), DisperseProtocol::Multicall3));
+        }
+        return Err(anyhow!("Disperse contract {:?} has no code on this network", contract));
+    }
+    let protocol = detect_protocol(code.as_ref()).ok_or_else(|| {
+        anyhow!(
+            "Contract {:?} implements neither beaugDisperse, disperseEther nor aggregate3Value",
+            contract
+        )
+    })?;
+    Ok((contract, protocol))
+}
+
+/// Interface for an ERC-20 disperse to `contract`. Fails unless the contract is a Beaug or
+/// disperse.app contract whose bytecode contains `disperseToken`.
+pub async fn resolve_token_disperse(provider: &Provider<Http>, contract: Address) -> Result<DisperseProtocol> {
+    let code = provider.get_code(contract, None).await?;
+    if code.is_empty() {
+        return Err(anyhow!("Disperse contract {:?} has no code on this network", contract));
+    }
+    token_protocol(code.as_ref())
+        .ok_or_else(|| anyhow!("Contract {:?} does not support token disperse (no disperseToken function)", contract))
+}
+
+/// Interface used for ERC-20 disperses by `code`, if it has a token entry point
+fn token_protocol(code: &[u8]) -> Option<DisperseProtocol> {
+    if !code.windows(4).any(|w| w == DISPERSE_TOKEN_SELECTOR) {
+        return None;
+    }
+    match detect_protocol(code) {
+        Some(DisperseProtocol::Multicall3) => None,
+        // disperseToken without a native disperse function is still the disperse.app ABI
+        protocol => Some(protocol.unwrap_or(DisperseProtocol::DisperseApp)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Protocol tests ====================
+
+    #[test]
+    fn test_disperse_ether_selector() {
+        assert_eq!(get_disperse_ether_function().short_signature(), DISPERSE_ETHER_SELECTOR);
+    }
+
+    #[test]
+    fn test_disperse_token_selector() {
+        let func = get_disperse_token_function();
+        assert_eq!(func.short_signature(), ethers::utils::id("disperseToken(address,address[],uint256[])"));
+        assert_eq!(func.short_signature(), DISPERSE_TOKEN_SELECTOR);
+    }
+
+    #[test]
+    fn test_beaug_disperse_selector() {
+        let func = get_beaug_disperse_function();
+        assert_eq!(func.short_signature(), ethers::utils::id("beaugDisperse(address[],uint256[])"));
+        assert_eq!(func.short_signature(), BEAUG_DISPERSE_SELECTOR);
+    }
+
+    #[test]
+    fn test_aggregate3_value_selector() {
+        assert_eq!(get_aggregate3_value_function().short_signature(), AGGREGATE3_VALUE_SELECTOR);
+    }
+
+    #[test]
+    fn test_multicall3_calldata() {
+        let recipients = vec![Address::from_low_u64_be(1), Address::from_low_u64_be(2)];
+        let amounts = vec![U256::from(5u64), U256::from(7u64)];
+        let calldata = DisperseProtocol::Multicall3.native_calldata(&recipients, &amounts).unwrap();
+        assert_eq!(calldata[..4], AGGREGATE3_VALUE_SELECTOR);
+
+        let decoded = get_aggregate3_value_function().decode_input(&calldata[4..]).unwrap();
+        let Token::Array(calls) = &decoded[0] else { panic!("expected an array of calls") };
+        assert_eq!(calls.len(), 2);
+        assert_eq!(
+            calls[1],
+            Token::Tuple(vec![
+                Token::Address(recipients[1]),
+                Token::Bool(false),
+                Token::Uint(amounts[1]),
+                Token::Bytes(Vec::new()),
+            ])
+        );
+        assert!(DisperseProtocol::Multicall3.token_calldata(Address::zero(), &recipients, &amounts).is_err());
+    }
+
+    #[test]
+    fn test_detect_protocol() {
+        let mut code = vec![0x60, 0x80, 0x63];
+        code.extend_from_slice(&DISPERSE_ETHER_SELECTOR);
+        assert_eq!(detect_protocol(&code), Some(DisperseProtocol::DisperseApp));
+
+        code.push(0x63);
+        code.extend_from_slice(&BEAUG_DISPERSE_SELECTOR);
+        assert_eq!(detect_protocol(&code), Some(DisperseProtocol::Beaug));
+
+        assert_eq!(detect_protocol(&[0x60, 0x80]), None);
+    }
+
+    #[test]
+    fn test_token_protocol_needs_disperse_token() {
+        let mut code = vec![0x63];
+        code.extend_from_slice(&BEAUG_DISPERSE_SELECTOR);
+        assert_eq!(token_protocol(&code), None);
+
+        code.push(0x63);
+        code.extend_from_slice(&DISPERSE_TOKEN_SELECTOR);
+        assert_eq!(token_protocol(&code), Some(DisperseProtocol::Beaug));
+        assert_eq!(token_protocol(&code[5..]), Some(DisperseProtocol::DisperseApp));
+
+        let mut multicall = vec![0x63];
+        multicall.extend_from_slice(&AGGREGATE3_VALUE_SELECTOR);
+        multicall.extend_from_slice(&DISPERSE_TOKEN_SELECTOR);
+        assert_eq!(token_protocol(&multicall), None);
+    }
+
+    #[test]
+    fn test_native_calldata_per_protocol() {
+        let recipients = vec![Address::from_low_u64_be(1)];
+        let amounts = vec![U256::from(5u64)];
+        let beaug = DisperseProtocol::Beaug.native_calldata(&recipients, &amounts).unwrap();
+        let classic = DisperseProtocol::DisperseApp.native_calldata(&recipients, &amounts).unwrap();
+        assert_eq!(beaug[..4], BEAUG_DISPERSE_SELECTOR);
+        assert_eq!(classic[..4], DISPERSE_ETHER_SELECTOR);
+        // Same argument encoding, different selector
+        assert_eq!(beaug[4..], classic[4..]);
+    }
+}
@@ -0,0 +1,230 @@
+//! ENS name resolution.
+//! `.eth` names in recipient lists and address fields are resolved forward to an
+//! address, and reverse records are looked up to label addresses on screen. Names
+//! resolve on the selected network when it has an ENS registry (Ethereum, Sepolia,
+//! Holesky), otherwise over the Ethereum mainnet RPC.
+//!
+//! Resolutions are kept in a process-wide cache so the synchronous recipient
+//! parsers can turn a name into its address once it has been resolved; an
+//! unresolved name is a parse error until then.
+
+use crate::config::Config;
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, OnceLock};
+use tracing::debug;
+
+/// ENS registry, deployed at the same address on Ethereum and its testnets
+pub const ENS_REGISTRY_ADDRESS: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+
+/// Chain the mainnet ENS deployment lives on
+pub const ENS_MAINNET_CHAIN_ID: u64 = 1;
+
+/// Most reverse records looked up in one round
+pub const REVERSE_LOOKUP_BATCH: usize = 25;
+
+/// A name and the address it resolved to, or why it did not resolve
+pub type NameResolution = (String, Result<Address, String>);
+
+/// An address and its verified primary name, if any
+pub type ReverseRecord = (Address, Option<String>);
+
+#[derive(Default)]
+struct EnsCache {
+    forward: HashMap<String, Address>,
+    /// Primary name of an address; `None` when it has no (verified) reverse record
+    reverse: HashMap<Address, Option<String>>,
+    /// Addresses shown on screen whose reverse record has not been looked up yet
+    reverse_wanted: BTreeSet<Address>,
+}
+
+static CACHE: OnceLock<Mutex<EnsCache>> = OnceLock::new();
+
+fn with_cache<T>(f: impl FnOnce(&mut EnsCache) -> T) -> T {
+    let cache = CACHE.get_or_init(|| Mutex::new(EnsCache::default()));
+    let mut guard = cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&mut guard)
+}
+
+/// True for text that looks like an ENS name (`name.eth`, `sub.name.eth`)
+pub fn is_ens_name(input: &str) -> bool {
+    let name = input.trim();
+    let Some(stem) = name.to_ascii_lowercase().strip_suffix(".eth").map(str::to_string) else {
+        return false;
+    };
+    !stem.is_empty()
+        && stem.split('.').all(|label| !label.is_empty())
+        && !name.chars().any(|c| c.is_whitespace() || matches!(c, ',' | ';' | '/' | ':' | '#' | '%' | '='))
+}
+
+/// Cache key of a name (ASCII case folded; full ENSIP-15 normalization is left to the resolver)
+pub fn normalize(name: &str) -> String {
+    name.trim().to_ascii_lowercase()
+}
+
+/// Address a name resolved to, if it has been resolved
+pub fn resolved_address(name: &str) -> Option<Address> {
+    with_cache(|cache| cache.forward.get(&normalize(name)).copied())
+}
+
+/// Parse a hex address, or an ENS name that has already been resolved
+pub fn parse_address_or_name(input: &str) -> Result<Address> {
+    let input = input.trim();
+    if let Ok(address) = input.parse::<Address>() {
+        return Ok(address);
+    }
+    if is_ens_name(input) {
+        return resolved_address(input).ok_or_else(|| anyhow!("ENS name '{}' is not resolved yet", input));
+    }
+    Err(anyhow!("Invalid address format: {}", input))
+}
+
+/// Names from `names` that are not in the cache yet
+pub fn unresolved<'a>(names: impl IntoIterator<Item = &'a String>) -> Vec<String> {
+    with_cache(|cache| {
+        names
+            .into_iter()
+            .map(|name| normalize(name))
+            .filter(|name| !cache.forward.contains_key(name))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    })
+}
+
+/// Primary ENS name of an address, when its reverse record is known. Unknown addresses
+/// are queued for a reverse lookup, so views can call this for every address they show.
+pub fn reverse_name(address: Address) -> Option<String> {
+    with_cache(|cache| match cache.reverse.get(&address) {
+        Some(name) => name.clone(),
+        None => {
+            cache.reverse_wanted.insert(address);
+            None
+        }
+    })
+}
+
+/// Take up to `limit` addresses waiting for a reverse lookup
+pub fn take_reverse_requests(limit: usize) -> Vec<Address> {
+    with_cache(|cache| {
+        let batch: Vec<Address> = cache.reverse_wanted.iter().take(limit).copied().collect();
+        for address in &batch {
+            cache.reverse_wanted.remove(address);
+        }
+        batch
+    })
+}
+
+/// Record forward resolutions
+pub fn store_resolved(resolved: &[(String, Address)]) {
+    with_cache(|cache| {
+        for (name, address) in resolved {
+            cache.forward.insert(normalize(name), *address);
+        }
+    });
+}
+
+/// Record reverse lookups; a verified primary name is also a forward resolution
+pub fn store_reverse(records: &[ReverseRecord]) {
+    with_cache(|cache| {
+        for (address, name) in records {
+            if let Some(name) = name {
+                cache.forward.insert(normalize(name), *address);
+            }
+            cache.reverse.insert(*address, name.clone());
+        }
+    });
+}
+
+/// Forget every resolution (names may resolve differently on another network)
+pub fn clear_cache() {
+    with_cache(|cache| *cache = EnsCache::default());
+}
+
+/// Provider to resolve names with: the selected network when it has an ENS registry,
+/// otherwise Ethereum mainnet over `mainnet_rpc_url`
+pub async fn ens_provider(config: &Config, mainnet_rpc_url: Option<String>) -> Result<Provider<Http>> {
+    let provider = (*config.get_provider().await?).clone();
+    if config.chain_id == ENS_MAINNET_CHAIN_ID {
+        return Ok(provider);
+    }
+    let registry: Address = ENS_REGISTRY_ADDRESS.parse()?;
+    match provider.get_code(registry, None).await {
+        Ok(code) if !code.is_empty() => return Ok(provider),
+        Ok(_) => {}
+        Err(e) => debug!("Could not check for an ENS registry on chain {}: {}", config.chain_id, e),
+    }
+    let url = mainnet_rpc_url.ok_or_else(|| anyhow!("No Ethereum mainnet RPC is configured for ENS resolution"))?;
+    Ok(Provider::<Http>::try_from(url.as_str())?)
+}
+
+/// Resolve names to addresses. Each name gets its own result so one missing record
+/// does not hide the others; only a failure to reach the resolver is an error.
+pub async fn resolve_names(
+    config: Config,
+    mainnet_rpc_url: Option<String>,
+    names: Vec<String>,
+) -> Result<Vec<NameResolution>> {
+    let provider = ens_provider(&config, mainnet_rpc_url).await?;
+    let mut results = Vec::with_capacity(names.len());
+    for name in names {
+        let result = match provider.resolve_name(&name).await {
+            Ok(address) if address.is_zero() => Err("has no address record".to_string()),
+            Ok(address) => Ok(address),
+            Err(ProviderError::EnsError(_)) => Err("is not registered or has no resolver".to_string()),
+            Err(e) => return Err(anyhow!("Failed to resolve {}: {}", name, e)),
+        };
+        results.push((name, result));
+    }
+    Ok(results)
+}
+
+/// Look up the primary name of each address. Names are only kept when they resolve back
+/// to the same address; addresses without a record get `None`.
+pub async fn lookup_addresses(
+    config: Config,
+    mainnet_rpc_url: Option<String>,
+    addresses: Vec<Address>,
+) -> Result<Vec<ReverseRecord>> {
+    let provider = ens_provider(&config, mainnet_rpc_url).await?;
+    let mut records = Vec::with_capacity(addresses.len());
+    for address in addresses {
+        let name = match provider.lookup_address(address).await {
+            Ok(name) => Some(name),
+            Err(ProviderError::EnsError(_)) | Err(ProviderError::EnsNotOwned(_)) => None,
+            Err(e) => return Err(anyhow!("Reverse lookup of {:?} failed: {}", address, e)),
+        };
+        records.push((address, name));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Name syntax tests ====================
+
+    #[test]
+    fn test_is_ens_name() {
+        assert!(is_ens_name("vitalik.eth"));
+        assert!(is_ens_name(" Pay.Vitalik.ETH "));
+        assert!(!is_ens_name(".eth"));
+        assert!(!is_ens_name("eth"));
+        assert!(!is_ens_name("a..eth"));
+        assert!(!is_ens_name("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0"));
+        assert!(!is_ens_name("vitalik.eth,1.5"));
+        assert!(!is_ens_name("example.com"));
+    }
+
+    #[test]
+    fn test_parse_address_or_name_uses_cache() {
+        let address: Address = "0x00000000000000000000000000000000000e45e1".parse().unwrap();
+        assert!(parse_address_or_name("cache-test-beaug.eth").is_err());
+        store_resolved(&[("Cache-Test-Beaug.eth".to_string(), address)]);
+        assert_eq!(parse_address_or_name("cache-test-beaug.eth").unwrap(), address);
+        assert!(unresolved(&["CACHE-TEST-BEAUG.eth".to_string()]).is_empty());
+        assert!(parse_address_or_name("not an address").is_err());
+    }
+}
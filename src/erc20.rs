@@ -0,0 +1,180 @@
+//! Minimal ERC-20 helpers for token dispersal.
+//! Reads token metadata, balances and allowances over `eth_call` and encodes
+//! the `approve` call signed by the Ledger.
+
+use anyhow::{anyhow, Result};
+use ethers::abi::{self, ParamType, Token};
+use ethers::prelude::*;
+use ethers::types::transaction::eip2718::TypedTransaction;
+
+/// Function selector for symbol()
+const SYMBOL_SELECTOR: [u8; 4] = [0x95, 0xd8, 0x9b, 0x41];
+
+/// Function selector for decimals()
+pub(crate) const DECIMALS_SELECTOR: [u8; 4] = [0x31, 0x3c, 0xe5, 0x67];
+
+/// Function selector for balanceOf(address)
+const BALANCE_OF_SELECTOR: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+
+/// Function selector for allowance(address,address)
+const ALLOWANCE_SELECTOR: [u8; 4] = [0xdd, 0x62, 0xed, 0x3e];
+
+/// Function selector for approve(address,uint256)
+pub const APPROVE_SELECTOR: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+
+/// Gas limit used for approve transactions (typical cost is 25k-50k)
+pub const APPROVE_GAS_LIMIT: u64 = 80_000;
+
+/// Token metadata read from the contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub address: Address,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl TokenInfo {
+    /// Format a raw token amount with the token's decimals
+    pub fn format_amount(&self, amount: U256) -> String {
+        crate::utils::format_token_amount(amount, self.decimals)
+    }
+
+    /// Parse a decimal amount string into raw token units
+    pub fn parse_amount(&self, input: &str) -> Result<U256> {
+        crate::utils::parse_token_amount(input, self.decimals)
+    }
+}
+
+fn encode_call(selector: [u8; 4], args: &[Token]) -> Vec<u8> {
+    let mut data = selector.to_vec();
+    data.extend(abi::encode(args));
+    data
+}
+
+async fn call(provider: &Provider<Http>, to: Address, data: Vec<u8>) -> Result<Bytes> {
+    let tx: TypedTransaction = TransactionRequest::new().to(to).data(data).into();
+    Ok(provider.call(&tx, None).await?)
+}
+
+fn decode_uint(data: &[u8], what: &str) -> Result<U256> {
+    if data.len() < 32 {
+        return Err(anyhow!("Token returned no {} (is this an ERC-20 contract?)", what));
+    }
+    Ok(U256::from_big_endian(&data[..32]))
+}
+
+/// Decode a symbol returned either as `string` or, by older tokens such as MKR, as `bytes32`
+fn decode_symbol(data: &[u8]) -> Result<String> {
+    if let Ok(tokens) = abi::decode(&[ParamType::String], data) {
+        if let Some(Token::String(symbol)) = tokens.into_iter().next() {
+            return Ok(symbol);
+        }
+    }
+    if data.len() == 32 {
+        let end = data.iter().position(|b| *b == 0).unwrap_or(32);
+        if let Ok(symbol) = std::str::from_utf8(&data[..end]) {
+            return Ok(symbol.to_string());
+        }
+    }
+    Err(anyhow!("Token returned an unreadable symbol"))
+}
+
+/// Read symbol and decimals from a token contract
+pub async fn fetch_token_info(provider: &Provider<Http>, token: Address) -> Result<TokenInfo> {
+    let code = provider.get_code(token, None).await?;
+    if code.is_empty() {
+        return Err(anyhow!("No contract at {:?} on this network", token));
+    }
+
+    let (symbol_data, decimals_data) = tokio::try_join!(
+        call(provider, token, SYMBOL_SELECTOR.to_vec()),
+        call(provider, token, DECIMALS_SELECTOR.to_vec()),
+    )?;
+
+    let decimals = decode_uint(&decimals_data, "decimals")?;
+    if decimals > U256::from(77u64) {
+        return Err(anyhow!("Token reports invalid decimals {}", decimals));
+    }
+
+    Ok(TokenInfo {
+        address: token,
+        symbol: decode_symbol(&symbol_data)?,
+        decimals: decimals.as_u32() as u8,
+    })
+}
+
+/// Token balance of an address
+pub async fn balance_of(provider: &Provider<Http>, token: Address, owner: Address) -> Result<U256> {
+    let data = call(provider, token, encode_call(BALANCE_OF_SELECTOR, &[Token::Address(owner)])).await?;
+    decode_uint(&data, "balance")
+}
+
+/// Amount `spender` may transfer on behalf of `owner`
+pub async fn allowance(provider: &Provider<Http>, token: Address, owner: Address, spender: Address) -> Result<U256> {
+    let data = call(
+        provider,
+        token,
+        encode_call(ALLOWANCE_SELECTOR, &[Token::Address(owner), Token::Address(spender)]),
+    )
+    .await?;
+    decode_uint(&data, "allowance")
+}
+
+/// Calldata for approve(spender, amount)
+pub fn approve_calldata(spender: Address, amount: U256) -> Vec<u8> {
+    encode_call(APPROVE_SELECTOR, &[Token::Address(spender), Token::Uint(amount)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::utils::id;
+
+    // ==================== Selector tests ====================
+
+    #[test]
+    fn test_selectors_match_signatures() {
+        assert_eq!(SYMBOL_SELECTOR, id("symbol()"));
+        assert_eq!(DECIMALS_SELECTOR, id("decimals()"));
+        assert_eq!(BALANCE_OF_SELECTOR, id("balanceOf(address)"));
+        assert_eq!(ALLOWANCE_SELECTOR, id("allowance(address,address)"));
+        assert_eq!(APPROVE_SELECTOR, id("approve(address,uint256)"));
+    }
+
+    #[test]
+    fn test_approve_calldata_layout() {
+        let spender = Address::from_low_u64_be(0xbeef);
+        let data = approve_calldata(spender, U256::from(1000u64));
+        assert_eq!(data.len(), 4 + 64);
+        assert_eq!(&data[..4], &APPROVE_SELECTOR);
+        assert_eq!(&data[16..36], spender.as_bytes());
+        assert_eq!(U256::from_big_endian(&data[36..68]), U256::from(1000u64));
+    }
+
+    // ==================== Decoding tests ====================
+
+    #[test]
+    fn test_decode_symbol_string() {
+        let data = abi::encode(&[Token::String("USDC".to_string())]);
+        assert_eq!(decode_symbol(&data).unwrap(), "USDC");
+    }
+
+    #[test]
+    fn test_decode_symbol_bytes32() {
+        let mut data = [0u8; 32];
+        data[..3].copy_from_slice(b"MKR");
+        assert_eq!(decode_symbol(&data).unwrap(), "MKR");
+    }
+
+    #[test]
+    fn test_token_info_amounts() {
+        let usdc = TokenInfo {
+            address: Address::zero(),
+            symbol: "USDC".to_string(),
+            decimals: 6,
+        };
+        assert_eq!(usdc.parse_amount("1.5").unwrap(), U256::from(1_500_000u64));
+        assert_eq!(usdc.format_amount(U256::from(1_500_000u64)), "1.500000");
+        assert!(usdc.parse_amount("0.0000001").is_err());
+    }
+}
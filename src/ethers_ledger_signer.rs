@@ -1,6 +1,7 @@
 //! Ledger signing operations using Foundry's cast CLI tool.
 //! Handles transaction signing and broadcasting via the cast command.
 
+use crate::fee_oracle::TxFees;
 use crate::ledger_ops::get_cast_path;
 use ethers::prelude::*;
 use ethers::providers::{Http, Provider};
@@ -127,12 +128,12 @@ pub async fn sign_and_send_transaction(
     to: Address,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     _chain_id: u64,
 ) -> anyhow::Result<TxHash> {
     sign_and_send_transaction_with_derivation(
-        _provider, rpc_url, from_index, to, value, gas_limit, gas_price, nonce, _chain_id,
+        _provider, rpc_url, from_index, to, value, gas_limit, fees, nonce, _chain_id,
         crate::config::DerivationMode::AccountIndex, 0, crate::config::DEFAULT_COIN_TYPE
     ).await
 }
@@ -145,7 +146,7 @@ pub async fn sign_and_send_transaction_with_derivation(
     to: Address,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     _chain_id: u64,
     derivation_mode: crate::config::DerivationMode,
@@ -153,7 +154,7 @@ pub async fn sign_and_send_transaction_with_derivation(
     coin_type: u32,
 ) -> anyhow::Result<TxHash> {
     sign_and_send_transaction_with_full_derivation(
-        _provider, rpc_url, from_index, to, value, gas_limit, gas_price, nonce, _chain_id,
+        _provider, rpc_url, from_index, to, value, gas_limit, fees, nonce, _chain_id,
         derivation_mode, custom_account, 0, coin_type
     ).await
 }
@@ -167,7 +168,7 @@ pub async fn sign_and_send_transaction_with_full_derivation(
     to: Address,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     chain_id: u64,
     derivation_mode: crate::config::DerivationMode,
@@ -182,7 +183,8 @@ pub async fn sign_and_send_transaction_with_full_derivation(
     
     // Convert values to strings for cast
     let value_str = format!("{}wei", value);
-    let gas_price_str = format!("{}wei", gas_price);
+    let gas_price_str = format!("{}wei", fees.max_fee_per_gas);
+    let priority_fee_str = format!("{}wei", fees.max_priority_fee_per_gas);
     let to_str = format!("{:?}", to);
     
     // Check if chain supports EIP-1559 (for logging purposes)
@@ -195,8 +197,8 @@ pub async fn sign_and_send_transaction_with_full_derivation(
     );
     
     // Build cast send command
-    // Note: We always use --gas-price. For EIP-1559 chains cast will auto-calculate priority fee.
-    // For legacy chains we must explicitly pass --legacy to avoid EIP-1559 fee estimation issues.
+    // Note: --gas-price is the max fee per gas. EIP-1559 chains also get the oracle's priority fee,
+    // legacy chains must explicitly pass --legacy to avoid EIP-1559 fee estimation issues.
     // We also pass --chain to ensure correct EIP-155 signature encoding.
     let mut command = Command::new(get_cast_path());
     command
@@ -216,7 +218,9 @@ pub async fn sign_and_send_transaction_with_full_derivation(
         .arg(nonce.to_string());
     
     // Use legacy transaction type for chains that don't support EIP-1559
-    if !use_eip1559 {
+    if use_eip1559 {
+        command.arg("--priority-gas-price").arg(&priority_fee_str);
+    } else {
         command.arg("--legacy");
     }
     
@@ -229,7 +233,7 @@ pub async fn sign_and_send_transaction_with_full_derivation(
     command.arg(&to_str);
     
     info!("Cast command: cast send --ledger --hd-path {} --rpc-url <rpc> --chain {} --gas-limit {} --gas-price {} --nonce {}{} --value {} {}",
-        hd_path, chain_id, gas_limit, gas_price_str, nonce, if use_eip1559 { "" } else { " --legacy" }, value_str, to_str);
+        hd_path, chain_id, gas_limit, gas_price_str, nonce, if use_eip1559 { format!(" --priority-gas-price {}", priority_fee_str) } else { " --legacy".to_string() }, value_str, to_str);
 
     let output = run_command_with_timeout(command, CAST_TIMEOUT_SECS).await?;
     let stdout = String::from_utf8_lossy(&output.stdout);
@@ -291,12 +295,12 @@ pub async fn sign_and_send_contract_call(
     calldata: Vec<u8>,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     _chain_id: u64,
 ) -> anyhow::Result<TxHash> {
     sign_and_send_contract_call_with_derivation(
-        _provider, rpc_url, from_index, to, calldata, value, gas_limit, gas_price, nonce, _chain_id,
+        _provider, rpc_url, from_index, to, calldata, value, gas_limit, fees, nonce, _chain_id,
         crate::config::DerivationMode::AccountIndex, 0, crate::config::DEFAULT_COIN_TYPE
     ).await
 }
@@ -310,7 +314,7 @@ pub async fn sign_and_send_contract_call_with_derivation(
     calldata: Vec<u8>,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     _chain_id: u64,
     derivation_mode: crate::config::DerivationMode,
@@ -318,7 +322,7 @@ pub async fn sign_and_send_contract_call_with_derivation(
     coin_type: u32,
 ) -> anyhow::Result<TxHash> {
     sign_and_send_contract_call_with_full_derivation(
-        _provider, rpc_url, from_index, to, calldata, value, gas_limit, gas_price, nonce, _chain_id,
+        _provider, rpc_url, from_index, to, calldata, value, gas_limit, fees, nonce, _chain_id,
         derivation_mode, custom_account, 0, coin_type
     ).await
 }
@@ -333,7 +337,7 @@ pub async fn sign_and_send_contract_call_with_full_derivation(
     calldata: Vec<u8>,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     chain_id: u64,
     derivation_mode: crate::config::DerivationMode,
@@ -347,7 +351,8 @@ pub async fn sign_and_send_contract_call_with_full_derivation(
     let _lock = crate::ledger_lock::ledger_lock().lock().await;
     
     let value_str = format!("{}wei", value);
-    let gas_price_str = format!("{}wei", gas_price);
+    let gas_price_str = format!("{}wei", fees.max_fee_per_gas);
+    let priority_fee_str = format!("{}wei", fees.max_priority_fee_per_gas);
     let calldata_hex = format!("0x{}", hex::encode(&calldata));
     let to_str = format!("{:?}", to);
     
@@ -361,8 +366,8 @@ pub async fn sign_and_send_contract_call_with_full_derivation(
     );
     
     // Build cast send command
-    // Note: We always use --gas-price. For EIP-1559 chains cast will auto-calculate priority fee.
-    // For legacy chains we must explicitly pass --legacy to avoid EIP-1559 fee estimation issues.
+    // Note: --gas-price is the max fee per gas. EIP-1559 chains also get the oracle's priority fee,
+    // legacy chains must explicitly pass --legacy to avoid EIP-1559 fee estimation issues.
     // We also pass --chain to ensure correct EIP-155 signature encoding.
     let mut command = Command::new(get_cast_path());
     command
@@ -382,7 +387,9 @@ pub async fn sign_and_send_contract_call_with_full_derivation(
         .arg(nonce.to_string());
     
     // Use legacy transaction type for chains that don't support EIP-1559
-    if !use_eip1559 {
+    if use_eip1559 {
+        command.arg("--priority-gas-price").arg(&priority_fee_str);
+    } else {
         command.arg("--legacy");
     }
     
@@ -399,7 +406,7 @@ pub async fn sign_and_send_contract_call_with_full_derivation(
         calldata_hex.clone()
     };
     info!("Cast command: cast send --ledger --hd-path {} --rpc-url <rpc> --chain {} --gas-limit {} --gas-price {} --nonce {}{} {} {}",
-        hd_path, chain_id, gas_limit, gas_price_str, nonce, if use_eip1559 { "" } else { " --legacy" }, to_str, calldata_preview);
+        hd_path, chain_id, gas_limit, gas_price_str, nonce, if use_eip1559 { format!(" --priority-gas-price {}", priority_fee_str) } else { " --legacy".to_string() }, to_str, calldata_preview);
 
     let output = run_command_with_timeout(command, CAST_TIMEOUT_SECS).await?;
     let stdout = String::from_utf8_lossy(&output.stdout);
@@ -0,0 +1,281 @@
+//! EIP-1559 fee oracle.
+//! Samples `eth_feeHistory` over the last blocks to price transactions: the next block's
+//! base fee plus a priority fee taken from the slow, standard or fast reward percentile.
+//! The max fee leaves headroom for the base fee to rise while the transaction waits, so
+//! the expected cost (base + priority) is usually well below the max cost that is signed.
+//!
+//! Legacy chains, and RPCs without `eth_feeHistory`, keep using `eth_gasPrice` times the
+//! gas speed multiplier as the max fee, with a priority fee of 10% of it.
+
+use crate::config::chain_supports_eip1559;
+use anyhow::Result;
+use ethers::prelude::*;
+use tracing::{info, warn};
+
+/// Blocks sampled by `eth_feeHistory`
+pub const FEE_HISTORY_BLOCKS: u64 = 20;
+
+/// Reward percentiles sampled for the slow, standard and fast tiers
+pub const REWARD_PERCENTILES: [f64; 3] = [10.0, 50.0, 90.0];
+
+/// Priority fee share of the gas price when there is no fee history, in percent
+const FALLBACK_PRIORITY_PERCENT: u64 = 10;
+
+/// Gas speed from which the fast priority fee is scaled up by the multiplier ("Aggressive")
+pub const AGGRESSIVE_GAS_SPEED: f32 = 1.8;
+
+/// Fee preset picked from the gas speed slider
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeTier {
+    Slow,
+    Standard,
+    Fast,
+}
+
+impl FeeTier {
+    /// Tier for a gas speed multiplier, with the same bands as the speed labels
+    pub fn from_speed(gas_speed: f32) -> Self {
+        if gas_speed < 0.9 {
+            FeeTier::Slow
+        } else if gas_speed < 1.2 {
+            FeeTier::Standard
+        } else {
+            FeeTier::Fast
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeeTier::Slow => "slow",
+            FeeTier::Standard => "standard",
+            FeeTier::Fast => "fast",
+        }
+    }
+
+    fn percentile_index(self) -> usize {
+        match self {
+            FeeTier::Slow => 0,
+            FeeTier::Standard => 1,
+            FeeTier::Fast => 2,
+        }
+    }
+
+    /// Max fee headroom over the current base fee, in percent. The base fee rises at most
+    /// 12.5% per full block: 125% covers about 2 full blocks, 200% about 6.
+    fn base_fee_headroom_percent(self) -> u64 {
+        match self {
+            FeeTier::Slow => 125,
+            FeeTier::Standard | FeeTier::Fast => 200,
+        }
+    }
+}
+
+/// Per-gas fees a transaction is signed with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TxFees {
+    /// Max fee per gas, or the gas price of a legacy transaction
+    pub max_fee_per_gas: U256,
+    /// Tip to the block producer, included in the max fee
+    pub max_priority_fee_per_gas: U256,
+}
+
+impl TxFees {
+    /// Fees from a single gas price: it is the max fee, and 10% of it the priority fee.
+    /// Legacy transactions sign the gas price and ignore the priority fee.
+    pub fn from_gas_price(gas_price: U256) -> Self {
+        Self {
+            max_fee_per_gas: gas_price,
+            max_priority_fee_per_gas: gas_price * U256::from(FALLBACK_PRIORITY_PERCENT) / U256::from(100u64),
+        }
+    }
+}
+
+/// Next block's base fee and the priority fee of each tier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeMarket {
+    pub base_fee: U256,
+    /// Slow, standard and fast priority fees
+    pub priority_fees: [U256; 3],
+}
+
+impl FeeMarket {
+    /// Base fee of the pending block and the median reward of each percentile over the
+    /// sampled blocks; empty blocks (all-zero rewards) are left out
+    pub fn from_history(history: &FeeHistory) -> Option<Self> {
+        let base_fee = *history.base_fee_per_gas.last()?;
+        let mut priority_fees = [U256::zero(); 3];
+        for (tier, fee) in priority_fees.iter_mut().enumerate() {
+            let mut rewards: Vec<U256> = history
+                .reward
+                .iter()
+                .zip(history.gas_used_ratio.iter().chain(std::iter::repeat(&1.0)))
+                .filter(|(_, used)| **used > 0.0)
+                .filter_map(|(block, _)| block.get(tier).copied())
+                .collect();
+            rewards.sort();
+            *fee = rewards.get(rewards.len() / 2).copied().unwrap_or_default();
+        }
+        // Higher tiers never tip less than lower ones
+        priority_fees[1] = priority_fees[1].max(priority_fees[0]);
+        priority_fees[2] = priority_fees[2].max(priority_fees[1]);
+        Some(Self { base_fee, priority_fees })
+    }
+}
+
+/// Network fees sampled once, priced for any gas speed with `quote`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeSnapshot {
+    /// `eth_gasPrice`, used when there is no fee market
+    pub gas_price: U256,
+    /// `None` on legacy chains and when `eth_feeHistory` is unavailable
+    pub market: Option<FeeMarket>,
+}
+
+impl FeeSnapshot {
+    /// Fees for a gas speed multiplier: its tier on EIP-1559 chains, the multiplied gas price otherwise
+    pub fn quote(&self, gas_speed: f32) -> FeeQuote {
+        let speed_bp = U256::from((gas_speed * 100.0) as u64);
+        let Some(market) = self.market else {
+            let gas_price = self.gas_price * speed_bp / U256::from(100u64);
+            return FeeQuote { tier: None, base_fee: None, fees: TxFees::from_gas_price(gas_price), expected_fee_per_gas: gas_price };
+        };
+
+        let tier = FeeTier::from_speed(gas_speed);
+        let mut priority_fee = market.priority_fees[tier.percentile_index()];
+        if gas_speed >= AGGRESSIVE_GAS_SPEED {
+            priority_fee = priority_fee * speed_bp / U256::from(100u64);
+        }
+        let max_fee = market.base_fee * U256::from(tier.base_fee_headroom_percent()) / U256::from(100u64) + priority_fee;
+        FeeQuote {
+            tier: Some(tier),
+            base_fee: Some(market.base_fee),
+            fees: TxFees { max_fee_per_gas: max_fee, max_priority_fee_per_gas: priority_fee },
+            expected_fee_per_gas: market.base_fee + priority_fee,
+        }
+    }
+}
+
+/// Fees for one transaction and what they are expected to cost
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeQuote {
+    /// Tier priced from the fee history, `None` for a legacy gas price
+    pub tier: Option<FeeTier>,
+    pub base_fee: Option<U256>,
+    pub fees: TxFees,
+    /// Base fee plus priority fee, or the gas price on legacy chains
+    pub expected_fee_per_gas: U256,
+}
+
+impl FeeQuote {
+    /// Fee expected to be paid for `gas` units at the current base fee
+    pub fn expected_cost(&self, gas: u64) -> U256 {
+        self.expected_fee_per_gas * U256::from(gas)
+    }
+
+    /// Most the transaction can cost; the balance has to cover it
+    pub fn max_cost(&self, gas: u64) -> U256 {
+        self.fees.max_fee_per_gas * U256::from(gas)
+    }
+
+    /// e.g. "standard: base 12.1 + tip 1.5 gwei, max 25.7 gwei"
+    pub fn describe(&self) -> String {
+        match (self.tier, self.base_fee) {
+            (Some(tier), Some(base_fee)) => format!(
+                "{}: base {} + tip {} gwei, max {} gwei",
+                tier.label(),
+                gwei(base_fee),
+                gwei(self.fees.max_priority_fee_per_gas),
+                gwei(self.fees.max_fee_per_gas)
+            ),
+            _ => format!("{} gwei", gwei(self.fees.max_fee_per_gas)),
+        }
+    }
+}
+
+fn gwei(wei: U256) -> String {
+    let gwei = ethers::utils::format_units(wei, "gwei").unwrap_or_default();
+    match gwei.parse::<f64>() {
+        Ok(value) if value >= 1.0 => format!("{:.2}", value),
+        Ok(value) => format!("{:.4}", value),
+        Err(_) => gwei,
+    }
+}
+
+/// Sample the gas price and, on EIP-1559 chains, the fee history
+pub async fn fetch_snapshot(provider: &Provider<Http>, chain_id: u64) -> Result<FeeSnapshot> {
+    let gas_price = provider.get_gas_price().await?;
+    if !chain_supports_eip1559(chain_id) {
+        return Ok(FeeSnapshot { gas_price, market: None });
+    }
+    let market = match provider.fee_history(FEE_HISTORY_BLOCKS, BlockNumber::Latest, &REWARD_PERCENTILES).await {
+        Ok(history) => FeeMarket::from_history(&history),
+        Err(e) => {
+            warn!("eth_feeHistory failed, pricing with eth_gasPrice: {}", e);
+            None
+        }
+    };
+    Ok(FeeSnapshot { gas_price, market })
+}
+
+/// Fees to sign with at `gas_speed`
+pub async fn quote_fees(provider: &Provider<Http>, chain_id: u64, gas_speed: f32) -> Result<FeeQuote> {
+    let quote = fetch_snapshot(provider, chain_id).await?.quote(gas_speed);
+    info!("Fees at {:.1}x speed: {}", gas_speed, quote.describe());
+    Ok(quote)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gwei_u256(gwei: u64) -> U256 {
+        U256::from(gwei) * U256::exp10(9)
+    }
+
+    fn history() -> FeeHistory {
+        FeeHistory {
+            base_fee_per_gas: vec![gwei_u256(8), gwei_u256(9), gwei_u256(10)],
+            gas_used_ratio: vec![0.5, 0.0],
+            oldest_block: U256::from(100u64),
+            reward: vec![vec![gwei_u256(1), gwei_u256(2), gwei_u256(5)], vec![U256::zero(); 3]],
+        }
+    }
+
+    // ==================== Fee history tests ====================
+
+    #[test]
+    fn test_market_from_history_skips_empty_blocks() {
+        let market = FeeMarket::from_history(&history()).unwrap();
+        assert_eq!(market.base_fee, gwei_u256(10));
+        assert_eq!(market.priority_fees, [gwei_u256(1), gwei_u256(2), gwei_u256(5)]);
+        assert!(FeeMarket::from_history(&FeeHistory { base_fee_per_gas: Vec::new(), ..history() }).is_none());
+    }
+
+    #[test]
+    fn test_quote_tiers() {
+        let snapshot = FeeSnapshot { gas_price: gwei_u256(12), market: FeeMarket::from_history(&history()) };
+
+        let standard = snapshot.quote(1.0);
+        assert_eq!(standard.tier, Some(FeeTier::Standard));
+        assert_eq!(standard.fees.max_priority_fee_per_gas, gwei_u256(2));
+        assert_eq!(standard.fees.max_fee_per_gas, gwei_u256(22));
+        assert_eq!(standard.expected_cost(21_000), gwei_u256(12) * U256::from(21_000u64));
+        assert_eq!(standard.max_cost(21_000), gwei_u256(22) * U256::from(21_000u64));
+
+        let slow = snapshot.quote(0.8);
+        assert_eq!(slow.fees.max_fee_per_gas, gwei_u256(27) / U256::from(2u64));
+        assert_eq!(snapshot.quote(1.5).fees.max_priority_fee_per_gas, gwei_u256(5));
+        assert_eq!(snapshot.quote(2.0).fees.max_priority_fee_per_gas, gwei_u256(10));
+    }
+
+    #[test]
+    fn test_quote_legacy() {
+        let quote = FeeSnapshot { gas_price: gwei_u256(10), market: None }.quote(1.5);
+        assert_eq!(quote.tier, None);
+        assert_eq!(quote.fees.max_fee_per_gas, gwei_u256(15));
+        // The whole gas price must not go to the tip on EIP-1559 chains
+        assert_eq!(quote.fees.max_priority_fee_per_gas, gwei_u256(15) / U256::from(10u64));
+        assert_eq!(quote.expected_cost(2), quote.max_cost(2));
+        assert_eq!(quote.describe(), "15.00 gwei");
+    }
+}
@@ -18,7 +18,7 @@ use anyhow::{anyhow, Result};
 use eframe::{egui, egui::RichText, App, Frame, NativeOptions};
 use egui_extras;
 use ethers::prelude::Middleware;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::mem;
 use std::sync::mpsc;
 use std::thread;
@@ -29,11 +29,13 @@ use tokio::runtime::Builder;
 // Future refactoring can move more types to submodules.
 use super::async_job::AsyncJob;
 use super::helpers::{
-    calculate_disperse_gas_limit, gas_speed_emoji, gas_speed_label, gas_speed_warning,
+    gas_speed_emoji, gas_speed_label, gas_speed_warning,
     load_icon, BEAUG_LOGO_WEBP,
 };
 use super::notifications::{poll_operation_state, NotificationEntry, OperationState};
 use super::theme::{configure_style, AppTheme};
+use super::views::ens::names_in_address_list;
+use super::views::recipient_report::RecipientFix;
 
 /// GUI section enum for navigation
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -43,6 +45,13 @@ pub enum GuiSection {
     SplitRandom,
     SplitEqual,
     BulkDisperse,
+    ScanHistory,
+    Watcher,
+    Dispersals,
+    Schedule,
+    Payroll,
+    Registry,
+    Admin,
     Settings,
 }
 
@@ -56,6 +65,14 @@ pub(crate) struct CheckBalancesState {
     pub(crate) cancel_sender: Option<tokio::sync::oneshot::Sender<()>>,
     pub(crate) progress_receiver: Option<tokio::sync::mpsc::UnboundedReceiver<balance::ScanProgress>>,
     pub(crate) show_parameters: bool, // Whether to show the parameters panel vs results
+    /// Block number or UTC date/time to read balances at (blank = latest)
+    pub(crate) snapshot_input: String,
+    /// Snapshot resolved for the current scan, if historical
+    pub(crate) snapshot: Option<balance::BalanceSnapshot>,
+    /// Interrupted scan that can be resumed on this network
+    pub(crate) checkpoint: Option<crate::scan_checkpoint::ScanCheckpoint>,
+    /// Whether `checkpoint` reflects the file on disk
+    pub(crate) checkpoint_checked: bool,
 }
 
 impl Default for CheckBalancesState {
@@ -70,6 +87,10 @@ impl Default for CheckBalancesState {
             cancel_sender: None,
             progress_receiver: None,
             show_parameters: true,
+            snapshot_input: String::new(),
+            snapshot: None,
+            checkpoint: None,
+            checkpoint_checked: false,
         }
     }
 }
@@ -87,6 +108,10 @@ impl CheckBalancesState {
             cancel_sender: None,
             progress_receiver: None,
             show_parameters: true,
+            snapshot_input: String::new(),
+            snapshot: None,
+            checkpoint: None,
+            checkpoint_checked: false,
         }
     }
 
@@ -243,8 +268,9 @@ pub struct BulkDisperseState {
     pub source_selection: SourceSelectionState,
     // Gas speed for transaction priority
     pub gas_speed: f32, // 0.8=Slow, 1.0=Standard, 1.5=Fast, 2.0+=Aggressive
-    pub current_gas_price: Option<ethers::types::U256>,
-    pub gas_price_job: Option<AsyncJob<ethers::types::U256>>,
+    /// Fee history sample, priced for the chosen speed with `quote`
+    pub current_fees: Option<crate::fee_oracle::FeeSnapshot>,
+    pub fees_job: Option<AsyncJob<crate::fee_oracle::FeeSnapshot>>,
     // Source balance tracking
     pub source_address: Option<String>,
     pub source_balance: Option<ethers::types::U256>,
@@ -254,6 +280,63 @@ pub struct BulkDisperseState {
     pub contract_validation: Option<crate::disperse::ContractValidationStatus>,
     pub contract_validation_job: Option<AsyncJob<crate::disperse::ContractValidationStatus>>,
     pub last_validated_address: Option<String>,
+    // Gas plan (one estimate per batch) for the current recipients, source and contract
+    pub gas_plan: Option<crate::disperse_batch::DispersePlan>,
+    pub gas_estimate_job: Option<AsyncJob<crate::disperse_batch::DispersePlan>>,
+    /// Hash of the inputs the estimate (or running job) was made for
+    pub gas_estimate_key: Option<u64>,
+    // Pre-flight eth_call simulation of the planned call(s); a failure blocks execution
+    pub simulation_failure: Option<String>,
+    pub simulation_job: Option<AsyncJob<Option<String>>>,
+    /// Hash of the inputs the simulation (or running job) was made for
+    pub simulation_key: Option<u64>,
+    // ERC-20 token mode
+    pub token_mode: bool,
+    pub token: TokenDisperseState,
+    // Batched disperse: one queued beaugDisperse per batch when the list does not fit one block
+    pub batch_view: Option<TransactionView>,
+    pub batch_prep_job: Option<AsyncJob<TransactionQueue>>,
+    pub batch_logged: bool,
+    pub batch_log_job: Option<AsyncJob<()>>,
+    /// Reports the batch hashes to the linked payroll once all batches are mined
+    pub batch_payroll: Option<tokio::sync::oneshot::Sender<Vec<ethers::types::TxHash>>>,
+    // Multi-source disperse: the recipients are split across several funded sources
+    pub multi_source: MultiSourceState,
+}
+
+/// Funded sources and plan for a bulk disperse paid from several derived addresses
+#[derive(Default)]
+pub struct MultiSourceState {
+    pub enabled: bool,
+    /// Funded addresses from a saved scan that may pay a share
+    pub candidates: Vec<BalanceScanRecord>,
+    /// Indexes of the candidates chosen as sources
+    pub selected: std::collections::BTreeSet<u32>,
+    pub plan: Option<crate::multi_source_disperse::MultiSourcePlan>,
+    pub plan_job: Option<AsyncJob<crate::multi_source_disperse::MultiSourcePlan>>,
+    /// Hash of the inputs the plan (or running job) was made for
+    pub plan_key: Option<u64>,
+    pub plan_error: Option<String>,
+}
+
+/// ERC-20 token selection, balance/allowance and approval for token disperses
+#[derive(Default)]
+pub struct TokenDisperseState {
+    pub address_input: String,
+    pub info: Option<crate::erc20::TokenInfo>,
+    pub info_job: Option<AsyncJob<crate::erc20::TokenInfo>>,
+    pub error: Option<String>,
+    /// Total to split in equal-distribution mode, in token units
+    pub amount_input: String,
+    /// (token balance, allowance granted to the disperse contract) of the source address
+    pub position: Option<(ethers::types::U256, ethers::types::U256)>,
+    /// Position plus why the disperse contract cannot take a token disperse, if it cannot
+    pub position_job: Option<AsyncJob<(ethers::types::U256, ethers::types::U256, Option<String>)>>,
+    /// Set when `resolve_token_disperse` refuses the contract; approving it would be useless
+    pub contract_unsupported: Option<String>,
+    /// Token, source and contract the position was last fetched for
+    pub position_key: Option<String>,
+    pub approve_job: Option<AsyncJob<ethers::types::TxHash>>,
 }
 
 impl Default for BulkDisperseState {
@@ -269,8 +352,8 @@ impl Default for BulkDisperseState {
             job: None,
             source_selection: SourceSelectionState::default(),
             gas_speed: 1.0, // Normal speed by default
-            current_gas_price: None,
-            gas_price_job: None,
+            current_fees: None,
+            fees_job: None,
             // Source balance tracking
             source_address: None,
             source_balance: None,
@@ -280,6 +363,20 @@ impl Default for BulkDisperseState {
             contract_validation: None,
             contract_validation_job: None,
             last_validated_address: None,
+            gas_plan: None,
+            gas_estimate_job: None,
+            gas_estimate_key: None,
+            simulation_failure: None,
+            simulation_job: None,
+            simulation_key: None,
+            token_mode: false,
+            token: TokenDisperseState::default(),
+            batch_view: None,
+            batch_prep_job: None,
+            batch_logged: false,
+            batch_log_job: None,
+            batch_payroll: None,
+            multi_source: MultiSourceState::default(),
         }
     }
 }
@@ -288,8 +385,8 @@ impl Default for BulkDisperseState {
 pub(crate) struct BalanceViewState {
     pub(crate) index: u32,
     pub(crate) address: Option<String>,
-    pub(crate) balance: Option<String>,
-    pub(crate) job: Option<AsyncJob<(String, String)>>,
+    pub(crate) balance: Option<ethers::types::U256>,
+    pub(crate) job: Option<AsyncJob<(String, ethers::types::U256)>>,
     pub(crate) error: Option<String>,
 }
 
@@ -324,6 +421,255 @@ impl Default for LogViewState {
     }
 }
 
+/// State for the stored scan history panel
+#[derive(Default)]
+pub(crate) struct ScanHistoryState {
+    pub(crate) scans: Vec<crate::scan_history::StoredScan>,
+    pub(crate) loaded: bool,
+    pub(crate) error: Option<String>,
+    /// Show scans from every network instead of only the selected one
+    pub(crate) show_all_networks: bool,
+    /// Older scan selected for the diff view
+    pub(crate) diff_base: Option<String>,
+    /// Newer scan selected for the diff view
+    pub(crate) diff_target: Option<String>,
+}
+
+/// State for the proof-of-dispersal and on-chain history panels
+pub(crate) struct DispersalsState {
+    /// Transaction hash or dispersal id to verify
+    pub(crate) verify_lookup: String,
+    /// Recipient list in the order it was dispersed (same format as Bulk Disperse)
+    pub(crate) verify_recipients: String,
+    pub(crate) verify_job: Option<AsyncJob<crate::dispersal_proof::DispersalVerification>>,
+    pub(crate) verify_result: Option<crate::dispersal_proof::DispersalVerification>,
+    pub(crate) verify_error: Option<String>,
+    /// Sender addresses to search history for, one per line
+    pub(crate) history_senders: String,
+    /// Blocks to search on networks where the deployment block cannot be found
+    pub(crate) history_lookback_blocks: u64,
+    pub(crate) history_job: Option<AsyncJob<Vec<crate::dispersal_history::NetworkDispersals>>>,
+    pub(crate) history_progress_receiver: Option<tokio::sync::mpsc::UnboundedReceiver<crate::dispersal_history::HistoryProgress>>,
+    /// Latest progress per chain id while a search runs
+    pub(crate) history_progress: HashMap<u64, crate::dispersal_history::HistoryProgress>,
+    pub(crate) history: Vec<crate::dispersal_history::NetworkDispersals>,
+    /// Number of Ledger indexes to derive as senders
+    pub(crate) derive_count: u32,
+    pub(crate) derive_job: Option<AsyncJob<Vec<ethers::types::Address>>>,
+    pub(crate) history_error: Option<String>,
+}
+
+impl Default for DispersalsState {
+    fn default() -> Self {
+        Self {
+            verify_lookup: String::new(),
+            verify_recipients: String::new(),
+            verify_job: None,
+            verify_result: None,
+            verify_error: None,
+            history_senders: String::new(),
+            history_lookback_blocks: crate::dispersal_history::DEFAULT_LOOKBACK_BLOCKS,
+            history_job: None,
+            history_progress_receiver: None,
+            history_progress: HashMap::new(),
+            history: Vec::new(),
+            derive_count: 5,
+            derive_job: None,
+            history_error: None,
+        }
+    }
+}
+
+/// State for the Beaug registry panel
+#[derive(Default)]
+pub(crate) struct RegistryState {
+    pub(crate) job: Option<AsyncJob<crate::registry::RegistrySnapshot>>,
+    pub(crate) snapshot: Option<crate::registry::RegistrySnapshot>,
+    /// Chain the snapshot was loaded for
+    pub(crate) snapshot_chain_id: Option<u64>,
+    pub(crate) error: Option<String>,
+    /// Contract to register
+    pub(crate) register_address: String,
+    /// Ledger index that signs the registration (must be the registry owner)
+    pub(crate) signer_index: u32,
+    pub(crate) signer_address: Option<ethers::types::Address>,
+    pub(crate) signer_job: Option<AsyncJob<ethers::types::Address>>,
+    pub(crate) register_job: Option<AsyncJob<ethers::types::TxHash>>,
+}
+
+/// State for the contract owner/admin console
+pub(crate) struct AdminState {
+    /// Contract to administer
+    pub(crate) contract_address: String,
+    pub(crate) status_job: Option<AsyncJob<crate::contract_admin::AdminStatus>>,
+    pub(crate) status: Option<crate::contract_admin::AdminStatus>,
+    pub(crate) error: Option<String>,
+    /// Ledger index that signs admin transactions
+    pub(crate) signer_index: u32,
+    pub(crate) signer_address: Option<ethers::types::Address>,
+    pub(crate) signer_job: Option<AsyncJob<ethers::types::Address>>,
+    /// New owner for transferOwnership
+    pub(crate) new_owner: String,
+    pub(crate) review_job: Option<AsyncJob<crate::contract_admin::AdminReview>>,
+    /// Prepared transaction awaiting confirmation
+    pub(crate) review: Option<crate::contract_admin::AdminReview>,
+    pub(crate) execute_job: Option<AsyncJob<ethers::types::TxHash>>,
+    // Deterministic Beaug deployment
+    /// Beaug and deployer presence, for the chain it was read on
+    pub(crate) deploy_target: Option<(u64, crate::beaug_deploy::DeployTargetStatus)>,
+    pub(crate) deploy_target_job: Option<AsyncJob<crate::beaug_deploy::DeployTargetStatus>>,
+    /// Creation bytecode loaded from an artifact file, with the file name
+    pub(crate) deploy_bytecode: Option<(String, ethers::types::Bytes)>,
+    /// CREATE2 salt, blank for zero
+    pub(crate) deploy_salt: String,
+    pub(crate) deploy_review_job: Option<AsyncJob<crate::beaug_deploy::DeployReview>>,
+    pub(crate) deploy_review: Option<crate::beaug_deploy::DeployReview>,
+    pub(crate) deploy_execute_job: Option<AsyncJob<crate::beaug_deploy::DeployOutcome>>,
+    pub(crate) deploy_outcome: Option<crate::beaug_deploy::DeployOutcome>,
+}
+
+impl Default for AdminState {
+    fn default() -> Self {
+        Self {
+            contract_address: crate::disperse::MAIN_BEAUG_ADDRESS.to_string(),
+            status_job: None,
+            status: None,
+            error: None,
+            signer_index: 0,
+            signer_address: None,
+            signer_job: None,
+            new_owner: String::new(),
+            review_job: None,
+            review: None,
+            execute_job: None,
+            deploy_target: None,
+            deploy_target_job: None,
+            deploy_bytecode: None,
+            deploy_salt: String::new(),
+            deploy_review_job: None,
+            deploy_review: None,
+            deploy_execute_job: None,
+            deploy_outcome: None,
+        }
+    }
+}
+
+/// State for the background balance watcher and its panel
+#[derive(Default)]
+pub(crate) struct WatcherState {
+    /// Balance each address's changes are measured against, per (chain_id, address)
+    pub(crate) baselines: crate::balance_watcher::WatchBalances,
+    /// Balance seen in the latest polling round, per (chain_id, address)
+    pub(crate) latest: crate::balance_watcher::WatchBalances,
+    pub(crate) job: Option<AsyncJob<crate::balance_watcher::WatchPollResult>>,
+    pub(crate) last_poll: Option<std::time::Instant>,
+    pub(crate) last_errors: Vec<String>,
+    // Add-to-watch-list form
+    pub(crate) form_label: String,
+    pub(crate) form_address: String,
+    pub(crate) form_derivation_index: u32,
+    pub(crate) form_from_ledger: Option<u32>,
+    pub(crate) form_threshold: String,
+    pub(crate) form_chain_ids: Vec<u64>,
+    pub(crate) form_error: Option<String>,
+    pub(crate) derive_job: Option<AsyncJob<(u32, ethers::types::Address)>>,
+    // Address book form
+    pub(crate) book_label: String,
+    pub(crate) book_address: String,
+    pub(crate) book_error: Option<String>,
+}
+
+/// USD price of the selected network's native token
+#[derive(Default)]
+pub(crate) struct PriceState {
+    pub(crate) quote: Option<crate::price_feed::PriceQuote>,
+    /// Fetch in flight, tagged with the chain it was started for
+    pub(crate) job: Option<AsyncJob<(u64, Option<crate::price_feed::PriceQuote>)>>,
+    pub(crate) last_fetch: Option<std::time::Instant>,
+    pub(crate) error: Option<String>,
+}
+
+/// ENS name resolutions requested from the address inputs, and reverse lookups for display
+#[derive(Default)]
+pub(crate) struct EnsState {
+    /// Forward resolution in flight, tagged with the chain it was started for
+    pub(crate) resolve_job: Option<AsyncJob<(u64, Vec<crate::ens::NameResolution>)>>,
+    /// Names that did not resolve, with the reason
+    pub(crate) failed: std::collections::BTreeMap<String, String>,
+    /// Resolutions the user has checked, as `(name, address)`
+    pub(crate) confirmed: std::collections::BTreeSet<(String, ethers::types::Address)>,
+    pub(crate) reverse_job: Option<AsyncJob<(u64, Vec<crate::ens::ReverseRecord>)>>,
+    /// Resolver unreachable: last error and when, to back off before retrying
+    pub(crate) error: Option<(String, std::time::Instant)>,
+}
+
+/// Code and balance lookups behind the recipient validation report
+#[derive(Default)]
+pub(crate) struct RecipientCheckState {
+    /// What the selected network says about each recipient checked so far
+    pub(crate) onchain: crate::recipient_check::OnchainInfos,
+    /// Lookup in flight, tagged with the chain it was started for
+    pub(crate) job: Option<AsyncJob<(u64, crate::recipient_check::OnchainInfos)>>,
+    pub(crate) error: Option<String>,
+}
+
+/// Scheduled runs and the scheduler that starts them
+#[derive(Default)]
+pub(crate) struct ScheduleState {
+    /// Every scheduled run, oldest first, as stored on disk
+    pub(crate) runs: Vec<crate::schedule::ScheduledRun>,
+    pub(crate) loaded: bool,
+    /// Latest block of the selected network, as (chain_id, block)
+    pub(crate) block: Option<(u64, u64)>,
+    pub(crate) block_job: Option<AsyncJob<(u64, u64)>>,
+    pub(crate) last_tick: Option<std::time::Instant>,
+    /// Trigger-time checks of a due run, with the run's id
+    pub(crate) check_job: Option<(String, AsyncJob<crate::schedule::TriggerCheck>)>,
+    /// Bulk disperse started by a run, with the run's id
+    pub(crate) run_job: Option<(String, AsyncJob<()>)>,
+    /// Split started by a run, with the run's id; it ends once its queue is signed or closed
+    pub(crate) split_run: Option<(String, SplitSelector)>,
+    /// Why a due run has not started yet, by run id
+    pub(crate) waiting: std::collections::HashMap<String, String>,
+    pub(crate) error: Option<String>,
+    /// Closing was held back once because runs are pending
+    pub(crate) close_warned: bool,
+    // Schedule form
+    pub(crate) form_label: String,
+    pub(crate) form_trigger: String,
+    pub(crate) form_max_gwei: String,
+    pub(crate) form_error: Option<String>,
+}
+
+/// Bulk disperse paying a payroll cycle, waiting for its transaction to be mined
+pub(crate) struct PendingPayrollRun {
+    pub(crate) payroll_id: String,
+    pub(crate) payments: Vec<(ethers::types::Address, ethers::types::U256)>,
+    /// Sends the transaction hashes once every batch is mined; dropped if the disperse fails
+    pub(crate) tx_hashes: tokio::sync::oneshot::Receiver<Vec<ethers::types::TxHash>>,
+}
+
+/// Recurring payroll distributions and the disperse paying the due one
+#[derive(Default)]
+pub(crate) struct PayrollState {
+    pub(crate) payrolls: Vec<crate::payroll::Payroll>,
+    pub(crate) loaded: bool,
+    /// Due cycles already announced, as (payroll id, cycle)
+    pub(crate) reminded: std::collections::HashSet<(String, u32)>,
+    /// Payroll whose due cycle is loaded in the bulk disperse form
+    pub(crate) linked: Option<String>,
+    pub(crate) pending: Option<PendingPayrollRun>,
+    pub(crate) error: Option<String>,
+    // New payroll form
+    pub(crate) form_name: String,
+    pub(crate) form_recipients: String,
+    pub(crate) form_recurrence: crate::payroll::Recurrence,
+    pub(crate) form_first_due: String,
+    pub(crate) form_source_index: u32,
+    pub(crate) form_contract: String,
+    pub(crate) form_error: Option<String>,
+}
+
 impl OperationState for SplitState {
     fn job_mut(&mut self) -> &mut Option<AsyncJob<()>> {
         &mut self.job
@@ -416,6 +762,16 @@ pub struct GuiApp {
     pub(crate) bulk_disperse_state: BulkDisperseState,
     pub(crate) balance_view: BalanceViewState,
     pub(crate) log_view: LogViewState,
+    pub(crate) scan_history: ScanHistoryState,
+    pub(crate) watcher: WatcherState,
+    pub(crate) dispersals: DispersalsState,
+    pub(crate) registry: RegistryState,
+    pub(crate) admin: AdminState,
+    pub(crate) price: PriceState,
+    pub(crate) ens: EnsState,
+    pub(crate) recipient_check: RecipientCheckState,
+    pub(crate) schedule: ScheduleState,
+    pub(crate) payroll: PayrollState,
     // Network selection
     pub(crate) network_selection: NetworkSelection,
     pub(crate) custom_rpc: String,
@@ -517,6 +873,16 @@ impl GuiApp {
             bulk_disperse_state: BulkDisperseState::default(),
             balance_view: BalanceViewState::default(),
             log_view: LogViewState::default(),
+            scan_history: ScanHistoryState::default(),
+            watcher: WatcherState::default(),
+            dispersals: DispersalsState::default(),
+            registry: RegistryState::default(),
+            admin: AdminState::default(),
+            price: PriceState::default(),
+            ens: EnsState::default(),
+            recipient_check: RecipientCheckState::default(),
+            schedule: ScheduleState::default(),
+            payroll: PayrollState::default(),
             network_selection,
             custom_rpc: String::new(),
             use_custom_rpc: false,
@@ -575,6 +941,14 @@ impl GuiApp {
         self.balance_view = BalanceViewState::default();
         self.check_state.result = None;
         self.check_state.error = None;
+        self.check_state.snapshot = None;
+        self.check_state.checkpoint_checked = false;
+        self.price = PriceState::default();
+        self.ens = EnsState::default();
+        crate::ens::clear_cache();
+        self.recipient_check = RecipientCheckState::default();
+        self.bulk_disperse_state.token = TokenDisperseState::default();
+        self.payroll.linked = None;
     }
 
     pub(crate) fn spawn_job<T, FutBuilder, Fut>(&self, builder: FutBuilder) -> AsyncJob<T>
@@ -604,14 +978,19 @@ impl GuiApp {
             // Process all available progress updates
             while let Ok(progress) = receiver.try_recv() {
                 match progress {
+                    balance::ScanProgress::SnapshotResolved(snapshot) => {
+                        self.check_state.snapshot = Some(snapshot);
+                    }
                     balance::ScanProgress::AddressFound(record) => {
                         self.check_state.streaming_records.push(record);
                     }
                     balance::ScanProgress::Completed(result) => {
-                        // Log the balance scan operation
+                        // Log the balance scan operation and keep it in the scan history
                         self.log_balance_scan(&result);
+                        self.save_scan_to_history(&result);
                         self.check_state.result = Some(result);
                         self.check_state.error = None;
+                        self.check_state.checkpoint_checked = false;
                         scan_completed = true;
                         break;
                     }
@@ -633,8 +1012,13 @@ impl GuiApp {
                     self.check_state.error = Some(e.to_string());
                     self.notifications
                         .push_back(NotificationEntry::new(format!("Balance scan failed: {}", e)));
+                    // Nothing was scanned (e.g. snapshot resolution failed), so go back to the parameters
+                    if self.check_state.streaming_records.is_empty() {
+                        self.check_state.show_parameters = true;
+                    }
                 }
                 // Clean up on completion or error
+                self.check_state.checkpoint_checked = false;
                 self.check_state.job = None;
                 self.check_state.progress_receiver = None;
                 self.check_state.cancel_sender = None;
@@ -655,8 +1039,13 @@ impl GuiApp {
                     // Log the failure
                     let chain_id = self.config.chain_id;
                     let network_label = self.config.network_label().to_string();
+                    let operation = if self.bulk_disperse_state.token_mode {
+                        "Beaug Token Disperse"
+                    } else {
+                        "Beaug Bulk Disperse"
+                    };
                     let _ = crate::operation_log::append_log(
-                        "Beaug Bulk Disperse",
+                        operation,
                         chain_id,
                         format!(
                             "Bulk disperse FAILED on {} (Chain ID: {})\nError: {}",
@@ -671,6 +1060,12 @@ impl GuiApp {
         
         self.notifications = notifications;
 
+        self.poll_balance_watcher();
+        self.poll_price_feed();
+        self.poll_ens();
+        self.poll_schedule();
+        self.poll_payroll();
+
         // Poll source selection scan jobs for split states
         Self::poll_source_selection(&mut self.split_random.source_selection);
         Self::poll_source_selection(&mut self.split_equal.source_selection);
@@ -729,18 +1124,18 @@ impl GuiApp {
             }
         }
 
-        // Poll gas price job for bulk disperse
-        if let Some(job) = &mut self.bulk_disperse_state.gas_price_job {
+        // Poll fee oracle job for bulk disperse
+        if let Some(job) = &mut self.bulk_disperse_state.fees_job {
             if let Some(res) = job.poll() {
                 match res {
-                    Ok(gas_price) => {
-                        self.bulk_disperse_state.current_gas_price = Some(gas_price);
+                    Ok(fees) => {
+                        self.bulk_disperse_state.current_fees = Some(fees);
                     }
                     Err(_) => {
-                        self.bulk_disperse_state.current_gas_price = None;
+                        self.bulk_disperse_state.current_fees = None;
                     }
                 }
-                self.bulk_disperse_state.gas_price_job = None;
+                self.bulk_disperse_state.fees_job = None;
             }
         }
         
@@ -761,6 +1156,44 @@ impl GuiApp {
             }
         }
 
+        // Poll gas limit estimate for bulk disperse
+        if let Some(job) = &mut self.bulk_disperse_state.gas_estimate_job {
+            if let Some(res) = job.poll() {
+                self.bulk_disperse_state.gas_estimate_job = None;
+                match res {
+                    Ok(plan) => {
+                        let changed = self.bulk_disperse_state.gas_plan.as_ref().map(|p| p.total_gas_limit()) != Some(plan.total_gas_limit());
+                        self.bulk_disperse_state.gas_plan = Some(plan);
+                        // The fee changed, so the amount available to send did too
+                        if changed && !self.bulk_disperse_state.token_mode {
+                            self.auto_calculate_amount_silent();
+                        }
+                    }
+                    Err(e) => {
+                        self.bulk_disperse_state.gas_plan = None;
+                        self.notifications.push_back(NotificationEntry::new(format!("[!!] Gas estimation failed: {}", e)));
+                    }
+                }
+            }
+        }
+
+        // Poll pre-flight simulation for bulk disperse
+        if let Some(job) = &mut self.bulk_disperse_state.simulation_job {
+            if let Some(res) = job.poll() {
+                self.bulk_disperse_state.simulation_job = None;
+                match res {
+                    Ok(failure) => self.bulk_disperse_state.simulation_failure = failure,
+                    Err(e) => {
+                        self.bulk_disperse_state.simulation_failure = None;
+                        self.notifications.push_back(NotificationEntry::new(format!("[!!] Could not simulate disperse: {}", e)));
+                    }
+                }
+            }
+        }
+
+        self.poll_token_disperse_jobs();
+        self.poll_disperse_batch_jobs();
+
         // Auto-refresh Ledger status based on user-configured interval
         let refresh_interval = self.user_settings.ledger_refresh_interval_secs;
         if refresh_interval > 0 {
@@ -789,7 +1222,10 @@ impl GuiApp {
         let mut invalid_count = 0;
 
         // Check if this is a balance scanner format by looking at headers
-        let mut rdr = csv::Reader::from_reader(contents.as_bytes());
+        // (`#` lines such as the snapshot header are skipped)
+        let mut rdr = csv::ReaderBuilder::new()
+            .comment(Some(b'#'))
+            .from_reader(contents.as_bytes());
         let mut is_balance_scanner_format = false;
 
         if let Ok(headers) = rdr.headers() {
@@ -812,8 +1248,11 @@ impl GuiApp {
                         let address = record.get(1).unwrap_or("").trim_matches('"').trim();
                         let status = record.get(4).unwrap_or("").trim_matches('"').trim();
 
-                        // Only include EMPTY addresses (not funded) with valid format
-                        if status == "Empty" && !address.is_empty() {
+                        // Only include fresh addresses (legacy "Empty" included) with valid format
+                        let is_fresh = balance::AddressActivity::from_label(status)
+                            .map(|activity| activity.is_fresh())
+                            .unwrap_or(false);
+                        if is_fresh && !address.is_empty() {
                             // Basic validation: should start with 0x and be 42 chars long
                             if address.starts_with("0x") && address.len() == 42 {
                                 result_lines.push(address.to_string());
@@ -827,7 +1266,7 @@ impl GuiApp {
                         let amount = record.get(1).unwrap_or("").trim_matches('"').trim();
 
                         if !address.is_empty() {
-                            if address.starts_with("0x") && address.len() == 42 {
+                            if crate::recipient_input::is_address_column(address) {
                                 if amount.is_empty() {
                                     // Just address, will be used for equal distribution
                                     result_lines.push(address.to_string());
@@ -842,7 +1281,7 @@ impl GuiApp {
                     } else if record.len() == 1 {
                         // Single column: just addresses
                         let address = record.get(0).unwrap_or("").trim_matches('"').trim();
-                        if !address.is_empty() && address.starts_with("0x") && address.len() == 42 {
+                        if crate::recipient_input::is_address_column(address) {
                             result_lines.push(address.to_string());
                         } else {
                             invalid_count += 1;
@@ -1033,7 +1472,7 @@ impl GuiApp {
     }
 
     /// Helper to block on async operations in the GUI context
-    fn block_on_async<T>(fut: impl std::future::Future<Output = T>) -> T {
+    pub(crate) fn block_on_async<T>(fut: impl std::future::Future<Output = T>) -> T {
         // Try to use existing runtime handle, or create a new one
         if let Ok(handle) = tokio::runtime::Handle::try_current() {
             // We're in an async context, use block_in_place
@@ -1068,7 +1507,6 @@ impl GuiApp {
             }
 
             // Extract all needed values first to avoid borrowing conflicts
-            let gas_price = self.bulk_disperse_state.current_gas_price.unwrap_or_default();
             let config_clone = self.config.clone();
 
             // Get the transaction view and queue
@@ -1088,13 +1526,11 @@ impl GuiApp {
 
                 // Create a job to re-randomize amounts for pending transactions
                 let rerandomize_job = self.spawn_job({
-                    let gas_price_clone = gas_price;
                     let gas_speed_clone = gas_speed;
                     let source_idx_clone = source_idx;
                     let config_chain_id_clone = config_chain_id;
                     move || {
                         let config = config_clone;
-                        let gas_price = gas_price_clone;
                         let _gas_speed = gas_speed_clone; // Unused but kept for future use
                         let source_idx = source_idx_clone;
                         let config_chain_id = config_chain_id_clone;
@@ -1124,6 +1560,13 @@ impl GuiApp {
                         return Ok::<(), anyhow::Error>(());
                     }
 
+                    // Price the reserve at the max fee the pending transfers were prepared with
+                    let gas_price = transactions
+                        .iter()
+                        .map(|tx| tx.transaction.fees.max_fee_per_gas)
+                        .max()
+                        .unwrap_or_default();
+
                     // Get source balance to calculate remaining
                     let provider = config.get_provider().await?;
                     let source_addr = ledger_ops::get_ledger_address_with_config(
@@ -1202,8 +1645,8 @@ impl GuiApp {
         }
         
         // Need both gas price and source balance for accurate calculation
-        let gas_price = match self.bulk_disperse_state.current_gas_price {
-            Some(gp) => gp,
+        let fees = match self.bulk_disperse_state.current_fees.clone() {
+            Some(fees) => fees,
             None => {
                 self.notifications.push_back(NotificationEntry::new("[!!] Waiting for gas price..."));
                 return;
@@ -1218,12 +1661,9 @@ impl GuiApp {
             }
         };
 
-        match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input) {
+        match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id) {
             Ok(disperse_type) => {
-                let recipient_count = match &disperse_type {
-                    bulk_disperse::BulkDisperseType::Equal(addresses) => addresses.len(),
-                    bulk_disperse::BulkDisperseType::Mixed(recipients) => recipients.len(),
-                };
+                let recipient_count = disperse_type.len();
 
                 if recipient_count == 0 {
                     self.notifications.push_back(NotificationEntry::new("[!!] No valid recipients found"));
@@ -1242,10 +1682,9 @@ impl GuiApp {
                 // Using updated formula: 150k base + 120k per recipient
                 // Note: Add 1 to recipient count if there's a tip (tip recipient added by backend)
                 let total_recipients = if tip_wei.is_zero() { recipient_count } else { recipient_count + 1 };
-                let gas_limit = calculate_disperse_gas_limit(total_recipients);
-                let speed = self.bulk_disperse_state.gas_speed;
-                let adjusted_gas_price = gas_price * ethers::types::U256::from((speed * 100.0) as u64) / 100;
-                let estimated_gas_cost = adjusted_gas_price * ethers::types::U256::from(gas_limit);
+                let gas_limit = self.disperse_gas_limit(total_recipients);
+                // The max fee has to be covered, not just the expected one
+                let estimated_gas_cost = fees.quote(self.bulk_disperse_state.gas_speed).max_cost(gas_limit);
                 // Add 5% buffer for gas price fluctuations between calculation and execution
                 let estimated_gas_cost_buffered = estimated_gas_cost * 105u64 / 100u64;
 
@@ -1286,7 +1725,7 @@ impl GuiApp {
                         // Return specified amounts + tip (backend expects total including tip for mixed)
                         total_needed
                     }
-                    bulk_disperse::BulkDisperseType::Equal(_) => {
+                    bulk_disperse::BulkDisperseType::Equal(_) | bulk_disperse::BulkDisperseType::Weighted(_) => {
                         // For equal: subtract tip from available (backend adds it back)
                         if available > tip_wei { available - tip_wei } else { ethers::types::U256::zero() }
                     }
@@ -1306,7 +1745,7 @@ impl GuiApp {
                             recipient_count
                         )));
                     }
-                    bulk_disperse::BulkDisperseType::Equal(_) => {
+                    bulk_disperse::BulkDisperseType::Equal(_) | bulk_disperse::BulkDisperseType::Weighted(_) => {
                         self.notifications.push_back(NotificationEntry::new(format!(
                             "[OK] Calculated: {} {} available ({} {} source - {} {} gas+buffer - {} {} reserve)",
                             utils::format_ether(amount_to_send), native_token,
@@ -1337,8 +1776,8 @@ impl GuiApp {
             return;
         }
         
-        let gas_price = match self.bulk_disperse_state.current_gas_price {
-            Some(gp) => gp,
+        let fees = match self.bulk_disperse_state.current_fees.clone() {
+            Some(fees) => fees,
             None => return,
         };
         
@@ -1347,11 +1786,8 @@ impl GuiApp {
             None => return,
         };
 
-        if let Ok(disperse_type) = bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input) {
-            let recipient_count = match &disperse_type {
-                bulk_disperse::BulkDisperseType::Equal(addresses) => addresses.len(),
-                bulk_disperse::BulkDisperseType::Mixed(recipients) => recipients.len(),
-            };
+        if let Ok(disperse_type) = bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id) {
+            let recipient_count = disperse_type.len();
 
             if recipient_count == 0 {
                 return;
@@ -1368,10 +1804,9 @@ impl GuiApp {
             // Calculate gas cost (with speed multiplier)
             // Note: Add 1 to recipient count if there's a tip (tip recipient added by backend)
             let total_recipients = if tip_wei.is_zero() { recipient_count } else { recipient_count + 1 };
-            let gas_limit = calculate_disperse_gas_limit(total_recipients);
-            let speed = self.bulk_disperse_state.gas_speed;
-            let adjusted_gas_price = gas_price * ethers::types::U256::from((speed * 100.0) as u64) / 100;
-            let estimated_gas_cost = adjusted_gas_price * ethers::types::U256::from(gas_limit);
+            let gas_limit = self.disperse_gas_limit(total_recipients);
+            // The max fee has to be covered, not just the expected one
+            let estimated_gas_cost = fees.quote(self.bulk_disperse_state.gas_speed).max_cost(gas_limit);
             // Add 5% buffer for gas price fluctuations between calculation and execution
             let estimated_gas_cost_buffered = estimated_gas_cost * 105u64 / 100u64;
 
@@ -1389,7 +1824,7 @@ impl GuiApp {
                         // Include tip in total for mixed distribution
                         total_specified + tip_wei
                     }
-                    bulk_disperse::BulkDisperseType::Equal(_) => {
+                    bulk_disperse::BulkDisperseType::Equal(_) | bulk_disperse::BulkDisperseType::Weighted(_) => {
                         // For equal: available minus tip (backend adds tip back)
                         let available = source_balance - reserved;
                         if available > tip_wei { available - tip_wei } else { ethers::types::U256::zero() }
@@ -1430,6 +1865,41 @@ impl GuiApp {
         }
     }
 
+    /// Validation report over the typed split receivers, applying the fix the user picks
+    fn render_split_recipient_report(&mut self, ui: &mut egui::Ui, selector: SplitSelector, id_source: &str) {
+        let state = self.split_state(selector);
+        let entries: Vec<String> = state.recipient_addresses.split(',').map(|entry| entry.trim().to_string()).collect();
+        let (positions, recipients): (Vec<usize>, Vec<crate::recipient_check::CheckedRecipient>) = entries
+            .iter()
+            .enumerate()
+            .filter_map(|(position, entry)| {
+                let address = crate::ens::parse_address_or_name(entry).ok()?;
+                Some((position, crate::recipient_check::CheckedRecipient { text: entry.clone(), address }))
+            })
+            .unzip();
+        let source_index = Self::parse_optional_usize(&state.source_index);
+        let sources: Vec<ethers::types::Address> = state
+            .source_selection
+            .get_all_funded_addresses()
+            .into_iter()
+            .filter(|record| Some(record.index as usize) == source_index)
+            .map(|record| record.address)
+            .collect();
+
+        let mut entries = entries;
+        match self.render_recipient_report(ui, id_source, &recipients, &sources) {
+            Some(RecipientFix::Remove(index)) => {
+                entries.remove(positions[index]);
+            }
+            Some(RecipientFix::Replace(index, address)) => {
+                entries[positions[index]] = ethers::utils::to_checksum(&address, None);
+            }
+            None => return,
+        }
+        entries.retain(|entry| !entry.is_empty());
+        self.split_state(selector).recipient_addresses = entries.join(", ");
+    }
+
     pub(crate) fn append_split_recipient(&mut self, selector: SplitSelector, address: &str) {
         let state = self.split_state(selector);
         let recipients = &mut state.recipient_addresses;
@@ -1515,7 +1985,7 @@ impl GuiApp {
 
                 let valid_addresses: Vec<String> = addresses
                     .into_iter()
-                    .filter(|addr| addr.starts_with("0x") && addr.len() == 42)
+                    .filter(|addr| (addr.starts_with("0x") && addr.len() == 42) || crate::ens::is_ens_name(addr))
                     .collect();
 
                 if valid_addresses.is_empty() { None } else { Some(valid_addresses) }
@@ -1559,8 +2029,11 @@ impl GuiApp {
                 let state = self.split_state(selector);
                 ui.add(egui::TextEdit::singleline(&mut state.recipient_addresses)
                     .desired_width(300.0)
-                    .hint_text("comma-separated addresses"));
+                    .hint_text("comma-separated addresses or ENS names"));
             });
+            let ens_names = names_in_address_list(&self.split_state(selector).recipient_addresses);
+            self.render_ens_resolutions(ui, mode.title, &ens_names);
+            self.render_split_recipient_report(ui, selector, mode.title);
             ui.add_space(spacing_sm);
 
             // Gas speed row - slider with labels
@@ -1726,9 +2199,12 @@ impl GuiApp {
             if !has_transaction_view {
                 // Check ledger status for the button
                 // Use is_usable() to allow operations during status checks
-                let ledger_ready = self.ledger_status.is_usable();
+                let ens_error = self.split_ens_error(selector);
+                let ledger_ready = self.ledger_status.is_usable() && ens_error.is_none();
                 let button_hover = if ledger_ready {
                     format!("Start {} operation", mode.title)
+                } else if let Some(error) = ens_error {
+                    error
                 } else {
                     self.get_ledger_warning_message().unwrap_or_else(|| "Ledger not ready".to_string())
                 };
@@ -1743,6 +2219,23 @@ impl GuiApp {
                 if !ledger_ready {
                     self.render_ledger_warning(ui);
                 }
+
+                let manual_source = {
+                    let state = self.split_state(selector);
+                    Self::parse_optional_usize(&state.source_index).is_some()
+                };
+                if manual_source {
+                    let plan = self.split_schedule_plan(selector);
+                    self.render_schedule_form(ui, mode.title, plan);
+                }
+                if !manual_source
+                    && ui
+                        .button("[H] Use Recent Scan")
+                        .on_hover_text("Pick the source from a saved scan of this network (last 24h) instead of scanning the Ledger again")
+                        .clicked()
+                {
+                    self.use_recent_scan_for_split(selector);
+                }
                 
                 if button_response.clicked() {
                     // Extract values first to avoid borrow issues
@@ -1769,7 +2262,7 @@ impl GuiApp {
                             let addresses: Vec<String> = state.recipient_addresses
                                 .split(',')
                                 .map(|s| s.trim().to_string())
-                                .filter(|s| !s.is_empty() && s.starts_with("0x") && s.len() == 42)
+                                .filter(|s| (s.starts_with("0x") && s.len() == 42) || crate::ens::is_ens_name(s))
                                 .collect();
                             if addresses.is_empty() { None } else { Some(addresses) }
                         } else {
@@ -1806,6 +2299,8 @@ impl GuiApp {
                             config,
                             start_idx,
                             empty_streak,
+                            // A split spends from the current balance
+                            balance::SnapshotTarget::Latest,
                             progress_sender,
                             cancel_receiver,
                             use_native_ledger,
@@ -1944,7 +2439,7 @@ impl GuiApp {
         let (_, native_token, _, _) = self.selected_network_info();
         let mut selected_idx: Option<usize> = None;
         // Use is_usable() to allow operations during status checks
-        let ledger_ready = self.ledger_status.is_usable();
+        let ledger_ready = self.ledger_status.is_usable() && self.split_ens_error(selector).is_none();
 
         ui.label(
             RichText::new("Select source address:")
@@ -2042,7 +2537,7 @@ impl GuiApp {
                 let addresses: Vec<String> = state.recipient_addresses
                     .split(',')
                     .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty() && s.starts_with("0x") && s.len() == 42)
+                        .filter(|s| (s.starts_with("0x") && s.len() == 42) || crate::ens::is_ens_name(s))
                     .collect();
                 if addresses.is_empty() { None } else { Some(addresses) }
             } else {
@@ -2111,6 +2606,7 @@ impl GuiApp {
         let ledger_ready = self.ledger_status.is_usable();
         let ledger_warning = self.get_ledger_warning_message();
         let native_token = self.config.native_token().to_string();
+        let show_ens_names = self.user_settings.show_ens_names;
         let mut needs_rerandomize = false;
         let mut tx_notifications = Vec::new();
         {
@@ -2120,6 +2616,7 @@ impl GuiApp {
                 egui::ScrollArea::vertical()
                     .auto_shrink([false, false])
                     .show(ui, |ui| {
+                        tx_view.set_show_ens_names(show_ens_names);
                         tx_view.show(ui, ledger_ready, ledger_warning.as_deref(), &native_token);
                     });
 
@@ -2298,6 +2795,645 @@ impl GuiApp {
         state.status = Some(format!("[OK] Complete! {} success, {} failed, {} skipped", stats.success, stats.failed, stats.skipped));
     }
 
+    /// Run the background balance watcher: start a polling round when due and report changes
+    fn poll_balance_watcher(&mut self) {
+        if let Some(job) = &mut self.watcher.job {
+            if let Some(res) = job.poll() {
+                self.watcher.job = None;
+                match res {
+                    Ok(poll) => {
+                        self.watcher.baselines.extend(poll.baselines);
+                        self.watcher.latest.extend(poll.latest);
+                        self.watcher.last_errors.extend(poll.errors);
+                        for change in poll.changes {
+                            let summary = change.describe();
+                            self.notifications
+                                .push_back(NotificationEntry::new(format!("[i] Balance change: {}", summary)));
+                            let details = format!(
+                                "Beaug Balance Watch on {} (Chain ID: {})\n\
+                                 Address: {:?}\n\
+                                 {} -> {} {}\n\
+                                 {}",
+                                change.network_label,
+                                change.chain_id,
+                                change.address,
+                                utils::format_ether(change.old_balance),
+                                utils::format_ether(change.new_balance),
+                                change.native_token,
+                                summary
+                            );
+                            if let Err(e) = crate::operation_log::append_log("Beaug Balance Watch", change.chain_id, &details) {
+                                tracing::warn!("Failed to log balance change: {}", e);
+                            }
+                        }
+                    }
+                    Err(e) => self.watcher.last_errors.push(e.to_string()),
+                }
+            }
+            return;
+        }
+
+        if !self.user_settings.watcher_enabled || self.user_settings.watched_addresses.is_empty() {
+            return;
+        }
+        let interval = std::time::Duration::from_secs(self.user_settings.watcher_interval_secs.max(10));
+        if self.watcher.last_poll.map(|t| t.elapsed() < interval).unwrap_or(false) {
+            return;
+        }
+
+        let (targets, errors) = crate::balance_watcher::build_targets(&self.user_settings);
+        let baselines = self.watcher.baselines.clone();
+        self.watcher.last_errors = errors;
+        self.watcher.last_poll = Some(std::time::Instant::now());
+        self.watcher.job = Some(self.spawn_job(move || async move {
+            Ok(crate::balance_watcher::poll_once(targets, baselines).await)
+        }));
+    }
+
+    /// Keep the USD price of the selected network's native token fresh while fiat values are enabled
+    fn poll_price_feed(&mut self) {
+        if let Some(job) = &mut self.price.job {
+            if let Some(res) = job.poll() {
+                self.price.job = None;
+                match res {
+                    // Ignore prices fetched for a network that is no longer selected
+                    Ok((chain_id, quote)) if chain_id == self.config.chain_id => {
+                        self.price.quote = quote;
+                        self.price.error = None;
+                    }
+                    Ok(_) => self.price.last_fetch = None,
+                    Err(e) => self.price.error = Some(e.to_string()),
+                }
+            }
+            return;
+        }
+
+        if !self.user_settings.show_fiat_values {
+            return;
+        }
+        let interval = std::time::Duration::from_secs(crate::price_feed::REFRESH_INTERVAL_SECS);
+        if self.price.last_fetch.map(|t| t.elapsed() < interval).unwrap_or(false) {
+            return;
+        }
+
+        let config = self.config.clone();
+        let feed = crate::price_feed::feed_for(config.chain_id, &self.user_settings);
+        self.price.last_fetch = Some(std::time::Instant::now());
+        self.price.job = Some(self.spawn_job(move || async move {
+            let Some(source) = feed? else {
+                return Ok((config.chain_id, None));
+            };
+            let provider = config.get_provider().await?;
+            let quote = crate::price_feed::fetch_price(&provider, source).await?;
+            Ok((config.chain_id, Some(quote)))
+        }));
+    }
+
+    /// Resolve ENS names typed into the address inputs, and look up reverse records of the
+    /// addresses on screen when ENS names are shown
+    fn poll_ens(&mut self) {
+        if let Some(job) = &mut self.ens.resolve_job {
+            if let Some(res) = job.poll() {
+                self.ens.resolve_job = None;
+                match res {
+                    Ok((chain_id, results)) if chain_id == self.config.chain_id => {
+                        let mut resolved = Vec::new();
+                        for (name, result) in results {
+                            match result {
+                                Ok(address) => resolved.push((name, address)),
+                                Err(reason) => {
+                                    self.ens.failed.insert(name, reason);
+                                }
+                            }
+                        }
+                        crate::ens::store_resolved(&resolved);
+                        self.ens.error = None;
+                    }
+                    Ok(_) => {}
+                    Err(e) => self.ens.error = Some((e.to_string(), std::time::Instant::now())),
+                }
+            }
+        }
+        if let Some(job) = &mut self.ens.reverse_job {
+            if let Some(res) = job.poll() {
+                self.ens.reverse_job = None;
+                match res {
+                    Ok((chain_id, records)) if chain_id == self.config.chain_id => crate::ens::store_reverse(&records),
+                    Ok(_) => {}
+                    Err(e) => self.ens.error = Some((e.to_string(), std::time::Instant::now())),
+                }
+            }
+        }
+
+        let backoff = std::time::Duration::from_secs(30);
+        if self.ens.error.as_ref().is_some_and(|(_, at)| at.elapsed() < backoff) {
+            return;
+        }
+        let mainnet_rpc_url = self.user_settings.rpc_url_for(crate::ens::ENS_MAINNET_CHAIN_ID);
+
+        if self.ens.resolve_job.is_none() {
+            let names: Vec<String> = crate::ens::unresolved(&self.ens_input_names())
+                .into_iter()
+                .filter(|name| !self.ens.failed.contains_key(name))
+                .collect();
+            if !names.is_empty() {
+                let config = self.config.clone();
+                let mainnet_rpc_url = mainnet_rpc_url.clone();
+                self.ens.resolve_job = Some(self.spawn_job(move || async move {
+                    let chain_id = config.chain_id;
+                    Ok((chain_id, crate::ens::resolve_names(config, mainnet_rpc_url, names).await?))
+                }));
+            }
+        }
+
+        if self.ens.reverse_job.is_none() && self.user_settings.show_ens_names {
+            let addresses = crate::ens::take_reverse_requests(crate::ens::REVERSE_LOOKUP_BATCH);
+            if !addresses.is_empty() {
+                let config = self.config.clone();
+                self.ens.reverse_job = Some(self.spawn_job(move || async move {
+                    let chain_id = config.chain_id;
+                    Ok((chain_id, crate::ens::lookup_addresses(config, mainnet_rpc_url, addresses).await?))
+                }));
+            }
+        }
+    }
+
+    /// Drive scheduled runs: check due runs on the selected network again, start the ones
+    /// that pass, and mark the ones that can no longer start as missed
+    fn poll_schedule(&mut self) {
+        use crate::schedule::{ScheduleStatus, ScheduleTrigger, TriggerCheck};
+
+        if !self.schedule.loaded {
+            self.schedule.loaded = true;
+            match crate::schedule::load_runs() {
+                Ok(mut runs) => {
+                    // A run still marked running was cut off when the app closed
+                    for run in runs.iter_mut().filter(|run| run.status == ScheduleStatus::Running) {
+                        run.status = ScheduleStatus::Failed("interrupted: Beaug was closed while it ran".to_string());
+                        run.log_status();
+                    }
+                    self.schedule.runs = runs;
+                }
+                Err(e) => self.schedule.error = Some(format!("Could not load scheduled runs: {}", e)),
+            }
+        }
+
+        if let Some(job) = &mut self.schedule.block_job {
+            if let Some(res) = job.poll() {
+                self.schedule.block_job = None;
+                match res {
+                    Ok(block) => self.schedule.block = Some(block),
+                    Err(e) => self.schedule.error = Some(format!("Could not read the block number: {}", e)),
+                }
+            }
+        }
+
+        if let Some((id, job)) = &mut self.schedule.check_job {
+            if let Some(res) = job.poll() {
+                let id = id.clone();
+                self.schedule.check_job = None;
+                match res {
+                    Ok(TriggerCheck::Ready(summary)) => self.start_scheduled_run(&id, &summary),
+                    Ok(TriggerCheck::Wait(reason)) => {
+                        self.schedule.waiting.insert(id, reason);
+                    }
+                    Ok(TriggerCheck::Fail(reason)) => self.finish_scheduled_run(&id, ScheduleStatus::Failed(reason)),
+                    Err(e) => {
+                        self.schedule.waiting.insert(id, e.to_string());
+                    }
+                }
+            }
+        }
+
+        if let Some((id, job)) = &mut self.schedule.run_job {
+            if let Some(res) = job.poll() {
+                let id = id.clone();
+                self.schedule.run_job = None;
+                let status = match res {
+                    Ok(()) => ScheduleStatus::Done("disperse sent".to_string()),
+                    Err(e) => ScheduleStatus::Failed(e.to_string()),
+                };
+                self.finish_scheduled_run(&id, status);
+            }
+        }
+
+        if let Some((id, selector)) = self.schedule.split_run.clone() {
+            if let Some(status) = self.scheduled_split_outcome(selector) {
+                self.schedule.split_run = None;
+                self.finish_scheduled_run(&id, status);
+            }
+        }
+
+        let tick = std::time::Duration::from_secs(crate::schedule::TICK_SECS);
+        if self.schedule.last_tick.is_some_and(|t| t.elapsed() < tick) {
+            return;
+        }
+        self.schedule.last_tick = Some(std::time::Instant::now());
+
+        let chain_id = self.config.chain_id;
+        let pending: Vec<crate::schedule::ScheduledRun> = self
+            .schedule
+            .runs
+            .iter()
+            .filter(|run| run.status == ScheduleStatus::Pending)
+            .cloned()
+            .collect();
+        if pending.is_empty() {
+            return;
+        }
+
+        let needs_block = pending
+            .iter()
+            .any(|run| run.chain_id == chain_id && matches!(run.trigger, ScheduleTrigger::Block(_)));
+        if needs_block && self.schedule.block_job.is_none() {
+            let config = self.config.clone();
+            self.schedule.block_job = Some(self.spawn_job(move || async move {
+                let provider = config.get_provider().await?;
+                Ok((config.chain_id, provider.get_block_number().await?.as_u64()))
+            }));
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let block = self.schedule.block.filter(|(block_chain, _)| *block_chain == chain_id).map(|(_, block)| block);
+        for run in pending {
+            let run_block = if run.chain_id == chain_id { block } else { None };
+            if run.trigger.is_missed(now, run_block) {
+                let reason = self.schedule.waiting.remove(&run.id).unwrap_or_else(|| {
+                    if run.chain_id == chain_id {
+                        "Beaug was not running when it was due".to_string()
+                    } else {
+                        format!("{} was not selected when it was due", run.network_label)
+                    }
+                });
+                self.finish_scheduled_run(&run.id, ScheduleStatus::Missed(reason));
+                continue;
+            }
+            if !run.trigger.is_due(now, run_block)
+                || self.schedule.check_job.is_some()
+                || self.schedule.run_job.is_some()
+                || self.schedule.split_run.is_some()
+            {
+                continue;
+            }
+            if run.chain_id != chain_id {
+                self.schedule.waiting.insert(run.id.clone(), format!("switch to {} to run it", run.network_label));
+                continue;
+            }
+            if let Some(reason) = self.scheduled_run_blocker(&run.plan) {
+                self.schedule.waiting.insert(run.id.clone(), reason);
+                continue;
+            }
+
+            let config = self.config.clone();
+            let default_gas_speed = self.user_settings.default_gas_speed;
+            let id = run.id.clone();
+            self.schedule.check_job = Some((
+                id,
+                self.spawn_job(move || async move { crate::schedule::check_trigger(config, run, default_gas_speed).await }),
+            ));
+        }
+    }
+
+    /// Why a due run cannot start right now (Ledger or the view it runs in is busy), if anything
+    fn scheduled_run_blocker(&self, plan: &crate::schedule::SchedulePlan) -> Option<String> {
+        if !self.ledger_status.is_usable() {
+            return Some(self.get_ledger_warning_message().unwrap_or_else(|| "Ledger not ready".to_string()));
+        }
+        let busy = match plan {
+            crate::schedule::SchedulePlan::BulkDisperse { .. } => {
+                self.bulk_disperse_state.job.is_some() || self.bulk_disperse_state.batch_view.is_some()
+            }
+            crate::schedule::SchedulePlan::Split { equal, .. } => {
+                let state = if *equal { &self.split_equal } else { &self.split_random };
+                state.job.is_some() || state.prep_job.is_some() || state.transaction_view.is_some()
+            }
+        };
+        busy.then(|| "waiting for the current operation to finish".to_string())
+    }
+
+    /// Hand a checked run to the signer: bulk disperses sign right away, splits open their queue
+    fn start_scheduled_run(&mut self, id: &str, summary: &str) {
+        use crate::schedule::{SchedulePlan, ScheduleStatus};
+
+        let Some(run) = self.schedule.runs.iter().find(|run| run.id == id).cloned() else {
+            return;
+        };
+        self.schedule.waiting.remove(id);
+        match run.plan {
+            SchedulePlan::BulkDisperse { recipients, amount, contract, source_index, tip, gas_speed, gas_limit, .. } => {
+                let disperse_type = match crate::bulk_disperse::parse_bulk_disperse_input(&recipients, run.chain_id) {
+                    Ok(disperse_type) => disperse_type,
+                    Err(e) => {
+                        self.finish_scheduled_run(id, ScheduleStatus::Failed(format!("recipients no longer parse: {}", e)));
+                        return;
+                    }
+                };
+                let tip_recipient = tip.and_then(|_| crate::disperse::BEAUG_OWNER_ADDRESS.parse().ok());
+                let config = self.config.clone();
+                let gas_margin_percent = self.user_settings.gas_margin_percent;
+                let use_native_ledger = self.user_settings.use_native_ledger;
+                let job = self.spawn_job(move || async move {
+                    crate::bulk_disperse::bulk_disperse(
+                        config,
+                        disperse_type,
+                        amount,
+                        contract,
+                        Some(source_index as usize),
+                        tip,
+                        tip_recipient,
+                        crate::bulk_disperse::DisperseSendOptions {
+                            gas_speed,
+                            gas_limit_override: Some(gas_limit),
+                            gas_margin_percent,
+                            fees_override: None,
+                            use_native_ledger,
+                        },
+                    )
+                    .await
+                    .map(|_| ())
+                });
+                self.schedule.run_job = Some((id.to_string(), job));
+                self.set_scheduled_run_status(id, ScheduleStatus::Running);
+                self.notifications.push_back(NotificationEntry::new(format!(
+                    "[!!] Scheduled run '{}' is due ({}): confirm the disperse on your Ledger",
+                    run.label, summary
+                )));
+            }
+            SchedulePlan::Split { equal, outputs, source_index, recipients, remaining_balance, gas_speed, .. } => {
+                let (selector, mode, section) = if equal {
+                    (SplitSelector::Equal, SplitModeDescriptor::equal(), GuiSection::SplitEqual)
+                } else {
+                    (SplitSelector::Random, SplitModeDescriptor::random(), GuiSection::SplitRandom)
+                };
+                self.split_state(selector).source_selection.reset();
+                self.start_split_job(selector, mode.kind, outputs, gas_speed, Some(source_index), recipients, remaining_balance);
+                self.section = section;
+                self.schedule.split_run = Some((id.to_string(), selector));
+                self.set_scheduled_run_status(id, ScheduleStatus::Running);
+                self.notifications.push_back(NotificationEntry::new(format!(
+                    "[!!] Scheduled run '{}' is due ({}): review and sign the split transactions on your Ledger",
+                    run.label, summary
+                )));
+            }
+        }
+    }
+
+    /// How a split started by a run ended, or `None` while it is prepared or signed
+    fn scheduled_split_outcome(&mut self, selector: SplitSelector) -> Option<crate::schedule::ScheduleStatus> {
+        use crate::schedule::ScheduleStatus;
+
+        let state = self.split_state(selector);
+        if state.prep_job.is_some() {
+            return None;
+        }
+        let Some(tx_view) = &state.transaction_view else {
+            // Preparation failed, or the queue was closed before it was signed
+            let reason = state
+                .status
+                .clone()
+                .filter(|status| status.starts_with("[!!]"))
+                .unwrap_or_else(|| "split queue closed before it was signed".to_string());
+            return Some(ScheduleStatus::Failed(reason));
+        };
+        let stats = Self::block_on_async(tx_view.queue().get_statistics());
+        if stats.total == 0 {
+            return Some(ScheduleStatus::Failed("split prepared no transactions".to_string()));
+        }
+        if !stats.is_complete() {
+            return None;
+        }
+        Some(if stats.failed == 0 && stats.skipped == 0 {
+            ScheduleStatus::Done(format!("split sent: {} transfers", stats.success))
+        } else {
+            ScheduleStatus::Failed(format!(
+                "{} of {} split transfers sent, {} failed, {} skipped",
+                stats.success, stats.total, stats.failed, stats.skipped
+            ))
+        })
+    }
+
+    fn set_scheduled_run_status(&mut self, id: &str, status: crate::schedule::ScheduleStatus) {
+        if let Some(run) = self.schedule.runs.iter_mut().find(|run| run.id == id) {
+            run.status = status;
+        }
+        self.save_schedule();
+    }
+
+    /// Record how a run ended, log it and tell the user
+    fn finish_scheduled_run(&mut self, id: &str, status: crate::schedule::ScheduleStatus) {
+        self.schedule.waiting.remove(id);
+        self.set_scheduled_run_status(id, status);
+        if let Some(run) = self.schedule.runs.iter().find(|run| run.id == id) {
+            run.log_status();
+            let tag = if matches!(run.status, crate::schedule::ScheduleStatus::Done(_)) { "[OK]" } else { "[XX]" };
+            self.notifications
+                .push_back(NotificationEntry::new(format!("{} Scheduled run '{}': {}", tag, run.label, run.status.label())));
+        }
+    }
+
+    pub(crate) fn save_schedule(&mut self) {
+        match crate::schedule::save_runs(&self.schedule.runs) {
+            Ok(()) => self.schedule.error = None,
+            Err(e) => self.schedule.error = Some(format!("Could not save scheduled runs: {}", e)),
+        }
+    }
+
+    /// Plan of the split form for scheduling, or why it cannot be scheduled
+    pub(crate) fn split_schedule_plan(&self, selector: SplitSelector) -> Result<crate::schedule::SchedulePlan, String> {
+        let state = match selector {
+            SplitSelector::Random => &self.split_random,
+            SplitSelector::Equal => &self.split_equal,
+        };
+        let source_index = Self::parse_optional_usize(&state.source_index)
+            .ok_or_else(|| "Enter a source index to schedule a split".to_string())?;
+        if let Some(error) = self.split_ens_error(selector) {
+            return Err(error);
+        }
+        let recipients: Vec<String> = state
+            .recipient_addresses
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| crate::ens::parse_address_or_name(entry).map(|address| format!("{:?}", address)))
+            .collect::<anyhow::Result<_>>()
+            .map_err(|e| e.to_string())?;
+        let source_address = state
+            .source_selection
+            .get_all_funded_addresses()
+            .into_iter()
+            .find(|record| record.index as usize == source_index)
+            .map(|record| record.address);
+        Ok(crate::schedule::SchedulePlan::Split {
+            equal: matches!(selector, SplitSelector::Equal),
+            outputs: state.output_count,
+            source_index,
+            source_address,
+            recipients: (!recipients.is_empty()).then_some(recipients),
+            remaining_balance: Self::parse_optional_eth_to_wei(&state.remaining_balance).and_then(|u| u.try_into().ok()),
+            gas_speed: state.gas_speed,
+        })
+    }
+
+    /// Remind once per due payroll cycle and record the disperse paying a cycle once it is mined
+    fn poll_payroll(&mut self) {
+        use tokio::sync::oneshot::error::TryRecvError;
+
+        if !self.payroll.loaded {
+            self.payroll.loaded = true;
+            match crate::payroll::load_payrolls() {
+                Ok(payrolls) => self.payroll.payrolls = payrolls,
+                Err(e) => self.payroll.error = Some(format!("Could not load payrolls: {}", e)),
+            }
+        }
+
+        if let Some(pending) = &mut self.payroll.pending {
+            match pending.tx_hashes.try_recv() {
+                Ok(tx_hashes) => {
+                    let pending = self.payroll.pending.take().expect("pending payroll run");
+                    self.record_payroll_run(pending, tx_hashes);
+                }
+                Err(TryRecvError::Empty) => {}
+                // The disperse failed; the form stays linked so it can be sent again
+                Err(TryRecvError::Closed) => self.payroll.pending = None,
+            }
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        for payroll in &self.payroll.payrolls {
+            if payroll.is_due(now) && self.payroll.reminded.insert((payroll.id.clone(), payroll.cycles_done)) {
+                self.notifications.push_back(NotificationEntry::new(format!(
+                    "[!!] Payroll '{}' on {} was due {}: open Payroll to prepare the disperse",
+                    payroll.name,
+                    payroll.network_label,
+                    crate::payroll::format_timestamp(payroll.next_due())
+                )));
+            }
+        }
+    }
+
+    /// Record a mined disperse against its payroll and move the payroll to its next cycle
+    fn record_payroll_run(&mut self, pending: PendingPayrollRun, tx_hashes: Vec<ethers::types::TxHash>) {
+        let Some(payroll) = self.payroll.payrolls.iter_mut().find(|payroll| payroll.id == pending.payroll_id) else {
+            return;
+        };
+        let total = pending.payments.iter().fold(ethers::types::U256::zero(), |acc, (_, amount)| acc + *amount);
+        let due = crate::payroll::format_timestamp(payroll.next_due());
+        let tx_list = crate::payroll::format_tx_hashes(&tx_hashes);
+        payroll.record_run(tx_hashes, pending.payments, chrono::Utc::now().timestamp());
+        let details = format!(
+            "Payroll '{}' on {} (Chain ID: {})\nPaid the cycle due {}: {} {} to {} recipients\nTx hashes: {}\nNext due: {}",
+            payroll.name,
+            payroll.network_label,
+            payroll.chain_id,
+            due,
+            utils::format_ether(total),
+            payroll.native_token,
+            payroll.runs.last().map_or(0, |run| run.payments.len()),
+            tx_list,
+            crate::payroll::format_timestamp(payroll.next_due())
+        );
+        if let Err(e) = crate::operation_log::append_log("Beaug Payroll", payroll.chain_id, details) {
+            tracing::warn!("Failed to log payroll run: {}", e);
+        }
+        self.notifications
+            .push_back(NotificationEntry::new(format!("[OK] Payroll '{}': cycle due {} recorded", payroll.name, due)));
+        if self.payroll.linked.as_deref() == Some(pending.payroll_id.as_str()) {
+            self.payroll.linked = None;
+        }
+        self.save_payrolls();
+    }
+
+    pub(crate) fn save_payrolls(&mut self) {
+        match crate::payroll::save_payrolls(&self.payroll.payrolls) {
+            Ok(()) => self.payroll.error = None,
+            Err(e) => self.payroll.error = Some(format!("Could not save payrolls: {}", e)),
+        }
+    }
+
+    /// USD value of a native amount on the selected network, when fiat values are enabled and a price is known
+    pub(crate) fn usd_value(&self, wei: ethers::types::U256) -> Option<String> {
+        if !self.user_settings.show_fiat_values {
+            return None;
+        }
+        self.price
+            .quote
+            .as_ref()
+            .filter(|quote| quote.source.chain_id == self.config.chain_id)
+            .map(|quote| format!("≈ {}", quote.format_usd(wei)))
+    }
+
+    /// Small line naming the price feed, its price and how old it is
+    pub(crate) fn render_price_source(&self, ui: &mut egui::Ui) {
+        if !self.user_settings.show_fiat_values {
+            return;
+        }
+        if let Some(err) = &self.price.error {
+            ui.label(RichText::new(format!("[!!] USD price unavailable: {}", err)).small().color(self.theme.warning));
+            return;
+        }
+        let Some(quote) = self.price.quote.as_ref().filter(|q| q.source.chain_id == self.config.chain_id) else {
+            if self.price.job.is_none() && self.price.last_fetch.is_some() {
+                ui.label(
+                    RichText::new("No Chainlink price feed for this network (set one in Settings)")
+                        .small()
+                        .color(self.theme.text_secondary),
+                );
+            }
+            return;
+        };
+
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        let text = format!(
+            "{} {} via Chainlink, updated {}",
+            quote.source.pair,
+            quote.format_price(),
+            quote.describe_age(now)
+        );
+        if quote.is_stale(now) {
+            ui.label(RichText::new(format!("[!!] {} (stale)", text)).small().color(self.theme.warning));
+        } else {
+            ui.label(RichText::new(text).small().color(self.theme.text_secondary));
+        }
+    }
+
+    /// Persist a completed scan so it can be reopened, diffed or reused later
+    fn save_scan_to_history(&mut self, result: &balance::BalanceScanResult) {
+        if result.records.is_empty() {
+            return;
+        }
+        let stored = crate::scan_history::StoredScan::new(&self.config, result.clone());
+        match crate::scan_history::save_scan(&stored) {
+            Ok(_) => self.scan_history.loaded = false,
+            Err(e) => {
+                tracing::warn!("Failed to save scan history: {}", e);
+                self.notifications.push_back(NotificationEntry::new(format!(
+                    "[!!] Failed to save scan to history: {}",
+                    e
+                )));
+            }
+        }
+    }
+
+    /// Fill a split's source selection from the most recent saved scan instead of scanning the Ledger
+    fn use_recent_scan_for_split(&mut self, selector: SplitSelector) {
+        match crate::scan_history::recent_scan_for(&self.config) {
+            Some(scan) => {
+                let scanned_at = scan.scanned_at_display();
+                let state = self.split_state(selector);
+                state.transaction_view = None;
+                state.source_selection.reset();
+                state.source_selection.funded_addresses = Some(scan.funded_records());
+                state.source_selection.empty_addresses = Some(scan.fresh_records());
+                state.status = Some(format!("[i] Using saved scan from {}", scanned_at));
+            }
+            None => {
+                self.notifications.push_back(NotificationEntry::new(
+                    "[i] No saved scan for this network and derivation from the last 24 hours",
+                ));
+            }
+        }
+    }
+
     /// Log a balance scan operation to the operation log
     fn log_balance_scan(&self, result: &balance::BalanceScanResult) {
         let chain_id = self.config.chain_id;
@@ -2306,6 +3442,8 @@ impl GuiApp {
 
         let funded_count = result.records.iter().filter(|r| !r.balance.is_zero()).count();
         let empty_count = result.records.iter().filter(|r| r.balance.is_zero()).count();
+        let used_count = result.records.iter().filter(|r| r.activity == balance::AddressActivity::UsedEmpty).count();
+        let code_count = result.records.iter().filter(|r| r.activity == balance::AddressActivity::HasCode).count();
 
         // Calculate total balance
         let total_balance: ethers::types::U256 = result.records
@@ -2317,33 +3455,37 @@ impl GuiApp {
         let address_lines: Vec<String> = result.records
             .iter()
             .map(|r| {
-                let status = if r.balance.is_zero() { "⚪" } else { "🟢" };
                 format!(
-                    "  {} {} → {:?} - {} {}",
-                    status,
+                    "  {} {} → {:?} - {} {} [{}]",
+                    r.activity.icon(),
                     r.derivation_path,
                     r.address,
                     utils::format_ether(r.balance),
-                    native_token
+                    native_token,
+                    r.activity.label()
                 )
             })
             .collect();
 
         let details = format!(
             "Beaug Balance Scan on {} (Chain ID: {})\n\
-             Scanned {} addresses: {} funded, {} empty\n\
+             Scanned {} addresses: {} funded, {} empty ({} used, {} with code)\n\
              Total balance found: {} {}\n\
              Met target: {} | Cancelled: {}\n\
+             Balances at: {}\n\
              Addresses:\n{}",
             network_label,
             chain_id,
             result.records.len(),
             funded_count,
             empty_count,
+            used_count,
+            code_count,
             utils::format_ether(total_balance),
             native_token,
             result.met_target,
             result.cancelled,
+            result.snapshot.map(|s| s.describe()).unwrap_or_else(|| "latest block".to_string()),
             address_lines.join("\n")
         );
 
@@ -2436,6 +3578,19 @@ impl App for GuiApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
         self.poll_jobs();
 
+        // Scheduled runs only fire while the app is open: hold the first close back
+        if ctx.input(|i| i.viewport().close_requested()) && !self.schedule.close_warned {
+            let pending = self.schedule.runs.iter().filter(|run| !run.status.is_finished()).count();
+            if pending > 0 {
+                self.schedule.close_warned = true;
+                ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+                self.notifications.push_back(NotificationEntry::new(format!(
+                    "[!!] {} scheduled run(s) only start while Beaug is open; close again to quit anyway",
+                    pending
+                )));
+            }
+        }
+
         egui::TopBottomPanel::top("top_bar").show(ctx, |ui| {
             // Add vertical padding above the logo
             ui.add_space(10.0);
@@ -2818,6 +3973,13 @@ impl App for GuiApp {
                     (GuiSection::SplitRandom, "[~] Split Random"),
                     (GuiSection::SplitEqual, "[=] Split Even"),
                     (GuiSection::BulkDisperse, "[$] Bulk Disperse"),
+                    (GuiSection::ScanHistory, "[%] Scan History"),
+                    (GuiSection::Watcher, "[!] Balance Watcher"),
+                    (GuiSection::Dispersals, "[#] Dispersals"),
+                    (GuiSection::Schedule, "[@] Scheduled Runs"),
+                    (GuiSection::Payroll, "[P] Payroll"),
+                    (GuiSection::Registry, "[R] Registry"),
+                    (GuiSection::Admin, "[A] Contract Admin"),
                     (GuiSection::Settings, "[*] Settings"),
                 ];
 
@@ -2887,6 +4049,13 @@ impl App for GuiApp {
                         self.view_split(ui, SplitSelector::Equal, SplitModeDescriptor::equal())
                     }
                     GuiSection::BulkDisperse => self.view_bulk_disperse(ui),
+                    GuiSection::ScanHistory => super::views::view_scan_history(self, ui),
+                    GuiSection::Watcher => super::views::view_balance_watcher(self, ui),
+                    GuiSection::Dispersals => super::views::view_dispersals(self, ui),
+                    GuiSection::Schedule => super::views::view_schedule(self, ui),
+                    GuiSection::Payroll => super::views::view_payroll(self, ui),
+                    GuiSection::Registry => super::views::view_registry(self, ui),
+                    GuiSection::Admin => super::views::view_contract_admin(self, ui),
                     GuiSection::Settings => self.view_settings(ui),
                 }
             });
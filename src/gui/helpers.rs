@@ -63,16 +63,10 @@ pub fn format_gwei(wei: ethers::types::U256) -> String {
     }
 }
 
-/// Calculate gas limit for disperse contract calls
-/// Uses a formula based on recipient count with a 10% safety buffer
+/// Heuristic gas limit for disperse contract calls, used when `eth_estimateGas` fails
+/// (see `disperse::heuristic_disperse_gas_limit`)
 pub fn calculate_disperse_gas_limit(recipient_count: usize) -> u64 {
-    // Base gas: 150k for function call overhead
-    // Per recipient: 120k (transfer + storage writes + events)
-    // Safety buffer: 10%
-    // Max cap: 15M (typical block gas limit)
-    let base_gas_limit = 150_000u64 + 120_000u64 * recipient_count as u64;
-    let with_buffer = (base_gas_limit as f64 * 1.10) as u64;
-    with_buffer.min(15_000_000u64)
+    crate::disperse::heuristic_disperse_gas_limit(recipient_count)
 }
 
 /// Load the application icon for the window
@@ -2,10 +2,11 @@
 //!
 //! This module contains the balance scanning panel rendering including:
 //! - Scan parameters configuration
+//! - Resuming interrupted scans from their checkpoint
 //! - Live streaming results display
 //! - Export functionality
 
-use crate::{balance, utils};
+use crate::{balance, ens, payment_uri, scan_checkpoint, utils};
 use eframe::egui::{self, RichText};
 use std::fs;
 use std::io::Write;
@@ -31,6 +32,12 @@ pub fn view_check_balances(app: &mut GuiApp, ui: &mut egui::Ui) {
         app.check_state.result.is_some() || !app.check_state.streaming_records.is_empty();
     let scan_complete = app.check_state.result.is_some() && !running;
 
+    // Look for an interrupted scan to resume on this network
+    if !running && !app.check_state.checkpoint_checked {
+        app.check_state.checkpoint = scan_checkpoint::load_checkpoint(&app.config);
+        app.check_state.checkpoint_checked = true;
+    }
+
     // When scan starts, hide parameters
     if running && app.check_state.show_parameters {
         app.check_state.show_parameters = false;
@@ -45,6 +52,8 @@ pub fn view_check_balances(app: &mut GuiApp, ui: &mut egui::Ui) {
         );
         ui.add_space(app.theme.spacing_md);
 
+        render_resume_panel(app, ui);
+
         // Scan parameters in a grid layout
         app.theme.frame_panel().show(ui, |ui| {
             ui.label(
@@ -79,7 +88,22 @@ pub fn view_check_balances(app: &mut GuiApp, ui: &mut egui::Ui) {
                                 .clamp_range(1..=100),
                         );
                         ui.label(
-                            RichText::new("consecutive empty addresses")
+                            RichText::new("consecutive fresh addresses (no balance, nonce or code)")
+                                .small()
+                                .color(app.theme.text_secondary),
+                        );
+                    });
+                    ui.end_row();
+
+                    ui.label("Balances at:");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut app.check_state.snapshot_input)
+                                .hint_text("latest")
+                                .desired_width(160.0),
+                        );
+                        ui.label(
+                            RichText::new("(Block number or UTC date/time - needs an archive RPC)")
                                 .small()
                                 .color(app.theme.text_secondary),
                         );
@@ -88,6 +112,11 @@ pub fn view_check_balances(app: &mut GuiApp, ui: &mut egui::Ui) {
                 });
         });
 
+        if let Some(err) = &app.check_state.error {
+            ui.add_space(app.theme.spacing_sm);
+            ui.colored_label(app.theme.error, format!("[XX] {}", err));
+        }
+
         ui.add_space(app.theme.spacing_md);
 
         // Check ledger status for enabling the button
@@ -102,29 +131,22 @@ pub fn view_check_balances(app: &mut GuiApp, ui: &mut egui::Ui) {
                 .unwrap_or_else(|| "Ledger not ready".to_string())
         };
 
+        let snapshot_target = balance::SnapshotTarget::parse(&app.check_state.snapshot_input);
+        if let Err(e) = &snapshot_target {
+            ui.colored_label(app.theme.warning, format!("[!!] {}", e));
+        }
+
         if ui
-            .add_enabled(button_enabled, button)
+            .add_enabled(button_enabled && snapshot_target.is_ok(), button)
             .on_hover_text(&hover_text)
             .clicked()
         {
+            let snapshot_target = snapshot_target.unwrap_or_default();
             let config = app.config.clone();
             let target = app.check_state.empty_target;
             let start = app.check_state.start_index;
             let use_native_ledger = app.user_settings.use_native_ledger;
-
-            // Clear previous results and switch to results mode
-            app.check_state.result = None;
-            app.check_state.streaming_records.clear();
-            app.check_state.error = None;
-            app.check_state.show_parameters = false;
-
-            // Create channels for progress and cancellation
-            let (progress_sender, progress_receiver) = tokio::sync::mpsc::unbounded_channel();
-            let (cancel_sender, cancel_receiver) = tokio::sync::oneshot::channel();
-
-            // Store receivers and cancel sender
-            app.check_state.progress_receiver = Some(progress_receiver);
-            app.check_state.cancel_sender = Some(cancel_sender);
+            let (progress_sender, cancel_receiver) = begin_scan(app, Vec::new());
 
             // Start the streaming scan
             app.check_state.job = Some(app.spawn_job(move || async move {
@@ -132,6 +154,7 @@ pub fn view_check_balances(app: &mut GuiApp, ui: &mut egui::Ui) {
                     config,
                     target,
                     start,
+                    snapshot_target,
                     progress_sender,
                     cancel_receiver,
                     use_native_ledger,
@@ -181,7 +204,27 @@ pub fn view_check_balances(app: &mut GuiApp, ui: &mut egui::Ui) {
                     app.check_state.result = None;
                     app.check_state.streaming_records.clear();
                     app.check_state.error = None;
+                    app.check_state.snapshot = None;
                     app.check_state.show_parameters = true;
+                    app.check_state.checkpoint_checked = false;
+                }
+
+                let interrupted = app
+                    .check_state
+                    .result
+                    .as_ref()
+                    .map(|r| r.cancelled)
+                    .unwrap_or(app.check_state.error.is_some());
+                if interrupted && app.check_state.checkpoint.is_some() {
+                    let ledger_ready = app.ledger_status.is_usable();
+                    if ui
+                        .add_enabled(ledger_ready, egui::Button::new("[>] Resume Scan"))
+                        .on_hover_text("Continue scanning from where this scan stopped")
+                        .on_disabled_hover_text("Connect and unlock your Ledger")
+                        .clicked()
+                    {
+                        resume_scan(app);
+                    }
                 }
 
                 ui.separator();
@@ -205,6 +248,106 @@ pub fn view_check_balances(app: &mut GuiApp, ui: &mut egui::Ui) {
     }
 }
 
+/// Reset the scan state, switch to results mode and create the progress / cancel channels.
+/// `initial_records` pre-fills the live table (records already scanned before a resume).
+fn begin_scan(
+    app: &mut GuiApp,
+    initial_records: Vec<balance::BalanceScanRecord>,
+) -> (
+    tokio::sync::mpsc::UnboundedSender<balance::ScanProgress>,
+    tokio::sync::oneshot::Receiver<()>,
+) {
+    // Clear previous results and switch to results mode
+    app.check_state.result = None;
+    app.check_state.streaming_records = initial_records;
+    app.check_state.error = None;
+    app.check_state.snapshot = None;
+    app.check_state.show_parameters = false;
+
+    // Create channels for progress and cancellation
+    let (progress_sender, progress_receiver) = tokio::sync::mpsc::unbounded_channel();
+    let (cancel_sender, cancel_receiver) = tokio::sync::oneshot::channel();
+
+    // Store receivers and cancel sender
+    app.check_state.progress_receiver = Some(progress_receiver);
+    app.check_state.cancel_sender = Some(cancel_sender);
+
+    (progress_sender, cancel_receiver)
+}
+
+/// Continue the interrupted scan stored in the checkpoint
+fn resume_scan(app: &mut GuiApp) {
+    let Some(checkpoint) = app.check_state.checkpoint.take() else {
+        return;
+    };
+    let config = app.config.clone();
+    let use_native_ledger = app.user_settings.use_native_ledger;
+    app.check_state.empty_target = checkpoint.empty_target;
+    app.check_state.start_index = checkpoint.start_index;
+    let (progress_sender, cancel_receiver) = begin_scan(app, checkpoint.records.clone());
+
+    app.check_state.job = Some(app.spawn_job(move || async move {
+        balance::resume_consecutive_empty_streaming(
+            config,
+            checkpoint,
+            progress_sender,
+            cancel_receiver,
+            use_native_ledger,
+        )
+        .await
+    }));
+}
+
+/// Offer to resume or discard an interrupted scan on this network
+fn render_resume_panel(app: &mut GuiApp, ui: &mut egui::Ui) {
+    let Some(checkpoint) = &app.check_state.checkpoint else {
+        return;
+    };
+    let description = checkpoint.describe();
+    let updated_at = checkpoint.updated_at_display();
+    let chain_id = checkpoint.chain_id;
+    let ledger_ready = app.ledger_status.is_usable();
+
+    let mut resume = false;
+    let mut discard = false;
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(
+            RichText::new("[>] Interrupted scan")
+                .strong()
+                .color(app.theme.warning),
+        );
+        ui.label(RichText::new(description).color(app.theme.text_primary));
+        ui.label(
+            RichText::new(format!("Last progress saved {}", updated_at))
+                .small()
+                .color(app.theme.text_secondary),
+        );
+        ui.add_space(app.theme.spacing_xs);
+        ui.horizontal(|ui| {
+            resume = ui
+                .add_enabled(ledger_ready, egui::Button::new("[>] Resume Scan"))
+                .on_hover_text("Continue from the next unscanned index")
+                .on_disabled_hover_text("Connect and unlock your Ledger")
+                .clicked();
+            discard = ui
+                .button("[X] Discard")
+                .on_hover_text("Forget this scan's progress")
+                .clicked();
+        });
+    });
+    ui.add_space(app.theme.spacing_md);
+
+    if resume {
+        resume_scan(app);
+    } else if discard {
+        if let Err(e) = scan_checkpoint::clear_checkpoint(chain_id) {
+            app.notifications
+                .push_back(NotificationEntry::new(format!("[XX] Failed to discard scan progress: {}", e)));
+        }
+        app.check_state.checkpoint = None;
+    }
+}
+
 /// Render the address results table
 fn render_address_table(
     app: &mut GuiApp,
@@ -217,9 +360,16 @@ fn render_address_table(
 
     ui.add_space(app.theme.spacing_sm);
 
-    // Show summary with counts
-    let funded_count = records.iter().filter(|r| !r.balance.is_zero()).count();
-    let empty_count = records.iter().filter(|r| r.balance.is_zero()).count();
+    // Show summary with counts per activity class
+    let count_of = |activity: balance::AddressActivity| {
+        records.iter().filter(|r| r.activity == activity).count()
+    };
+    let class_counts = [
+        (balance::AddressActivity::Funded, count_of(balance::AddressActivity::Funded), "funded"),
+        (balance::AddressActivity::HasCode, count_of(balance::AddressActivity::HasCode), "with code"),
+        (balance::AddressActivity::UsedEmpty, count_of(balance::AddressActivity::UsedEmpty), "used (empty)"),
+        (balance::AddressActivity::Fresh, count_of(balance::AddressActivity::Fresh), "fresh"),
+    ];
 
     ui.horizontal(|ui| {
         let is_complete = app.check_state.result.is_some();
@@ -230,21 +380,44 @@ fn render_address_table(
         };
         ui.label(label);
 
-        if funded_count > 0 {
-            ui.colored_label(
-                egui::Color32::from_rgb(100, 200, 150),
-                format!("🟢 {} funded", funded_count),
-            );
+        for (activity, count, label) in class_counts {
+            if count > 0 {
+                let (r, g, b) = activity.color();
+                ui.colored_label(
+                    egui::Color32::from_rgb(r, g, b),
+                    format!("{} {} {}", activity.icon(), count, label),
+                );
+            }
         }
-        if empty_count > 0 {
-            ui.colored_label(egui::Color32::GRAY, format!("⚪ {} empty", empty_count));
+    });
+
+    let total = records
+        .iter()
+        .fold(ethers::types::U256::zero(), |acc, r| acc + r.balance);
+    ui.horizontal(|ui| {
+        ui.label(format!(
+            "Total: {} {}",
+            utils::format_ether(total),
+            app.config.native_token()
+        ));
+        if let Some(usd) = app.usd_value(total) {
+            let note = if app.check_state.snapshot.is_some() { " at today's price" } else { "" };
+            ui.label(RichText::new(format!("{}{}", usd, note)).color(app.theme.text_secondary));
         }
     });
+    app.render_price_source(ui);
+
+    if let Some(snapshot) = &app.check_state.snapshot {
+        ui.colored_label(
+            app.theme.accent_blue,
+            format!("[@] Historical snapshot: balances at {}", snapshot.describe()),
+        );
+    }
 
     if let Some(result) = &app.check_state.result {
         if result.met_target {
             ui.label(format!(
-                "[OK] Found {} consecutive empty addresses (target met)",
+                "[OK] Found {} consecutive fresh addresses (target met)",
                 result.empty_addresses.len()
             ));
         }
@@ -283,14 +456,14 @@ fn render_address_table(
                 path.push(format!("addresses_{}.csv", timestamp));
                 let filename = path.to_string_lossy().to_string();
 
-                // Generate CSV content
-                let mut csv = String::from("Path,Address,Balance,Token,Status\n");
+                // Generate CSV content, recording the snapshot block as a header comment
+                let mut csv = String::new();
+                if let Some(snapshot) = &app.check_state.snapshot {
+                    csv.push_str(&format!("# Snapshot: {}\n", snapshot.describe()));
+                }
+                csv.push_str("Path,Address,Balance,Token,Status\n");
                 for record in records {
-                    let status = if record.balance.is_zero() {
-                        "Empty"
-                    } else {
-                        "Funded"
-                    };
+                    let status = record.activity.label();
                     csv.push_str(&format!(
                         "\"{}\",\"{:?}\",\"{}\",\"{}\",\"{}\"\n",
                         record.derivation_path,
@@ -346,20 +519,20 @@ fn render_address_table(
             }
         }
 
-        if records.iter().any(|r| r.balance.is_zero()) {
+        if records.iter().any(|r| r.activity.is_fresh()) {
             if ui
-                .button("[∅] Copy Empty Only")
-                .on_hover_text("Copy only empty addresses")
+                .button("[∅] Copy Fresh Only")
+                .on_hover_text("Copy only fresh addresses (no balance, no transactions, no code)")
                 .clicked()
             {
-                let empty: Vec<String> = records
+                let fresh: Vec<String> = records
                     .iter()
-                    .filter(|r| r.balance.is_zero())
+                    .filter(|r| r.activity.is_fresh())
                     .map(|r| format!("{:?}", r.address))
                     .collect();
-                ui.output_mut(|o| o.copied_text = empty.join("\n"));
+                ui.output_mut(|o| o.copied_text = fresh.join("\n"));
                 app.notifications
-                    .push_back(NotificationEntry::new("[OK] Empty addresses copied to clipboard"));
+                    .push_back(NotificationEntry::new("[OK] Fresh addresses copied to clipboard"));
             }
         }
     });
@@ -371,6 +544,9 @@ fn render_address_table(
     let mut add_split_even: Option<String> = None;
     let mut add_split_random: Option<String> = None;
     let mut add_bulk_disperse: Option<String> = None;
+    let show_ens_names = app.user_settings.show_ens_names;
+    let chain_id = app.config.chain_id;
+    let mut copied_request: Option<String> = None;
 
     egui::ScrollArea::vertical()
         .max_height(ui.available_height() - 20.0)
@@ -381,14 +557,12 @@ fn render_address_table(
                 let address_str = format!("{:?}", record.address);
                 
                 ui.horizontal(|ui| {
-                    // Choose color based on balance
-                    let (color, status_icon) = if record.balance.is_zero() {
-                        (egui::Color32::GRAY, "⚪")
-                    } else {
-                        (egui::Color32::from_rgb(100, 200, 150), "🟢")
-                    };
+                    // Choose color based on activity class
+                    let (r, g, b) = record.activity.color();
+                    let color = egui::Color32::from_rgb(r, g, b);
 
-                    ui.colored_label(color, status_icon);
+                    ui.colored_label(color, record.activity.icon())
+                        .on_hover_text(record.activity.label());
 
                     // Format the display text
                     let display_text = format!(
@@ -412,6 +586,13 @@ fn render_address_table(
                         copied_address = Some(address_str.clone());
                     }
 
+                    if show_ens_names {
+                        if let Some(name) = ens::reverse_name(record.address) {
+                            ui.label(RichText::new(name).strong().color(app.theme.info))
+                                .on_hover_text("ENS primary name");
+                        }
+                    }
+
                     ui.scope(|ui| {
                         ui.style_mut().spacing.button_padding = egui::vec2(2.0, 1.0);
 
@@ -425,6 +606,16 @@ fn render_address_table(
                             copied_address = Some(address_str.clone());
                         }
 
+                        if ui
+                            .add(egui::Button::new("[R]").small())
+                            .on_hover_text("Copy an EIP-681 receive request for this address")
+                            .clicked()
+                        {
+                            let uri = payment_uri::receive_request_uri(record.address, chain_id, None);
+                            ui.output_mut(|o| o.copied_text = uri.clone());
+                            copied_request = Some(uri);
+                        }
+
                         if ui
                             .add(egui::Button::new("[=]").small())
                             .on_hover_text("Add to Split Even recipients")
@@ -453,6 +644,11 @@ fn render_address_table(
             }
         });
 
+    if let Some(uri) = copied_request {
+        app.notifications
+            .push_back(NotificationEntry::new(format!("[OK] Receive request copied: {}", uri)));
+    }
+
     // Add notification if an address was copied
     if let Some(addr) = copied_address {
         app.notifications.push_back(NotificationEntry::new(format!(
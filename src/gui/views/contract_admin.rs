@@ -0,0 +1,606 @@
+//! Contract admin view implementation
+//!
+//! This module contains the owner console for Beaug contracts including:
+//! - Owner, pending owner and stuck ETH balance of a contract
+//! - Transfer ownership, accept ownership and withdraw stuck funds, each shown on a review
+//!   screen before it is signed on the Ledger
+//! - Deterministic CREATE2 deployment of Beaug to `MAIN_BEAUG_ADDRESS` on networks without it
+
+use crate::beaug_deploy;
+use crate::contract_admin::{self, AdminAction, AdminReview, AdminRole};
+use crate::{ledger_dispatch, utils};
+use eframe::egui::{self, RichText};
+use ethers::types::Address;
+
+use super::super::app::GuiApp;
+use super::super::notifications::NotificationEntry;
+
+/// Renders the Contract Admin view
+pub fn view_contract_admin(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.render_section_header(ui, "[A]", "CONTRACT ADMIN");
+    ui.add_space(app.theme.spacing_sm);
+
+    poll_admin_jobs(app);
+    poll_deploy_jobs(app);
+
+    if app.admin.review.is_some() {
+        render_review(app, ui);
+        return;
+    }
+    if app.admin.deploy_review.is_some() {
+        render_deploy_review(app, ui);
+        return;
+    }
+
+    ui.label(
+        RichText::new("Two-step ownership transfer and stuck fund recovery for a Beaug contract. Actions are enabled for the owner (or pending owner) only.")
+            .color(app.theme.text_secondary),
+    );
+    ui.add_space(app.theme.spacing_md);
+
+    render_status(app, ui);
+    ui.add_space(app.theme.spacing_md);
+    render_actions(app, ui);
+    ui.add_space(app.theme.spacing_md);
+    render_deploy(app, ui);
+}
+
+fn poll_admin_jobs(app: &mut GuiApp) {
+    if let Some(job) = &mut app.admin.status_job {
+        if let Some(res) = job.poll() {
+            app.admin.status_job = None;
+            match res {
+                Ok(status) => {
+                    app.admin.status = Some(status);
+                    app.admin.error = None;
+                }
+                Err(e) => {
+                    app.admin.status = None;
+                    app.admin.error = Some(e.to_string());
+                }
+            }
+        }
+    }
+
+    if let Some(job) = &mut app.admin.signer_job {
+        if let Some(res) = job.poll() {
+            app.admin.signer_job = None;
+            match res {
+                Ok(address) => app.admin.signer_address = Some(address),
+                Err(e) => app.notifications.push_back(NotificationEntry::new(format!("[XX] Failed to get Ledger address: {}", e))),
+            }
+        }
+    }
+
+    if let Some(job) = &mut app.admin.review_job {
+        if let Some(res) = job.poll() {
+            app.admin.review_job = None;
+            match res {
+                Ok(review) => app.admin.review = Some(review),
+                Err(e) => app.notifications.push_back(NotificationEntry::new(format!("[XX] Cannot prepare transaction: {}", e))),
+            }
+        }
+    }
+
+    if let Some(job) = &mut app.admin.execute_job {
+        if let Some(res) = job.poll() {
+            app.admin.execute_job = None;
+            match res {
+                Ok(tx_hash) => {
+                    app.notifications.push_back(NotificationEntry::new(format!("[OK] Admin transaction confirmed: {:?}", tx_hash)));
+                    app.admin.review = None;
+                    app.admin.new_owner.clear();
+                    start_status_load(app);
+                }
+                Err(e) => app.notifications.push_back(NotificationEntry::new(format!("[XX] Admin transaction failed: {}", e))),
+            }
+        }
+    }
+}
+
+fn start_status_load(app: &mut GuiApp) {
+    let Ok(contract) = app.admin.contract_address.trim().parse::<Address>() else {
+        app.admin.error = Some("Invalid contract address".to_string());
+        return;
+    };
+    let config = app.config.clone();
+    app.admin.error = None;
+    app.admin.status_job = Some(app.spawn_job(move || async move {
+        let provider = config.get_provider().await?;
+        contract_admin::fetch_admin_status(&provider, contract).await
+    }));
+}
+
+fn render_status(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[i] Contract").size(16.0).strong().color(app.theme.text_primary));
+        ui.add_space(app.theme.spacing_sm);
+
+        ui.horizontal(|ui| {
+            ui.label("Contract:");
+            if ui
+                .add(egui::TextEdit::singleline(&mut app.admin.contract_address).desired_width(400.0).hint_text("0x..."))
+                .changed()
+            {
+                app.admin.status = None;
+            }
+            let loading = app.admin.status_job.is_some();
+            if ui.add_enabled(!loading, egui::Button::new(if loading { "[..] Loading..." } else { "Load" })).clicked() {
+                start_status_load(app);
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Signer index:");
+            if ui.add(egui::DragValue::new(&mut app.admin.signer_index).clamp_range(0..=10_000)).changed() {
+                app.admin.signer_address = None;
+            }
+            let ledger_ready = app.ledger_status.is_usable();
+            let fetching = app.admin.signer_job.is_some();
+            if ui
+                .add_enabled(ledger_ready && !fetching, egui::Button::new(if fetching { "[..]" } else { "Fetch from Ledger" }))
+                .on_disabled_hover_text("Connect and unlock your Ledger")
+                .clicked()
+            {
+                let config = app.config.clone();
+                let index = app.admin.signer_index;
+                let use_native_ledger = app.user_settings.use_native_ledger;
+                app.admin.signer_job = Some(app.spawn_job(move || async move {
+                    ledger_dispatch::get_ledger_address_with_retry_config(use_native_ledger, config.chain_id, index, Some(&config)).await
+                }));
+            }
+            if let Some(address) = app.admin.signer_address {
+                ui.monospace(format!("{:?}", address));
+            }
+        });
+
+        if let Some(error) = &app.admin.error {
+            ui.add_space(app.theme.spacing_xs);
+            ui.colored_label(app.theme.error, format!("[XX] {}", error));
+        }
+
+        let Some(status) = app.admin.status.clone() else {
+            return;
+        };
+        ui.add_space(app.theme.spacing_sm);
+        egui::Grid::new("admin_status_grid").num_columns(2).spacing([20.0, 4.0]).show(ui, |ui| {
+            ui.label("Owner:");
+            ui.monospace(format!("{:?}", status.owner));
+            ui.end_row();
+
+            ui.label("Pending owner:");
+            match status.pending_owner {
+                Some(pending) => ui.monospace(format!("{:?}", pending)),
+                None => ui.label(RichText::new("none").color(app.theme.text_secondary)),
+            };
+            ui.end_row();
+
+            ui.label("Stuck balance:");
+            let color = if status.stuck_balance.is_zero() { app.theme.text_primary } else { app.theme.warning };
+            ui.label(
+                RichText::new(format!("{} {}", utils::format_ether(status.stuck_balance), app.config.native_token()))
+                    .color(color),
+            );
+            ui.end_row();
+        });
+
+        if let Some(signer) = app.admin.signer_address {
+            ui.add_space(app.theme.spacing_xs);
+            let (color, text) = match status.role_of(signer) {
+                AdminRole::Owner => (app.theme.accent_green, "[OK] The signer is the owner".to_string()),
+                AdminRole::PendingOwner => (app.theme.info, "[i] The signer is the pending owner and can accept ownership".to_string()),
+                AdminRole::None => (
+                    app.theme.warning,
+                    format!("[!!] Index {} is neither the owner nor the pending owner", app.admin.signer_index),
+                ),
+            };
+            ui.colored_label(color, text);
+        }
+    });
+}
+
+fn render_actions(app: &mut GuiApp, ui: &mut egui::Ui) {
+    let Some(status) = app.admin.status.clone() else {
+        return;
+    };
+    let role = app.admin.signer_address.map(|signer| status.role_of(signer));
+    let busy = app.admin.review_job.is_some() || !app.ledger_status.is_usable();
+
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[>] Actions").size(16.0).strong().color(app.theme.text_primary));
+        ui.add_space(app.theme.spacing_sm);
+        if role.is_none() {
+            ui.label(RichText::new("Fetch the signer address from the Ledger to enable actions.").color(app.theme.text_secondary));
+            ui.add_space(app.theme.spacing_xs);
+        }
+
+        let is_owner = role == Some(AdminRole::Owner);
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.label("New owner:");
+            ui.add_enabled(
+                is_owner,
+                egui::TextEdit::singleline(&mut app.admin.new_owner).desired_width(380.0).hint_text("0x..."),
+            );
+            let new_owner = app.admin.new_owner.trim().parse::<Address>().ok();
+            if ui
+                .add_enabled(is_owner && !busy && new_owner.is_some(), egui::Button::new("Transfer Ownership"))
+                .on_hover_text("Nominates the new owner; they must accept before ownership changes")
+                .clicked()
+            {
+                action = new_owner.map(AdminAction::TransferOwnership);
+            }
+        });
+        ui.add_space(app.theme.spacing_xs);
+
+        ui.horizontal(|ui| {
+            let can_accept = role == Some(AdminRole::PendingOwner) && !busy;
+            if ui.add_enabled(can_accept, egui::Button::new("Accept Ownership")).clicked() {
+                action = Some(AdminAction::AcceptOwnership);
+            }
+            let can_withdraw = is_owner && !busy && !status.stuck_balance.is_zero();
+            if ui
+                .add_enabled(can_withdraw, app.theme.button_warning("Withdraw Stuck Funds"))
+                .on_hover_text("Sends the contract's ETH balance to the owner")
+                .clicked()
+            {
+                action = Some(AdminAction::WithdrawStuckFunds);
+            }
+            if app.admin.review_job.is_some() {
+                ui.label(RichText::new("[..] Preparing...").color(app.theme.text_secondary));
+            }
+        });
+
+        if let Some(action) = action {
+            start_review(app, status.contract, action);
+        }
+    });
+}
+
+fn start_review(app: &mut GuiApp, contract: Address, action: AdminAction) {
+    let config = app.config.clone();
+    let index = app.admin.signer_index as usize;
+    let gas_speed = app.bulk_disperse_state.gas_speed;
+    let use_native_ledger = app.user_settings.use_native_ledger;
+    app.admin.review_job = Some(app.spawn_job(move || async move {
+        contract_admin::prepare_admin_action(config, contract, action, index, gas_speed, use_native_ledger).await
+    }));
+}
+
+fn render_review(app: &mut GuiApp, ui: &mut egui::Ui) {
+    let Some(review) = app.admin.review.clone() else {
+        return;
+    };
+    let native_token = app.config.native_token().to_string();
+    let executing = app.admin.execute_job.is_some();
+
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[!] Review Admin Transaction").size(16.0).strong().color(app.theme.warning));
+        ui.add_space(app.theme.spacing_sm);
+
+        egui::Grid::new("admin_review_grid").num_columns(2).spacing([20.0, 6.0]).show(ui, |ui| {
+            ui.label("Action:");
+            ui.label(RichText::new(review.action.describe()).monospace().strong());
+            ui.end_row();
+
+            ui.label("Effect:");
+            ui.label(review_effect(&review, &native_token));
+            ui.end_row();
+
+            ui.label("Contract:");
+            ui.monospace(format!("{:?}", review.status.contract));
+            ui.end_row();
+
+            ui.label("Network:");
+            ui.label(app.config.network_label());
+            ui.end_row();
+
+            ui.label("Signer:");
+            ui.monospace(format!("{} ({:?})", review.signer_path, review.signer_address));
+            ui.end_row();
+
+            ui.label("Value:");
+            ui.label(format!("0 {}", native_token));
+            ui.end_row();
+
+            ui.label("Gas:");
+            ui.label(format!("{} ({})", review.gas_limit, review.fees.describe()));
+            ui.end_row();
+
+            ui.label("Fee:");
+            ui.label(format!(
+                "~{} {} expected, {} {} max",
+                utils::format_ether(review.expected_fee()),
+                native_token,
+                utils::format_ether(review.max_fee()),
+                native_token
+            ));
+            ui.end_row();
+        });
+
+        ui.add_space(app.theme.spacing_md);
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!executing, app.theme.button_warning(if executing { "[..] Confirm on Ledger..." } else { "Sign on Ledger" }))
+                .clicked()
+            {
+                let config = app.config.clone();
+                let use_native_ledger = app.user_settings.use_native_ledger;
+                let review = review.clone();
+                app.admin.execute_job = Some(app.spawn_job(move || async move {
+                    contract_admin::execute_admin_action(config, review, use_native_ledger).await
+                }));
+                app.notifications.push_back(NotificationEntry::new("Admin transaction initiated, confirm on your Ledger..."));
+            }
+            if ui.add_enabled(!executing, egui::Button::new("Cancel")).clicked() {
+                app.admin.review = None;
+            }
+        });
+    });
+}
+
+fn review_effect(review: &AdminReview, native_token: &str) -> String {
+    match &review.action {
+        AdminAction::TransferOwnership(new_owner) => format!(
+            "{:?} becomes the pending owner. Ownership moves only when they accept; until then {:?} stays owner.",
+            new_owner, review.status.owner
+        ),
+        AdminAction::AcceptOwnership => format!(
+            "{:?} becomes the owner, replacing {:?}.",
+            review.signer_address, review.status.owner
+        ),
+        AdminAction::WithdrawStuckFunds => format!(
+            "{} {} held by the contract is sent to the owner {:?}.",
+            utils::format_ether(review.status.stuck_balance),
+            native_token,
+            review.status.owner
+        ),
+    }
+}
+
+fn poll_deploy_jobs(app: &mut GuiApp) {
+    if let Some(job) = &mut app.admin.deploy_target_job {
+        if let Some(res) = job.poll() {
+            app.admin.deploy_target_job = None;
+            match res {
+                Ok(status) => app.admin.deploy_target = Some((app.config.chain_id, status)),
+                Err(e) => app.notifications.push_back(NotificationEntry::new(format!("[XX] Failed to check the Beaug deployment: {}", e))),
+            }
+        }
+    }
+
+    if let Some(job) = &mut app.admin.deploy_review_job {
+        if let Some(res) = job.poll() {
+            app.admin.deploy_review_job = None;
+            match res {
+                Ok(review) => app.admin.deploy_review = Some(review),
+                Err(e) => app.notifications.push_back(NotificationEntry::new(format!("[XX] Cannot prepare deployment: {}", e))),
+            }
+        }
+    }
+
+    if let Some(job) = &mut app.admin.deploy_execute_job {
+        if let Some(res) = job.poll() {
+            app.admin.deploy_execute_job = None;
+            match res {
+                Ok(outcome) => {
+                    let message = match outcome.code_hash_matches() {
+                        Some(true) => format!("[OK] Beaug deployed and code hash verified: {:?}", outcome.tx_hash),
+                        Some(false) => format!("[!!] Beaug deployed but its code hash differs from the reference deployment: {:?}", outcome.tx_hash),
+                        None => format!("[OK] Beaug deployed (code hash not verified, no reference RPC): {:?}", outcome.tx_hash),
+                    };
+                    app.notifications.push_back(NotificationEntry::new(message));
+                    app.admin.deploy_outcome = Some(outcome);
+                    app.admin.deploy_review = None;
+                    app.admin.deploy_target = None;
+                    // Re-validate so Bulk Disperse switches from the Multicall3 fallback to Beaug
+                    app.bulk_disperse_state.last_validated_address = None;
+                    app.bulk_disperse_state.contract_validation = None;
+                    app.bulk_disperse_state.contract_validation_job = None;
+                }
+                Err(e) => app.notifications.push_back(NotificationEntry::new(format!("[XX] Deployment failed: {}", e))),
+            }
+        }
+    }
+}
+
+fn render_deploy(app: &mut GuiApp, ui: &mut egui::Ui) {
+    let chain_id = app.config.chain_id;
+    let stale = app.admin.deploy_target.map(|(id, _)| id) != Some(chain_id);
+    if stale && app.admin.deploy_target_job.is_none() {
+        let config = app.config.clone();
+        app.admin.deploy_outcome = None;
+        app.admin.deploy_target_job = Some(app.spawn_job(move || async move {
+            let provider = config.get_provider().await?;
+            beaug_deploy::fetch_deploy_target_status(&provider).await
+        }));
+    }
+
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[^] Deploy Beaug").size(16.0).strong().color(app.theme.text_primary));
+        ui.add_space(app.theme.spacing_sm);
+
+        let Some((_, target)) = app.admin.deploy_target.filter(|(id, _)| *id == chain_id) else {
+            ui.label(RichText::new("[..] Checking this network...").color(app.theme.text_secondary));
+            return;
+        };
+
+        if target.beaug_deployed {
+            ui.colored_label(
+                app.theme.accent_green,
+                format!("[OK] Beaug is deployed at {} on {}", crate::disperse::MAIN_BEAUG_ADDRESS, app.config.network_label()),
+            );
+            if let Some(outcome) = &app.admin.deploy_outcome {
+                let (color, text) = match outcome.code_hash_matches() {
+                    Some(true) => (app.theme.accent_green, format!("[OK] Runtime code hash {:?} matches the reference deployment", outcome.code_hash)),
+                    Some(false) => (app.theme.error, format!("[XX] Runtime code hash {:?} differs from the reference deployment", outcome.code_hash)),
+                    None => (app.theme.warning, format!("[!!] Runtime code hash {:?} (no reference RPC to compare with)", outcome.code_hash)),
+                };
+                ui.colored_label(color, text);
+            }
+            return;
+        }
+
+        ui.label(
+            RichText::new(format!(
+                "Beaug is not deployed on this network. It can be created at {} through the deterministic CREATE2 deployer from the signer index above, using the Beaug release creation bytecode.",
+                crate::disperse::MAIN_BEAUG_ADDRESS
+            ))
+            .color(app.theme.text_secondary),
+        );
+        ui.label(
+            RichText::new(
+                "The creation bytecode is not bundled with Beaug: load the release artifact. Bytecode or a salt that would deploy anywhere else than the Beaug address is refused.",
+            )
+            .small()
+            .color(app.theme.warning),
+        );
+        if !target.deployer_present {
+            ui.add_space(app.theme.spacing_xs);
+            ui.colored_label(
+                app.theme.warning,
+                format!("[!!] The deterministic deployer {} is not on this network", beaug_deploy::DETERMINISTIC_DEPLOYER_ADDRESS),
+            );
+            return;
+        }
+        ui.add_space(app.theme.spacing_sm);
+
+        ui.horizontal(|ui| {
+            if ui.button("📄 Load Bytecode Artifact").on_hover_text("Raw hex or a Foundry / Hardhat JSON artifact").clicked() {
+                if let Some(path) = rfd::FileDialog::new().add_filter("Bytecode", &["json", "hex", "bin", "txt"]).pick_file() {
+                    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                    match std::fs::read_to_string(&path).map_err(anyhow::Error::from).and_then(|text| beaug_deploy::parse_creation_bytecode(&text)) {
+                        Ok(bytecode) => app.admin.deploy_bytecode = Some((name, bytecode)),
+                        Err(e) => app.notifications.push_back(NotificationEntry::new(format!("[XX] Failed to load bytecode: {}", e))),
+                    }
+                }
+            }
+            match &app.admin.deploy_bytecode {
+                Some((name, bytecode)) => ui.label(format!("{} ({} bytes)", name, bytecode.len())),
+                None => ui.label(RichText::new("no bytecode loaded").color(app.theme.text_secondary)),
+            };
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Salt:");
+            ui.add(egui::TextEdit::singleline(&mut app.admin.deploy_salt).desired_width(520.0).hint_text("0x00...00"));
+        });
+
+        let salt = beaug_deploy::parse_salt(&app.admin.deploy_salt);
+        let mut ready = false;
+        match (&salt, &app.admin.deploy_bytecode) {
+            (Err(e), _) => {
+                ui.colored_label(app.theme.error, format!("[XX] {}", e));
+            }
+            (Ok(salt), Some((_, bytecode))) => match beaug_deploy::check_predicted_address(*salt, bytecode) {
+                Ok(address) => {
+                    ui.colored_label(app.theme.accent_green, format!("[OK] Deploys to {:?}", address));
+                    ready = true;
+                }
+                Err(e) => {
+                    ui.colored_label(app.theme.error, format!("[XX] {}", e));
+                }
+            },
+            (Ok(_), None) => {}
+        }
+
+        ui.add_space(app.theme.spacing_xs);
+        ui.horizontal(|ui| {
+            let preparing = app.admin.deploy_review_job.is_some();
+            if ui
+                .add_enabled(ready && !preparing && app.ledger_status.is_usable(), egui::Button::new("Review Deployment"))
+                .on_disabled_hover_text("Load bytecode that deploys to the Beaug address and connect your Ledger")
+                .clicked()
+            {
+                if let (Ok(salt), Some((_, bytecode))) = (salt, app.admin.deploy_bytecode.clone()) {
+                    let config = app.config.clone();
+                    let index = app.admin.signer_index as usize;
+                    let gas_speed = app.bulk_disperse_state.gas_speed;
+                    let use_native_ledger = app.user_settings.use_native_ledger;
+                    app.admin.deploy_review_job = Some(app.spawn_job(move || async move {
+                        beaug_deploy::prepare_deployment(config, bytecode, salt, index, gas_speed, use_native_ledger).await
+                    }));
+                }
+            }
+            if preparing {
+                ui.label(RichText::new("[..] Preparing...").color(app.theme.text_secondary));
+            }
+        });
+    });
+}
+
+fn render_deploy_review(app: &mut GuiApp, ui: &mut egui::Ui) {
+    let Some(review) = app.admin.deploy_review.clone() else {
+        return;
+    };
+    let native_token = app.config.native_token().to_string();
+    let executing = app.admin.deploy_execute_job.is_some();
+
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[!] Review Beaug Deployment").size(16.0).strong().color(app.theme.warning));
+        ui.add_space(app.theme.spacing_sm);
+
+        egui::Grid::new("deploy_review_grid").num_columns(2).spacing([20.0, 6.0]).show(ui, |ui| {
+            ui.label("Contract:");
+            ui.monospace(format!("{:?}", review.target));
+            ui.end_row();
+
+            ui.label("Deployer:");
+            ui.monospace(beaug_deploy::DETERMINISTIC_DEPLOYER_ADDRESS);
+            ui.end_row();
+
+            ui.label("Salt:");
+            ui.monospace(format!("{:?}", review.salt));
+            ui.end_row();
+
+            ui.label("Bytecode:");
+            ui.label(format!("{} bytes", review.init_code.len()));
+            ui.end_row();
+
+            ui.label("Network:");
+            ui.label(app.config.network_label());
+            ui.end_row();
+
+            ui.label("Signer:");
+            ui.monospace(format!("{} ({:?})", review.signer_path, review.signer_address));
+            ui.end_row();
+
+            ui.label("Gas:");
+            ui.label(format!("{} ({})", review.gas_limit, review.fees.describe()));
+            ui.end_row();
+
+            ui.label("Fee:");
+            ui.label(format!(
+                "~{} {} expected, {} {} max",
+                utils::format_ether(review.expected_fee()),
+                native_token,
+                utils::format_ether(review.max_fee()),
+                native_token
+            ));
+            ui.end_row();
+        });
+
+        ui.add_space(app.theme.spacing_md);
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!executing, app.theme.button_warning(if executing { "[..] Confirm on Ledger..." } else { "Sign on Ledger" }))
+                .clicked()
+            {
+                let config = app.config.clone();
+                let use_native_ledger = app.user_settings.use_native_ledger;
+                let reference_rpc_url = (config.chain_id != beaug_deploy::REFERENCE_CHAIN_ID)
+                    .then(|| app.user_settings.rpc_url_for(beaug_deploy::REFERENCE_CHAIN_ID))
+                    .flatten();
+                let review = review.clone();
+                app.admin.deploy_execute_job = Some(app.spawn_job(move || async move {
+                    beaug_deploy::execute_deployment(config, review, reference_rpc_url, use_native_ledger).await
+                }));
+                app.notifications.push_back(NotificationEntry::new("Beaug deployment initiated, confirm on your Ledger..."));
+            }
+            if ui.add_enabled(!executing, egui::Button::new("Cancel")).clicked() {
+                app.admin.deploy_review = None;
+            }
+        });
+    });
+}
@@ -174,6 +174,12 @@ impl GuiApp {
                     ui.label(RichText::new(&native_token).color(self.theme.accent_green));
                     ui.end_row();
 
+                    if self.user_settings.show_fiat_values {
+                        ui.label(RichText::new("USD Price:").color(self.theme.text_secondary));
+                        self.render_price_source(ui);
+                        ui.end_row();
+                    }
+
                     ui.label(RichText::new("RPC:").color(self.theme.text_secondary));
                     ui.horizontal(|ui| {
                         ui.label(RichText::new(&self.config.rpc_url).small().color(self.theme.accent_green));
@@ -235,7 +241,7 @@ impl GuiApp {
                         let address = crate::ledger_ops::get_ledger_address_with_config(config.chain_id, index, Some(&config)).await?;
                         let balance = provider.get_balance(address, None).await?;
                         let derivation_path = config.get_derivation_path(index);
-                        Ok((format!("{} -> {:?}", derivation_path, address), crate::utils::format_ether(balance)))
+                        Ok((format!("{} -> {:?}", derivation_path, address), balance))
                     }));
                 }
             });
@@ -244,9 +250,13 @@ impl GuiApp {
                 ui.add_space(self.theme.spacing_xs);
                 ui.monospace(RichText::new(format!("  {}", addr)).small());
             }
-            if let Some(balance) = &self.balance_view.balance {
+            if let Some(balance) = self.balance_view.balance {
                 let (_, native_token, _, _) = self.selected_network_info();
-                ui.label(RichText::new(format!("  Balance: {} {}", balance, native_token)).color(self.theme.success));
+                let usd = self.usd_value(balance).map(|usd| format!(" ({})", usd)).unwrap_or_default();
+                ui.label(
+                    RichText::new(format!("  Balance: {} {}{}", crate::utils::format_ether(balance), native_token, usd))
+                        .color(self.theme.success),
+                );
             }
             if let Some(err) = &self.balance_view.error {
                 ui.colored_label(self.theme.error, format!("  [XX] {}", err));
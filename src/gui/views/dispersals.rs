@@ -0,0 +1,507 @@
+//! Dispersals view implementation
+//!
+//! This module contains the proof-of-dispersal panel rendering including:
+//! - Looking up a Beaug dispersal by transaction hash or dispersal id
+//! - Recomputing `keccak256(recipients)` for a recipient list and comparing it to the event
+//! - Searching every network for the dispersals sent by a set of addresses
+
+use crate::dispersal_history::{self, NetworkDispersals};
+use crate::dispersal_proof::{self, DispersalVerification};
+use crate::{bulk_disperse, ledger_dispatch, utils};
+use eframe::egui::{self, RichText};
+use ethers::types::Address;
+
+use super::super::app::GuiApp;
+
+/// Renders the Dispersals view
+pub fn view_dispersals(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.render_section_header(ui, "[#]", "DISPERSALS");
+    ui.add_space(app.theme.spacing_sm);
+
+    poll_verify_job(app);
+    poll_history_jobs(app);
+
+    ui.label(
+        RichText::new("Every Beaug dispersal records a dispersal id and a hash of its recipient list on-chain.")
+            .color(app.theme.text_secondary),
+    );
+    ui.add_space(app.theme.spacing_md);
+
+    render_verifier(app, ui);
+    ui.add_space(app.theme.spacing_md);
+    render_history(app, ui);
+}
+
+fn poll_verify_job(app: &mut GuiApp) {
+    if let Some(job) = &mut app.dispersals.verify_job {
+        if let Some(res) = job.poll() {
+            app.dispersals.verify_job = None;
+            match res {
+                Ok(verification) => {
+                    app.dispersals.verify_result = Some(verification);
+                    app.dispersals.verify_error = None;
+                }
+                Err(e) => {
+                    app.dispersals.verify_result = None;
+                    app.dispersals.verify_error = Some(e.to_string());
+                }
+            }
+        }
+    }
+}
+
+/// Recipient addresses in input order; amounts, if present, are ignored
+fn parse_recipient_addresses(input: &str, chain_id: u64) -> anyhow::Result<Vec<Address>> {
+    Ok(bulk_disperse::parse_bulk_disperse_input(input, chain_id)?.addresses())
+}
+
+fn render_verifier(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[✓] Verify a Dispersal").size(16.0).strong().color(app.theme.text_primary));
+        ui.add_space(app.theme.spacing_sm);
+
+        ui.horizontal(|ui| {
+            ui.label("Tx hash or dispersal id:");
+            ui.add(
+                egui::TextEdit::singleline(&mut app.dispersals.verify_lookup)
+                    .desired_width(480.0)
+                    .hint_text("0x... or 42"),
+            );
+        });
+        ui.label(
+            RichText::new(format!(
+                "Dispersal ids are looked up on the contract set in Bulk Disperse ({}) on {}.",
+                app.bulk_disperse_state.disperse_contract_address.trim(),
+                app.config.network_label()
+            ))
+            .small()
+            .color(app.theme.text_secondary),
+        );
+        ui.add_space(app.theme.spacing_sm);
+
+        ui.label("Recipients, in the order they were dispersed (one per line, amounts are ignored; include the tip recipient if one was added):");
+        ui.add(
+            egui::TextEdit::multiline(&mut app.dispersals.verify_recipients)
+                .desired_rows(6)
+                .desired_width(f32::INFINITY)
+                .font(egui::TextStyle::Monospace)
+                .hint_text("0x...\n0x..."),
+        );
+        ui.add_space(app.theme.spacing_sm);
+
+        let verifying = app.dispersals.verify_job.is_some();
+        if ui
+            .add_enabled(!verifying, egui::Button::new(if verifying { "[..] Verifying..." } else { "Verify" }))
+            .clicked()
+        {
+            start_verify(app);
+        }
+
+        if let Some(error) = &app.dispersals.verify_error {
+            ui.add_space(app.theme.spacing_xs);
+            ui.colored_label(app.theme.error, format!("[XX] {}", error));
+        }
+
+        if let Some(verification) = &app.dispersals.verify_result {
+            ui.add_space(app.theme.spacing_sm);
+            render_verification(app, ui, verification);
+        }
+    });
+}
+
+fn start_verify(app: &mut GuiApp) {
+    let lookup = match dispersal_proof::parse_dispersal_lookup(&app.dispersals.verify_lookup) {
+        Ok(lookup) => lookup,
+        Err(e) => {
+            app.dispersals.verify_error = Some(e.to_string());
+            return;
+        }
+    };
+    let recipients = match parse_recipient_addresses(&app.dispersals.verify_recipients, app.config.chain_id) {
+        Ok(recipients) if !recipients.is_empty() => recipients,
+        Ok(_) => {
+            app.dispersals.verify_error = Some("Enter the recipient list to verify".to_string());
+            return;
+        }
+        Err(e) => {
+            app.dispersals.verify_error = Some(format!("Invalid recipient list: {}", e));
+            return;
+        }
+    };
+    let Ok(contract) = app.bulk_disperse_state.disperse_contract_address.trim().parse::<Address>() else {
+        app.dispersals.verify_error = Some("Set a valid Beaug contract address in Bulk Disperse".to_string());
+        return;
+    };
+
+    let config = app.config.clone();
+    app.dispersals.verify_error = None;
+    app.dispersals.verify_result = None;
+    app.dispersals.verify_job = Some(app.spawn_job(move || async move {
+        let provider = config.get_provider().await?;
+        dispersal_proof::verify_dispersal(&provider, contract, &lookup, &recipients).await
+    }));
+}
+
+fn render_verification(app: &GuiApp, ui: &mut egui::Ui, verification: &DispersalVerification) {
+    let record = &verification.record;
+    let native_token = app.config.native_token();
+
+    if verification.hash_matches() {
+        ui.colored_label(app.theme.accent_green, "[OK] The recipient list matches this dispersal");
+    } else {
+        ui.colored_label(app.theme.error, "[XX] The recipient list does NOT match this dispersal");
+    }
+    ui.add_space(app.theme.spacing_xs);
+
+    egui::Grid::new("dispersal_verification_grid")
+        .num_columns(2)
+        .spacing([20.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("Dispersal ID:");
+            ui.label(RichText::new(record.dispersal_id.to_string()).strong());
+            ui.end_row();
+
+            ui.label("Sender:");
+            ui.monospace(format!("{:?}", record.sender));
+            ui.end_row();
+
+            ui.label("Total distributed:");
+            ui.label(format!("{} {}", utils::format_ether(record.total_distributed), native_token));
+            ui.end_row();
+
+            ui.label("Recipients:");
+            let count_color = if verification.count_matches() { app.theme.text_primary } else { app.theme.warning };
+            ui.label(
+                RichText::new(format!("{} on-chain, {} provided", record.recipient_count, verification.provided_count))
+                    .color(count_color),
+            );
+            ui.end_row();
+
+            ui.label("On-chain hash:");
+            ui.monospace(format!("{:?}", record.recipients_hash));
+            ui.end_row();
+
+            ui.label("Computed hash:");
+            let hash_color = if verification.hash_matches() { app.theme.accent_green } else { app.theme.error };
+            ui.label(RichText::new(format!("{:?}", verification.computed_hash)).monospace().color(hash_color));
+            ui.end_row();
+
+            if let Some(block) = record.block_number {
+                ui.label("Block:");
+                ui.label(block.to_string());
+                ui.end_row();
+            }
+
+            if let Some(tx_hash) = record.tx_hash {
+                let hash_str = format!("{:?}", tx_hash);
+                ui.label("Transaction:");
+                ui.horizontal(|ui| {
+                    ui.monospace(&hash_str);
+                    if let Some(explorer_url) = crate::config::get_tx_explorer_url(app.config.chain_id, &hash_str) {
+                        if ui.add(egui::Button::new("🔗").small()).on_hover_text("View on block explorer").clicked() {
+                            if let Err(e) = open::that(&explorer_url) {
+                                tracing::warn!("Failed to open explorer URL: {}", e);
+                            }
+                        }
+                    }
+                });
+                ui.end_row();
+            }
+        });
+}
+
+fn poll_history_jobs(app: &mut GuiApp) {
+    if let Some(receiver) = &mut app.dispersals.history_progress_receiver {
+        while let Ok(progress) = receiver.try_recv() {
+            app.dispersals.history_progress.insert(progress.chain_id, progress);
+        }
+    }
+
+    if let Some(job) = &mut app.dispersals.history_job {
+        if let Some(res) = job.poll() {
+            app.dispersals.history_job = None;
+            app.dispersals.history_progress_receiver = None;
+            app.dispersals.history_progress.clear();
+            match res {
+                Ok(history) => app.dispersals.history = history,
+                Err(e) => app.dispersals.history_error = Some(e.to_string()),
+            }
+        }
+    }
+
+    if let Some(job) = &mut app.dispersals.derive_job {
+        if let Some(res) = job.poll() {
+            app.dispersals.derive_job = None;
+            match res {
+                Ok(addresses) => {
+                    for address in addresses {
+                        add_sender(&mut app.dispersals.history_senders, &format!("{:?}", address));
+                    }
+                }
+                Err(e) => app.dispersals.history_error = Some(format!("Failed to derive Ledger addresses: {}", e)),
+            }
+        }
+    }
+}
+
+/// Append `address` to the sender list unless it is already there
+fn add_sender(senders: &mut String, address: &str) {
+    if senders.lines().any(|line| line.trim().eq_ignore_ascii_case(address)) {
+        return;
+    }
+    if !senders.is_empty() && !senders.ends_with('\n') {
+        senders.push('\n');
+    }
+    senders.push_str(address);
+}
+
+fn parse_senders(input: &str) -> anyhow::Result<Vec<Address>> {
+    let mut senders = Vec::new();
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let address: Address = line.parse().map_err(|_| anyhow::anyhow!("Invalid sender address: {}", line))?;
+        if !senders.contains(&address) {
+            senders.push(address);
+        }
+    }
+    Ok(senders)
+}
+
+fn render_history(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[=] On-chain History").size(16.0).strong().color(app.theme.text_primary));
+        ui.add_space(app.theme.spacing_sm);
+        ui.label(
+            RichText::new("Lists every BeaugDispersed event sent by these addresses, on every network the contract is deployed on.")
+                .color(app.theme.text_secondary),
+        );
+        ui.add_space(app.theme.spacing_sm);
+
+        ui.label("Sender addresses (one per line):");
+        ui.add(
+            egui::TextEdit::multiline(&mut app.dispersals.history_senders)
+                .desired_rows(4)
+                .desired_width(f32::INFINITY)
+                .font(egui::TextStyle::Monospace)
+                .hint_text("0x..."),
+        );
+
+        ui.horizontal(|ui| {
+            let source = app.bulk_disperse_state.source_address.clone();
+            if ui.add_enabled(source.is_some(), egui::Button::new("+ Bulk Disperse source")).clicked() {
+                if let Some(source) = source {
+                    add_sender(&mut app.dispersals.history_senders, &source);
+                }
+            }
+
+            if ui.add_enabled(!app.user_settings.address_book.is_empty(), egui::Button::new("+ Address book")).clicked() {
+                for entry in &app.user_settings.address_book {
+                    add_sender(&mut app.dispersals.history_senders, &entry.address);
+                }
+            }
+
+            ui.separator();
+            let ledger_ready = app.ledger_status.is_usable();
+            let deriving = app.dispersals.derive_job.is_some();
+            ui.label("Ledger indexes 0 to");
+            ui.add(egui::DragValue::new(&mut app.dispersals.derive_count).clamp_range(1..=50));
+            if ui
+                .add_enabled(ledger_ready && !deriving, egui::Button::new(if deriving { "[..]" } else { "+ Derive" }))
+                .on_disabled_hover_text("Connect and unlock your Ledger")
+                .clicked()
+            {
+                start_derive(app);
+            }
+        });
+        ui.add_space(app.theme.spacing_sm);
+
+        ui.horizontal(|ui| {
+            ui.label("Lookback without an archive node:");
+            ui.add(
+                egui::DragValue::new(&mut app.dispersals.history_lookback_blocks)
+                    .clamp_range(1_000..=100_000_000)
+                    .speed(10_000)
+                    .suffix(" blocks"),
+            );
+
+            let searching = app.dispersals.history_job.is_some();
+            if ui
+                .add_enabled(!searching, egui::Button::new(if searching { "[..] Searching..." } else { "Search all networks" }))
+                .clicked()
+            {
+                start_history(app);
+            }
+        });
+        ui.label(
+            RichText::new(format!(
+                "Uses the contract set in Bulk Disperse ({}) and the configured RPC of each network.",
+                app.bulk_disperse_state.disperse_contract_address.trim()
+            ))
+            .small()
+            .color(app.theme.text_secondary),
+        );
+
+        if let Some(error) = &app.dispersals.history_error {
+            ui.add_space(app.theme.spacing_xs);
+            ui.colored_label(app.theme.error, format!("[XX] {}", error));
+        }
+
+        if app.dispersals.history_job.is_some() {
+            ui.add_space(app.theme.spacing_sm);
+            let mut progress: Vec<_> = app.dispersals.history_progress.values().cloned().collect();
+            progress.sort_by_key(|p| p.chain_id);
+            for p in progress {
+                let label = crate::config::find_network_by_chain_id(p.chain_id)
+                    .map(|n| n.label.to_string())
+                    .unwrap_or_else(|| format!("Chain {}", p.chain_id));
+                let span = (p.to_block - p.from_block).max(1) as f32;
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(label).small());
+                    ui.add(
+                        egui::ProgressBar::new((p.scanned_to - p.from_block) as f32 / span)
+                            .desired_width(200.0)
+                            .text(format!("block {}", p.scanned_to)),
+                    );
+                });
+            }
+        }
+
+        if !app.dispersals.history.is_empty() {
+            ui.add_space(app.theme.spacing_sm);
+            render_history_results(app, ui);
+        }
+    });
+}
+
+fn start_derive(app: &mut GuiApp) {
+    let config = app.config.clone();
+    let count = app.dispersals.derive_count;
+    let use_native_ledger = app.user_settings.use_native_ledger;
+    app.dispersals.history_error = None;
+    app.dispersals.derive_job = Some(app.spawn_job(move || async move {
+        let mut addresses = Vec::new();
+        for index in 0..count {
+            addresses.push(
+                ledger_dispatch::get_ledger_address_with_retry_config(use_native_ledger, config.chain_id, index, Some(&config))
+                    .await?,
+            );
+        }
+        Ok(addresses)
+    }));
+}
+
+fn start_history(app: &mut GuiApp) {
+    let senders = match parse_senders(&app.dispersals.history_senders) {
+        Ok(senders) if !senders.is_empty() => senders,
+        Ok(_) => {
+            app.dispersals.history_error = Some("Add at least one sender address".to_string());
+            return;
+        }
+        Err(e) => {
+            app.dispersals.history_error = Some(e.to_string());
+            return;
+        }
+    };
+    let Ok(contract) = app.bulk_disperse_state.disperse_contract_address.trim().parse::<Address>() else {
+        app.dispersals.history_error = Some("Set a valid Beaug contract address in Bulk Disperse".to_string());
+        return;
+    };
+
+    let networks = dispersal_history::history_networks(&app.user_settings);
+    let lookback = app.dispersals.history_lookback_blocks;
+    let (progress_tx, progress_rx) = tokio::sync::mpsc::unbounded_channel();
+    app.dispersals.history_error = None;
+    app.dispersals.history.clear();
+    app.dispersals.history_progress.clear();
+    app.dispersals.history_progress_receiver = Some(progress_rx);
+    app.dispersals.history_job = Some(app.spawn_job(move || async move {
+        Ok(dispersal_history::fetch_dispersal_history(networks, contract, senders, lookback, Some(progress_tx)).await)
+    }));
+}
+
+fn short_address(address: Address) -> String {
+    let full = format!("{:?}", address);
+    format!("{}...{}", &full[..8], &full[full.len() - 6..])
+}
+
+fn render_history_results(app: &GuiApp, ui: &mut egui::Ui) {
+    let deployed: Vec<&NetworkDispersals> = app.dispersals.history.iter().filter(|n| n.deployed || n.error.is_some()).collect();
+    let not_deployed = app.dispersals.history.len() - deployed.len();
+
+    for network in &deployed {
+        if let Some(error) = &network.error {
+            ui.colored_label(app.theme.warning, format!("[!!] {}: {}", network.label, error));
+        } else {
+            let range = if network.from_deployment {
+                format!("blocks {}-{} (since deployment)", network.from_block, network.to_block)
+            } else {
+                format!("blocks {}-{} (partial: deployment block unavailable on this RPC)", network.from_block, network.to_block)
+            };
+            let color = if network.from_deployment { app.theme.text_secondary } else { app.theme.warning };
+            ui.label(
+                RichText::new(format!("{}: {} dispersal(s), {}", network.label, network.records.len(), range))
+                    .small()
+                    .color(color),
+            );
+        }
+    }
+    if not_deployed > 0 {
+        ui.label(
+            RichText::new(format!("Contract not deployed on {} other network(s)", not_deployed))
+                .small()
+                .color(app.theme.text_secondary),
+        );
+    }
+
+    let total: usize = deployed.iter().map(|n| n.records.len()).sum();
+    if total == 0 {
+        ui.add_space(app.theme.spacing_xs);
+        ui.label("No dispersals found for these senders.");
+        return;
+    }
+
+    ui.add_space(app.theme.spacing_sm);
+    egui::ScrollArea::vertical().id_source("dispersal_history_scroll").max_height(400.0).show(ui, |ui| {
+        egui::Grid::new("dispersal_history_grid")
+            .num_columns(7)
+            .striped(true)
+            .spacing([16.0, 4.0])
+            .show(ui, |ui| {
+                for header in ["Network", "ID", "Sender", "Total", "Recipients", "Block", "Tx"] {
+                    ui.label(RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for network in &deployed {
+                    for record in network.records.iter().rev() {
+                        ui.label(&network.label);
+                        ui.label(record.dispersal_id.to_string());
+                        ui.monospace(short_address(record.sender)).on_hover_text(format!("{:?}", record.sender));
+                        ui.label(format!("{} {}", utils::format_ether(record.total_distributed), network.native_token));
+                        ui.label(record.recipient_count.to_string());
+                        ui.label(record.block_number.map(|b| b.to_string()).unwrap_or_default());
+                        match record.tx_hash {
+                            Some(tx_hash) => {
+                                let hash_str = format!("{:?}", tx_hash);
+                                match crate::config::get_tx_explorer_url(network.chain_id, &hash_str) {
+                                    Some(explorer_url) => {
+                                        if ui.link(format!("{}...", &hash_str[..10])).on_hover_text(&hash_str).clicked() {
+                                            if let Err(e) = open::that(&explorer_url) {
+                                                tracing::warn!("Failed to open explorer URL: {}", e);
+                                            }
+                                        }
+                                    }
+                                    None => {
+                                        ui.monospace(format!("{}...", &hash_str[..10]));
+                                    }
+                                }
+                            }
+                            None => {
+                                ui.label("-");
+                            }
+                        }
+                        ui.end_row();
+                    }
+                }
+            });
+    });
+}
@@ -6,29 +6,49 @@
 //! - Recipient list management with parsing
 //! - Amount calculation (auto or manual)
 //! - Gas estimation and execution
+//!
+//! ERC-20 token mode lives in `token_disperse`, disperses paid from several funded
+//! sources in `multi_source_disperse`.
 
 use crate::bulk_disperse;
-use crate::gui::app::GuiApp;
-use crate::gui::helpers::{calculate_disperse_gas_limit, format_gwei, gas_speed_emoji, gas_speed_label, gas_speed_warning};
+use crate::disperse_batch;
+use crate::fee_oracle;
+use crate::gui::app::{GuiApp, PendingPayrollRun};
+use crate::gui::helpers::{calculate_disperse_gas_limit, gas_speed_emoji, gas_speed_label, gas_speed_warning};
 use crate::gui::notifications::NotificationEntry;
+use crate::gui::views::recipient_report::RecipientFix;
 use crate::ledger_ops;
+use crate::payment_uri::PaymentAsset;
+use crate::recipient_check::CheckedRecipient;
+use crate::recipient_input;
+use crate::schedule::SchedulePlan;
 use crate::utils;
 use eframe::egui::{self, RichText};
 use ethers::prelude::Middleware;
+use ethers::types::{Address, U256};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 impl GuiApp {
     /// Render the bulk disperse view
     pub(crate) fn view_bulk_disperse(&mut self, ui: &mut egui::Ui) {
+        // A prepared batch queue replaces the form until the user goes back
+        if self.bulk_disperse_state.batch_view.is_some() {
+            self.view_disperse_batches(ui);
+            return;
+        }
+
         // Section header
         self.render_section_header(ui, "[$]", "BULK DISPERSE");
         ui.add_space(self.theme.spacing_md);
+        self.render_payroll_banner(ui);
 
-        // Fetch gas price if not available or job not running
-        if self.bulk_disperse_state.current_gas_price.is_none() && self.bulk_disperse_state.gas_price_job.is_none() {
+        // Fetch network fees if not available or job not running
+        if self.bulk_disperse_state.current_fees.is_none() && self.bulk_disperse_state.fees_job.is_none() {
             let config = self.config.clone();
-            self.bulk_disperse_state.gas_price_job = Some(self.spawn_job(move || async move {
+            self.bulk_disperse_state.fees_job = Some(self.spawn_job(move || async move {
                 let provider = config.get_provider().await?;
-                provider.get_gas_price().await.map_err(anyhow::Error::from)
+                fee_oracle::fetch_snapshot(&provider, config.chain_id).await
             }));
         }
 
@@ -37,9 +57,32 @@ impl GuiApp {
         
         ui.add_space(self.theme.spacing_sm);
 
+        // Native currency or ERC-20 token
+        self.render_asset_selector(ui);
+
+        ui.add_space(self.theme.spacing_sm);
+
         // Recipients Input - Large text box for pasting
         self.render_recipients_input(ui);
 
+        if self.bulk_disperse_state.token_mode {
+            self.render_token_disperse(ui);
+            return;
+        }
+
+        ui.add_space(self.theme.spacing_sm);
+        ui.checkbox(&mut self.bulk_disperse_state.multi_source.enabled, "Pool funds from several sources")
+            .on_hover_text("Split the recipients across funded addresses from a saved scan, one disperse transaction per source");
+        if self.bulk_disperse_state.multi_source.enabled {
+            self.render_recipients_preview(ui);
+            ui.add_space(self.theme.spacing_md);
+            self.view_multi_source_disperse(ui);
+            return;
+        }
+
+        // Estimate the gas limit against the real calldata once source and recipients are known
+        self.maybe_estimate_disperse_gas();
+
         // Show parsed preview if there's input
         self.render_recipients_preview(ui);
         
@@ -140,30 +183,15 @@ impl GuiApp {
             
             if let Some(ref status) = self.bulk_disperse_state.contract_validation {
                 ui.add_space(8.0);
-                let (icon, color, text) = match status {
-                    crate::disperse::ContractValidationStatus::MainBeaugRegistry => {
-                        ("★", egui::Color32::from_rgb(0, 220, 120), status.display_text())
-                    }
-                    crate::disperse::ContractValidationStatus::RegisteredAndCompatible => {
-                        ("✓", egui::Color32::from_rgb(0, 200, 100), status.display_text())
-                    }
-                    crate::disperse::ContractValidationStatus::RegisteredButIncompatible => {
-                        ("⚠", egui::Color32::from_rgb(255, 180, 0), status.display_text())
-                    }
-                    crate::disperse::ContractValidationStatus::CompatibleButUnregistered => {
-                        ("ℹ", egui::Color32::from_rgb(100, 150, 255), status.display_text())
-                    }
-                    crate::disperse::ContractValidationStatus::Unknown => {
-                        ("✗", egui::Color32::from_rgb(255, 80, 80), "Unknown contract - use with caution")
-                    }
-                    crate::disperse::ContractValidationStatus::Checking => {
-                        ("⋯", egui::Color32::from_rgb(150, 150, 150), status.display_text())
-                    }
-                    crate::disperse::ContractValidationStatus::Error(_) => {
-                        ("!", egui::Color32::from_rgb(255, 100, 100), status.display_text())
-                    }
-                };
+                let (icon, color, text) = contract_status_badge(status);
                 ui.label(RichText::new(format!("{} {}", icon, text)).color(color).size(11.0));
+                if let Some(protocol) = status.protocol().filter(|p| !p.emits_dispersal_proof()) {
+                    ui.label(
+                        RichText::new(format!("(uses {}; no proof-of-dispersal event)", protocol.native_function_name()))
+                            .color(self.theme.text_secondary)
+                            .size(11.0),
+                    );
+                }
             }
         });
     }
@@ -183,7 +211,14 @@ impl GuiApp {
         });
 
         ui.add_space(self.theme.spacing_sm);
-        ui.label(RichText::new(format!("Format: addresses only (equal split) OR address,amount_in_{} per line", self.config.native_token().to_lowercase())).italics().size(11.0));
+        let unit = match (&self.bulk_disperse_state.token.info, self.bulk_disperse_state.token_mode) {
+            (Some(info), true) => info.symbol.to_lowercase(),
+            _ => self.config.native_token().to_lowercase(),
+        };
+        ui.label(RichText::new(format!("Format: addresses only (equal split) OR address,amount_in_{} per line; addresses may be ENS names, `base:0x…` or `ethereum:` payment links", unit)).italics().size(11.0));
+
+        let ens_names = recipient_input::ens_names(&self.bulk_disperse_state.recipients_input);
+        self.render_ens_resolutions(ui, "bulk_disperse", &ens_names);
 
         // Optional CSV upload
         ui.add_space(self.theme.spacing_sm);
@@ -221,7 +256,7 @@ impl GuiApp {
         ui.add_space(self.theme.spacing_md);
         ui.label(RichText::new("[#] Parsed Recipients Preview:").strong());
 
-        match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input) {
+        match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id) {
             Ok(disperse_type) => {
                 match disperse_type {
                     bulk_disperse::BulkDisperseType::Equal(ref addresses) => {
@@ -247,6 +282,13 @@ impl GuiApp {
                             ui.label("[$] Specified amounts will be distributed");
                         }
                     }
+                    bulk_disperse::BulkDisperseType::Weighted(ref recipients) => {
+                        ui.colored_label(
+                            egui::Color32::GREEN,
+                            format!("[OK] Weighted distribution: {} recipients", recipients.len())
+                        );
+                        ui.label("[$] Percentages and weights are resolved against the amount to send");
+                    }
                 }
 
                 // Amount validation
@@ -265,6 +307,17 @@ impl GuiApp {
                                             format!("[OK] Amount to send: {} {} (will be split evenly)", amount_str, native_token)
                                         );
                                     }
+                                    bulk_disperse::BulkDisperseType::Weighted(_) => match disperse_type.resolve(amount_wei) {
+                                        Ok(_) => {
+                                            ui.colored_label(
+                                                egui::Color32::GREEN,
+                                                format!("[OK] Amount to send: {} {} (split by share, exact to the wei)", amount_str, native_token)
+                                            );
+                                        }
+                                        Err(e) => {
+                                            ui.colored_label(egui::Color32::RED, format!("[XX] {}", e));
+                                        }
+                                    },
                                     bulk_disperse::BulkDisperseType::Mixed(ref recipients) => {
                                         let total_specified: ethers::types::U256 = recipients.iter().map(|(_, amount)| *amount).fold(ethers::types::U256::zero(), |acc, x| acc + x);
                                         let display_tip_wei = Self::parse_optional_eth_to_wei(&self.bulk_disperse_state.tip_amount)
@@ -322,6 +375,25 @@ impl GuiApp {
                                             ui.label(format!("{}. {:?} → {} {}", i + 1, addr, utils::format_ether(*amount), native_token));
                                         }
                                     }
+                                    bulk_disperse::BulkDisperseType::Weighted(ref recipients) => {
+                                        let resolved = utils::parse_eth_str_to_wei(self.bulk_disperse_state.amount_input.trim())
+                                            .ok()
+                                            .and_then(|total| disperse_type.resolve(total).ok());
+                                        for (i, (addr, share)) in recipients.iter().enumerate() {
+                                            if i >= 10 {
+                                                ui.label(format!("... and {} more recipients", recipients.len() - 10));
+                                                break;
+                                            }
+                                            let share_text = share.describe(|value| format!("{} {}", utils::format_ether(value), native_token));
+                                            match &resolved {
+                                                Some(resolved) => ui.label(format!(
+                                                    "{}. {:?} → {} {} ({})",
+                                                    i + 1, addr, utils::format_ether(resolved[i].1), native_token, share_text
+                                                )),
+                                                None => ui.label(format!("{}. {:?} ({})", i + 1, addr, share_text)),
+                                            };
+                                        }
+                                    }
                                 }
                             });
                     });
@@ -330,9 +402,45 @@ impl GuiApp {
                 ui.colored_label(egui::Color32::RED, format!("[XX] Parse error: {}", e));
             }
         }
+
+        self.render_bulk_recipient_report(ui, PaymentAsset::Native);
+    }
+
+    /// Validation report over the recipient list, applying the fix the user picks to the input
+    pub(crate) fn render_bulk_recipient_report(&mut self, ui: &mut egui::Ui, asset: PaymentAsset) {
+        let Ok(lines) = recipient_input::parse_recipient_addresses(&self.bulk_disperse_state.recipients_input, self.config.chain_id, asset) else {
+            return;
+        };
+        let recipients: Vec<CheckedRecipient> = lines
+            .iter()
+            .map(|line| CheckedRecipient { text: line.text.clone(), address: line.address })
+            .collect();
+        let state = &self.bulk_disperse_state;
+        let mut sources: Vec<Address> = state.source_address.iter().filter_map(|address| address.parse().ok()).collect();
+        if state.multi_source.enabled {
+            sources.extend(
+                state.multi_source.candidates
+                    .iter()
+                    .filter(|candidate| state.multi_source.selected.contains(&candidate.index))
+                    .map(|candidate| candidate.address),
+            );
+        }
+
+        let fix = self.render_recipient_report(ui, "bulk_disperse", &recipients, &sources);
+        let input = &mut self.bulk_disperse_state.recipients_input;
+        match fix {
+            Some(RecipientFix::Remove(index)) => {
+                *input = recipient_input::remove_line(input, lines[index].line);
+            }
+            Some(RecipientFix::Replace(index, address)) => {
+                let line = &lines[index];
+                *input = recipient_input::replace_in_line(input, line.line, &line.text, &ethers::utils::to_checksum(&address, None));
+            }
+            None => {}
+        }
     }
 
-    fn render_source_address_selection(&mut self, ui: &mut egui::Ui) {
+    pub(crate) fn render_source_address_selection(&mut self, ui: &mut egui::Ui) {
         let ledger_ready_for_fetch = self.ledger_status.is_usable();
         
         ui.horizontal(|ui| {
@@ -356,6 +464,23 @@ impl GuiApp {
                 egui::Button::new("🔄 Refresh")
             ).on_hover_text(refresh_hover).clicked();
             
+            if ui
+                .button("[H] From Recent Scan")
+                .on_hover_text("Pick the source from a saved scan of this network (last 24h)")
+                .clicked()
+            {
+                match crate::scan_history::recent_scan_for(&self.config) {
+                    Some(scan) => {
+                        self.bulk_disperse_state.source_selection.funded_addresses = Some(scan.funded_records());
+                    }
+                    None => {
+                        self.notifications.push_back(NotificationEntry::new(
+                            "[i] No saved scan for this network and derivation from the last 24 hours",
+                        ));
+                    }
+                }
+            }
+            
             if should_fetch || refresh_clicked {
                 let config = self.config.clone();
                 let index = self.bulk_disperse_state.source_index;
@@ -369,6 +494,41 @@ impl GuiApp {
             }
         });
         
+        // Funded addresses from a saved scan, click one to use it as the source
+        if let Some(funded) = self.bulk_disperse_state.source_selection.funded_addresses.clone() {
+            let mut picked: Option<u32> = None;
+            let mut close = false;
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(format!("Funded in saved scan ({}):", funded.len())).small().color(self.theme.text_secondary));
+                if ui.small_button("[X]").on_hover_text("Hide saved scan").clicked() {
+                    close = true;
+                }
+            });
+            if funded.is_empty() {
+                ui.label(RichText::new("No funded addresses in the saved scan").italics().size(11.0).color(self.theme.warning));
+            }
+            for record in &funded {
+                let text = format!(
+                    "{} → {:?} - {} {}",
+                    record.derivation_path,
+                    record.address,
+                    utils::format_ether(record.balance),
+                    self.config.native_token()
+                );
+                if ui.selectable_label(false, RichText::new(text).monospace().size(11.0)).clicked() {
+                    picked = Some(record.index);
+                }
+            }
+            if let Some(index) = picked {
+                // The balance is re-fetched from the chain once the index changes
+                self.bulk_disperse_state.source_index = index;
+                close = true;
+            }
+            if close {
+                self.bulk_disperse_state.source_selection.funded_addresses = None;
+            }
+        }
+        
         // Display source address and balance
         if self.bulk_disperse_state.source_balance_job.is_some() {
             ui.horizontal(|ui| {
@@ -403,7 +563,7 @@ impl GuiApp {
 
         let is_mixed_mode = if !self.bulk_disperse_state.recipients_input.trim().is_empty() {
             matches!(
-                bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input),
+                bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id),
                 Ok(bulk_disperse::BulkDisperseType::Mixed(_))
             )
         } else {
@@ -482,17 +642,168 @@ impl GuiApp {
         
         if (gas_speed_changed || tip_changed || keep_on_source_changed) 
             && self.bulk_disperse_state.source_balance.is_some()
-            && self.bulk_disperse_state.current_gas_price.is_some()
+            && self.bulk_disperse_state.current_fees.is_some()
             && !self.bulk_disperse_state.recipients_input.trim().is_empty()
         {
             self.auto_calculate_amount_silent();
         }
     }
 
+    /// Gas limit used for the fee preview and passed to the signer: the node estimate (summed
+    /// over all batches) once available, otherwise the heuristic
+    pub(crate) fn disperse_gas_limit(&self, total_recipients: usize) -> u64 {
+        match &self.bulk_disperse_state.gas_plan {
+            Some(plan) => plan.total_gas_limit(),
+            None => calculate_disperse_gas_limit(total_recipients),
+        }
+    }
+
+    /// Start a gas estimate unless one is running or nothing changed since the last one
+    pub(crate) fn request_disperse_gas_estimate<Fut>(&mut self, key: u64, estimate: impl FnOnce() -> Fut + Send + 'static)
+    where
+        Fut: std::future::Future<Output = anyhow::Result<disperse_batch::DispersePlan>> + 'static,
+    {
+        if self.bulk_disperse_state.gas_estimate_job.is_some() || self.bulk_disperse_state.gas_estimate_key == Some(key) {
+            return;
+        }
+        self.bulk_disperse_state.gas_estimate_key = Some(key);
+        self.bulk_disperse_state.gas_estimate_job = Some(self.spawn_job(estimate));
+    }
+
+    /// Start a pre-flight simulation once the gas plan for `key` is ready, unless one already ran
+    pub(crate) fn request_disperse_simulation<Fut>(&mut self, key: u64, simulate: impl FnOnce() -> Fut + Send + 'static)
+    where
+        Fut: std::future::Future<Output = anyhow::Result<Option<String>>> + 'static,
+    {
+        let state = &self.bulk_disperse_state;
+        if state.gas_estimate_job.is_some() || state.gas_plan.is_none() || state.simulation_job.is_some() {
+            return;
+        }
+        if state.simulation_key == Some(key) {
+            return;
+        }
+        self.bulk_disperse_state.simulation_key = Some(key);
+        self.bulk_disperse_state.simulation_failure = None;
+        self.bulk_disperse_state.simulation_job = Some(self.spawn_job(simulate));
+    }
+
+    /// Validation message while the simulation runs or after it failed
+    pub(crate) fn disperse_simulation_error(&self, key: Option<u64>) -> Option<String> {
+        let state = &self.bulk_disperse_state;
+        if state.simulation_job.is_some() || key.is_none() || state.simulation_key != key {
+            return Some("Simulating transaction...".to_string());
+        }
+        state.simulation_failure.as_ref().map(|reason| format!("Simulation failed: {}", reason))
+    }
+
+    fn maybe_estimate_disperse_gas(&mut self) {
+        let state = &self.bulk_disperse_state;
+        let Some(source) = state.source_address.as_deref().and_then(|a| a.parse::<Address>().ok()) else {
+            return;
+        };
+        let Ok(contract) = state.disperse_contract_address.trim().parse::<Address>() else {
+            return;
+        };
+        let Ok(disperse_type) = bulk_disperse::parse_bulk_disperse_input(&state.recipients_input, self.config.chain_id) else {
+            return;
+        };
+
+        // Same recipients and amounts the signer will build; an equal or weighted split without
+        // an amount yet uses 1 wei each, which costs the same gas
+        let (mut recipients, mut amounts) = match disperse_type {
+            bulk_disperse::BulkDisperseType::Equal(addresses) => {
+                if addresses.is_empty() {
+                    return;
+                }
+                let per_recipient = utils::parse_eth_str_to_wei(state.amount_input.trim())
+                    .map(|amount| amount / U256::from(addresses.len()))
+                    .unwrap_or_default()
+                    .max(U256::one());
+                let amounts = vec![per_recipient; addresses.len()];
+                (addresses, amounts)
+            }
+            bulk_disperse::BulkDisperseType::Mixed(recipients) => {
+                if recipients.is_empty() {
+                    return;
+                }
+                recipients.into_iter().unzip()
+            }
+            weighted @ bulk_disperse::BulkDisperseType::Weighted(_) => {
+                if weighted.is_empty() {
+                    return;
+                }
+                utils::parse_eth_str_to_wei(state.amount_input.trim())
+                    .ok()
+                    .and_then(|amount| weighted.resolve(amount).ok())
+                    .unwrap_or_else(|| weighted.addresses().into_iter().map(|address| (address, U256::one())).collect())
+                    .into_iter()
+                    .unzip()
+            }
+        };
+        let tip_wei = Self::parse_optional_eth_to_wei(&state.tip_amount).unwrap_or_default();
+        if !tip_wei.is_zero() {
+            if let Ok(tip_recipient) = crate::disperse::BEAUG_OWNER_ADDRESS.parse::<Address>() {
+                recipients.push(tip_recipient);
+                amounts.push(tip_wei);
+            }
+        }
+
+        let gas_margin_percent = self.user_settings.gas_margin_percent;
+        let mut hasher = DefaultHasher::new();
+        (self.config.chain_id, contract, source, &recipients, &amounts, gas_margin_percent).hash(&mut hasher);
+        let key = hasher.finish();
+
+        let config = self.config.clone();
+        let (plan_recipients, plan_amounts) = (recipients.clone(), amounts.clone());
+        self.request_disperse_gas_estimate(key, move || async move {
+            disperse_batch::plan_native_disperse(config, contract, source, plan_recipients, plan_amounts, gas_margin_percent).await
+        });
+
+        // Simulate the planned batches once the plan for these inputs is in
+        let Some(batch_size) = self.bulk_disperse_state.gas_plan.as_ref().map(|plan| plan.batch_size) else {
+            return;
+        };
+        if self.bulk_disperse_state.gas_estimate_key != Some(key) {
+            return;
+        }
+        let config = self.config.clone();
+        self.request_disperse_simulation(key, move || async move {
+            disperse_batch::simulate_native_disperse(config, contract, source, recipients, amounts, batch_size).await
+        });
+    }
+
+    /// Where the gas limit came from, next to the gas limit row
+    pub(crate) fn render_gas_estimate_source(&self, ui: &mut egui::Ui) {
+        if self.bulk_disperse_state.gas_estimate_job.is_some() {
+            ui.spinner();
+            ui.label(RichText::new("estimating...").small().italics().color(self.theme.text_secondary));
+            return;
+        }
+        match &self.bulk_disperse_state.gas_plan {
+            Some(plan) => match &plan.representative().source {
+                crate::disperse::GasEstimateSource::Node { margin_percent } => {
+                    ui.label(RichText::new(format!("(eth_estimateGas +{}%)", margin_percent)).small().color(self.theme.text_secondary));
+                    if plan.is_batched() {
+                        ui.label(RichText::new(format!("across {} batches", plan.batch_count())).small().color(self.theme.info))
+                            .on_hover_text(format!("Too many recipients for one block; sent as {} transactions of up to {} recipients", plan.batch_count(), plan.batch_size));
+                    }
+                }
+                crate::disperse::GasEstimateSource::Heuristic { reason } => {
+                    ui.label(RichText::new("(heuristic, estimate failed)").small().color(self.theme.warning))
+                        .on_hover_text(reason);
+                }
+            },
+            None => {
+                ui.label(RichText::new("(heuristic)").small().color(self.theme.text_secondary));
+            }
+        }
+    }
+
     fn render_calculation_summary(&mut self, ui: &mut egui::Ui) {
         let (recipient_count, is_mixed_distribution, mixed_total) = if !self.bulk_disperse_state.recipients_input.trim().is_empty() {
-            match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input) {
+            match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id) {
                 Ok(bulk_disperse::BulkDisperseType::Equal(addrs)) => (addrs.len(), false, ethers::types::U256::zero()),
+                Ok(bulk_disperse::BulkDisperseType::Weighted(recips)) => (recips.len(), false, ethers::types::U256::zero()),
                 Ok(bulk_disperse::BulkDisperseType::Mixed(recips)) => {
                     let total: ethers::types::U256 = recips.iter().map(|(_, amt)| *amt).fold(ethers::types::U256::zero(), |acc, x| acc + x);
                     (recips.len(), true, total)
@@ -507,8 +818,8 @@ impl GuiApp {
             ui.label(RichText::new("📊 Calculation Summary").strong());
             ui.add_space(4.0);
             
-            if let (Some(gas_price), Some(source_balance)) = (
-                self.bulk_disperse_state.current_gas_price,
+            if let (Some(fees), Some(source_balance)) = (
+                self.bulk_disperse_state.current_fees.clone(),
                 self.bulk_disperse_state.source_balance
             ) {
                 let speed = self.bulk_disperse_state.gas_speed;
@@ -517,38 +828,46 @@ impl GuiApp {
                     .unwrap_or(ethers::types::U256::zero());
                 
                 let total_recipients = if tip_wei.is_zero() { recipient_count } else { recipient_count + 1 };
-                let gas_limit = if total_recipients > 0 { calculate_disperse_gas_limit(total_recipients) } else { 150_000u64 };
-                let fee_wei = gas_price * ethers::types::U256::from(gas_limit);
-                let adjusted_fee_wei = fee_wei * ethers::types::U256::from((speed * 100.0) as u64) / 100;
-                let adjusted_gas_price = gas_price * ethers::types::U256::from((speed * 100.0) as u64) / 100;
+                let gas_limit = if total_recipients > 0 { self.disperse_gas_limit(total_recipients) } else { 150_000u64 };
+                let fee_quote = fees.quote(speed);
+                let expected_fee_wei = fee_quote.expected_cost(gas_limit);
+                let max_fee_wei = fee_quote.max_cost(gas_limit);
                 
                 let keep_on_source_wei = Self::parse_optional_eth_to_wei(&self.bulk_disperse_state.remaining_balance)
                     .unwrap_or(ethers::types::U256::zero());
                 
-                let reserved = adjusted_fee_wei + keep_on_source_wei;
+                let reserved = max_fee_wei + keep_on_source_wei;
                 let available = if source_balance > reserved { source_balance - reserved } else { ethers::types::U256::zero() };
                 
                 let native_token = self.config.native_token();
                 let speed_label = gas_speed_label(speed);
+                let usd = |wei: ethers::types::U256| self.usd_value(wei).map(|usd| format!(" ({})", usd)).unwrap_or_default();
                 
                 egui::Grid::new("calc_summary_grid")
                     .num_columns(2)
                     .spacing([20.0, 4.0])
                     .show(ui, |ui| {
                         ui.label("Source Balance:");
-                        ui.label(RichText::new(format!("{} {}", utils::format_ether(source_balance), native_token)).strong());
+                        ui.label(RichText::new(format!("{} {}{}", utils::format_ether(source_balance), native_token, usd(source_balance))).strong());
                         ui.end_row();
                         
                         ui.label("Gas Price:");
-                        ui.label(RichText::new(format!("{} Gwei ({:.1}x {})", format_gwei(adjusted_gas_price), speed, speed_label)).color(self.theme.text_secondary));
+                        ui.label(RichText::new(format!("{} ({:.1}x {})", fee_quote.describe(), speed, speed_label)).color(self.theme.text_secondary));
                         ui.end_row();
                         
                         ui.label("Gas Limit:");
-                        ui.label(RichText::new(format!("{} units", gas_limit)).color(self.theme.text_secondary));
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(format!("{} units", gas_limit)).color(self.theme.text_secondary));
+                            self.render_gas_estimate_source(ui);
+                        });
                         ui.end_row();
                         
+                        ui.label("Expected Gas Fee:");
+                        ui.label(RichText::new(format!("~{} {}{}", utils::format_ether(expected_fee_wei), native_token, usd(expected_fee_wei))).color(self.theme.text_secondary));
+                        ui.end_row();
+
                         ui.label("Max Gas Fee:");
-                        ui.label(format!("-{} {}", utils::format_ether(adjusted_fee_wei), native_token));
+                        ui.label(format!("-{} {}{}", utils::format_ether(max_fee_wei), native_token, usd(max_fee_wei)));
                         ui.end_row();
                         
                         if !is_mixed_distribution && keep_on_source_wei > ethers::types::U256::zero() {
@@ -562,7 +881,7 @@ impl GuiApp {
                         ui.end_row();
                         
                         ui.label("Available to Send:");
-                        ui.label(RichText::new(format!("{} {}", utils::format_ether(available), native_token)).strong().color(self.theme.accent_green));
+                        ui.label(RichText::new(format!("{} {}{}", utils::format_ether(available), native_token, usd(available))).strong().color(self.theme.accent_green));
                         ui.end_row();
                         
                         if tip_wei > ethers::types::U256::zero() {
@@ -572,32 +891,33 @@ impl GuiApp {
                             
                             let to_recipients = if available > tip_wei { available - tip_wei } else { ethers::types::U256::zero() };
                             ui.label("  └ To Recipients:");
-                            ui.label(RichText::new(format!("{} {}", utils::format_ether(to_recipients), native_token)).color(self.theme.accent_green));
+                            ui.label(RichText::new(format!("{} {}{}", utils::format_ether(to_recipients), native_token, usd(to_recipients))).color(self.theme.accent_green));
                             ui.end_row();
                             
                             if recipient_count > 0 {
                                 if is_mixed_distribution {
                                     ui.label(format!("  └ Distribution ({}):", recipient_count));
-                                    ui.label(format!("Mixed (specified amounts: {} {})", utils::format_ether(mixed_total), native_token));
+                                    ui.label(format!("Mixed (specified amounts: {} {}{})", utils::format_ether(mixed_total), native_token, usd(mixed_total)));
                                 } else {
                                     let per_recipient = to_recipients / ethers::types::U256::from(recipient_count);
                                     ui.label(format!("  └ Per Recipient ({}):", recipient_count));
-                                    ui.label(format!("~{} {} each", utils::format_ether(per_recipient), native_token));
+                                    ui.label(format!("~{} {} each{}", utils::format_ether(per_recipient), native_token, usd(per_recipient)));
                                 }
                                 ui.end_row();
                             }
                         } else if recipient_count > 0 {
                             if is_mixed_distribution {
                                 ui.label(format!("Distribution ({}):", recipient_count));
-                                ui.label(format!("Mixed (specified amounts: {} {})", utils::format_ether(mixed_total), native_token));
+                                ui.label(format!("Mixed (specified amounts: {} {}{})", utils::format_ether(mixed_total), native_token, usd(mixed_total)));
                             } else {
                                 let per_recipient = available / ethers::types::U256::from(recipient_count);
                                 ui.label(format!("Per Recipient ({}):", recipient_count));
-                                ui.label(format!("~{} {} each", utils::format_ether(per_recipient), native_token));
+                                ui.label(format!("~{} {} each{}", utils::format_ether(per_recipient), native_token, usd(per_recipient)));
                             }
                             ui.end_row();
                         }
                     });
+                self.render_price_source(ui);
             } else {
                 ui.label(RichText::new("Fetch source balance to see calculations").italics().color(self.theme.text_secondary));
             }
@@ -642,12 +962,18 @@ impl GuiApp {
             "Ready to disperse".to_string()
         };
 
+        let button_text = match &self.bulk_disperse_state.gas_plan {
+            Some(plan) if plan.is_batched() => format!("Prepare {} Disperse Batches", plan.batch_count()),
+            _ => "Initiate Bulk Disperse".to_string(),
+        };
         if ui.add_enabled(
-            can_proceed,
-            self.theme.button_warning("Initiate Bulk Disperse")
+            can_proceed && self.bulk_disperse_state.batch_prep_job.is_none(),
+            self.theme.button_warning(&button_text)
         ).on_hover_text(&validation_message).clicked() {
             self.execute_bulk_disperse();
         }
+        let plan = self.bulk_schedule_plan();
+        self.render_schedule_form(ui, "bulk_disperse", plan);
         ui.add_space(self.theme.spacing_sm);
 
         // Status Display
@@ -656,6 +982,50 @@ impl GuiApp {
         }
     }
 
+    /// Plan of the bulk disperse form for scheduling, or why it cannot be scheduled
+    fn bulk_schedule_plan(&self) -> Result<SchedulePlan, String> {
+        let state = &self.bulk_disperse_state;
+        if let Some(error) = self.ens_confirmation_error(&recipient_input::ens_names(&state.recipients_input)) {
+            return Err(error);
+        }
+        let lines = recipient_input::parse_recipient_lines(
+            &state.recipients_input,
+            self.config.chain_id,
+            PaymentAsset::Native,
+            recipient_input::parse_native_amount,
+        )
+        .map_err(|e| e.to_string())?;
+        if lines.is_empty() {
+            return Err("No recipients entered".to_string());
+        }
+        if state.gas_plan.as_ref().is_some_and(|plan| plan.is_batched()) {
+            return Err("Disperses split into batches cannot be scheduled".to_string());
+        }
+        let source_address = state
+            .source_address
+            .as_deref()
+            .and_then(|address| address.parse().ok())
+            .ok_or_else(|| "Fetch the source address first".to_string())?;
+        let amount = utils::parse_eth_str_to_wei(state.amount_input.trim())
+            .ok()
+            .filter(|amount| !amount.is_zero())
+            .ok_or_else(|| "Enter the amount to send".to_string())?;
+        let tip = Self::parse_optional_eth_to_wei(&state.tip_amount).filter(|tip| !tip.is_zero());
+        let recipient_count = lines.len();
+        Ok(SchedulePlan::BulkDisperse {
+            recipients: crate::schedule::canonical_recipient_list(&lines),
+            recipient_count,
+            amount,
+            contract: Some(state.disperse_contract_address.trim().to_string()).filter(|contract| !contract.is_empty()),
+            source_index: state.source_index,
+            source_address,
+            tip,
+            keep_on_source: Self::parse_optional_eth_to_wei(&state.remaining_balance).unwrap_or_default(),
+            gas_speed: state.gas_speed,
+            gas_limit: self.disperse_gas_limit(recipient_count + usize::from(tip.is_some())),
+        })
+    }
+
     fn validate_disperse_parameters(&self) -> (bool, Vec<String>) {
         let mut can_proceed = true;
         let mut validation_errors: Vec<String> = Vec::new();
@@ -668,6 +1038,12 @@ impl GuiApp {
             }
         }
 
+        let ens_names = recipient_input::ens_names(&self.bulk_disperse_state.recipients_input);
+        if let Some(error) = self.ens_confirmation_error(&ens_names) {
+            can_proceed = false;
+            validation_errors.push(error);
+        }
+
         // Check basic requirements
         if self.bulk_disperse_state.recipients_input.trim().is_empty() {
             can_proceed = false;
@@ -695,8 +1071,9 @@ impl GuiApp {
             .unwrap_or(ethers::types::U256::zero());
 
         let (recipient_count, is_mixed_distribution) = if !self.bulk_disperse_state.recipients_input.trim().is_empty() {
-            match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input) {
+            match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id) {
                 Ok(bulk_disperse::BulkDisperseType::Equal(addrs)) => (addrs.len(), false),
+                Ok(bulk_disperse::BulkDisperseType::Weighted(recips)) => (recips.len(), false),
                 Ok(bulk_disperse::BulkDisperseType::Mixed(recips)) => (recips.len(), true),
                 Err(_) => (0, false),
             }
@@ -705,14 +1082,12 @@ impl GuiApp {
         };
 
         if let Some(source_balance) = self.bulk_disperse_state.source_balance {
-            if let Some(gas_price) = self.bulk_disperse_state.current_gas_price {
-                let speed = self.bulk_disperse_state.gas_speed;
+            if let Some(fees) = self.bulk_disperse_state.current_fees.clone() {
                 let total_recipients = if tip_wei.is_zero() { recipient_count } else { recipient_count + 1 };
-                let gas_limit = if total_recipients > 0 { calculate_disperse_gas_limit(total_recipients) } else { 150_000u64 };
-                let fee_wei = gas_price * ethers::types::U256::from(gas_limit);
-                let adjusted_fee_wei = fee_wei * ethers::types::U256::from((speed * 100.0) as u64) / 100;
+                let gas_limit = if total_recipients > 0 { self.disperse_gas_limit(total_recipients) } else { 150_000u64 };
+                let max_fee_wei = fees.quote(self.bulk_disperse_state.gas_speed).max_cost(gas_limit);
                 
-                let total_needed = amount_wei + adjusted_fee_wei + keep_on_source_wei;
+                let total_needed = amount_wei + max_fee_wei + keep_on_source_wei;
                 let tolerance = ethers::types::U256::from(1_000_000_000u64);
                 let native_token = self.config.native_token();
                 
@@ -747,6 +1122,14 @@ impl GuiApp {
                 can_proceed = false;
                 validation_errors.push("Waiting for gas price...".to_string());
             }
+
+            if self.bulk_disperse_state.gas_estimate_job.is_some() || self.bulk_disperse_state.gas_plan.is_none() {
+                can_proceed = false;
+                validation_errors.push("Estimating gas limit...".to_string());
+            } else if let Some(error) = self.disperse_simulation_error(self.bulk_disperse_state.gas_estimate_key) {
+                can_proceed = false;
+                validation_errors.push(error);
+            }
         } else {
             can_proceed = false;
             validation_errors.push("Fetch source balance first".to_string());
@@ -754,7 +1137,13 @@ impl GuiApp {
 
         // Check for mixed distribution minimum amounts
         if can_proceed && !self.bulk_disperse_state.recipients_input.trim().is_empty() {
-            if let Ok(disperse_type) = bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input) {
+            if let Ok(disperse_type) = bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id) {
+                if let bulk_disperse::BulkDisperseType::Weighted(_) = disperse_type {
+                    if let Err(e) = disperse_type.resolve(amount_wei) {
+                        can_proceed = false;
+                        validation_errors.push(e.to_string());
+                    }
+                }
                 if let bulk_disperse::BulkDisperseType::Mixed(ref recipients) = disperse_type {
                     let total_specified: ethers::types::U256 = recipients.iter()
                         .map(|(_, amount)| *amount)
@@ -781,7 +1170,7 @@ impl GuiApp {
         let tip_wei = Self::parse_optional_eth_to_wei(&self.bulk_disperse_state.tip_amount)
             .unwrap_or(ethers::types::U256::zero());
 
-        if let Ok(disperse_type) = bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input) {
+        if let Ok(disperse_type) = bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id) {
             let native_token = self.config.native_token();
             let to_recipients = if amount_wei > tip_wei { amount_wei - tip_wei } else { ethers::types::U256::zero() };
 
@@ -805,20 +1194,22 @@ impl GuiApp {
                             utils::format_ether(per_recipient)
                         )).color(self.theme.accent_green));
                     }
+                    bulk_disperse::BulkDisperseType::Weighted(ref recipients) => {
+                        ui.label(RichText::new(format!(
+                            "Ready: {} {} to {} recipients (weighted shares)",
+                            utils::format_ether(amount_wei), native_token,
+                            recipients.len()
+                        )).color(self.theme.accent_green));
+                    }
                 }
             });
         }
     }
 
     fn execute_bulk_disperse(&mut self) {
-        match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input) {
+        match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id) {
             Ok(disperse_type) => {
-                let is_empty = match &disperse_type {
-                    bulk_disperse::BulkDisperseType::Equal(addresses) => addresses.is_empty(),
-                    bulk_disperse::BulkDisperseType::Mixed(recipients) => recipients.is_empty(),
-                };
-
-                if is_empty {
+                if disperse_type.is_empty() {
                     self.notifications.push_back(NotificationEntry::new("[XX] No recipients found in input"));
                     return;
                 }
@@ -857,13 +1248,46 @@ impl GuiApp {
                     None
                 };
                 
+                // A disperse paying a payroll cycle reports its hashes so the cycle is recorded once mined
+                let payroll_sender = match (&self.payroll.linked, disperse_type.resolve(amount_to_send)) {
+                    (Some(payroll_id), Ok(payments)) => {
+                        let (sender, receiver) = tokio::sync::oneshot::channel();
+                        self.payroll.pending = Some(PendingPayrollRun {
+                            payroll_id: payroll_id.clone(),
+                            payments,
+                            tx_hashes: receiver,
+                        });
+                        Some(sender)
+                    }
+                    _ => None,
+                };
+
+                // Too many recipients for one block: queue one transaction per planned batch
+                if let Some(plan) = self.bulk_disperse_state.gas_plan.clone().filter(|plan| plan.is_batched()) {
+                    // The cycle is recorded once every batch is mined
+                    self.bulk_disperse_state.batch_payroll = payroll_sender;
+                    self.start_disperse_batches(disperse_batch::DisperseBatchRequest {
+                        disperse_type,
+                        amount_to_send,
+                        disperse_address_override: disperse_contract,
+                        source_idx_override: Some(source_index),
+                        tip_amount,
+                        tip_recipient,
+                        plan,
+                    });
+                    return;
+                }
+
                 let gas_speed = self.bulk_disperse_state.gas_speed;
+                // Sign with the previewed gas limit so the fee shown is the fee signed
+                let gas_limit = self.bulk_disperse_state.gas_plan.as_ref().map(|plan| plan.total_gas_limit());
+                let gas_margin_percent = self.user_settings.gas_margin_percent;
                 let use_native_ledger = self.user_settings.use_native_ledger;
 
                 self.bulk_disperse_state.status = Some("Preparing transaction...".to_string());
 
                 let job = self.spawn_job(move || async move {
-                    bulk_disperse::bulk_disperse(
+                    let tx_hash = bulk_disperse::bulk_disperse(
                         config,
                         disperse_type,
                         amount_to_send,
@@ -871,9 +1295,18 @@ impl GuiApp {
                         Some(source_index),
                         tip_amount,
                         tip_recipient,
-                        gas_speed,
-                        use_native_ledger,
-                    ).await
+                        bulk_disperse::DisperseSendOptions {
+                            gas_speed,
+                            gas_limit_override: gas_limit,
+                            gas_margin_percent,
+                            fees_override: None,
+                            use_native_ledger,
+                        },
+                    ).await?;
+                    if let Some(sender) = payroll_sender {
+                        let _ = sender.send(vec![tx_hash]);
+                    }
+                    Ok(())
                 });
 
                 self.bulk_disperse_state.job = Some(job);
@@ -885,3 +1318,36 @@ impl GuiApp {
         }
     }
 }
+
+/// Icon, color and text shown for a contract validation status
+pub(crate) fn contract_status_badge(status: &crate::disperse::ContractValidationStatus) -> (&'static str, egui::Color32, &'static str) {
+    match status {
+        crate::disperse::ContractValidationStatus::MainBeaugRegistry => {
+            ("★", egui::Color32::from_rgb(0, 220, 120), status.display_text())
+        }
+        crate::disperse::ContractValidationStatus::RegisteredAndCompatible => {
+            ("✓", egui::Color32::from_rgb(0, 200, 100), status.display_text())
+        }
+        crate::disperse::ContractValidationStatus::RegisteredButIncompatible => {
+            ("⚠", egui::Color32::from_rgb(255, 180, 0), status.display_text())
+        }
+        crate::disperse::ContractValidationStatus::CompatibleButUnregistered => {
+            ("ℹ", egui::Color32::from_rgb(100, 150, 255), status.display_text())
+        }
+        crate::disperse::ContractValidationStatus::DisperseAppCompatible => {
+            ("◆", egui::Color32::from_rgb(100, 150, 255), status.display_text())
+        }
+        crate::disperse::ContractValidationStatus::Multicall3Fallback => {
+            ("◆", egui::Color32::from_rgb(100, 150, 255), status.display_text())
+        }
+        crate::disperse::ContractValidationStatus::Unknown => {
+            ("✗", egui::Color32::from_rgb(255, 80, 80), "Unknown contract - use with caution")
+        }
+        crate::disperse::ContractValidationStatus::Checking => {
+            ("⋯", egui::Color32::from_rgb(150, 150, 150), status.display_text())
+        }
+        crate::disperse::ContractValidationStatus::Error(_) => {
+            ("!", egui::Color32::from_rgb(255, 100, 100), status.display_text())
+        }
+    }
+}
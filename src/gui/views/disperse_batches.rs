@@ -0,0 +1,224 @@
+//! Batched bulk disperse view implementation
+//!
+//! When a disperse list is too large for one block, it is sent as several
+//! `beaugDisperse` (or `disperseEther`) transactions:
+//! - Preparation of one queued transaction per batch with consecutive nonces
+//! - Transaction queue with per-batch status, retry and skip
+//! - One operation log entry covering every batch hash and dispersal id
+
+use crate::dispersal_proof;
+use crate::disperse_batch::{self, DisperseBatchRequest};
+use crate::gui::app::GuiApp;
+use crate::gui::notifications::NotificationEntry;
+use crate::gui::widgets::TransactionView;
+use crate::transaction_queue::{TransactionQueue, TransactionStatus};
+use crate::utils;
+use eframe::egui::{self, RichText};
+use ethers::prelude::Middleware;
+use ethers::types::U256;
+
+impl GuiApp {
+    /// Transaction queue for a batched disperse, shown in place of the disperse form
+    pub(crate) fn view_disperse_batches(&mut self, ui: &mut egui::Ui) {
+        let all_complete = match &self.bulk_disperse_state.batch_view {
+            Some(tx_view) => {
+                let stats = Self::block_on_async(tx_view.queue().get_statistics());
+                stats.is_complete() && stats.total > 0
+            }
+            None => false,
+        };
+
+        // Header with back button
+        ui.horizontal(|ui| {
+            ui.heading(RichText::new("[$] BULK DISPERSE - Batch Queue"));
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                // Back/Cancel button - changes to "New Transaction" when complete
+                let button_text = if all_complete { "[←] New Transaction" } else { "[←] Cancel" };
+                if ui.add(self.theme.button_warning(button_text)).clicked() {
+                    self.bulk_disperse_state.batch_view = None;
+                    self.bulk_disperse_state.batch_payroll = None;
+                    self.bulk_disperse_state.status = None;
+                }
+            });
+        });
+
+        ui.add_space(self.theme.spacing_sm);
+
+        if let Some(status) = &self.bulk_disperse_state.status {
+            ui.label(RichText::new(status).color(self.theme.text_secondary));
+        }
+
+        ui.separator();
+
+        let ledger_ready = self.ledger_status.is_usable();
+        let ledger_warning = self.get_ledger_warning_message();
+        let native_token = self.config.native_token().to_string();
+        let show_ens_names = self.user_settings.show_ens_names;
+        let mut tx_notifications = Vec::new();
+        if let Some(tx_view) = &mut self.bulk_disperse_state.batch_view {
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    tx_view.set_show_ens_names(show_ens_names);
+                    tx_view.show(ui, ledger_ready, ledger_warning.as_deref(), &native_token);
+                });
+            tx_notifications = tx_view.take_notifications();
+        }
+        for notification in tx_notifications {
+            self.notifications.push_back(NotificationEntry::new(notification));
+        }
+
+        self.check_and_log_disperse_batches();
+    }
+
+    /// Build the batch queue in the background; the view switches to it once ready
+    pub(crate) fn start_disperse_batches(&mut self, request: DisperseBatchRequest) {
+        let config = self.config.clone();
+        let gas_speed = self.bulk_disperse_state.gas_speed;
+        let use_native_ledger = self.user_settings.use_native_ledger;
+        let batch_count = request.plan.batch_count();
+
+        let job = self.spawn_job(move || async move {
+            let (tx_list, manager) =
+                disperse_batch::prepare_disperse_batches(config, request, gas_speed, use_native_ledger).await?;
+
+            let mut queue = TransactionQueue::new();
+            queue.set_manager(manager);
+            queue.add_transactions(tx_list).await;
+            Ok::<TransactionQueue, anyhow::Error>(queue)
+        });
+
+        self.bulk_disperse_state.batch_prep_job = Some(job);
+        self.bulk_disperse_state.batch_view = None;
+        self.bulk_disperse_state.status = Some(format!("Preparing {} disperse batches...", batch_count));
+    }
+
+    pub(crate) fn poll_disperse_batch_jobs(&mut self) {
+        if let Some(job) = &mut self.bulk_disperse_state.batch_log_job {
+            if let Some(res) = job.poll() {
+                self.bulk_disperse_state.batch_log_job = None;
+                if let Err(e) = res {
+                    tracing::warn!("Failed to log batched disperse: {}", e);
+                }
+            }
+        }
+
+        let Some(job) = &mut self.bulk_disperse_state.batch_prep_job else {
+            return;
+        };
+        let Some(res) = job.poll() else {
+            return;
+        };
+        self.bulk_disperse_state.batch_prep_job = None;
+        match res {
+            Ok(queue) => {
+                let total = Self::block_on_async(queue.get_statistics()).total;
+                self.bulk_disperse_state.batch_view = Some(TransactionView::new(queue, self.config.chain_id));
+                self.bulk_disperse_state.batch_logged = false;
+                self.bulk_disperse_state.status = Some(format!("[OK] Prepared {} disperse batches. Ready to sign!", total));
+                self.notifications.push_back(NotificationEntry::new(format!("Prepared {} disperse batches", total)));
+            }
+            Err(e) => {
+                self.bulk_disperse_state.batch_payroll = None;
+                self.bulk_disperse_state.status = Some(format!("[!!] Failed to prepare batches: {}", e));
+                self.notifications.push_back(NotificationEntry::new(format!("[XX] Batch preparation failed: {}", e)));
+            }
+        }
+    }
+
+    /// Log the batched disperse once every batch has finished, as one operation with all hashes
+    fn check_and_log_disperse_batches(&mut self) {
+        if self.bulk_disperse_state.batch_logged {
+            return;
+        }
+        let Some(tx_view) = &self.bulk_disperse_state.batch_view else {
+            return;
+        };
+
+        let stats = Self::block_on_async(tx_view.queue().get_statistics());
+        if !stats.is_complete() || stats.total == 0 {
+            return;
+        }
+
+        let transactions = Self::block_on_async(tx_view.queue().get_transactions());
+        let native_token = self.config.native_token().to_string();
+        let mut batches = Vec::new();
+        let mut total_sent = U256::zero();
+        for tx in &transactions {
+            let (status_str, tx_hash) = match &tx.status {
+                TransactionStatus::Success { tx_hash, .. } => {
+                    total_sent += tx.transaction.value;
+                    (format!("✓ {:?}", tx_hash), Some(*tx_hash))
+                }
+                TransactionStatus::Failed { error, .. } => (format!("✗ Failed: {}", error), None),
+                TransactionStatus::Skipped => ("⏭ Skipped".to_string(), None),
+                _ => ("?".to_string(), None),
+            };
+            batches.push((format!("  {} [{}]", tx.description, status_str), tx_hash));
+        }
+
+        // The payroll cycle is paid only if every batch was mined
+        if let Some(sender) = self.bulk_disperse_state.batch_payroll.take() {
+            if stats.success == stats.total {
+                let _ = sender.send(batches.iter().filter_map(|(_, tx_hash)| *tx_hash).collect());
+            } else {
+                self.notifications.push_back(NotificationEntry::new(format!(
+                    "[!!] Payroll cycle not recorded: only {} of {} batches were mined. Pay the failed batches' recipients before sending the cycle again",
+                    stats.success, stats.total
+                )));
+            }
+        }
+
+        let contract = transactions
+            .first()
+            .map(|tx| format!("{:?}", tx.transaction.to))
+            .unwrap_or_default();
+        let header = format!(
+            "Bulk disperse executed in {} batches on {} (Chain ID: {})\n\
+             Source: {}\n\
+             Contract: {}\n\
+             Summary: {} success, {} failed, {} skipped\n\
+             Total distributed: {} {}\n\
+             Batches:",
+            stats.total,
+            self.config.network_label(),
+            self.config.chain_id,
+            self.bulk_disperse_state.source_address.as_deref().unwrap_or("unknown"),
+            contract,
+            stats.success,
+            stats.failed,
+            stats.skipped,
+            utils::format_ether(total_sent),
+            native_token,
+        );
+
+        // Receipts are read in the background to add each batch's dispersal id and recipients hash
+        let config = self.config.clone();
+        self.bulk_disperse_state.batch_log_job = Some(self.spawn_job(move || async move {
+            let provider = config.get_provider().await.ok();
+            let mut lines = vec![header];
+            for (line, tx_hash) in batches {
+                lines.push(line);
+                let (Some(provider), Some(tx_hash)) = (&provider, tx_hash) else {
+                    continue;
+                };
+                if let Ok(Some(receipt)) = provider.get_transaction_receipt(tx_hash).await {
+                    for record in dispersal_proof::dispersals_in_receipt(&receipt) {
+                        lines.push(format!(
+                            "    Dispersal ID: {}, recipients hash: {:?}",
+                            record.dispersal_id, record.recipients_hash
+                        ));
+                    }
+                }
+            }
+            crate::operation_log::append_log("Beaug Bulk Disperse", config.chain_id, lines.join("\n"))
+        }));
+
+        self.bulk_disperse_state.batch_logged = true;
+        self.bulk_disperse_state.status = Some(format!(
+            "[OK] Complete! {} success, {} failed, {} skipped",
+            stats.success, stats.failed, stats.skipped
+        ));
+    }
+}
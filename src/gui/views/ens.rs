@@ -0,0 +1,131 @@
+//! ENS name resolution shared by the address inputs
+//!
+//! Names typed into the recipient lists, split receivers and address book are resolved
+//! in the background (see `GuiApp::poll_ens`). This module renders what they resolved to:
+//! - One row per name with its address, a spinner while resolving, or why it failed
+//! - A confirm step, required before anything is sent to a resolved address
+
+use crate::ens;
+use crate::gui::app::{GuiApp, SplitSelector};
+use crate::recipient_input;
+use eframe::egui::{self, RichText};
+
+/// ENS names in a comma-separated address field
+pub(crate) fn names_in_address_list(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| ens::is_ens_name(entry))
+        .map(ens::normalize)
+        .collect()
+}
+
+impl GuiApp {
+    /// Every ENS name currently typed into an address input
+    pub(crate) fn ens_input_names(&self) -> Vec<String> {
+        let mut names = recipient_input::ens_names(&self.bulk_disperse_state.recipients_input);
+        names.extend(recipient_input::ens_names(&self.dispersals.verify_recipients));
+        names.extend(names_in_address_list(&self.split_random.recipient_addresses));
+        names.extend(names_in_address_list(&self.split_equal.recipient_addresses));
+        if ens::is_ens_name(&self.watcher.book_address) {
+            names.push(ens::normalize(&self.watcher.book_address));
+        }
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Why the resolved `names` cannot be used yet: pending, failed or not confirmed
+    pub(crate) fn ens_confirmation_error(&self, names: &[String]) -> Option<String> {
+        for name in names {
+            if let Some(reason) = self.ens.failed.get(name) {
+                return Some(format!("ENS name {} {}", name, reason));
+            }
+            let Some(address) = ens::resolved_address(name) else {
+                return Some(format!("Resolving ENS name {}...", name));
+            };
+            if !self.ens.confirmed.contains(&(name.clone(), address)) {
+                return Some("Confirm the resolved ENS addresses".to_string());
+            }
+        }
+        None
+    }
+
+    /// ENS problem blocking a split to the typed receivers, if any
+    pub(crate) fn split_ens_error(&self, selector: SplitSelector) -> Option<String> {
+        let state = match selector {
+            SplitSelector::Random => &self.split_random,
+            SplitSelector::Equal => &self.split_equal,
+        };
+        self.ens_confirmation_error(&names_in_address_list(&state.recipient_addresses))
+    }
+
+    /// Resolved address of each name, with a confirm button once all have resolved
+    pub(crate) fn render_ens_resolutions(&mut self, ui: &mut egui::Ui, id_source: &str, names: &[String]) {
+        let mut names = names.to_vec();
+        names.sort();
+        names.dedup();
+        if names.is_empty() {
+            return;
+        }
+
+        ui.add_space(self.theme.spacing_xs);
+        ui.label(RichText::new("ENS names:").strong());
+        let mut all_resolved = true;
+        let mut retry = false;
+        egui::Grid::new(("ens_resolutions", id_source))
+            .num_columns(2)
+            .spacing([12.0, 2.0])
+            .show(ui, |ui| {
+                for name in &names {
+                    ui.label(RichText::new(name).monospace());
+                    if let Some(reason) = self.ens.failed.get(name) {
+                        all_resolved = false;
+                        ui.horizontal(|ui| {
+                            ui.colored_label(self.theme.error, format!("[XX] {}", reason));
+                            retry |= ui.small_button("Retry").clicked();
+                        });
+                    } else if let Some(address) = ens::resolved_address(name) {
+                        let confirmed = self.ens.confirmed.contains(&(name.clone(), address));
+                        let color = if confirmed { self.theme.accent_green } else { self.theme.warning };
+                        ui.label(RichText::new(format!("→ {:?}", address)).monospace().color(color));
+                    } else {
+                        all_resolved = false;
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label(RichText::new("resolving...").small().color(self.theme.text_secondary));
+                        });
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if retry {
+            for name in &names {
+                self.ens.failed.remove(name);
+            }
+            self.ens.error = None;
+        }
+        if let Some((error, _)) = &self.ens.error {
+            ui.colored_label(self.theme.warning, format!("[!!] {}", error));
+        }
+        if !all_resolved {
+            return;
+        }
+
+        let pending: Vec<(String, ethers::types::Address)> = names
+            .iter()
+            .filter_map(|name| ens::resolved_address(name).map(|address| (name.clone(), address)))
+            .filter(|pair| !self.ens.confirmed.contains(pair))
+            .collect();
+        if pending.is_empty() {
+            ui.label(RichText::new("[OK] Resolved addresses confirmed").small().color(self.theme.accent_green));
+        } else if ui
+            .add(self.theme.button_small("Confirm resolved addresses"))
+            .on_hover_text("Check each address above before sending to it")
+            .clicked()
+        {
+            self.ens.confirmed.extend(pending);
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! Scan history view implementation
+//!
+//! This module contains the saved scan history panel including:
+//! - List of stored scans per network
+//! - Reopening a stored scan in the Scan Addresses view
+//! - Diff view between two scans (new funds, drained addresses, deltas)
+
+use crate::{scan_history, utils};
+use eframe::egui::{self, RichText};
+
+use super::super::app::{GuiApp, GuiSection};
+use super::super::notifications::NotificationEntry;
+
+/// Renders the Scan History view
+pub fn view_scan_history(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.render_section_header(ui, "[%]", "SCAN HISTORY");
+    ui.add_space(app.theme.spacing_sm);
+
+    if !app.scan_history.loaded {
+        reload_history(app);
+    }
+
+    ui.label(
+        RichText::new("Completed scans are saved automatically. Reopen one or compare two to see what changed.")
+            .color(app.theme.text_secondary),
+    );
+    ui.add_space(app.theme.spacing_sm);
+
+    ui.horizontal(|ui| {
+        if ui.button("[R] Refresh").clicked() {
+            reload_history(app);
+        }
+        ui.checkbox(&mut app.scan_history.show_all_networks, "Show all networks");
+    });
+
+    if let Some(err) = &app.scan_history.error {
+        ui.colored_label(app.theme.error, format!("[XX] {}", err));
+    }
+
+    ui.add_space(app.theme.spacing_sm);
+
+    let chain_id = app.config.chain_id;
+    let show_all = app.scan_history.show_all_networks;
+    let scans: Vec<scan_history::StoredScan> = app
+        .scan_history
+        .scans
+        .iter()
+        .filter(|scan| show_all || scan.chain_id == chain_id)
+        .cloned()
+        .collect();
+
+    if scans.is_empty() {
+        ui.label(
+            RichText::new("No saved scans yet. Run a scan from Scan Addresses.")
+                .italics()
+                .color(app.theme.text_secondary),
+        );
+        return;
+    }
+
+    let mut open_scan: Option<scan_history::StoredScan> = None;
+    let mut delete_id: Option<String> = None;
+
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(
+            RichText::new(format!("Saved scans ({})", scans.len()))
+                .strong()
+                .color(app.theme.text_primary),
+        );
+        ui.label(
+            RichText::new("Select two scans as A and B to compare; the older one is the baseline")
+                .small()
+                .color(app.theme.text_secondary),
+        );
+        ui.add_space(app.theme.spacing_xs);
+
+        egui::ScrollArea::vertical()
+            .id_source("scan_history_list")
+            .max_height(260.0)
+            .show(ui, |ui| {
+                for scan in &scans {
+                    ui.horizontal(|ui| {
+                        let is_base = app.scan_history.diff_base.as_deref() == Some(scan.id.as_str());
+                        let is_target = app.scan_history.diff_target.as_deref() == Some(scan.id.as_str());
+
+                        if ui.selectable_label(is_base, "A").on_hover_text("Compare from this scan").clicked() {
+                            app.scan_history.diff_base = if is_base { None } else { Some(scan.id.clone()) };
+                        }
+                        if ui.selectable_label(is_target, "B").on_hover_text("Compare to this scan").clicked() {
+                            app.scan_history.diff_target = if is_target { None } else { Some(scan.id.clone()) };
+                        }
+
+                        let snapshot = scan
+                            .result
+                            .snapshot
+                            .map(|s| format!(" @ block {}", s.block_number))
+                            .unwrap_or_default();
+                        ui.label(
+                            RichText::new(format!(
+                                "{} | {}{} | {} addresses | {} {}",
+                                scan.scanned_at_display(),
+                                scan.network_label,
+                                snapshot,
+                                scan.result.records.len(),
+                                utils::format_ether(scan.total_balance()),
+                                scan.native_token
+                            ))
+                            .monospace()
+                            .size(11.0),
+                        );
+
+                        let same_network = scan.matches_config(&app.config);
+                        if ui
+                            .add_enabled(same_network, egui::Button::new("[>] Open").small())
+                            .on_hover_text("Open in Scan Addresses")
+                            .on_disabled_hover_text("Switch to this scan's network and derivation settings to open it")
+                            .clicked()
+                        {
+                            open_scan = Some(scan.clone());
+                        }
+                        if ui
+                            .add(egui::Button::new("[X]").small())
+                            .on_hover_text("Delete this saved scan")
+                            .clicked()
+                        {
+                            delete_id = Some(scan.id.clone());
+                        }
+                    });
+                }
+            });
+    });
+
+    if let Some(scan) = open_scan {
+        app.check_state.result = Some(scan.result.clone());
+        app.check_state.snapshot = scan.result.snapshot;
+        app.check_state.streaming_records.clear();
+        app.check_state.error = None;
+        app.check_state.show_parameters = false;
+        app.previous_section = app.section;
+        app.section = GuiSection::CheckBalances;
+        app.notifications.push_back(NotificationEntry::new(format!(
+            "[i] Opened scan from {}",
+            scan.scanned_at_display()
+        )));
+        return;
+    }
+
+    if let Some(id) = delete_id {
+        match scan_history::delete_scan(&id) {
+            Ok(()) => {
+                if app.scan_history.diff_base.as_deref() == Some(id.as_str()) {
+                    app.scan_history.diff_base = None;
+                }
+                if app.scan_history.diff_target.as_deref() == Some(id.as_str()) {
+                    app.scan_history.diff_target = None;
+                }
+                reload_history(app);
+            }
+            Err(e) => {
+                app.notifications
+                    .push_back(NotificationEntry::new(format!("[XX] Failed to delete scan: {}", e)));
+            }
+        }
+        return;
+    }
+
+    let find = |id: &Option<String>| {
+        id.as_ref()
+            .and_then(|id| scans.iter().find(|scan| &scan.id == id))
+    };
+    if let (Some(base), Some(target)) = (
+        find(&app.scan_history.diff_base),
+        find(&app.scan_history.diff_target),
+    ) {
+        ui.add_space(app.theme.spacing_md);
+        render_scan_diff(app, ui, base, target);
+    }
+}
+
+fn reload_history(app: &mut GuiApp) {
+    match scan_history::load_scans() {
+        Ok(scans) => {
+            app.scan_history.scans = scans;
+            app.scan_history.error = None;
+        }
+        Err(e) => app.scan_history.error = Some(format!("Failed to load scan history: {}", e)),
+    }
+    app.scan_history.loaded = true;
+}
+
+/// Render the differences between two stored scans
+fn render_scan_diff(
+    app: &GuiApp,
+    ui: &mut egui::Ui,
+    base: &scan_history::StoredScan,
+    target: &scan_history::StoredScan,
+) {
+    // A and B can be picked in either order; always diff the older scan against the newer one
+    let (base, target) = if base.scanned_at > target.scanned_at { (target, base) } else { (base, target) };
+    let token = &target.native_token;
+
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(
+            RichText::new(format!(
+                "Diff: {} -> {}",
+                base.scanned_at_display(),
+                target.scanned_at_display()
+            ))
+            .strong()
+            .color(app.theme.text_primary),
+        );
+
+        if base.chain_id != target.chain_id || base.derivation != target.derivation {
+            ui.colored_label(
+                app.theme.warning,
+                "[!!] These scans use different networks or derivation settings; addresses may not line up",
+            );
+        }
+
+        let diff = scan_history::diff_scans(&base.result, &target.result);
+
+        let (total_sign, total_change) = if diff.new_total >= diff.old_total {
+            ("+", diff.new_total - diff.old_total)
+        } else {
+            ("-", diff.old_total - diff.new_total)
+        };
+        ui.label(format!(
+            "Total: {} -> {} {} ({}{})",
+            utils::format_ether(diff.old_total),
+            utils::format_ether(diff.new_total),
+            token,
+            total_sign,
+            utils::format_ether(total_change)
+        ));
+        ui.add_space(app.theme.spacing_xs);
+
+        if diff.is_empty() {
+            ui.label(
+                RichText::new("No balance changes between these scans.")
+                    .italics()
+                    .color(app.theme.text_secondary),
+            );
+            return;
+        }
+
+        let sections = [
+            ("New funds", &diff.new_funds, app.theme.success),
+            ("Drained", &diff.drained, app.theme.error),
+            ("Changed", &diff.changed, app.theme.accent_blue),
+        ];
+
+        for (title, deltas, color) in sections {
+            if deltas.is_empty() {
+                continue;
+            }
+            ui.add_space(app.theme.spacing_xs);
+            ui.label(RichText::new(format!("{} ({})", title, deltas.len())).strong().color(color));
+            for delta in deltas {
+                let sign = if delta.is_increase() { "+" } else { "-" };
+                ui.label(
+                    RichText::new(format!(
+                        "  {} {:?}: {} -> {} {} ({}{})",
+                        delta.derivation_path,
+                        delta.address,
+                        utils::format_ether(delta.old_balance),
+                        utils::format_ether(delta.new_balance),
+                        token,
+                        sign,
+                        utils::format_ether(delta.magnitude())
+                    ))
+                    .monospace()
+                    .size(11.0),
+                );
+            }
+        }
+
+        // The newer scan stopped before these addresses, so their balance now is unknown
+        if !diff.removed.is_empty() {
+            ui.add_space(app.theme.spacing_xs);
+            ui.label(
+                RichText::new(format!("Missing from newer scan ({})", diff.removed.len()))
+                    .strong()
+                    .color(app.theme.warning),
+            );
+            for record in &diff.removed {
+                ui.label(
+                    RichText::new(format!(
+                        "  {} {:?}: {} {} -> not scanned",
+                        record.derivation_path,
+                        record.address,
+                        utils::format_ether(record.balance),
+                        token
+                    ))
+                    .monospace()
+                    .size(11.0),
+                );
+            }
+        }
+    });
+}
@@ -9,7 +9,19 @@
 //! - `settings` - Application configuration and network settings
 //! - `split` - Split operation (random and equal distribution)
 //! - `disperse` - Bulk disperse operation
+//! - `disperse_batches` - Transaction queue for bulk disperses split into batches
+//! - `token_disperse` - ERC-20 token mode of the bulk disperse operation
+//! - `multi_source_disperse` - Bulk disperse paid from several funded sources
+//! - `ens` - ENS name resolutions of the address inputs and their confirmation
+//! - `recipient_report` - Validation report over recipient lists with per-issue fixes
 //! - `balances` - Balance scanning and viewing
+//! - `history` - Saved scan history and scan diffs
+//! - `watcher` - Background balance watcher and address book
+//! - `dispersals` - Proof-of-dispersal verification and on-chain dispersal history
+//! - `schedule` - Scheduled split and bulk disperse runs
+//! - `payroll` - Recurring payroll distributions and their payment history
+//! - `registry` - Beaug contract registry browser and registration
+//! - `contract_admin` - Owner console: ownership transfer and stuck fund withdrawal
 //!
 //! ## Implementation Notes
 //!
@@ -17,11 +29,30 @@
 //! These functions are called from the main `App::update` method in `app.rs`.
 
 pub mod balances;
+pub mod contract_admin;
 pub mod dashboard;
+pub mod dispersals;
 pub mod disperse;
+pub mod disperse_batches;
+pub mod ens;
+pub mod history;
+pub mod multi_source_disperse;
+pub mod payroll;
+pub mod recipient_report;
+pub mod registry;
+pub mod schedule;
 pub mod settings;
 pub mod split;
+pub mod token_disperse;
+pub mod watcher;
 
 // Re-export main view functions for convenient access
 pub use balances::view_check_balances;
+pub use contract_admin::view_contract_admin;
+pub use dispersals::view_dispersals;
+pub use history::view_scan_history;
+pub use payroll::view_payroll;
+pub use registry::view_registry;
+pub use schedule::view_schedule;
 pub use split::{view_split_equal, view_split_random};
+pub use watcher::view_balance_watcher;
@@ -0,0 +1,330 @@
+//! Multi-source bulk disperse view implementation
+//!
+//! When no single derived address can fund the whole disperse, the recipients are split
+//! across several funded addresses from a saved scan:
+//! - Source selection from the funded addresses of a recent scan
+//! - A plan with one disperse transaction per source, each covering its share plus gas
+//! - Sequential execution, one Ledger signature per source
+
+use crate::bulk_disperse::{self, BulkDisperseType};
+use crate::gui::app::GuiApp;
+use crate::gui::helpers::{format_gwei, gas_speed_emoji, gas_speed_label};
+use crate::gui::notifications::NotificationEntry;
+use crate::multi_source_disperse;
+use crate::recipient_input;
+use crate::utils;
+use eframe::egui::{self, RichText};
+use ethers::types::U256;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+impl GuiApp {
+    /// Sources, plan and execution of a multi-source disperse, shown in place of the
+    /// single-source form
+    pub(crate) fn view_multi_source_disperse(&mut self, ui: &mut egui::Ui) {
+        self.poll_multi_source_plan();
+
+        self.render_multi_source_sources(ui);
+        ui.add_space(self.theme.spacing_sm);
+        self.render_multi_source_settings(ui);
+        ui.add_space(self.theme.spacing_sm);
+        self.render_multi_source_plan(ui);
+    }
+
+    fn poll_multi_source_plan(&mut self) {
+        let Some(job) = &mut self.bulk_disperse_state.multi_source.plan_job else {
+            return;
+        };
+        let Some(res) = job.poll() else {
+            return;
+        };
+        let state = &mut self.bulk_disperse_state.multi_source;
+        state.plan_job = None;
+        match res {
+            Ok(plan) => {
+                state.plan = Some(plan);
+                state.plan_error = None;
+            }
+            Err(e) => {
+                state.plan = None;
+                state.plan_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Hash of every input the plan depends on
+    fn multi_source_plan_key(&self) -> u64 {
+        let state = &self.bulk_disperse_state;
+        let mut hasher = DefaultHasher::new();
+        state.recipients_input.hash(&mut hasher);
+        state.amount_input.trim().hash(&mut hasher);
+        state.tip_amount.trim().hash(&mut hasher);
+        state.disperse_contract_address.trim().hash(&mut hasher);
+        state.gas_speed.to_bits().hash(&mut hasher);
+        state.multi_source.selected.hash(&mut hasher);
+        self.config.chain_id.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn render_multi_source_sources(&mut self, ui: &mut egui::Ui) {
+        let native_token = self.config.native_token().to_string();
+        self.theme.frame_panel().show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("[+] Sources").strong());
+                if ui
+                    .button("[H] Load From Recent Scan")
+                    .on_hover_text("Use the funded addresses of a saved scan of this network (last 24h)")
+                    .clicked()
+                {
+                    match crate::scan_history::recent_scan_for(&self.config) {
+                        Some(scan) => {
+                            let state = &mut self.bulk_disperse_state.multi_source;
+                            state.candidates = scan.funded_records();
+                            state.selected = state.candidates.iter().map(|r| r.index).collect();
+                        }
+                        None => self.notifications.push_back(NotificationEntry::new(
+                            "[i] No saved scan for this network and derivation from the last 24 hours",
+                        )),
+                    }
+                }
+            });
+            ui.add_space(self.theme.spacing_xs);
+
+            let state = &mut self.bulk_disperse_state.multi_source;
+            if state.candidates.is_empty() {
+                ui.label(
+                    RichText::new("Scan balances first, then load the funded addresses here.")
+                        .italics()
+                        .size(11.0)
+                        .color(self.theme.text_secondary),
+                );
+                return;
+            }
+
+            for record in &state.candidates {
+                let mut checked = state.selected.contains(&record.index);
+                let text = format!(
+                    "{} → {:?} - {} {}",
+                    record.derivation_path,
+                    record.address,
+                    utils::format_ether(record.balance),
+                    native_token
+                );
+                if ui.checkbox(&mut checked, RichText::new(text).monospace().size(11.0)).changed() {
+                    if checked {
+                        state.selected.insert(record.index);
+                    } else {
+                        state.selected.remove(&record.index);
+                    }
+                }
+            }
+
+            let pooled = state
+                .candidates
+                .iter()
+                .filter(|r| state.selected.contains(&r.index))
+                .fold(U256::zero(), |acc, r| acc + r.balance);
+            ui.add_space(self.theme.spacing_xs);
+            ui.label(format!(
+                "{} sources selected, pooled balance (at scan time): {} {}",
+                state.selected.len(),
+                utils::format_ether(pooled),
+                native_token
+            ));
+        });
+    }
+
+    fn render_multi_source_settings(&mut self, ui: &mut egui::Ui) {
+        let native_token = self.config.native_token().to_string();
+        let is_mixed = matches!(
+            bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id),
+            Ok(BulkDisperseType::Mixed(_))
+        );
+
+        ui.horizontal(|ui| {
+            ui.label("Gas Speed:");
+            let speed = self.bulk_disperse_state.gas_speed;
+            ui.add(egui::Slider::new(&mut self.bulk_disperse_state.gas_speed, 0.8..=2.5).show_value(false).step_by(0.1));
+            ui.label(
+                RichText::new(format!("{} {:.1}x ({})", gas_speed_emoji(speed), speed, gas_speed_label(speed)))
+                    .color(self.theme.accent_green),
+            );
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(format!("Tip ({}):", native_token));
+            ui.add(egui::TextEdit::singleline(&mut self.bulk_disperse_state.tip_amount).desired_width(100.0).hint_text("0.0"));
+            ui.label(RichText::new("(added as recipient)").small().color(self.theme.text_secondary));
+        });
+
+        if !is_mixed {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(format!("Amount to Send ({}):", native_token)).strong());
+                ui.add(egui::TextEdit::singleline(&mut self.bulk_disperse_state.amount_input).desired_width(150.0));
+            });
+            ui.label(
+                RichText::new("Split equally across all recipients, whichever source pays them.")
+                    .italics()
+                    .size(11.0)
+                    .color(self.theme.text_secondary),
+            );
+        }
+    }
+
+    fn render_multi_source_plan(&mut self, ui: &mut egui::Ui) {
+        let key = self.multi_source_plan_key();
+        let native_token = self.config.native_token().to_string();
+        let planning = self.bulk_disperse_state.multi_source.plan_job.is_some();
+        let executing = self.bulk_disperse_state.job.is_some();
+        let fresh = self.bulk_disperse_state.multi_source.plan_key == Some(key);
+
+        ui.horizontal(|ui| {
+            let can_plan = !planning && !executing && !self.bulk_disperse_state.multi_source.selected.is_empty();
+            if ui
+                .add_enabled(can_plan, egui::Button::new(if planning { "[..] Planning..." } else { "Plan Disperse" }))
+                .on_disabled_hover_text("Select at least one funded source")
+                .clicked()
+            {
+                self.start_multi_source_plan(key);
+            }
+            if self.bulk_disperse_state.multi_source.plan.is_some() && !fresh && !planning {
+                ui.colored_label(self.theme.warning, "[!!] Inputs changed since planning, plan again");
+            }
+        });
+
+        if let Some(error) = &self.bulk_disperse_state.multi_source.plan_error {
+            ui.colored_label(self.theme.error, format!("[XX] {}", error));
+        }
+
+        let Some(plan) = self.bulk_disperse_state.multi_source.plan.clone() else {
+            return;
+        };
+
+        ui.add_space(self.theme.spacing_xs);
+        self.theme.frame_panel().show(ui, |ui| {
+            ui.label(RichText::new(format!("📊 Plan: {} transactions", plan.shares.len())).strong());
+            ui.add_space(4.0);
+            egui::Grid::new("multi_source_plan_grid").num_columns(5).spacing([16.0, 4.0]).striped(true).show(ui, |ui| {
+                ui.label(RichText::new("Source").strong());
+                ui.label(RichText::new("Recipients").strong());
+                ui.label(RichText::new("Value").strong());
+                ui.label(RichText::new("Gas Limit").strong());
+                ui.label(RichText::new("Balance").strong());
+                ui.end_row();
+
+                let mut first = 1;
+                for share in &plan.shares {
+                    let last = first + share.recipients.len() - 1;
+                    ui.monospace(&share.derivation_path).on_hover_text(format!("{:?}", share.address));
+                    ui.label(format!("#{}-#{} ({})", first, last, share.recipients.len()));
+                    ui.label(format!("{} {}", utils::format_ether(share.value()), native_token));
+                    ui.label(share.gas_limit.to_string());
+                    ui.label(format!("{} {}", utils::format_ether(share.balance), native_token));
+                    ui.end_row();
+                    first = last + 1;
+                }
+            });
+            ui.add_space(self.theme.spacing_xs);
+            ui.label(format!(
+                "Total: {} {} to {} recipients, max gas fee {} {} at {} Gwei",
+                utils::format_ether(plan.total_value()),
+                native_token,
+                plan.recipient_count(),
+                utils::format_ether(plan.total_gas_cost()),
+                native_token,
+                format_gwei(plan.gas_price())
+            ));
+        });
+
+        ui.add_space(self.theme.spacing_sm);
+        let ledger_ready = self.ledger_status.is_usable();
+        let ens_error = self.ens_confirmation_error(&recipient_input::ens_names(&self.bulk_disperse_state.recipients_input));
+        if let Some(error) = &ens_error {
+            ui.colored_label(self.theme.warning, format!("[!!] {}", error));
+        }
+        let can_execute = fresh && ledger_ready && !planning && !executing && ens_error.is_none();
+        if ui
+            .add_enabled(
+                can_execute,
+                self.theme.button_warning(&format!("Initiate Multi-Source Disperse ({} transactions)", plan.shares.len())),
+            )
+            .on_disabled_hover_text("Plan with the current inputs, confirm any ENS names and connect your Ledger")
+            .clicked()
+        {
+            self.execute_multi_source_disperse(plan);
+        }
+
+        if let Some(status) = &self.bulk_disperse_state.status {
+            ui.add_space(self.theme.spacing_sm);
+            ui.label(status);
+        }
+    }
+
+    fn start_multi_source_plan(&mut self, key: u64) {
+        let disperse_type = match bulk_disperse::parse_bulk_disperse_input(&self.bulk_disperse_state.recipients_input, self.config.chain_id) {
+            Ok(disperse_type) => disperse_type,
+            Err(e) => {
+                self.bulk_disperse_state.multi_source.plan_error = Some(format!("Failed to parse recipients: {}", e));
+                return;
+            }
+        };
+        let amount_to_send = match &disperse_type {
+            BulkDisperseType::Mixed(recipients) => recipients.iter().fold(U256::zero(), |acc, (_, amount)| acc + *amount),
+            BulkDisperseType::Equal(_) | BulkDisperseType::Weighted(_) => match utils::parse_eth_str_to_wei(self.bulk_disperse_state.amount_input.trim()) {
+                Ok(wei) if !wei.is_zero() => wei,
+                _ => {
+                    self.bulk_disperse_state.multi_source.plan_error = Some("Enter the amount to send".to_string());
+                    return;
+                }
+            },
+        };
+        let tip_amount = Self::parse_optional_eth_to_wei(&self.bulk_disperse_state.tip_amount).filter(|tip| !tip.is_zero());
+        let tip_recipient = tip_amount.and_then(|_| crate::disperse::BEAUG_OWNER_ADDRESS.parse().ok());
+
+        let state = &self.bulk_disperse_state.multi_source;
+        let sources: Vec<_> = state.candidates.iter().filter(|r| state.selected.contains(&r.index)).cloned().collect();
+        let config = self.config.clone();
+        let disperse_contract = Some(self.bulk_disperse_state.disperse_contract_address.trim().to_string()).filter(|s| !s.is_empty());
+        let gas_speed = self.bulk_disperse_state.gas_speed;
+        let gas_margin_percent = self.user_settings.gas_margin_percent;
+
+        let request = multi_source_disperse::MultiSourceRequest {
+            disperse_type,
+            amount_to_send,
+            disperse_address_override: disperse_contract,
+            sources,
+            tip_amount,
+            tip_recipient,
+            gas_speed,
+            gas_margin_percent,
+        };
+
+        let job = self.spawn_job(move || async move { multi_source_disperse::plan_multi_source_disperse(config, request).await });
+        let state = &mut self.bulk_disperse_state.multi_source;
+        state.plan_job = Some(job);
+        state.plan_key = Some(key);
+        state.plan_error = None;
+    }
+
+    fn execute_multi_source_disperse(&mut self, plan: multi_source_disperse::MultiSourcePlan) {
+        let config = self.config.clone();
+        let disperse_contract = Some(self.bulk_disperse_state.disperse_contract_address.trim().to_string()).filter(|s| !s.is_empty());
+        let gas_margin_percent = self.user_settings.gas_margin_percent;
+        let use_native_ledger = self.user_settings.use_native_ledger;
+        let share_count = plan.shares.len();
+
+        let job = self.spawn_job(move || async move {
+            multi_source_disperse::execute_multi_source_disperse(
+                config,
+                plan,
+                disperse_contract,
+                gas_margin_percent,
+                use_native_ledger,
+            )
+            .await
+        });
+        self.bulk_disperse_state.job = Some(job);
+        self.bulk_disperse_state.status = Some(format!("Sending {} disperse transactions, confirm each on your Ledger...", share_count));
+        self.notifications.push_back(NotificationEntry::new("Multi-source disperse initiated..."));
+    }
+}
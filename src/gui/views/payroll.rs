@@ -0,0 +1,388 @@
+//! Payroll view implementation
+//!
+//! This module contains:
+//! - The payroll panel: saved recurring distributions, their due dates and paid cycles
+//! - The new payroll form
+//! - Payment history per recipient across all payrolls
+//! - The banner shown on the bulk disperse form while it holds a payroll cycle
+//!
+//! Reminders and recording a paid cycle run from `GuiApp::poll_payroll` on every frame.
+
+use crate::bulk_disperse::{self, BulkDisperseType};
+use crate::payroll::{self, Payroll, Recurrence};
+use crate::utils;
+use eframe::egui::{self, RichText};
+use ethers::types::{Address, U256};
+
+use super::super::app::{GuiApp, GuiSection};
+use super::super::notifications::NotificationEntry;
+
+/// Renders the Payroll view
+pub fn view_payroll(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.render_section_header(ui, "[P]", "PAYROLL");
+    ui.add_space(app.theme.spacing_sm);
+
+    ui.label(
+        RichText::new(
+            "Recurring distributions with fixed amounts. When one is due, Beaug reminds you and \
+             \"Prepare disperse\" loads it into Bulk Disperse with current gas; the cycle is recorded \
+             with its transaction once the disperse is mined.",
+        )
+        .color(app.theme.text_secondary),
+    );
+    if let Some(error) = &app.payroll.error {
+        ui.colored_label(app.theme.error, format!("[XX] {}", error));
+    }
+    ui.add_space(app.theme.spacing_md);
+
+    render_payroll_list(app, ui);
+    ui.add_space(app.theme.spacing_lg);
+    render_new_payroll_form(app, ui);
+    ui.add_space(app.theme.spacing_lg);
+    render_recipient_history(app, ui);
+}
+
+fn render_payroll_list(app: &mut GuiApp, ui: &mut egui::Ui) {
+    let payrolls = app.payroll.payrolls.clone();
+    let now = chrono::Utc::now().timestamp();
+    let busy = app.bulk_disperse_state.job.is_some()
+        || app.bulk_disperse_state.batch_view.is_some()
+        || app.payroll.pending.is_some();
+
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(
+            RichText::new(format!("[=] Payrolls ({})", payrolls.len()))
+                .size(16.0)
+                .strong()
+                .color(app.theme.text_primary),
+        );
+        ui.add_space(app.theme.spacing_sm);
+
+        if payrolls.is_empty() {
+            ui.label(RichText::new("No payrolls yet. Create one below.").italics().color(app.theme.text_secondary));
+            return;
+        }
+
+        let mut prepare: Option<String> = None;
+        let mut skip: Option<String> = None;
+        let mut delete: Option<String> = None;
+        egui::Grid::new("payroll_grid")
+            .num_columns(6)
+            .spacing([app.theme.spacing_md, app.theme.spacing_xs])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Name").strong());
+                ui.label(RichText::new("Network").strong());
+                ui.label(RichText::new("Pays").strong());
+                ui.label(RichText::new("Repeats").strong());
+                ui.label(RichText::new("Next due").strong());
+                ui.label(RichText::new("Actions").strong());
+                ui.end_row();
+
+                for payroll in &payrolls {
+                    ui.label(&payroll.name);
+                    ui.label(&payroll.network_label);
+                    ui.label(format!(
+                        "{} {} to {} recipients from index {}",
+                        utils::format_ether(payroll.total()),
+                        payroll.native_token,
+                        payroll.recipients.len(),
+                        payroll.source_index
+                    ));
+                    ui.label(payroll.recurrence.describe());
+                    ui.horizontal(|ui| {
+                        ui.label(payroll::format_timestamp(payroll.next_due()));
+                        if payroll.is_due(now) {
+                            ui.colored_label(app.theme.warning, "[!!] due");
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        let on_network = payroll.chain_id == app.config.chain_id;
+                        let hover = if !on_network {
+                            format!("Switch to {} first", payroll.network_label)
+                        } else if busy {
+                            "Wait for the current bulk disperse to finish".to_string()
+                        } else {
+                            "Load this cycle into Bulk Disperse".to_string()
+                        };
+                        if ui
+                            .add_enabled(on_network && !busy, app.theme.button_small("Prepare disperse"))
+                            .on_hover_text(hover)
+                            .clicked()
+                        {
+                            prepare = Some(payroll.id.clone());
+                        }
+                        if ui
+                            .add(app.theme.button_small("Skip"))
+                            .on_hover_text("Move to the next cycle without paying this one")
+                            .clicked()
+                        {
+                            skip = Some(payroll.id.clone());
+                        }
+                        if ui.add(app.theme.button_warning("Delete")).clicked() {
+                            delete = Some(payroll.id.clone());
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+        if let Some(id) = prepare {
+            app.prepare_payroll_disperse(&id);
+        }
+        if let Some(id) = skip {
+            if let Some(payroll) = app.payroll.payrolls.iter_mut().find(|payroll| payroll.id == id) {
+                payroll.skip_cycle();
+            }
+            app.save_payrolls();
+        }
+        if let Some(id) = delete {
+            app.payroll.payrolls.retain(|payroll| payroll.id != id);
+            if app.payroll.linked.as_deref() == Some(id.as_str()) {
+                app.payroll.linked = None;
+            }
+            app.save_payrolls();
+        }
+
+        for payroll in payrolls.iter().filter(|payroll| !payroll.runs.is_empty()) {
+            egui::CollapsingHeader::new(format!("Paid cycles of {} ({})", payroll.name, payroll.runs.len()))
+                .id_source(("payroll_runs", &payroll.id))
+                .show(ui, |ui| {
+                    for run in payroll.runs.iter().rev() {
+                        let total = run.payments.iter().fold(U256::zero(), |acc, (_, amount)| acc + *amount);
+                        ui.label(format!(
+                            "Due {}, paid {}: {} {} to {} recipients, tx {}",
+                            payroll::format_timestamp(run.due_at),
+                            payroll::format_timestamp(run.paid_at),
+                            utils::format_ether(total),
+                            payroll.native_token,
+                            run.payments.len(),
+                            payroll::format_tx_hashes(&run.tx_hashes)
+                        ));
+                    }
+                });
+        }
+    });
+}
+
+fn render_new_payroll_form(app: &mut GuiApp, ui: &mut egui::Ui) {
+    egui::CollapsingHeader::new("[+] New payroll").id_source("payroll_form").show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            ui.add(egui::TextEdit::singleline(&mut app.payroll.form_name).desired_width(160.0));
+            ui.label("First due:");
+            ui.add(
+                egui::TextEdit::singleline(&mut app.payroll.form_first_due)
+                    .desired_width(140.0)
+                    .hint_text("2025-01-31 09:00"),
+            );
+        });
+        ui.horizontal(|ui| {
+            ui.label("Repeats every");
+            let (mut count, unit) = match app.payroll.form_recurrence {
+                Recurrence::Days(n) => (n, 0),
+                Recurrence::Weeks(n) => (n, 1),
+                Recurrence::Months(n) => (n, 2),
+            };
+            let mut unit = unit;
+            ui.add(egui::DragValue::new(&mut count).clamp_range(1..=365));
+            egui::ComboBox::from_id_source("payroll_recurrence_unit")
+                .selected_text(["days", "weeks", "months"][unit])
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut unit, 0, "days");
+                    ui.selectable_value(&mut unit, 1, "weeks");
+                    ui.selectable_value(&mut unit, 2, "months");
+                });
+            app.payroll.form_recurrence = match unit {
+                0 => Recurrence::Days(count),
+                1 => Recurrence::Weeks(count),
+                _ => Recurrence::Months(count),
+            };
+        });
+        ui.horizontal(|ui| {
+            ui.label("Source Address Index:");
+            ui.add(egui::DragValue::new(&mut app.payroll.form_source_index).clamp_range(0..=1000));
+            ui.label("Disperse contract:");
+            ui.add(
+                egui::TextEdit::singleline(&mut app.payroll.form_contract)
+                    .desired_width(320.0)
+                    .hint_text("network default"),
+            );
+        });
+        ui.label("Recipients, one per line with a fixed amount (address,amount):");
+        ui.add(
+            egui::TextEdit::multiline(&mut app.payroll.form_recipients)
+                .desired_rows(5)
+                .desired_width(f32::INFINITY)
+                .font(egui::TextStyle::Monospace),
+        );
+
+        ui.horizontal(|ui| {
+            if ui
+                .add(app.theme.button_small("Fill from Bulk Disperse form"))
+                .on_hover_text("Copy the recipients, source index and contract of the bulk disperse form")
+                .clicked()
+            {
+                app.payroll.form_recipients = app.bulk_disperse_state.recipients_input.clone();
+                app.payroll.form_source_index = app.bulk_disperse_state.source_index;
+                app.payroll.form_contract = app.bulk_disperse_state.disperse_contract_address.trim().to_string();
+            }
+            if ui
+                .add(app.theme.button_small("Create"))
+                .on_hover_text(format!("Save the payroll for {}", app.config.network_label()))
+                .clicked()
+            {
+                match app.new_payroll_from_form() {
+                    Ok(payroll) => {
+                        app.notifications.push_back(NotificationEntry::new(format!(
+                            "[OK] Payroll '{}' created, first due {}",
+                            payroll.name,
+                            payroll::format_timestamp(payroll.next_due())
+                        )));
+                        app.payroll.payrolls.push(payroll);
+                        app.save_payrolls();
+                        app.payroll.form_name.clear();
+                        app.payroll.form_recipients.clear();
+                        app.payroll.form_first_due.clear();
+                        app.payroll.form_error = None;
+                    }
+                    Err(e) => app.payroll.form_error = Some(e),
+                }
+            }
+        });
+        if let Some(error) = &app.payroll.form_error {
+            ui.colored_label(app.theme.error, format!("[XX] {}", error));
+        }
+    });
+}
+
+fn render_recipient_history(app: &mut GuiApp, ui: &mut egui::Ui) {
+    let history = payroll::payments_by_recipient(&app.payroll.payrolls);
+    egui::CollapsingHeader::new(format!("[#] Payments per recipient ({})", history.len()))
+        .id_source("payroll_recipient_history")
+        .show(ui, |ui| {
+            if history.is_empty() {
+                ui.label(RichText::new("No paid cycles recorded yet.").italics().color(app.theme.text_secondary));
+                return;
+            }
+            egui::ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+                for (address, payments) in &history {
+                    let label = app
+                        .user_settings
+                        .address_book
+                        .iter()
+                        .find(|entry| entry.address.parse::<Address>().ok() == Some(*address))
+                        .map(|entry| format!(" ({})", entry.label))
+                        .unwrap_or_default();
+                    egui::CollapsingHeader::new(format!("{:?}{}: {} payments", address, label, payments.len()))
+                        .id_source(("payroll_recipient", address))
+                        .show(ui, |ui| {
+                            for payment in payments {
+                                ui.label(format!(
+                                    "{}  {} {}  ({}, tx {})",
+                                    payroll::format_timestamp(payment.paid_at),
+                                    utils::format_ether(payment.amount),
+                                    payment.native_token,
+                                    payment.payroll,
+                                    payroll::format_tx_hashes(&payment.tx_hashes)
+                                ));
+                            }
+                        });
+                }
+            });
+        });
+}
+
+impl GuiApp {
+    /// Validate the new payroll form; recipients need fixed amounts so every cycle pays the same
+    fn new_payroll_from_form(&self) -> Result<Payroll, String> {
+        let name = self.payroll.form_name.trim();
+        if name.is_empty() {
+            return Err("Give the payroll a name".to_string());
+        }
+        let first_due = payroll::parse_due_date(&self.payroll.form_first_due).map_err(|e| e.to_string())?;
+        let recipients = match bulk_disperse::parse_bulk_disperse_input(&self.payroll.form_recipients, self.config.chain_id) {
+            Ok(BulkDisperseType::Mixed(recipients)) if !recipients.is_empty() => recipients,
+            Ok(_) => return Err("Every recipient needs a fixed amount (address,amount)".to_string()),
+            Err(e) => return Err(format!("Failed to parse recipients: {}", e)),
+        };
+        let contract = match self.payroll.form_contract.trim() {
+            "" => None,
+            contract => Some(contract.to_string()),
+        };
+        Ok(Payroll::new(
+            &self.config,
+            name.to_string(),
+            self.payroll.form_source_index,
+            contract,
+            recipients,
+            self.payroll.form_recurrence,
+            first_due,
+        ))
+    }
+
+    /// Load the payroll's next cycle into the bulk disperse form, fetching gas and balance again
+    fn prepare_payroll_disperse(&mut self, id: &str) {
+        let Some(payroll) = self.payroll.payrolls.iter().find(|payroll| payroll.id == id).cloned() else {
+            return;
+        };
+        let state = &mut self.bulk_disperse_state;
+        state.token_mode = false;
+        state.multi_source.enabled = false;
+        state.recipients_input = payroll.recipient_input();
+        state.amount_input = utils::format_ether(payroll.total());
+        state.tip_amount.clear();
+        state.source_index = payroll.source_index;
+        state.disperse_contract_address = payroll.contract.clone().unwrap_or_default();
+        state.last_validated_address = None;
+        state.contract_validation = None;
+        state.current_fees = None;
+        state.last_fetched_source_index = None;
+        state.gas_plan = None;
+        state.gas_estimate_key = None;
+        state.simulation_key = None;
+        state.status = None;
+        self.payroll.linked = Some(payroll.id.clone());
+        self.section = GuiSection::BulkDisperse;
+        self.notifications.push_back(NotificationEntry::new(format!(
+            "[i] Payroll '{}' loaded into Bulk Disperse for the cycle due {}",
+            payroll.name,
+            payroll::format_timestamp(payroll.next_due())
+        )));
+    }
+
+    /// Banner on the bulk disperse form while it holds a payroll cycle
+    pub(crate) fn render_payroll_banner(&mut self, ui: &mut egui::Ui) {
+        let Some(id) = self.payroll.linked.clone() else {
+            return;
+        };
+        let Some(payroll) = self.payroll.payrolls.iter().find(|payroll| payroll.id == id) else {
+            self.payroll.linked = None;
+            return;
+        };
+        let text = if self.payroll.pending.is_some() {
+            format!("[P] Paying payroll '{}': the cycle is recorded once the disperse is mined", payroll.name)
+        } else if self.bulk_disperse_state.token_mode || self.bulk_disperse_state.multi_source.enabled {
+            format!("[!!] Payroll '{}': token and multi-source disperses are not recorded against it", payroll.name)
+        } else {
+            format!(
+                "[P] Payroll '{}', cycle due {}: the disperse below is recorded against it",
+                payroll.name,
+                payroll::format_timestamp(payroll.next_due())
+            )
+        };
+        let pending = self.payroll.pending.is_some();
+        ui.horizontal(|ui| {
+            ui.colored_label(self.theme.info, text);
+            if !pending
+                && ui
+                    .add(self.theme.button_small("Detach"))
+                    .on_hover_text("Send this disperse without recording it as a payroll cycle")
+                    .clicked()
+            {
+                self.payroll.linked = None;
+            }
+        });
+        ui.add_space(self.theme.spacing_sm);
+    }
+}
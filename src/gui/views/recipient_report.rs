@@ -0,0 +1,163 @@
+//! Recipient validation report shared by the bulk disperse and split views
+//!
+//! Runs `recipient_check` over the parsed recipients and lists what it flags:
+//! - Duplicates, the zero address, self-sends and mistyped checksums
+//! - Look-alikes of address book entries (address poisoning)
+//! - Contracts and already funded addresses, once their lookup has finished
+//!
+//! Each issue has a fix the caller applies to its own input text: remove the recipient,
+//! or for a look-alike, use the address book entry instead.
+
+use crate::gui::app::GuiApp;
+use crate::recipient_check::{self, CheckedRecipient, IssueKind, ONCHAIN_CHECK_BATCH};
+use crate::utils;
+use eframe::egui::{self, RichText};
+use ethers::types::Address;
+
+/// Most issues listed before the rest are summarized
+const MAX_LISTED_ISSUES: usize = 25;
+
+/// Edit to the recipient input picked from the report
+pub(crate) enum RecipientFix {
+    /// Drop the recipient at this index
+    Remove(usize),
+    /// Put this address in place of the recipient at this index
+    Replace(usize, Address),
+}
+
+impl GuiApp {
+    /// Look up code and balance of the recipients not checked yet, a batch at a time
+    fn maybe_check_recipients_onchain(&mut self, recipients: &[CheckedRecipient]) {
+        if let Some(job) = &mut self.recipient_check.job {
+            if let Some(result) = job.poll() {
+                self.recipient_check.job = None;
+                match result {
+                    Ok((chain_id, infos)) if chain_id == self.config.chain_id => {
+                        self.recipient_check.onchain.extend(infos);
+                        self.recipient_check.error = None;
+                    }
+                    Ok(_) => {}
+                    Err(e) => self.recipient_check.error = Some(e.to_string()),
+                }
+            }
+            return;
+        }
+        if self.recipient_check.error.is_some() {
+            return;
+        }
+
+        let mut missing: Vec<Address> = recipients
+            .iter()
+            .map(|recipient| recipient.address)
+            .filter(|address| !self.recipient_check.onchain.contains_key(address))
+            .collect();
+        missing.sort();
+        missing.dedup();
+        missing.truncate(ONCHAIN_CHECK_BATCH);
+        if missing.is_empty() {
+            return;
+        }
+        let config = self.config.clone();
+        self.recipient_check.job = Some(self.spawn_job(move || async move {
+            let chain_id = config.chain_id;
+            Ok((chain_id, recipient_check::fetch_onchain(config, missing).await?))
+        }));
+    }
+
+    /// Report what looks wrong with `recipients` sent from `sources`; returns the fix clicked
+    pub(crate) fn render_recipient_report(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_source: &str,
+        recipients: &[CheckedRecipient],
+        sources: &[Address],
+    ) -> Option<RecipientFix> {
+        if recipients.is_empty() {
+            return None;
+        }
+        self.maybe_check_recipients_onchain(recipients);
+
+        let issues = recipient_check::check_recipients(
+            recipients,
+            sources,
+            &self.user_settings.address_book,
+            &self.recipient_check.onchain,
+        );
+        let checked = recipients
+            .iter()
+            .filter(|recipient| self.recipient_check.onchain.contains_key(&recipient.address))
+            .count();
+        let native_token = self.config.native_token();
+        let mut fix = None;
+
+        ui.add_space(self.theme.spacing_xs);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Recipient checks:").strong());
+            if issues.is_empty() && checked == recipients.len() {
+                ui.colored_label(self.theme.accent_green, format!("[OK] No issues in {} recipients", recipients.len()));
+            } else if !issues.is_empty() {
+                let severe = issues.iter().filter(|issue| issue.kind.is_severe()).count();
+                let color = if severe > 0 { self.theme.error } else { self.theme.warning };
+                ui.colored_label(color, format!("{} issue(s), {} likely mistakes", issues.len(), severe));
+            }
+            if checked < recipients.len() && self.recipient_check.error.is_none() {
+                ui.spinner();
+                ui.label(
+                    RichText::new(format!("checking contracts and balances ({}/{})", checked, recipients.len()))
+                        .small()
+                        .color(self.theme.text_secondary),
+                );
+            }
+            if ui
+                .add(self.theme.button_small("Recheck"))
+                .on_hover_text("Look up contracts and balances again")
+                .clicked()
+            {
+                self.recipient_check.onchain.clear();
+                self.recipient_check.error = None;
+            }
+        });
+        if let Some(error) = &self.recipient_check.error {
+            ui.colored_label(self.theme.warning, format!("[!!] Contract and balance checks failed: {}", error));
+        }
+        if issues.is_empty() {
+            return None;
+        }
+
+        egui::ScrollArea::vertical()
+            .id_source(("recipient_report", id_source))
+            .max_height(140.0)
+            .show(ui, |ui| {
+                for issue in issues.iter().take(MAX_LISTED_ISSUES) {
+                    let recipient = &recipients[issue.index];
+                    let (tag, color) = if issue.kind.is_severe() {
+                        ("[XX]", self.theme.error)
+                    } else {
+                        ("[!!]", self.theme.warning)
+                    };
+                    let description = issue
+                        .kind
+                        .describe(|balance| format!("{} {}", utils::format_ether(balance), native_token));
+                    ui.horizontal(|ui| {
+                        ui.colored_label(color, format!("{} #{} {}: {}", tag, issue.index + 1, recipient.text, description));
+                        if let IssueKind::Lookalike { label, address } = &issue.kind {
+                            if ui
+                                .small_button(format!("Use {}", label))
+                                .on_hover_text(format!("Replace with {:?}", address))
+                                .clicked()
+                            {
+                                fix = Some(RecipientFix::Replace(issue.index, *address));
+                            }
+                        }
+                        if ui.small_button("Remove").clicked() {
+                            fix = Some(RecipientFix::Remove(issue.index));
+                        }
+                    });
+                }
+                if issues.len() > MAX_LISTED_ISSUES {
+                    ui.label(format!("... and {} more issues", issues.len() - MAX_LISTED_ISSUES));
+                }
+            });
+        fix
+    }
+}
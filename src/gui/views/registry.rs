@@ -0,0 +1,269 @@
+//! Registry view implementation
+//!
+//! This module contains the Beaug registry panel rendering including:
+//! - Listing every registered disperse contract with its validation status
+//! - Registering a contract, signed on the Ledger by the registry owner
+
+use crate::{ledger_dispatch, registry};
+use eframe::egui::{self, RichText};
+use ethers::types::Address;
+
+use super::super::app::GuiApp;
+use super::super::notifications::NotificationEntry;
+use super::disperse::contract_status_badge;
+
+/// Renders the Registry view
+pub fn view_registry(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.render_section_header(ui, "[R]", "REGISTRY");
+    ui.add_space(app.theme.spacing_sm);
+
+    poll_registry_jobs(app);
+
+    // Load the registry of the selected network on first visit and after a network change
+    let stale = app.registry.snapshot_chain_id != Some(app.config.chain_id);
+    if stale && app.registry.job.is_none() {
+        start_load(app);
+    }
+
+    ui.label(
+        RichText::new("The main Beaug contract keeps a registry of compatible disperse contracts.")
+            .color(app.theme.text_secondary),
+    );
+    ui.add_space(app.theme.spacing_md);
+
+    render_registry_list(app, ui);
+    ui.add_space(app.theme.spacing_md);
+    render_register_form(app, ui);
+}
+
+fn start_load(app: &mut GuiApp) {
+    let config = app.config.clone();
+    app.registry.error = None;
+    app.registry.snapshot_chain_id = Some(config.chain_id);
+    app.registry.job = Some(app.spawn_job(move || async move {
+        let provider = config.get_provider().await?;
+        registry::fetch_registry(provider, config.chain_id).await
+    }));
+}
+
+fn poll_registry_jobs(app: &mut GuiApp) {
+    if let Some(job) = &mut app.registry.job {
+        if let Some(res) = job.poll() {
+            app.registry.job = None;
+            match res {
+                Ok(snapshot) => app.registry.snapshot = Some(snapshot),
+                Err(e) => {
+                    app.registry.snapshot = None;
+                    app.registry.error = Some(e.to_string());
+                }
+            }
+        }
+    }
+
+    if let Some(job) = &mut app.registry.signer_job {
+        if let Some(res) = job.poll() {
+            app.registry.signer_job = None;
+            match res {
+                Ok(address) => app.registry.signer_address = Some(address),
+                Err(e) => app.notifications.push_back(NotificationEntry::new(format!("[XX] Failed to get Ledger address: {}", e))),
+            }
+        }
+    }
+
+    if let Some(job) = &mut app.registry.register_job {
+        if let Some(res) = job.poll() {
+            app.registry.register_job = None;
+            match res {
+                Ok(tx_hash) => {
+                    app.notifications.push_back(NotificationEntry::new(format!("[OK] Contract registered: {:?}", tx_hash)));
+                    app.registry.register_address.clear();
+                    start_load(app);
+                }
+                Err(e) => app.notifications.push_back(NotificationEntry::new(format!("[XX] Registration failed: {}", e))),
+            }
+        }
+    }
+}
+
+fn render_registry_list(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("[#] Registered Contracts").size(16.0).strong().color(app.theme.text_primary));
+            let loading = app.registry.job.is_some();
+            if ui.add_enabled(!loading, egui::Button::new(if loading { "[..] Loading..." } else { "Refresh" })).clicked() {
+                start_load(app);
+            }
+        });
+        ui.add_space(app.theme.spacing_sm);
+
+        if let Some(error) = &app.registry.error {
+            ui.colored_label(app.theme.error, format!("[XX] {}", error));
+            return;
+        }
+
+        let Some(snapshot) = app.registry.snapshot.clone() else {
+            return;
+        };
+
+        ui.label(
+            RichText::new(format!(
+                "Registry {:?} on {}, owner {}",
+                snapshot.registry,
+                app.config.network_label(),
+                snapshot.owner.map(|o| format!("{:?}", o)).unwrap_or_else(|| "unknown".to_string())
+            ))
+            .small()
+            .color(app.theme.text_secondary),
+        );
+        ui.add_space(app.theme.spacing_sm);
+
+        if snapshot.entries.is_empty() {
+            ui.label("No contracts are registered on this network yet.");
+            return;
+        }
+
+        let mut use_contract = None;
+        egui::Grid::new("registry_grid")
+            .num_columns(4)
+            .striped(true)
+            .spacing([16.0, 4.0])
+            .show(ui, |ui| {
+                for header in ["#", "Contract", "Status", ""] {
+                    ui.label(RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for (i, entry) in snapshot.entries.iter().enumerate() {
+                    let (icon, color, text) = contract_status_badge(&entry.status);
+                    ui.label((i + 1).to_string());
+                    ui.monospace(format!("{:?}", entry.address));
+                    let status_label = ui.label(RichText::new(format!("{} {}", icon, text)).color(color));
+                    if let crate::disperse::ContractValidationStatus::Error(reason) = &entry.status {
+                        status_label.on_hover_text(reason);
+                    }
+                    if ui
+                        .add_enabled(entry.status.is_safe_to_use(), egui::Button::new("Use in Bulk Disperse").small())
+                        .clicked()
+                    {
+                        use_contract = Some(entry.address);
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(address) = use_contract {
+            // Bulk Disperse re-validates the address when it changes
+            app.bulk_disperse_state.disperse_contract_address = format!("{:?}", address);
+            app.notifications.push_back(NotificationEntry::new(format!("[OK] Bulk Disperse will use {:?}", address)));
+        }
+    });
+}
+
+fn render_register_form(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[+] Register a Contract").size(16.0).strong().color(app.theme.text_primary));
+        ui.add_space(app.theme.spacing_xs);
+        ui.label(
+            RichText::new("Only the registry owner can register. The transaction is signed by the selected Ledger index.")
+                .small()
+                .color(app.theme.text_secondary),
+        );
+        ui.add_space(app.theme.spacing_sm);
+
+        egui::Grid::new("registry_register_grid")
+            .num_columns(2)
+            .spacing([20.0, 8.0])
+            .show(ui, |ui| {
+                ui.label("Contract:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut app.registry.register_address)
+                        .desired_width(400.0)
+                        .hint_text("0x..."),
+                );
+                ui.end_row();
+
+                ui.label("Owner index:");
+                ui.horizontal(|ui| {
+                    if ui.add(egui::DragValue::new(&mut app.registry.signer_index).clamp_range(0..=10_000)).changed() {
+                        app.registry.signer_address = None;
+                    }
+                    let ledger_ready = app.ledger_status.is_usable();
+                    let fetching = app.registry.signer_job.is_some();
+                    if ui
+                        .add_enabled(ledger_ready && !fetching, egui::Button::new(if fetching { "[..]" } else { "Fetch from Ledger" }))
+                        .on_disabled_hover_text("Connect and unlock your Ledger")
+                        .clicked()
+                    {
+                        let config = app.config.clone();
+                        let index = app.registry.signer_index;
+                        let use_native_ledger = app.user_settings.use_native_ledger;
+                        app.registry.signer_job = Some(app.spawn_job(move || async move {
+                            ledger_dispatch::get_ledger_address_with_retry_config(use_native_ledger, config.chain_id, index, Some(&config))
+                                .await
+                        }));
+                    }
+                    if let Some(address) = app.registry.signer_address {
+                        ui.monospace(format!("{:?}", address));
+                    }
+                });
+                ui.end_row();
+            });
+        ui.add_space(app.theme.spacing_sm);
+
+        let contract = app.registry.register_address.trim().parse::<Address>().ok();
+        let owner = app.registry.snapshot.as_ref().and_then(|s| s.owner);
+        let already_registered = contract.is_some_and(|c| {
+            app.registry.snapshot.as_ref().is_some_and(|s| s.entries.iter().any(|e| e.address == c))
+        });
+
+        let mut problems = Vec::new();
+        if !app.registry.register_address.trim().is_empty() && contract.is_none() {
+            problems.push("Invalid contract address".to_string());
+        }
+        if already_registered {
+            problems.push("This contract is already registered".to_string());
+        }
+        match (app.registry.signer_address, owner) {
+            (Some(signer), Some(owner)) if signer != owner => {
+                problems.push(format!("Index {} is not the registry owner {:?}", app.registry.signer_index, owner))
+            }
+            (None, _) => problems.push("Fetch the owner address from the Ledger".to_string()),
+            _ => {}
+        }
+        for problem in &problems {
+            ui.colored_label(app.theme.warning, format!("[!!] {}", problem));
+        }
+
+        let registering = app.registry.register_job.is_some();
+        let can_register = contract.is_some() && problems.is_empty() && !registering && app.ledger_status.is_usable();
+        if let (Some(contract), true) = (contract, can_register) {
+            ui.add_space(app.theme.spacing_xs);
+            ui.label(
+                RichText::new(format!(
+                    "Review: registerContract({:?}) on the registry {:?} ({}), signed by index {}",
+                    contract,
+                    crate::disperse::get_beaug_registry_address(app.config.chain_id).unwrap_or_default(),
+                    app.config.network_label(),
+                    app.registry.signer_index
+                ))
+                .color(app.theme.text_primary),
+            );
+        }
+        ui.add_space(app.theme.spacing_xs);
+        if ui
+            .add_enabled(can_register, app.theme.button_warning(if registering { "[..] Registering..." } else { "Register on Ledger" }))
+            .clicked()
+        {
+            if let Some(contract) = contract {
+                let config = app.config.clone();
+                let index = app.registry.signer_index as usize;
+                let gas_speed = app.bulk_disperse_state.gas_speed;
+                let use_native_ledger = app.user_settings.use_native_ledger;
+                app.registry.register_job = Some(app.spawn_job(move || async move {
+                    registry::register_contract(config, contract, index, gas_speed, use_native_ledger).await
+                }));
+                app.notifications.push_back(NotificationEntry::new("Registration initiated, confirm on your Ledger..."));
+            }
+        }
+    });
+}
@@ -0,0 +1,219 @@
+//! Scheduled runs view implementation
+//!
+//! This module contains:
+//! - The scheduled runs panel: pending and finished runs, cancelling and clearing them
+//! - The "Schedule for later" form shown under the split and bulk disperse buttons
+//!
+//! The scheduler itself runs from `GuiApp::poll_schedule` on every frame, whichever
+//! view is open.
+
+use crate::schedule::{ScheduleStatus, ScheduleTrigger, ScheduledRun, SchedulePlan};
+use eframe::egui::{self, RichText};
+use ethers::types::U256;
+
+use super::super::app::GuiApp;
+use super::super::notifications::NotificationEntry;
+
+/// Renders the Scheduled Runs view
+pub fn view_schedule(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.render_section_header(ui, "[@]", "SCHEDULED RUNS");
+    ui.add_space(app.theme.spacing_sm);
+
+    ui.label(
+        RichText::new(
+            "Splits and bulk disperses queued for a time or block. Keep Beaug open on the run's network: \
+             when a run is due, its balance and fees are checked again and your Ledger asks to sign.",
+        )
+        .color(app.theme.text_secondary),
+    );
+    if let Some((chain_id, block)) = app.schedule.block {
+        if chain_id == app.config.chain_id {
+            ui.label(RichText::new(format!("Current block: #{}", block)).small().color(app.theme.text_secondary));
+        }
+    }
+    if let Some(error) = &app.schedule.error {
+        ui.colored_label(app.theme.error, format!("[XX] {}", error));
+    }
+    ui.add_space(app.theme.spacing_md);
+
+    render_run_list(app, ui, false);
+    ui.add_space(app.theme.spacing_lg);
+    render_run_list(app, ui, true);
+}
+
+fn render_run_list(app: &mut GuiApp, ui: &mut egui::Ui, finished: bool) {
+    let runs: Vec<ScheduledRun> = app
+        .schedule
+        .runs
+        .iter()
+        .filter(|run| run.status.is_finished() == finished)
+        .cloned()
+        .collect();
+
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.horizontal(|ui| {
+            let title = if finished { "[=] Finished" } else { "[>] Upcoming" };
+            ui.label(
+                RichText::new(format!("{} ({})", title, runs.len()))
+                    .size(16.0)
+                    .strong()
+                    .color(app.theme.text_primary),
+            );
+            if finished && !runs.is_empty() && ui.add(app.theme.button_small("Clear")).clicked() {
+                app.schedule.runs.retain(|run| !run.status.is_finished());
+                app.save_schedule();
+            }
+        });
+        ui.add_space(app.theme.spacing_sm);
+
+        if runs.is_empty() {
+            let empty = if finished {
+                "No finished runs."
+            } else {
+                "Nothing scheduled. Use \"Schedule for later\" in Split or Bulk Disperse."
+            };
+            ui.label(RichText::new(empty).italics().color(app.theme.text_secondary));
+            return;
+        }
+
+        let mut cancel: Option<String> = None;
+        egui::Grid::new(("schedule_grid", finished))
+            .num_columns(5)
+            .spacing([app.theme.spacing_md, app.theme.spacing_xs])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Label").strong());
+                ui.label(RichText::new("When").strong());
+                ui.label(RichText::new("What").strong());
+                ui.label(RichText::new("Status").strong());
+                ui.label(RichText::new("Actions").strong());
+                ui.end_row();
+
+                for run in &runs {
+                    ui.label(&run.label);
+                    ui.label(format!("{} on {}", run.trigger.describe(), run.network_label));
+                    ui.label(run.plan.describe(&run.native_token));
+                    let color = match run.status {
+                        ScheduleStatus::Pending | ScheduleStatus::Running => app.theme.info,
+                        ScheduleStatus::Done(_) => app.theme.accent_green,
+                        ScheduleStatus::Cancelled => app.theme.text_secondary,
+                        ScheduleStatus::Failed(_) | ScheduleStatus::Missed(_) => app.theme.error,
+                    };
+                    ui.vertical(|ui| {
+                        ui.colored_label(color, run.status.label());
+                        if let Some(reason) = app.schedule.waiting.get(&run.id) {
+                            ui.label(RichText::new(format!("waiting: {}", reason)).small().color(app.theme.warning));
+                        }
+                    });
+                    if run.status == ScheduleStatus::Pending {
+                        if ui.add(app.theme.button_small("Cancel")).clicked() {
+                            cancel = Some(run.id.clone());
+                        }
+                    } else {
+                        ui.label("");
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(id) = cancel {
+            app.schedule.waiting.remove(&id);
+            if let Some(run) = app.schedule.runs.iter_mut().find(|run| run.id == id) {
+                run.status = ScheduleStatus::Cancelled;
+                run.log_status();
+            }
+            app.save_schedule();
+        }
+    });
+}
+
+impl GuiApp {
+    /// "Schedule for later" form for the operation described by `plan`
+    pub(crate) fn render_schedule_form(&mut self, ui: &mut egui::Ui, id_source: &str, plan: Result<SchedulePlan, String>) {
+        egui::CollapsingHeader::new("[@] Schedule for later")
+            .id_source(("schedule_form", id_source))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Label:");
+                    ui.add(egui::TextEdit::singleline(&mut self.schedule.form_label).desired_width(160.0));
+                    ui.label("When:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.schedule.form_trigger)
+                            .desired_width(160.0)
+                            .hint_text("2025-01-31 09:00, +2h or #21000000"),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Max gas price (gwei):");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.schedule.form_max_gwei)
+                            .desired_width(80.0)
+                            .hint_text("no cap"),
+                    );
+                    ui.label(
+                        RichText::new("A due run waits while fees are above the cap")
+                            .small()
+                            .color(self.theme.text_secondary),
+                    );
+                });
+
+                let hover = match &plan {
+                    Ok(plan) => plan.describe(self.config.native_token()),
+                    Err(reason) => reason.clone(),
+                };
+                if ui
+                    .add_enabled(plan.is_ok(), self.theme.button_small("Schedule"))
+                    .on_hover_text(hover)
+                    .clicked()
+                {
+                    if let Ok(plan) = plan {
+                        match self.schedule_run(plan) {
+                            Ok(run) => {
+                                self.notifications.push_back(NotificationEntry::new(format!(
+                                    "[OK] Scheduled '{}' for {}",
+                                    run.label,
+                                    run.trigger.describe()
+                                )));
+                                self.schedule.form_label.clear();
+                                self.schedule.form_trigger.clear();
+                                self.schedule.form_error = None;
+                            }
+                            Err(e) => self.schedule.form_error = Some(e),
+                        }
+                    }
+                }
+                if let Some(error) = &self.schedule.form_error {
+                    ui.colored_label(self.theme.error, format!("[XX] {}", error));
+                }
+            });
+    }
+
+    /// Validate the form and add the run to the schedule
+    fn schedule_run(&mut self, plan: SchedulePlan) -> Result<ScheduledRun, String> {
+        let now = chrono::Utc::now().timestamp();
+        let trigger = ScheduleTrigger::parse(&self.schedule.form_trigger, now).map_err(|e| e.to_string())?;
+        let current_block = self
+            .schedule
+            .block
+            .filter(|(chain_id, _)| *chain_id == self.config.chain_id)
+            .map(|(_, block)| block);
+        if trigger.is_due(now, current_block) {
+            return Err(format!("{} has already passed", trigger.describe()));
+        }
+        let max_gas_price = match self.schedule.form_max_gwei.trim() {
+            "" => None,
+            gwei => Some(U256::from(
+                ethers::utils::parse_units(gwei, "gwei").map_err(|e| format!("Invalid gas price cap: {}", e))?,
+            )),
+        };
+        let label = match self.schedule.form_label.trim() {
+            "" => format!("Run {}", self.schedule.runs.len() + 1),
+            label => label.to_string(),
+        };
+
+        let run = ScheduledRun::new(&self.config, label, trigger, plan, max_gas_price);
+        self.schedule.runs.push(run.clone());
+        self.save_schedule();
+        Ok(run)
+    }
+}
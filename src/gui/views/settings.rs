@@ -2,13 +2,14 @@
 //!
 //! Contains the settings panel rendering including:
 //! - Network & RPC configuration
-//! - Default gas speed settings  
+//! - Default gas speed and gas limit margin
 //! - Scan & operation defaults
+//! - USD valuation (Chainlink price feeds)
 //! - Hardware wallet settings
 //! - Derivation path configuration
 //! - Custom network management
 
-use crate::gui::app::GuiApp;
+use crate::gui::app::{GuiApp, PriceState};
 use crate::gui::helpers::{gas_speed_emoji, gas_speed_label, gas_speed_warning};
 use crate::gui::notifications::NotificationEntry;
 use crate::user_settings::CustomNetwork;
@@ -203,6 +204,21 @@ impl GuiApp {
             // Show if chain uses EIP-1559
             let uses_eip1559 = crate::config::chain_supports_eip1559(self.config.chain_id);
             ui.label(RichText::new(format!("Transaction type: {}", if uses_eip1559 { "EIP-1559 (dynamic fees)" } else { "Legacy (fixed gas price)" })).small().color(self.theme.text_secondary));
+
+            // Safety margin on top of eth_estimateGas for disperse transactions
+            ui.add_space(self.theme.spacing_sm);
+            ui.horizontal(|ui| {
+                ui.label("Gas limit margin:");
+                let changed = ui
+                    .add(egui::DragValue::new(&mut self.user_settings.gas_margin_percent).clamp_range(0..=100).suffix("%"))
+                    .changed();
+                if changed {
+                    if let Err(e) = self.user_settings.save() {
+                        self.notifications.push_back(NotificationEntry::new(format!("Failed to save settings: {}", e)));
+                    }
+                }
+            });
+            ui.label(RichText::new("Added to the node's gas estimate for disperse transactions. The recipient-count heuristic is only used when estimation fails.").small().color(self.theme.text_secondary));
         });
 
         ui.add_space(self.theme.spacing_lg);
@@ -312,6 +328,16 @@ impl GuiApp {
 
         ui.add_space(self.theme.spacing_lg);
 
+        // USD Valuation Panel
+        self.render_fiat_settings(ui);
+
+        ui.add_space(self.theme.spacing_lg);
+
+        // ENS Panel
+        self.render_ens_settings(ui);
+
+        ui.add_space(self.theme.spacing_lg);
+
         // Hardware Wallet Settings Panel
         self.render_hardware_wallet_settings(ui);
 
@@ -326,6 +352,103 @@ impl GuiApp {
         self.render_custom_networks_settings(ui);
     }
 
+    fn render_fiat_settings(&mut self, ui: &mut egui::Ui) {
+        self.theme.frame_panel().show(ui, |ui| {
+            ui.label(RichText::new("USD Valuation").size(18.0).strong().color(self.theme.text_primary));
+            ui.add_space(self.theme.spacing_sm);
+
+            if ui
+                .checkbox(&mut self.user_settings.show_fiat_values, "Show USD values for balances and distributions")
+                .changed()
+            {
+                self.price = PriceState::default();
+                if let Err(e) = self.user_settings.save() {
+                    self.notifications.push_back(NotificationEntry::new(format!("Failed to save settings: {}", e)));
+                }
+            }
+            ui.label(
+                RichText::new("Prices are read from Chainlink price feeds over the selected RPC. No third-party price API is used.")
+                    .small()
+                    .color(self.theme.text_secondary),
+            );
+
+            if !self.user_settings.show_fiat_values {
+                return;
+            }
+
+            ui.add_space(self.theme.spacing_sm);
+            let chain_id = self.config.chain_id;
+            let builtin = crate::price_feed::builtin_feed(chain_id);
+            match builtin {
+                Some(feed) => ui.label(format!("Built-in feed for {}: {} at {}", self.config.network_label(), feed.pair, feed.address)),
+                None => ui.label(format!("No built-in feed for {}", self.config.network_label())),
+            };
+
+            let mut custom_feed = self.user_settings.custom_price_feeds.get(&chain_id).cloned().unwrap_or_default();
+            let mut apply = false;
+            ui.horizontal(|ui| {
+                ui.label("Custom feed:");
+                if ui
+                    .add(
+                        egui::TextEdit::singleline(&mut custom_feed)
+                            .desired_width(360.0)
+                            .hint_text("Chainlink aggregator address (optional)"),
+                    )
+                    .changed()
+                {
+                    if custom_feed.trim().is_empty() {
+                        self.user_settings.custom_price_feeds.remove(&chain_id);
+                    } else {
+                        self.user_settings.custom_price_feeds.insert(chain_id, custom_feed.clone());
+                    }
+                }
+                apply = ui.add(self.theme.button_small("Apply")).clicked();
+            });
+
+            if apply {
+                match crate::price_feed::feed_for(chain_id, &self.user_settings) {
+                    Ok(_) => {
+                        self.price = PriceState::default();
+                        if let Err(e) = self.user_settings.save() {
+                            self.notifications.push_back(NotificationEntry::new(format!("Failed to save settings: {}", e)));
+                        } else {
+                            self.notifications.push_back(NotificationEntry::new("Price feed updated."));
+                        }
+                    }
+                    Err(e) => self.notifications.push_back(NotificationEntry::new(format!("[XX] {}", e))),
+                }
+            }
+
+            ui.add_space(self.theme.spacing_xs);
+            self.render_price_source(ui);
+        });
+    }
+
+    fn render_ens_settings(&mut self, ui: &mut egui::Ui) {
+        self.theme.frame_panel().show(ui, |ui| {
+            ui.label(RichText::new("ENS Names").size(18.0).strong().color(self.theme.text_primary));
+            ui.add_space(self.theme.spacing_sm);
+
+            if ui
+                .checkbox(&mut self.user_settings.show_ens_names, "Show ENS names next to addresses")
+                .changed()
+            {
+                if let Err(e) = self.user_settings.save() {
+                    self.notifications.push_back(NotificationEntry::new(format!("Failed to save settings: {}", e)));
+                }
+            }
+            ui.label(
+                RichText::new(
+                    "Reverse records of the addresses in the balances table and transaction queues are looked up \
+                     on the selected network if it has an ENS registry, otherwise over the Ethereum RPC. \
+                     Names typed into address fields are always resolved.",
+                )
+                .small()
+                .color(self.theme.text_secondary),
+            );
+        });
+    }
+
     fn render_hardware_wallet_settings(&mut self, ui: &mut egui::Ui) {
         self.theme.frame_panel().show(ui, |ui| {
             ui.label(RichText::new("Hardware Wallet Settings").size(18.0).strong().color(self.theme.text_primary));
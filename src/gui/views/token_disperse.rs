@@ -0,0 +1,726 @@
+//! ERC-20 token disperse view implementation
+//!
+//! Token mode of the bulk disperse panel:
+//! - Token selection with symbol and decimals read from the contract
+//! - Token balance and allowance of the source address
+//! - Approve step when the allowance does not cover the total
+//! - Execution via `disperseToken` (recipient input and CSV import are shared)
+
+use crate::bulk_disperse::{self, BulkDisperseType};
+use crate::disperse_batch::DispersePlan;
+use crate::disperse_protocol;
+use crate::erc20::{self, TokenInfo};
+use crate::gui::app::{GuiApp, TokenDisperseState};
+use crate::gui::helpers::{gas_speed_emoji, gas_speed_label, gas_speed_warning};
+use crate::gui::notifications::NotificationEntry;
+use crate::payment_uri::PaymentAsset;
+use crate::recipient_input;
+use crate::utils;
+use eframe::egui::{self, RichText};
+use ethers::types::{Address, U256};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+impl GuiApp {
+    /// Native / ERC-20 selector shown at the top of the bulk disperse panel
+    pub(crate) fn render_asset_selector(&mut self, ui: &mut egui::Ui) {
+        let native_token = self.config.native_token().to_string();
+        ui.horizontal(|ui| {
+            ui.label("Asset:");
+            ui.radio_value(&mut self.bulk_disperse_state.token_mode, false, native_token);
+            ui.radio_value(&mut self.bulk_disperse_state.token_mode, true, "ERC-20 Token");
+        });
+
+        if !self.bulk_disperse_state.token_mode {
+            return;
+        }
+
+        let token = &mut self.bulk_disperse_state.token;
+        let mut load_clicked = false;
+        ui.horizontal(|ui| {
+            ui.label("Token Contract:");
+            let changed = ui
+                .add(egui::TextEdit::singleline(&mut token.address_input).desired_width(380.0))
+                .changed();
+            if changed {
+                token.info = None;
+                token.error = None;
+                token.position = None;
+                token.position_key = None;
+            }
+            load_clicked = ui
+                .add_enabled(token.info_job.is_none(), egui::Button::new("Load Token"))
+                .on_hover_text("Read symbol and decimals from the token contract")
+                .clicked();
+        });
+
+        if load_clicked {
+            match token.address_input.trim().parse::<Address>() {
+                Ok(address) => {
+                    let config = self.config.clone();
+                    token.error = None;
+                    token.position = None;
+                    token.position_key = None;
+                    let job = self.spawn_job(move || async move {
+                        let provider = config.get_provider().await?;
+                        erc20::fetch_token_info(&provider, address).await
+                    });
+                    self.bulk_disperse_state.token.info_job = Some(job);
+                }
+                Err(_) => {
+                    token.error = Some("Invalid token address".to_string());
+                }
+            }
+        }
+
+        let token = &self.bulk_disperse_state.token;
+        if token.info_job.is_some() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(RichText::new("Reading token contract...").italics().color(self.theme.text_secondary));
+            });
+        } else if let Some(info) = &token.info {
+            ui.label(
+                RichText::new(format!("✓ {} ({} decimals)", info.symbol, info.decimals))
+                    .color(self.theme.accent_green)
+                    .size(11.0),
+            );
+        } else if let Some(error) = &token.error {
+            ui.label(RichText::new(format!("! {}", error)).color(self.theme.error).size(11.0));
+        } else {
+            ui.label(
+                RichText::new("Enter the token address and click Load Token")
+                    .italics()
+                    .size(11.0)
+                    .color(self.theme.text_secondary),
+            );
+        }
+    }
+
+    /// Token-mode body of the bulk disperse panel, rendered after the shared recipient input
+    pub(crate) fn render_token_disperse(&mut self, ui: &mut egui::Ui) {
+        let Some(info) = self.bulk_disperse_state.token.info.clone() else {
+            return;
+        };
+
+        self.render_token_recipients_preview(ui, &info);
+        ui.add_space(self.theme.spacing_md);
+
+        self.render_source_address_selection(ui);
+        ui.add_space(self.theme.spacing_sm);
+
+        self.maybe_fetch_token_position(&info);
+        self.maybe_estimate_token_gas(&info);
+        self.render_token_position(ui, &info);
+        ui.add_space(self.theme.spacing_sm);
+
+        self.render_token_execute_section(ui, &info);
+    }
+
+    fn render_token_recipients_preview(&mut self, ui: &mut egui::Ui, info: &TokenInfo) {
+        if self.bulk_disperse_state.recipients_input.trim().is_empty() {
+            return;
+        }
+
+        ui.add_space(self.theme.spacing_md);
+        ui.label(RichText::new("[#] Parsed Recipients Preview:").strong());
+
+        match bulk_disperse::parse_bulk_disperse_input_for_token(&self.bulk_disperse_state.recipients_input, self.config.chain_id, info) {
+            Ok(BulkDisperseType::Equal(addresses)) if !addresses.is_empty() => {
+                ui.colored_label(
+                    egui::Color32::GREEN,
+                    format!("[OK] Equal distribution: {} recipients", addresses.len()),
+                );
+                egui::ScrollArea::vertical().max_height(120.0).show(ui, |ui| {
+                    for (i, addr) in addresses.iter().enumerate().take(10) {
+                        ui.label(format!("{}. {:?} (equal share)", i + 1, addr));
+                    }
+                    if addresses.len() > 10 {
+                        ui.label(format!("... and {} more recipients", addresses.len() - 10));
+                    }
+                });
+            }
+            Ok(BulkDisperseType::Weighted(recipients)) if !recipients.is_empty() => {
+                let resolved = info
+                    .parse_amount(self.bulk_disperse_state.token.amount_input.trim())
+                    .ok()
+                    .and_then(|total| recipient_input::resolve_amounts(&recipients, total).ok());
+                ui.colored_label(
+                    egui::Color32::GREEN,
+                    format!("[OK] Weighted distribution: {} recipients", recipients.len()),
+                );
+                egui::ScrollArea::vertical().max_height(120.0).show(ui, |ui| {
+                    for (i, (addr, share)) in recipients.iter().enumerate().take(10) {
+                        let share_text = share.describe(|value| format!("{} {}", info.format_amount(value), info.symbol));
+                        match &resolved {
+                            Some(resolved) => ui.label(format!(
+                                "{}. {:?} → {} {} ({})",
+                                i + 1, addr, info.format_amount(resolved[i].1), info.symbol, share_text
+                            )),
+                            None => ui.label(format!("{}. {:?} ({})", i + 1, addr, share_text)),
+                        };
+                    }
+                    if recipients.len() > 10 {
+                        ui.label(format!("... and {} more recipients", recipients.len() - 10));
+                    }
+                });
+            }
+            Ok(BulkDisperseType::Mixed(recipients)) if !recipients.is_empty() => {
+                let total = recipients.iter().fold(U256::zero(), |acc, (_, amount)| acc + *amount);
+                ui.colored_label(
+                    egui::Color32::GREEN,
+                    format!(
+                        "[OK] Mixed distribution: {} recipients, total amount: {} {}",
+                        recipients.len(),
+                        info.format_amount(total),
+                        info.symbol
+                    ),
+                );
+                egui::ScrollArea::vertical().max_height(120.0).show(ui, |ui| {
+                    for (i, (addr, amount)) in recipients.iter().enumerate().take(10) {
+                        ui.label(format!("{}. {:?} → {} {}", i + 1, addr, info.format_amount(*amount), info.symbol));
+                    }
+                    if recipients.len() > 10 {
+                        ui.label(format!("... and {} more recipients", recipients.len() - 10));
+                    }
+                });
+            }
+            Ok(_) => {
+                ui.colored_label(egui::Color32::YELLOW, "No recipients found. Check your input format.");
+            }
+            Err(e) => {
+                ui.colored_label(egui::Color32::RED, format!("[XX] Parse error: {}", e));
+            }
+        }
+
+        self.render_bulk_recipient_report(ui, PaymentAsset::Token(info.address));
+    }
+
+    /// Fetch the source's token balance and allowance whenever token, source or contract change
+    fn maybe_fetch_token_position(&mut self, info: &TokenInfo) {
+        let state = &self.bulk_disperse_state;
+        let (Some(source), Ok(spender)) = (
+            state.source_address.as_ref(),
+            state.disperse_contract_address.trim().parse::<Address>(),
+        ) else {
+            return;
+        };
+        let Ok(owner) = source.parse::<Address>() else {
+            return;
+        };
+        let key = format!("{:?}|{:?}|{:?}", info.address, owner, spender);
+        if state.token.position_job.is_some() || state.token.position_key.as_deref() == Some(key.as_str()) {
+            return;
+        }
+        self.start_token_position_fetch(info.address, owner, spender, key);
+    }
+
+    fn start_token_position_fetch(&mut self, token: Address, owner: Address, spender: Address, key: String) {
+        let config = self.config.clone();
+        self.bulk_disperse_state.token.position_key = Some(key);
+        self.bulk_disperse_state.token.position_job = Some(self.spawn_job(move || async move {
+            let provider = config.get_provider().await?;
+            let (balance, allowance) = tokio::try_join!(
+                erc20::balance_of(&provider, token, owner),
+                erc20::allowance(&provider, token, owner, spender),
+            )?;
+            // Same check the approve and the disperse run before signing
+            let unsupported = disperse_protocol::resolve_token_disperse(&provider, spender)
+                .await
+                .err()
+                .map(|e| e.to_string());
+            Ok((balance, allowance, unsupported))
+        }));
+    }
+
+    /// Estimate the token disperse gas limit against the real calldata
+    fn maybe_estimate_token_gas(&mut self, info: &TokenInfo) {
+        let state = &self.bulk_disperse_state;
+        let Some(source) = state.source_address.as_deref().and_then(|a| a.parse::<Address>().ok()) else {
+            return;
+        };
+        let Ok(contract) = state.disperse_contract_address.trim().parse::<Address>() else {
+            return;
+        };
+        let (recipients, amounts): (Vec<Address>, Vec<U256>) =
+            match bulk_disperse::parse_bulk_disperse_input_for_token(&state.recipients_input, self.config.chain_id, info) {
+                Ok(BulkDisperseType::Equal(addresses)) if !addresses.is_empty() => {
+                    let Ok(total) = info.parse_amount(state.token.amount_input.trim()) else {
+                        return;
+                    };
+                    let per_recipient = total / U256::from(addresses.len());
+                    let amounts = vec![per_recipient; addresses.len()];
+                    (addresses, amounts)
+                }
+                Ok(BulkDisperseType::Mixed(recipients)) if !recipients.is_empty() => recipients.into_iter().unzip(),
+                Ok(disperse_type @ BulkDisperseType::Weighted(_)) if !disperse_type.is_empty() => {
+                    let Some(resolved) = info
+                        .parse_amount(state.token.amount_input.trim())
+                        .ok()
+                        .and_then(|total| disperse_type.resolve(total).ok())
+                    else {
+                        return;
+                    };
+                    resolved.into_iter().unzip()
+                }
+                _ => return,
+            };
+
+        let gas_margin_percent = self.user_settings.gas_margin_percent;
+        let mut hasher = DefaultHasher::new();
+        (self.config.chain_id, info.address, contract, source, &recipients, &amounts, gas_margin_percent).hash(&mut hasher);
+        let key = hasher.finish();
+
+        let config = self.config.clone();
+        let token = info.address;
+        let (plan_recipients, plan_amounts) = (recipients.clone(), amounts.clone());
+        self.request_disperse_gas_estimate(key, move || async move {
+            let recipient_count = plan_recipients.len();
+            let estimate = bulk_disperse::estimate_token_disperse_gas(config, token, contract, source, plan_recipients, plan_amounts, gas_margin_percent)
+                .await?;
+            Ok(DispersePlan::single(estimate, recipient_count))
+        });
+
+        // Simulate only once balance and allowance cover the total; before that it would revert anyway
+        let total = amounts.iter().fold(U256::zero(), |acc, x| acc + *x);
+        if let Some(simulation_key) = self.token_simulation_key(key, total) {
+            let config = self.config.clone();
+            self.request_disperse_simulation(simulation_key, move || async move {
+                let reason = bulk_disperse::simulate_token_disperse(config, token, contract, source, recipients, amounts).await?;
+                Ok(reason.map(|reason| reason.to_string()))
+            });
+        }
+    }
+
+    /// Simulation key for the current gas estimate and token position, if the source can cover `total`
+    fn token_simulation_key(&self, gas_key: u64, total: U256) -> Option<u64> {
+        let state = &self.bulk_disperse_state;
+        let (balance, allowance) = state.token.position?;
+        if state.gas_estimate_key != Some(gas_key) || balance < total || allowance < total {
+            return None;
+        }
+        let mut hasher = DefaultHasher::new();
+        (gas_key, balance, allowance).hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
+    /// Total amount of tokens the current input distributes
+    fn token_total_required(&self, info: &TokenInfo) -> Option<(usize, U256)> {
+        match bulk_disperse::parse_bulk_disperse_input_for_token(&self.bulk_disperse_state.recipients_input, self.config.chain_id, info).ok()? {
+            disperse_type @ (BulkDisperseType::Equal(_) | BulkDisperseType::Weighted(_)) => {
+                let amount = info.parse_amount(self.bulk_disperse_state.token.amount_input.trim()).ok()?;
+                Some((disperse_type.len(), amount))
+            }
+            BulkDisperseType::Mixed(recipients) => {
+                let total = recipients.iter().fold(U256::zero(), |acc, (_, amount)| acc + *amount);
+                Some((recipients.len(), total))
+            }
+        }
+    }
+
+    fn render_token_position(&mut self, ui: &mut egui::Ui, info: &TokenInfo) {
+        let token = &self.bulk_disperse_state.token;
+        if token.position_job.is_some() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(RichText::new("Reading token balance and allowance...").italics().color(self.theme.text_secondary));
+            });
+            return;
+        }
+        let Some((balance, allowance)) = token.position else {
+            return;
+        };
+        let required = self.token_total_required(info).map(|(_, total)| total);
+
+        let mut refresh_clicked = false;
+        let mut approve_clicked = false;
+        self.theme.frame_panel().show(ui, |ui| {
+            egui::Grid::new("token_position_grid").num_columns(2).spacing([12.0, 4.0]).show(ui, |ui| {
+                ui.label("Token balance:");
+                let balance_color = match required {
+                    Some(total) if balance < total => self.theme.error,
+                    _ => self.theme.accent_green,
+                };
+                ui.label(RichText::new(format!("{} {}", info.format_amount(balance), info.symbol)).strong().color(balance_color));
+                ui.end_row();
+
+                ui.label("Allowance:");
+                let allowance_text = if allowance == U256::MAX {
+                    "Unlimited".to_string()
+                } else {
+                    format!("{} {}", info.format_amount(allowance), info.symbol)
+                };
+                let allowance_color = match required {
+                    Some(total) if allowance < total => self.theme.warning,
+                    _ => self.theme.accent_green,
+                };
+                ui.label(RichText::new(allowance_text).strong().color(allowance_color));
+                ui.end_row();
+
+                if let Some(total) = required {
+                    ui.label("Required:");
+                    ui.label(RichText::new(format!("{} {}", info.format_amount(total), info.symbol)).strong());
+                    ui.end_row();
+                }
+            });
+
+            ui.add_space(self.theme.spacing_xs);
+            ui.horizontal(|ui| {
+                refresh_clicked = ui.small_button("🔄 Refresh").clicked();
+                if let Some(unsupported) = &self.bulk_disperse_state.token.contract_unsupported {
+                    ui.label(RichText::new(unsupported).color(self.theme.error));
+                } else if let Some(total) = required {
+                    if allowance < total && !total.is_zero() {
+                        let approving = self.bulk_disperse_state.token.approve_job.is_some();
+                        approve_clicked = ui
+                            .add_enabled(
+                                !approving && !self.ledger_status.has_problem(),
+                                self.theme.button_warning(&format!("Approve {} {}", info.format_amount(total), info.symbol)),
+                            )
+                            .on_hover_text("Sign an approve() for exactly the required amount with the Ledger")
+                            .clicked();
+                        if approving {
+                            ui.spinner();
+                            ui.label(RichText::new("Waiting for approval to be mined...").italics().color(self.theme.text_secondary));
+                        }
+                    }
+                }
+            });
+        });
+
+        if refresh_clicked {
+            self.bulk_disperse_state.token.position_key = None;
+        }
+        if approve_clicked {
+            if let Some(total) = required {
+                self.execute_token_approve(info.clone(), total);
+            }
+        }
+    }
+
+    fn execute_token_approve(&mut self, info: TokenInfo, amount: U256) {
+        let config = self.config.clone();
+        let disperse_contract = Some(self.bulk_disperse_state.disperse_contract_address.trim().to_string());
+        let source_index = self.bulk_disperse_state.source_index as usize;
+        let gas_speed = self.bulk_disperse_state.gas_speed;
+        let use_native_ledger = self.user_settings.use_native_ledger;
+
+        self.bulk_disperse_state.token.approve_job = Some(self.spawn_job(move || async move {
+            bulk_disperse::approve_token_for_disperse(
+                config,
+                info,
+                amount,
+                disperse_contract,
+                Some(source_index),
+                gas_speed,
+                use_native_ledger,
+            )
+            .await
+        }));
+        self.notifications.push_back(NotificationEntry::new("Token approval initiated, confirm on your Ledger..."));
+    }
+
+    fn validate_token_disperse_parameters(&self, info: &TokenInfo) -> Vec<String> {
+        let mut errors = Vec::new();
+        let state = &self.bulk_disperse_state;
+
+        if self.ledger_status.has_problem() {
+            if let Some(warning) = self.get_ledger_warning_message() {
+                errors.push(warning);
+            }
+        }
+        if let Some(error) = self.ens_confirmation_error(&recipient_input::ens_names(&state.recipients_input)) {
+            errors.push(error);
+        }
+        if state.recipients_input.trim().is_empty() {
+            errors.push("No recipients entered".to_string());
+        }
+        if state.disperse_contract_address.trim().is_empty() {
+            errors.push("No contract address".to_string());
+        }
+        if matches!(state.contract_validation, Some(crate::disperse::ContractValidationStatus::Multicall3Fallback)) {
+            errors.push("Multicall3 can only send the native currency; token disperse needs a Beaug or disperse.app contract".to_string());
+        } else if let Some(unsupported) = &state.token.contract_unsupported {
+            errors.push(unsupported.clone());
+        }
+
+        let parsed = bulk_disperse::parse_bulk_disperse_input_for_token(&state.recipients_input, self.config.chain_id, info);
+        if let Ok(disperse_type @ (BulkDisperseType::Equal(_) | BulkDisperseType::Weighted(_))) = &parsed {
+            match info.parse_amount(state.token.amount_input.trim()) {
+                Ok(amount) if amount.is_zero() => errors.push("Amount must be greater than 0".to_string()),
+                Ok(amount) => {
+                    if let (BulkDisperseType::Weighted(_), Err(e)) = (disperse_type, disperse_type.resolve(amount)) {
+                        errors.push(e.to_string());
+                    }
+                }
+                Err(_) if state.token.amount_input.trim().is_empty() => errors.push("No amount specified".to_string()),
+                Err(e) => errors.push(format!("Invalid amount: {}", e)),
+            }
+        }
+
+        let Some((recipient_count, total)) = self.token_total_required(info) else {
+            return errors;
+        };
+        if recipient_count == 0 {
+            errors.push("No recipients found".to_string());
+            return errors;
+        }
+        if (total / U256::from(recipient_count)).is_zero() {
+            errors.push("Amount per recipient would be 0".to_string());
+        }
+
+        match state.token.position {
+            Some((balance, allowance)) => {
+                if balance < total {
+                    errors.push(format!(
+                        "Insufficient {}: need {} more",
+                        info.symbol,
+                        info.format_amount(total - balance)
+                    ));
+                }
+                if allowance < total {
+                    errors.push("Allowance too low, approve the disperse contract first".to_string());
+                }
+            }
+            None => errors.push("Fetch source balance first".to_string()),
+        }
+
+        match (state.source_balance, &state.current_fees) {
+            (Some(native_balance), Some(fees)) => {
+                let gas_limit = self.disperse_gas_limit(recipient_count);
+                let fee = fees.quote(state.gas_speed).max_cost(gas_limit);
+                if native_balance < fee {
+                    errors.push(format!(
+                        "Insufficient {} for gas: need ~{}",
+                        self.config.native_token(),
+                        utils::format_ether(fee)
+                    ));
+                }
+            }
+            (_, None) => errors.push("Waiting for gas price...".to_string()),
+            (None, _) => {}
+        }
+        if state.gas_estimate_job.is_some() || state.gas_plan.is_none() {
+            errors.push("Estimating gas limit...".to_string());
+        } else if errors.is_empty() {
+            let simulation_key = match (state.gas_estimate_key, self.token_total_required(info)) {
+                (Some(gas_key), Some((_, total))) => self.token_simulation_key(gas_key, total),
+                _ => None,
+            };
+            if let Some(error) = self.disperse_simulation_error(simulation_key) {
+                errors.push(error);
+            }
+        }
+
+        errors
+    }
+
+    fn render_token_execute_section(&mut self, ui: &mut egui::Ui, info: &TokenInfo) {
+        let is_equal = matches!(
+            bulk_disperse::parse_bulk_disperse_input_for_token(&self.bulk_disperse_state.recipients_input, self.config.chain_id, info),
+            Ok(BulkDisperseType::Equal(_) | BulkDisperseType::Weighted(_))
+        );
+        if is_equal {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(format!("Amount to Send ({}):", info.symbol)).strong());
+                ui.add(egui::TextEdit::singleline(&mut self.bulk_disperse_state.token.amount_input).desired_width(150.0));
+                if let Some((balance, _)) = self.bulk_disperse_state.token.position {
+                    if ui.button("Max").clicked() {
+                        self.bulk_disperse_state.token.amount_input = info.format_amount(balance);
+                    }
+                }
+            });
+            ui.label(RichText::new("Split evenly among all recipients").italics().size(11.0).color(self.theme.text_secondary));
+        }
+
+        // Gas is paid in the native currency
+        ui.horizontal(|ui| {
+            ui.label("Gas Speed:");
+            let speed = self.bulk_disperse_state.gas_speed;
+            ui.label(
+                RichText::new(format!("{} {:.1}x ({})", gas_speed_emoji(speed), speed, gas_speed_label(speed)))
+                    .color(self.theme.accent_green),
+            );
+            if let Some(fees) = &self.bulk_disperse_state.current_fees {
+                ui.label(RichText::new(fees.quote(speed).describe()).small().color(self.theme.text_secondary));
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Slow").small().color(self.theme.text_secondary));
+            ui.add(
+                egui::Slider::new(&mut self.bulk_disperse_state.gas_speed, 0.8..=2.5)
+                    .show_value(false)
+                    .step_by(0.1),
+            );
+            ui.label(RichText::new("Aggressive").small().color(self.theme.text_secondary));
+        });
+        if let Some(warning) = gas_speed_warning(self.bulk_disperse_state.gas_speed) {
+            ui.colored_label(self.theme.warning, warning);
+        }
+        if let (Some((count, _)), Some(fees)) = (self.token_total_required(info), self.bulk_disperse_state.current_fees.clone()) {
+            let gas_limit = self.disperse_gas_limit(count);
+            let fee_quote = fees.quote(self.bulk_disperse_state.gas_speed);
+            ui.horizontal(|ui| {
+                ui.label(format!("Gas Limit: {} units", gas_limit));
+                self.render_gas_estimate_source(ui);
+            });
+            ui.label(format!(
+                "Gas Fee: ~{} {} expected, {} max",
+                utils::format_ether(fee_quote.expected_cost(gas_limit)),
+                self.config.native_token(),
+                utils::format_ether(fee_quote.max_cost(gas_limit))
+            ));
+        }
+        ui.add_space(self.theme.spacing_sm);
+
+        let errors = self.validate_token_disperse_parameters(info);
+        if errors.is_empty() {
+            if let Some((count, total)) = self.token_total_required(info) {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("✓").color(self.theme.accent_green));
+                    ui.label(
+                        RichText::new(format!("Ready: {} {} to {} recipients", info.format_amount(total), info.symbol, count))
+                            .color(self.theme.accent_green),
+                    );
+                });
+            }
+        } else {
+            for error in &errors {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("⚠").color(self.theme.warning));
+                    ui.label(RichText::new(error).color(self.theme.warning));
+                });
+            }
+        }
+        ui.add_space(self.theme.spacing_md);
+
+        let can_proceed = errors.is_empty() && self.bulk_disperse_state.job.is_none();
+        let hover = if errors.is_empty() { "Ready to disperse".to_string() } else { errors.join("; ") };
+        if ui
+            .add_enabled(can_proceed, self.theme.button_warning(&format!("Initiate {} Disperse", info.symbol)))
+            .on_hover_text(hover)
+            .clicked()
+        {
+            self.execute_token_disperse(info.clone());
+        }
+        ui.add_space(self.theme.spacing_sm);
+
+        if let Some(status) = &self.bulk_disperse_state.status {
+            ui.label(status);
+        }
+    }
+
+    fn execute_token_disperse(&mut self, info: TokenInfo) {
+        let disperse_type = match bulk_disperse::parse_bulk_disperse_input_for_token(&self.bulk_disperse_state.recipients_input, self.config.chain_id, &info) {
+            Ok(disperse_type) => disperse_type,
+            Err(e) => {
+                self.notifications.push_back(NotificationEntry::new(format!("[XX] Failed to parse recipients: {}", e)));
+                return;
+            }
+        };
+        let Some((_, amount_to_send)) = self.token_total_required(&info) else {
+            self.notifications.push_back(NotificationEntry::new("[XX] Invalid amount"));
+            return;
+        };
+
+        let disperse_contract = Some(self.bulk_disperse_state.disperse_contract_address.trim().to_string());
+        let disperse_address = match bulk_disperse::resolve_disperse_address(self.config.chain_id, disperse_contract) {
+            Ok(address) => address,
+            Err(e) => {
+                self.notifications.push_back(NotificationEntry::new(format!("[XX] {}", e)));
+                return;
+            }
+        };
+
+        let config = self.config.clone();
+        let source_index = self.bulk_disperse_state.source_index as usize;
+        let gas_speed = self.bulk_disperse_state.gas_speed;
+        let gas_limit = self.bulk_disperse_state.gas_plan.as_ref().map(|plan| plan.total_gas_limit());
+        let gas_margin_percent = self.user_settings.gas_margin_percent;
+        let use_native_ledger = self.user_settings.use_native_ledger;
+
+        self.bulk_disperse_state.status = Some("Preparing transaction...".to_string());
+        let job = self.spawn_job(move || async move {
+            bulk_disperse::bulk_disperse_token(
+                config,
+                info,
+                disperse_type,
+                amount_to_send,
+                disperse_address,
+                Some(source_index),
+                bulk_disperse::DisperseSendOptions {
+                    gas_speed,
+                    gas_limit_override: gas_limit,
+                    gas_margin_percent,
+                    fees_override: None,
+                    use_native_ledger,
+                },
+            )
+            .await
+        });
+        self.bulk_disperse_state.job = Some(job);
+        // Balance and allowance change once the disperse is mined
+        self.bulk_disperse_state.token.position_key = None;
+        self.notifications.push_back(NotificationEntry::new("Token disperse initiated..."));
+    }
+
+    /// Poll token info, balance/allowance and approval jobs
+    pub(crate) fn poll_token_disperse_jobs(&mut self) {
+        let token: &mut TokenDisperseState = &mut self.bulk_disperse_state.token;
+
+        if let Some(job) = &mut token.info_job {
+            if let Some(res) = job.poll() {
+                match res {
+                    Ok(info) => {
+                        token.info = Some(info);
+                        token.error = None;
+                    }
+                    Err(e) => {
+                        token.info = None;
+                        token.error = Some(e.to_string());
+                    }
+                }
+                token.info_job = None;
+            }
+        }
+
+        if let Some(job) = &mut token.position_job {
+            if let Some(res) = job.poll() {
+                match res {
+                    Ok((balance, allowance, unsupported)) => {
+                        token.position = Some((balance, allowance));
+                        token.contract_unsupported = unsupported;
+                    }
+                    Err(e) => {
+                        token.position = None;
+                        token.contract_unsupported = None;
+                        self.notifications.push_back(NotificationEntry::new(format!(
+                            "[!!] Failed to read token balance: {}",
+                            e
+                        )));
+                    }
+                }
+                token.position_job = None;
+            }
+        }
+
+        if let Some(job) = &mut token.approve_job {
+            if let Some(res) = job.poll() {
+                match res {
+                    Ok(tx_hash) => {
+                        self.notifications.push_back(NotificationEntry::new(format!(
+                            "[OK] Token approval confirmed: {:?}",
+                            tx_hash
+                        )));
+                    }
+                    Err(e) => {
+                        self.notifications.push_back(NotificationEntry::new(format!("[XX] Approval failed: {}", e)));
+                    }
+                }
+                token.approve_job = None;
+                // Re-read the allowance
+                token.position_key = None;
+            }
+        }
+    }
+}
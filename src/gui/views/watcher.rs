@@ -0,0 +1,412 @@
+//! Balance watcher view implementation
+//!
+//! This module contains the balance watcher panel rendering including:
+//! - Watcher on/off switch and polling interval
+//! - Adding derived, address book or manual addresses to the watch list
+//! - Watch list with per-address thresholds and last known balances
+//! - Address book management
+
+use crate::user_settings::{AddressBookEntry, WatchedAddress};
+use crate::{balance_watcher, ens, ledger_dispatch, utils};
+use eframe::egui::{self, RichText};
+use ethers::types::Address;
+
+use super::super::app::GuiApp;
+use super::super::notifications::NotificationEntry;
+
+/// Renders the Balance Watcher view
+pub fn view_balance_watcher(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.render_section_header(ui, "[!]", "BALANCE WATCHER");
+    ui.add_space(app.theme.spacing_sm);
+
+    poll_derive_job(app);
+
+    ui.label(
+        RichText::new("Poll addresses in the background and get notified when their balances change.")
+            .color(app.theme.text_secondary),
+    );
+    ui.add_space(app.theme.spacing_md);
+
+    render_watcher_controls(app, ui);
+    ui.add_space(app.theme.spacing_lg);
+    render_add_watch_form(app, ui);
+    ui.add_space(app.theme.spacing_lg);
+    render_watch_list(app, ui);
+    ui.add_space(app.theme.spacing_lg);
+    render_address_book(app, ui);
+}
+
+fn save_settings(app: &mut GuiApp) {
+    if let Err(e) = app.user_settings.save() {
+        app.notifications
+            .push_back(NotificationEntry::new(format!("Failed to save settings: {}", e)));
+    }
+}
+
+fn poll_derive_job(app: &mut GuiApp) {
+    if let Some(job) = &mut app.watcher.derive_job {
+        if let Some(res) = job.poll() {
+            app.watcher.derive_job = None;
+            match res {
+                Ok((index, address)) => {
+                    app.watcher.form_address = format!("{:?}", address);
+                    app.watcher.form_from_ledger = Some(index);
+                    if app.watcher.form_label.trim().is_empty() {
+                        app.watcher.form_label = format!("Index {}", index);
+                    }
+                    app.watcher.form_error = None;
+                }
+                Err(e) => app.watcher.form_error = Some(format!("Ledger error: {}", e)),
+            }
+        }
+    }
+}
+
+fn render_watcher_controls(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("Watcher").size(16.0).strong().color(app.theme.text_primary));
+        ui.add_space(app.theme.spacing_sm);
+
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            changed |= ui
+                .checkbox(&mut app.user_settings.watcher_enabled, "Enable background watcher")
+                .changed();
+            ui.add_space(app.theme.spacing_md);
+            ui.label("Poll every");
+            changed |= ui
+                .add(egui::DragValue::new(&mut app.user_settings.watcher_interval_secs).clamp_range(10..=3600))
+                .changed();
+            ui.label("seconds");
+        });
+        if changed {
+            if !app.user_settings.watcher_enabled {
+                app.watcher.last_poll = None;
+            }
+            save_settings(app);
+        }
+
+        ui.add_space(app.theme.spacing_xs);
+        let status = if !app.user_settings.watcher_enabled {
+            "Stopped".to_string()
+        } else if app.watcher.job.is_some() {
+            "[..] Polling...".to_string()
+        } else if let Some(last) = app.watcher.last_poll {
+            format!("Last poll {}s ago", last.elapsed().as_secs())
+        } else {
+            "Waiting for first poll".to_string()
+        };
+        ui.label(RichText::new(status).small().color(app.theme.text_secondary));
+
+        for err in &app.watcher.last_errors {
+            ui.colored_label(app.theme.warning, format!("[!!] {}", err));
+        }
+    });
+}
+
+/// All selectable networks as (chain_id, label)
+fn network_options(app: &GuiApp) -> Vec<(u64, String)> {
+    crate::config::NETWORKS
+        .iter()
+        .map(|n| (n.chain_id, n.label.to_string()))
+        .chain(
+            app.user_settings
+                .custom_networks
+                .iter()
+                .map(|n| (n.chain_id, n.label.clone())),
+        )
+        .collect()
+}
+
+fn network_label(app: &GuiApp, chain_id: u64) -> String {
+    network_options(app)
+        .into_iter()
+        .find(|(id, _)| *id == chain_id)
+        .map(|(_, label)| label)
+        .unwrap_or_else(|| format!("Chain {}", chain_id))
+}
+
+fn render_add_watch_form(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[+] Watch an Address").size(16.0).strong().color(app.theme.text_primary));
+        ui.add_space(app.theme.spacing_sm);
+
+        egui::Grid::new("watch_form_grid")
+            .num_columns(2)
+            .spacing([app.theme.spacing_md, app.theme.spacing_sm])
+            .show(ui, |ui| {
+                ui.label("Label:");
+                ui.add(egui::TextEdit::singleline(&mut app.watcher.form_label).desired_width(200.0).hint_text("e.g., Vault"));
+                ui.end_row();
+
+                ui.label("Address:");
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(egui::TextEdit::singleline(&mut app.watcher.form_address).desired_width(340.0).hint_text("0x..."))
+                        .changed()
+                    {
+                        app.watcher.form_from_ledger = None;
+                    }
+                    if !app.user_settings.address_book.is_empty() {
+                        egui::ComboBox::from_id_source("watch_form_address_book")
+                            .selected_text("Address book")
+                            .show_ui(ui, |ui| {
+                                for entry in &app.user_settings.address_book {
+                                    if ui.selectable_label(false, &entry.label).clicked() {
+                                        app.watcher.form_label = entry.label.clone();
+                                        app.watcher.form_address = entry.address.clone();
+                                        app.watcher.form_from_ledger = None;
+                                    }
+                                }
+                            });
+                    }
+                });
+                ui.end_row();
+
+                ui.label("Or derive:");
+                ui.horizontal(|ui| {
+                    ui.label("index");
+                    ui.add(egui::DragValue::new(&mut app.watcher.form_derivation_index).clamp_range(0..=100_000));
+                    let ledger_ready = app.ledger_status.is_usable();
+                    let deriving = app.watcher.derive_job.is_some();
+                    if ui
+                        .add_enabled(ledger_ready && !deriving, egui::Button::new(if deriving { "[..]" } else { "Fetch from Ledger" }))
+                        .on_disabled_hover_text("Connect and unlock your Ledger")
+                        .clicked()
+                    {
+                        let config = app.config.clone();
+                        let index = app.watcher.form_derivation_index;
+                        let use_native_ledger = app.user_settings.use_native_ledger;
+                        app.watcher.derive_job = Some(app.spawn_job(move || async move {
+                            let address = ledger_dispatch::get_ledger_address_with_retry_config(
+                                use_native_ledger,
+                                config.chain_id,
+                                index,
+                                Some(&config),
+                            )
+                            .await?;
+                            Ok((index, address))
+                        }));
+                    }
+                });
+                ui.end_row();
+
+                ui.label("Threshold:");
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut app.watcher.form_threshold).desired_width(100.0).hint_text("any change"));
+                    ui.label(RichText::new("(minimum change in native units to notify)").small().color(app.theme.text_secondary));
+                });
+                ui.end_row();
+            });
+
+        ui.add_space(app.theme.spacing_xs);
+        ui.label("Networks:");
+        let options = network_options(app);
+        ui.horizontal_wrapped(|ui| {
+            for (chain_id, label) in options {
+                let mut selected = app.watcher.form_chain_ids.contains(&chain_id);
+                if ui.checkbox(&mut selected, label).changed() {
+                    if selected {
+                        app.watcher.form_chain_ids.push(chain_id);
+                    } else {
+                        app.watcher.form_chain_ids.retain(|id| *id != chain_id);
+                    }
+                }
+            }
+        });
+
+        if let Some(err) = &app.watcher.form_error {
+            ui.colored_label(app.theme.error, err);
+        }
+
+        ui.add_space(app.theme.spacing_sm);
+        if ui.add(app.theme.button_primary("Add to Watch List")).clicked() {
+            match build_watched_address(app) {
+                Ok(watched) => {
+                    let label = watched.label.clone();
+                    if app.user_settings.add_watched_address(watched) {
+                        save_settings(app);
+                        app.notifications
+                            .push_back(NotificationEntry::new(format!("[OK] Watching {}", label)));
+                        app.watcher.form_label.clear();
+                        app.watcher.form_address.clear();
+                        app.watcher.form_threshold.clear();
+                        app.watcher.form_from_ledger = None;
+                        app.watcher.form_error = None;
+                        // Poll the new address on the next frame
+                        app.watcher.last_poll = None;
+                    } else {
+                        app.watcher.form_error = Some("This address is already being watched.".to_string());
+                    }
+                }
+                Err(e) => app.watcher.form_error = Some(e),
+            }
+        }
+    });
+}
+
+fn build_watched_address(app: &GuiApp) -> Result<WatchedAddress, String> {
+    let address_input = app.watcher.form_address.trim();
+    let address: Address = address_input
+        .parse()
+        .map_err(|_| "Enter a valid 0x address or fetch one from the Ledger.".to_string())?;
+    if app.watcher.form_chain_ids.is_empty() {
+        return Err("Select at least one network.".to_string());
+    }
+
+    let label = match app.watcher.form_label.trim() {
+        "" => format!("{:?}", address),
+        label => label.to_string(),
+    };
+    let watched = WatchedAddress {
+        label,
+        address: format!("{:?}", address),
+        chain_ids: app.watcher.form_chain_ids.clone(),
+        threshold: app.watcher.form_threshold.trim().to_string(),
+        derivation_index: app.watcher.form_from_ledger,
+    };
+    balance_watcher::parse_threshold(&watched).map_err(|e| format!("Threshold: {}", e))?;
+    Ok(watched)
+}
+
+fn render_watch_list(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(
+            RichText::new(format!("[=] Watch List ({})", app.user_settings.watched_addresses.len()))
+                .size(16.0)
+                .strong()
+                .color(app.theme.text_primary),
+        );
+        ui.add_space(app.theme.spacing_sm);
+
+        if app.user_settings.watched_addresses.is_empty() {
+            ui.label(RichText::new("No addresses watched yet.").italics().color(app.theme.text_secondary));
+            return;
+        }
+
+        let mut remove: Option<String> = None;
+        egui::Grid::new("watch_list_grid")
+            .num_columns(5)
+            .spacing([app.theme.spacing_md, app.theme.spacing_xs])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Label").strong());
+                ui.label(RichText::new("Address").strong());
+                ui.label(RichText::new("Threshold").strong());
+                ui.label(RichText::new("Balances").strong());
+                ui.label(RichText::new("Actions").strong());
+                ui.end_row();
+
+                for watched in &app.user_settings.watched_addresses {
+                    let label = match watched.derivation_index {
+                        Some(index) => format!("{} (#{})", watched.label, index),
+                        None => watched.label.clone(),
+                    };
+                    ui.label(label);
+                    ui.label(RichText::new(&watched.address).monospace().size(11.0));
+                    ui.label(if watched.threshold.is_empty() { "any" } else { watched.threshold.as_str() });
+
+                    ui.vertical(|ui| {
+                        let address = watched.address.parse::<Address>().ok();
+                        for chain_id in &watched.chain_ids {
+                            let balance = address
+                                .and_then(|a| app.watcher.latest.get(&(*chain_id, a)))
+                                .map(|b| utils::format_ether(*b))
+                                .unwrap_or_else(|| "-".to_string());
+                            ui.label(
+                                RichText::new(format!("{}: {}", network_label(app, *chain_id), balance))
+                                    .small(),
+                            );
+                        }
+                    });
+
+                    if ui.add(app.theme.button_small("Remove")).clicked() {
+                        remove = Some(watched.address.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(address) = remove {
+            app.user_settings.remove_watched_address(&address);
+            save_settings(app);
+        }
+    });
+}
+
+fn render_address_book(app: &mut GuiApp, ui: &mut egui::Ui) {
+    app.theme.frame_panel().show(ui, |ui| {
+        ui.label(RichText::new("[#] Address Book").size(16.0).strong().color(app.theme.text_primary));
+        ui.add_space(app.theme.spacing_sm);
+
+        ui.horizontal(|ui| {
+            ui.label("Label:");
+            ui.add(egui::TextEdit::singleline(&mut app.watcher.book_label).desired_width(140.0));
+            ui.label("Address:");
+            ui.add(egui::TextEdit::singleline(&mut app.watcher.book_address).desired_width(340.0).hint_text("0x... or name.eth"));
+            if ui.add(app.theme.button_small("Save")).clicked() {
+                match ens::parse_address_or_name(&app.watcher.book_address) {
+                    Ok(address) if !app.watcher.book_label.trim().is_empty() => {
+                        let entry = AddressBookEntry {
+                            label: app.watcher.book_label.trim().to_string(),
+                            address: format!("{:?}", address),
+                        };
+                        if app.user_settings.add_address_book_entry(entry) {
+                            save_settings(app);
+                            app.watcher.book_label.clear();
+                            app.watcher.book_address.clear();
+                            app.watcher.book_error = None;
+                        } else {
+                            app.watcher.book_error = Some("This address is already in the address book.".to_string());
+                        }
+                    }
+                    Ok(_) => app.watcher.book_error = Some("A label is required.".to_string()),
+                    Err(e) => app.watcher.book_error = Some(format!("{}.", e)),
+                }
+            }
+        });
+
+        // Saving stores the resolved address, so show it before the entry is saved
+        let book_name = ens::normalize(&app.watcher.book_address);
+        if ens::is_ens_name(&book_name) {
+            match (app.ens.failed.get(&book_name), ens::resolved_address(&book_name)) {
+                (Some(reason), _) => ui.colored_label(app.theme.error, format!("[XX] {} {}", book_name, reason)),
+                (None, Some(address)) => ui.label(
+                    RichText::new(format!("{} → {:?}", book_name, address)).monospace().color(app.theme.text_secondary),
+                ),
+                (None, None) => ui.label(RichText::new(format!("Resolving {}...", book_name)).color(app.theme.text_secondary)),
+            };
+        }
+
+        if let Some(err) = &app.watcher.book_error {
+            ui.colored_label(app.theme.error, err);
+        }
+
+        ui.add_space(app.theme.spacing_xs);
+
+        let mut remove: Option<String> = None;
+        let mut watch: Option<AddressBookEntry> = None;
+        for entry in &app.user_settings.address_book {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(&entry.label).strong());
+                ui.label(RichText::new(&entry.address).monospace().size(11.0));
+                if ui.small_button("Watch").on_hover_text("Fill the watch form with this entry").clicked() {
+                    watch = Some(entry.clone());
+                }
+                if ui.small_button("[X]").on_hover_text("Remove from address book").clicked() {
+                    remove = Some(entry.address.clone());
+                }
+            });
+        }
+
+        if let Some(entry) = watch {
+            app.watcher.form_label = entry.label;
+            app.watcher.form_address = entry.address;
+            app.watcher.form_from_ledger = None;
+        }
+        if let Some(address) = remove {
+            app.user_settings.remove_address_book_entry(&address);
+            save_settings(app);
+        }
+    });
+}
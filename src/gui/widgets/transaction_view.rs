@@ -28,6 +28,8 @@ pub struct TransactionView {
     pending_notifications: VecDeque<String>,
     /// Track which transaction IDs we've already notified about to avoid duplicates
     notified_tx_ids: std::collections::HashSet<usize>,
+    /// Show ENS primary names next to destination addresses
+    show_ens_names: bool,
 }
 
 struct TransactionJob {
@@ -48,6 +50,7 @@ impl TransactionView {
             chain_id,
             pending_notifications: VecDeque::new(),
             notified_tx_ids: std::collections::HashSet::new(),
+            show_ens_names: false,
         }
     }
 
@@ -64,6 +67,7 @@ impl TransactionView {
             chain_id,
             pending_notifications: VecDeque::new(),
             notified_tx_ids: std::collections::HashSet::new(),
+            show_ens_names: false,
         }
     }
 
@@ -77,6 +81,11 @@ impl TransactionView {
         self.chain_id = chain_id;
     }
 
+    /// Show or hide ENS primary names of destinations
+    pub fn set_show_ens_names(&mut self, show: bool) {
+        self.show_ens_names = show;
+    }
+
     /// Render the transaction view
     /// 
     /// # Arguments
@@ -364,6 +373,12 @@ impl TransactionView {
                         if full_addr.len() >= 10 {
                             let short_addr = format!("{}...{}", &full_addr[..6], &full_addr[full_addr.len().saturating_sub(4)..]);
                             ui.label(short_addr);
+
+                            if self.show_ens_names {
+                                if let Some(name) = full_addr.parse().ok().and_then(crate::ens::reverse_name) {
+                                    ui.label(egui::RichText::new(name).strong());
+                                }
+                            }
                             
                             // Copy button for full address
                             if ui.add(egui::Button::new("📋").small())
@@ -4,6 +4,7 @@
 
 use crate::config::DerivationMode;
 use crate::ethers_ledger_signer;
+use crate::fee_oracle::TxFees;
 use crate::ledger_ops::{self, LedgerStatus};
 use crate::native_ledger;
 use crate::user_settings::UserSettings;
@@ -141,7 +142,7 @@ pub async fn sign_and_send_transaction(
     to: Address,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     chain_id: u64,
     derivation_mode: DerivationMode,
@@ -158,7 +159,7 @@ pub async fn sign_and_send_transaction(
             to,
             value,
             gas_limit,
-            gas_price,
+            fees,
             nonce,
             chain_id,
             derivation_mode,
@@ -175,7 +176,7 @@ pub async fn sign_and_send_transaction(
             to,
             value,
             gas_limit,
-            gas_price,
+            fees,
             nonce,
             chain_id,
             derivation_mode,
@@ -196,7 +197,7 @@ pub async fn sign_and_send_contract_call(
     calldata: Vec<u8>,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     chain_id: u64,
     derivation_mode: DerivationMode,
@@ -214,7 +215,7 @@ pub async fn sign_and_send_contract_call(
             calldata,
             value,
             gas_limit,
-            gas_price,
+            fees,
             nonce,
             chain_id,
             derivation_mode,
@@ -232,7 +233,7 @@ pub async fn sign_and_send_contract_call(
             calldata,
             value,
             gas_limit,
-            gas_price,
+            fees,
             nonce,
             chain_id,
             derivation_mode,
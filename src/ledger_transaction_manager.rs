@@ -1,6 +1,7 @@
 //! Ledger transaction manager for reliable hardware wallet operations.
 //! Provides nonce management, retry logic, and transaction confirmation tracking.
 
+use crate::fee_oracle::TxFees;
 use ethers::prelude::*;
 use ethers::providers::{Http, Provider};
 use anyhow::{anyhow, Result};
@@ -61,8 +62,10 @@ pub enum TransactionResult {
 pub struct PendingTransaction {
     pub to: Address,
     pub value: U256,
+    /// Contract calldata; empty for plain value transfers
+    pub data: Vec<u8>,
     pub gas_limit: u64,
-    pub gas_price: U256,
+    pub fees: TxFees,
     pub operation_name: String,
 }
 
@@ -147,6 +150,18 @@ impl LedgerTransactionManager {
         let mut attempt = 0;
         let mut delay = self.config.retry_delay_ms;
 
+        // Simulate right before signing; chain state may have changed since the queue was prepared
+        match crate::simulation::simulate_call(&self.provider, self.source_address, tx.to, tx.data.clone(), tx.value).await {
+            Ok(Some(reason)) => {
+                return Ok(TransactionResult::Failed {
+                    error: format!("Simulation failed: {}", reason),
+                    retryable: true,
+                });
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Could not simulate {}: {}", tx.operation_name, e),
+        }
+
         loop {
             attempt += 1;
             let nonce = self.get_next_nonce().await?;
@@ -162,7 +177,16 @@ impl LedgerTransactionManager {
 
                     if self.config.wait_for_confirmation {
                         match self.wait_for_confirmation(tx_hash).await {
-                            Ok((block_number, gas_used)) => {
+                            Ok((block_number, _, false)) => {
+                                return Ok(TransactionResult::Failed {
+                                    error: format!(
+                                        "Transaction {:?} reverted in block {:?}",
+                                        tx_hash, block_number
+                                    ),
+                                    retryable: true,
+                                });
+                            }
+                            Ok((block_number, gas_used, true)) => {
                                 return Ok(TransactionResult::Success {
                                     tx_hash,
                                     block_number,
@@ -187,6 +211,9 @@ impl LedgerTransactionManager {
                     }
                 }
                 Err(e) => {
+                    // Nothing was broadcast, so hand the nonce back to keep nonces consecutive
+                    self.release_nonce(nonce).await;
+
                     let error_str = e.to_string();
                     let retryable = self.is_retryable_error(&error_str);
 
@@ -215,13 +242,42 @@ impl LedgerTransactionManager {
         }
     }
 
+    /// Return a nonce that was taken but never broadcast, if no later nonce was handed out
+    async fn release_nonce(&self, nonce: u64) {
+        let mut nonce_guard = self.current_nonce.lock().await;
+        if *nonce_guard == Some(nonce + 1) {
+            *nonce_guard = Some(nonce);
+        }
+    }
+
     /// Internal method to send a transaction using the configured Ledger backend
     async fn send_transaction_internal(
         &self,
         tx: &PendingTransaction,
         nonce: u64,
     ) -> Result<TxHash> {
-        use crate::ledger_dispatch::sign_and_send_transaction;
+        use crate::ledger_dispatch::{sign_and_send_contract_call, sign_and_send_transaction};
+
+        if !tx.data.is_empty() {
+            return sign_and_send_contract_call(
+                self.config.use_native_ledger,
+                self.provider.clone(),
+                &self.rpc_url,
+                self.source_index,
+                tx.to,
+                tx.data.clone(),
+                tx.value,
+                tx.gas_limit,
+                tx.fees,
+                nonce,
+                self.chain_id,
+                self.config.derivation_mode,
+                self.config.custom_account,
+                self.config.custom_address_index,
+                self.config.coin_type,
+            )
+            .await;
+        }
 
         // Use the dispatch layer to route to the appropriate backend
         sign_and_send_transaction(
@@ -232,7 +288,7 @@ impl LedgerTransactionManager {
             tx.to,
             tx.value,
             tx.gas_limit,
-            tx.gas_price,
+            tx.fees,
             nonce,
             self.chain_id,
             self.config.derivation_mode,
@@ -243,8 +299,8 @@ impl LedgerTransactionManager {
         .await
     }
 
-    /// Wait for transaction confirmation
-    async fn wait_for_confirmation(&self, tx_hash: TxHash) -> Result<(Option<u64>, u64)> {
+    /// Wait for transaction confirmation, returning block, gas used and whether it succeeded
+    async fn wait_for_confirmation(&self, tx_hash: TxHash) -> Result<(Option<u64>, u64, bool)> {
         let mut attempts = 0;
         let max_attempts = (self.config.confirmation_timeout_secs * 2) as usize;
         
@@ -253,6 +309,7 @@ impl LedgerTransactionManager {
                 return Ok((
                     receipt.block_number.map(|n| n.as_u64()),
                     receipt.gas_used.map(|g| g.as_u64()).unwrap_or(0),
+                    receipt.status != Some(U64::zero()),
                 ));
             }
             
@@ -1,15 +1,36 @@
 pub mod balance;
+pub mod balance_watcher;
+pub mod beaug_deploy;
 pub mod bulk_disperse;
 pub mod config;
+pub mod contract_admin;
+pub mod dispersal_history;
+pub mod dispersal_proof;
 pub mod disperse;
+pub mod disperse_batch;
+pub mod disperse_protocol;
+pub mod ens;
+pub mod erc20;
 pub mod ethers_ledger_signer;
+pub mod fee_oracle;
 pub mod gui;
 pub mod ledger_dispatch;
 pub mod ledger_lock;
 pub mod ledger_ops;
 pub mod ledger_transaction_manager;
+pub mod multi_source_disperse;
 pub mod native_ledger;
 pub mod operation_log;
+pub mod payment_uri;
+pub mod payroll;
+pub mod price_feed;
+pub mod recipient_check;
+pub mod recipient_input;
+pub mod registry;
+pub mod schedule;
+pub mod scan_checkpoint;
+pub mod scan_history;
+pub mod simulation;
 pub mod split_operations;
 pub mod transaction_queue;
 pub mod types;
@@ -0,0 +1,389 @@
+//! Bulk disperse funded from several derived addresses.
+//! When no single address holds the whole amount plus gas, the recipient list is split
+//! into consecutive shares, one per funded source from a scan, so each source covers its
+//! recipients and its own gas. Each share is sent as its own disperse transaction.
+
+use crate::balance::BalanceScanRecord;
+use crate::bulk_disperse::{self, BulkDisperseType};
+use crate::config::Config;
+use crate::disperse::{self, MAX_DISPERSE_GAS_LIMIT};
+use crate::disperse_protocol;
+use crate::fee_oracle::TxFees;
+use crate::{fee_oracle, operation_log, simulation, utils};
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+use tracing::info;
+
+/// Recipients paid by one source address in one disperse transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceShare {
+    pub index: u32,
+    pub address: Address,
+    pub derivation_path: String,
+    pub balance: U256,
+    pub recipients: Vec<Address>,
+    pub amounts: Vec<U256>,
+    pub gas_limit: u64,
+}
+
+impl SourceShare {
+    /// Value sent by this source (the sum of its amounts)
+    pub fn value(&self) -> U256 {
+        self.amounts.iter().fold(U256::zero(), |acc, x| acc + *x)
+    }
+
+    /// Value plus the maximum gas fee at `gas_price`
+    pub fn total_cost(&self, gas_price: U256) -> U256 {
+        self.value() + gas_price * U256::from(self.gas_limit)
+    }
+}
+
+/// A disperse split across several sources. Every share is sized against, and signed with, `fees`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSourcePlan {
+    pub fees: TxFees,
+    pub shares: Vec<SourceShare>,
+}
+
+impl MultiSourcePlan {
+    /// Max fee per gas the shares were sized against
+    pub fn gas_price(&self) -> U256 {
+        self.fees.max_fee_per_gas
+    }
+
+    pub fn total_value(&self) -> U256 {
+        self.shares.iter().fold(U256::zero(), |acc, s| acc + s.value())
+    }
+
+    pub fn total_gas_cost(&self) -> U256 {
+        self.shares
+            .iter()
+            .fold(U256::zero(), |acc, s| acc + self.gas_price() * U256::from(s.gas_limit))
+    }
+
+    pub fn recipient_count(&self) -> usize {
+        self.shares.iter().map(|s| s.recipients.len()).sum()
+    }
+}
+
+/// Split recipients, in order, into consecutive shares paid by `sources`.
+/// Sources are used largest balance first; a share grows while its source can pay the
+/// amounts plus `gas_price * gas_for(n)` and `gas_for(n)` stays under `max_gas`.
+/// A recipient is never split across sources.
+pub fn partition_recipients(
+    sources: &[BalanceScanRecord],
+    recipients: &[Address],
+    amounts: &[U256],
+    gas_price: U256,
+    max_gas: u64,
+    gas_for: impl Fn(usize) -> u64,
+) -> Result<Vec<SourceShare>> {
+    if recipients.is_empty() {
+        return Err(anyhow!("No recipients specified"));
+    }
+    if recipients.len() != amounts.len() {
+        return Err(anyhow!("{} recipients but {} amounts", recipients.len(), amounts.len()));
+    }
+
+    let mut sources: Vec<&BalanceScanRecord> = sources.iter().filter(|s| !s.balance.is_zero()).collect();
+    sources.sort_by(|a, b| b.balance.cmp(&a.balance).then(a.index.cmp(&b.index)));
+
+    let mut shares = Vec::new();
+    let mut next = 0;
+    for source in sources {
+        if next == recipients.len() {
+            break;
+        }
+        let mut value = U256::zero();
+        let mut end = next;
+        while end < recipients.len() {
+            let count = end - next + 1;
+            let gas = gas_for(count);
+            if count > 1 && gas >= max_gas {
+                break;
+            }
+            if value + amounts[end] + gas_price * U256::from(gas) > source.balance {
+                break;
+            }
+            value += amounts[end];
+            end += 1;
+        }
+        if end == next {
+            // Sources are sorted, so no later source can pay this recipient either
+            return Err(anyhow!(
+                "Recipient {} ({:?}, {}) cannot be paid by any single source; the largest remaining balance is {}",
+                next + 1,
+                recipients[next],
+                utils::format_ether(amounts[next]),
+                utils::format_ether(source.balance)
+            ));
+        }
+        shares.push(SourceShare {
+            index: source.index,
+            address: source.address,
+            derivation_path: source.derivation_path.clone(),
+            balance: source.balance,
+            recipients: recipients[next..end].to_vec(),
+            amounts: amounts[next..end].to_vec(),
+            gas_limit: gas_for(end - next),
+        });
+        next = end;
+    }
+
+    if next < recipients.len() {
+        let unpaid = amounts[next..].iter().fold(U256::zero(), |acc, x| acc + *x);
+        return Err(anyhow!(
+            "The selected sources cannot cover {} of {} recipients ({} plus gas); add more funded sources",
+            recipients.len() - next,
+            recipients.len(),
+            utils::format_ether(unpaid)
+        ));
+    }
+    Ok(shares)
+}
+
+/// A disperse to plan across several sources: the recipients, the funded sources from a
+/// scan, and how the shares are priced
+#[derive(Debug, Clone)]
+pub struct MultiSourceRequest {
+    pub disperse_type: BulkDisperseType,
+    pub amount_to_send: U256,
+    pub disperse_address_override: Option<String>,
+    pub sources: Vec<BalanceScanRecord>,
+    pub tip_amount: Option<U256>,
+    pub tip_recipient: Option<Address>,
+    pub gas_speed: f32,
+    pub gas_margin_percent: u32,
+}
+
+/// Plan a disperse across the request's sources: refresh their balances, partition the recipients
+/// (tip included) using the recipient-count gas heuristic, then tighten each share's gas
+/// limit with `eth_estimateGas` from its own source and simulate it. Nothing is signed.
+pub async fn plan_multi_source_disperse(config: Config, request: MultiSourceRequest) -> Result<MultiSourcePlan> {
+    let MultiSourceRequest {
+        disperse_type,
+        amount_to_send,
+        disperse_address_override,
+        sources,
+        tip_amount,
+        tip_recipient,
+        gas_speed,
+        gas_margin_percent,
+    } = request;
+    if sources.is_empty() {
+        return Err(anyhow!("Select at least one funded source"));
+    }
+    let provider = config.get_provider().await?;
+    let (disperse_address, protocol) = disperse_protocol::resolve_native_disperse(
+        &provider,
+        bulk_disperse::resolve_disperse_address(config.chain_id, disperse_address_override)?,
+    )
+    .await?;
+
+    // Scan balances may be stale; plan against the current ones
+    let mut sources = sources;
+    for source in &mut sources {
+        source.balance = provider.get_balance(source.address, None).await?;
+    }
+
+    let (recipients, amounts, _) = bulk_disperse::disperse_recipients(disperse_type, amount_to_send, tip_amount, tip_recipient)?;
+    // Plan against the max fee per gas each source signs with
+    let fees = fee_oracle::quote_fees(&provider, config.chain_id, gas_speed).await?.fees;
+    let gas_price = fees.max_fee_per_gas;
+    let mut shares = partition_recipients(
+        &sources,
+        &recipients,
+        &amounts,
+        gas_price,
+        MAX_DISPERSE_GAS_LIMIT,
+        disperse::heuristic_disperse_gas_limit,
+    )?;
+
+    for share in &mut shares {
+        let calldata = protocol.native_calldata(&share.recipients, &share.amounts)?;
+        let value = share.value();
+        if let Some(reason) = simulation::simulate_call(&provider, share.address, disperse_address, calldata.clone(), value).await? {
+            return Err(anyhow!("Simulation failed for source index {}: {}", share.index, reason));
+        }
+        let estimate = disperse::estimate_disperse_gas(
+            &provider,
+            disperse_address,
+            share.address,
+            calldata,
+            value,
+            share.recipients.len(),
+            gas_margin_percent,
+        )
+        .await;
+        if !estimate.is_heuristic() {
+            share.gas_limit = estimate.gas_limit;
+        }
+        if share.total_cost(gas_price) > share.balance {
+            return Err(anyhow!(
+                "Source index {} has {} but its share needs {}; re-plan with more sources",
+                share.index,
+                utils::format_ether(share.balance),
+                utils::format_ether(share.total_cost(gas_price))
+            ));
+        }
+    }
+
+    info!(
+        "Multi-source disperse of {} recipients planned across {} sources",
+        recipients.len(),
+        shares.len()
+    );
+    Ok(MultiSourcePlan { fees, shares })
+}
+
+/// Send one disperse transaction per share, in order, each signed by its own source with
+/// the planned fees. Every source's balance is re-checked before the first one is sent;
+/// after that it stops at the first failure, and the error names the sources already sent.
+pub async fn execute_multi_source_disperse(
+    config: Config,
+    plan: MultiSourcePlan,
+    disperse_address_override: Option<String>,
+    gas_margin_percent: u32,
+    use_native_ledger: bool,
+) -> Result<()> {
+    // A source that can no longer pay must fail before any share is broadcast
+    let provider = config.get_provider().await?;
+    for share in &plan.shares {
+        let balance = provider.get_balance(share.address, None).await?;
+        if share.total_cost(plan.gas_price()) > balance {
+            return Err(anyhow!(
+                "Source index {} now has {} but its share needs {}; nothing was sent, re-plan",
+                share.index,
+                utils::format_ether(balance),
+                utils::format_ether(share.total_cost(plan.gas_price()))
+            ));
+        }
+    }
+
+    let share_count = plan.shares.len();
+    let mut sent = Vec::new();
+    for (idx, share) in plan.shares.iter().enumerate() {
+        info!(
+            "Multi-source disperse {}/{}: {} to {} recipients from index {}",
+            idx + 1,
+            share_count,
+            utils::format_ether(share.value()),
+            share.recipients.len(),
+            share.index
+        );
+        let recipients = share.recipients.iter().copied().zip(share.amounts.iter().copied()).collect();
+        let result = bulk_disperse::bulk_disperse(
+            config.clone(),
+            BulkDisperseType::Mixed(recipients),
+            share.value(),
+            disperse_address_override.clone(),
+            Some(share.index as usize),
+            None,
+            None,
+            bulk_disperse::DisperseSendOptions {
+                // Ignored: the planned fees are signed, so every share costs what was checked
+                gas_speed: 1.0,
+                gas_limit_override: Some(share.gas_limit),
+                gas_margin_percent,
+                fees_override: Some(plan.fees),
+                use_native_ledger,
+            },
+        )
+        .await;
+        if let Err(e) = result {
+            let done = if sent.is_empty() {
+                "no source has sent yet".to_string()
+            } else {
+                format!("already sent from {}", sent.join(", "))
+            };
+            return Err(anyhow!("Source {}/{} (index {}) failed ({}): {}", idx + 1, share_count, share.index, done, e));
+        }
+        sent.push(share.derivation_path.clone());
+    }
+
+    let lines = plan
+        .shares
+        .iter()
+        .map(|s| format!("  {} → {:?}: {} to {} recipients", s.derivation_path, s.address, utils::format_ether(s.value()), s.recipients.len()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    operation_log::append_log(
+        "Beaug Bulk Disperse",
+        config.chain_id,
+        format!(
+            "Multi-source disperse completed: {} recipients from {} sources\nTotal distributed: {} {}\nSources:\n{}",
+            plan.recipient_count(),
+            share_count,
+            utils::format_ether(plan.total_value()),
+            config.native_token(),
+            lines
+        ),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance::AddressActivity;
+
+    fn source(index: u32, balance: u64) -> BalanceScanRecord {
+        BalanceScanRecord {
+            index,
+            address: Address::from_low_u64_be(1000 + index as u64),
+            balance: U256::from(balance),
+            nonce: 0,
+            activity: AddressActivity::classify(U256::from(balance), 0, false),
+            derivation_path: format!("m/44'/60'/0'/0/{}", index),
+        }
+    }
+
+    fn recipients(count: u64) -> Vec<Address> {
+        (1..=count).map(Address::from_low_u64_be).collect()
+    }
+
+    // ==================== Partition tests ====================
+
+    #[test]
+    fn test_partition_largest_source_first() {
+        // Gas: 10 + n per share at gas price 1
+        let sources = vec![source(0, 40), source(1, 100)];
+        let shares = partition_recipients(&sources, &recipients(5), &[U256::from(20u64); 5], U256::one(), 1_000, |n| 10 + n as u64).unwrap();
+
+        assert_eq!(shares.len(), 2);
+        // Index 1 pays 4 recipients: 80 + 14 gas <= 100
+        assert_eq!(shares[0].index, 1);
+        assert_eq!(shares[0].recipients, recipients(5)[..4].to_vec());
+        assert_eq!(shares[0].gas_limit, 14);
+        // Index 0 pays the last one: 20 + 11 gas <= 40
+        assert_eq!(shares[1].index, 0);
+        assert_eq!(shares[1].recipients, vec![Address::from_low_u64_be(5)]);
+        assert!(shares.iter().all(|s| s.total_cost(U256::one()) <= s.balance));
+    }
+
+    #[test]
+    fn test_partition_respects_max_gas() {
+        let sources = vec![source(0, 1_000), source(1, 1_000)];
+        let shares = partition_recipients(&sources, &recipients(4), &[U256::one(); 4], U256::one(), 13, |n| 10 + n as u64).unwrap();
+        assert_eq!(shares.iter().map(|s| s.recipients.len()).collect::<Vec<_>>(), vec![2, 2]);
+    }
+
+    #[test]
+    fn test_partition_shortfall_errors() {
+        let sources = vec![source(0, 50), source(1, 50)];
+        // Each source pays one 30 recipient plus gas; the third is left over
+        assert!(partition_recipients(&sources, &recipients(3), &[U256::from(30u64); 3], U256::one(), 1_000, |n| 10 + n as u64).is_err());
+        // No source can pay a single 60 recipient
+        assert!(partition_recipients(&sources, &recipients(1), &[U256::from(60u64)], U256::one(), 1_000, |_| 1).is_err());
+    }
+
+    #[test]
+    fn test_plan_totals() {
+        let sources = vec![source(0, 100), source(1, 100)];
+        let shares = partition_recipients(&sources, &recipients(6), &[U256::from(25u64); 6], U256::one(), 1_000, |n| n as u64).unwrap();
+        let plan = MultiSourcePlan { fees: TxFees::from_gas_price(U256::from(2u64)), shares };
+        assert_eq!(plan.recipient_count(), 6);
+        assert_eq!(plan.total_value(), U256::from(150u64));
+        assert_eq!(plan.total_gas_cost(), U256::from(12u64));
+    }
+}
@@ -2,6 +2,7 @@
 //! Provides an alternative to the cast CLI method for Ledger interactions.
 
 use crate::config::{chain_supports_eip1559, DerivationMode};
+use crate::fee_oracle::TxFees;
 use crate::ledger_lock;
 use ethers::prelude::*;
 use ethers::signers::Ledger;
@@ -185,7 +186,7 @@ pub async fn sign_and_send_transaction_native(
     to: Address,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     chain_id: u64,
     derivation_mode: DerivationMode,
@@ -202,8 +203,8 @@ pub async fn sign_and_send_transaction_native(
             .to(to)
             .value(value)
             .gas(gas_limit)
-            .max_fee_per_gas(gas_price)
-            .max_priority_fee_per_gas(gas_price / 10) // 10% priority fee
+            .max_fee_per_gas(fees.max_fee_per_gas)
+            .max_priority_fee_per_gas(fees.max_priority_fee_per_gas)
             .nonce(nonce)
             .chain_id(chain_id);
         TypedTransaction::Eip1559(tx)
@@ -213,14 +214,14 @@ pub async fn sign_and_send_transaction_native(
             .to(to)
             .value(value)
             .gas(gas_limit)
-            .gas_price(gas_price)
+            .gas_price(fees.max_fee_per_gas)
             .nonce(nonce)
             .chain_id(chain_id);
         TypedTransaction::Legacy(tx)
     };
     
-    info!("Transaction built: to={:?}, value={}, gas_limit={}, gas_price={}, nonce={}", 
-          to, value, gas_limit, gas_price, nonce);
+    info!("Transaction built: to={:?}, value={}, gas_limit={}, max_fee={}, priority_fee={}, nonce={}", 
+          to, value, gas_limit, fees.max_fee_per_gas, fees.max_priority_fee_per_gas, nonce);
     
     // Retry loop for connection and signing (NOT for broadcast)
     for attempt in 1..=MAX_RETRY_ATTEMPTS {
@@ -290,7 +291,7 @@ pub async fn sign_and_send_contract_call_native(
     calldata: Vec<u8>,
     value: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     nonce: u64,
     chain_id: u64,
     derivation_mode: DerivationMode,
@@ -308,8 +309,8 @@ pub async fn sign_and_send_contract_call_native(
             .value(value)
             .data(calldata.clone())
             .gas(gas_limit)
-            .max_fee_per_gas(gas_price)
-            .max_priority_fee_per_gas(gas_price / 10)
+            .max_fee_per_gas(fees.max_fee_per_gas)
+            .max_priority_fee_per_gas(fees.max_priority_fee_per_gas)
             .nonce(nonce)
             .chain_id(chain_id);
         TypedTransaction::Eip1559(tx)
@@ -320,7 +321,7 @@ pub async fn sign_and_send_contract_call_native(
             .value(value)
             .data(calldata.clone())
             .gas(gas_limit)
-            .gas_price(gas_price)
+            .gas_price(fees.max_fee_per_gas)
             .nonce(nonce)
             .chain_id(chain_id);
         TypedTransaction::Legacy(tx)
@@ -11,7 +11,7 @@ const OPERATION_LOG_FILE: &str = "operation_log.txt";
 const OLD_LOG_FILE: &str = "operation_log.txt";
 
 /// Get the directory where app data is stored (same as settings)
-fn app_data_dir() -> PathBuf {
+pub(crate) fn app_data_dir() -> PathBuf {
     if let Some(config_dir) = dirs::config_dir() {
         let app_dir = config_dir.join("beaug");
         if !app_dir.exists() {
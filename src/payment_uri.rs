@@ -0,0 +1,211 @@
+//! Payment request URIs and chain-prefixed addresses.
+//! - EIP-681: `ethereum:[pay-]<address>[@chain_id][?value=<wei>]` for native payments, and
+//!   `ethereum:<token>[@chain_id]/transfer?address=<recipient>&uint256=<amount>` for ERC-20
+//! - EIP-3770: `<short name>:<address>`, e.g. `base:0x…`
+//!
+//! The chain named by either form is returned so callers can refuse requests meant for
+//! another network. Addresses may also be ENS names (see [`crate::ens`]).
+
+use crate::config;
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+
+/// URI scheme of EIP-681 payment requests
+pub const EIP681_SCHEME: &str = "ethereum:";
+
+/// What a payment request pays in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentAsset {
+    Native,
+    Token(Address),
+}
+
+/// An address column after removing its URI or chain prefix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentTarget {
+    /// Recipient as written: a hex address or an ENS name
+    pub recipient: String,
+    /// Chain named by the URI or short name, if any
+    pub chain_id: Option<u64>,
+    /// Asset of an EIP-681 request; `None` for plain and EIP-3770 addresses
+    pub asset: Option<PaymentAsset>,
+    /// Amount in base units, when the request carries one
+    pub amount: Option<U256>,
+}
+
+impl PaymentTarget {
+    fn plain(recipient: &str, chain_id: Option<u64>) -> Self {
+        Self {
+            recipient: recipient.to_string(),
+            chain_id,
+            asset: None,
+            amount: None,
+        }
+    }
+
+    /// Error if the request names a chain other than `chain_id`
+    pub fn check_chain(&self, chain_id: u64) -> Result<()> {
+        match self.chain_id {
+            Some(requested) if requested != chain_id => Err(anyhow!(
+                "{} is for {}, but {} is selected",
+                self.recipient,
+                chain_label(requested),
+                chain_label(chain_id)
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn chain_label(chain_id: u64) -> String {
+    match config::find_network_by_chain_id(chain_id) {
+        Some(network) => format!("{} (chain {})", network.label, chain_id),
+        None => format!("chain {}", chain_id),
+    }
+}
+
+/// Parse an address column: an EIP-681 URI, an EIP-3770 `short:address`, or a bare address
+pub fn parse_target(input: &str) -> Result<PaymentTarget> {
+    let input = input.trim();
+    if input.len() > EIP681_SCHEME.len() && input[..EIP681_SCHEME.len()].eq_ignore_ascii_case(EIP681_SCHEME) {
+        return parse_eip681(&input[EIP681_SCHEME.len()..]);
+    }
+    if let Some((prefix, address)) = input.split_once(':') {
+        let chain_id = config::find_chain_id_by_short_name(prefix)
+            .ok_or_else(|| anyhow!("Unknown chain short name '{}' in {}", prefix, input))?;
+        return Ok(PaymentTarget::plain(address, Some(chain_id)));
+    }
+    Ok(PaymentTarget::plain(input, None))
+}
+
+/// Recipient part of an address column, without parsing amounts (for ENS name discovery)
+pub fn recipient_text(input: &str) -> String {
+    parse_target(input).map(|target| target.recipient).unwrap_or_else(|_| input.trim().to_string())
+}
+
+fn parse_eip681(rest: &str) -> Result<PaymentTarget> {
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let (target_and_chain, function) = match path.split_once('/') {
+        Some((target, function)) => (target, Some(function)),
+        None => (path, None),
+    };
+    let (target, chain) = match target_and_chain.split_once('@') {
+        Some((target, chain)) => (target, Some(chain)),
+        None => (target_and_chain, None),
+    };
+    let target = target.strip_prefix("pay-").unwrap_or(target);
+    let chain_id = chain
+        .map(|chain| chain.parse::<u64>().map_err(|_| anyhow!("Invalid chain ID '{}' in payment request", chain)))
+        .transpose()?;
+    let params: Vec<(&str, &str)> = query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| param.split_once('=').unwrap_or((param, "")))
+        .collect();
+    let param = |key: &str| params.iter().find(|(k, _)| *k == key).map(|(_, v)| *v);
+
+    match function {
+        None => Ok(PaymentTarget {
+            recipient: target.to_string(),
+            chain_id,
+            asset: Some(PaymentAsset::Native),
+            amount: param("value").map(parse_number).transpose()?,
+        }),
+        Some("transfer") => {
+            let token: Address = target
+                .parse()
+                .map_err(|_| anyhow!("Invalid token address '{}' in transfer request", target))?;
+            let recipient = param("address").ok_or_else(|| anyhow!("Transfer request without an `address` parameter"))?;
+            Ok(PaymentTarget {
+                recipient: recipient.to_string(),
+                chain_id,
+                asset: Some(PaymentAsset::Token(token)),
+                amount: param("uint256").map(parse_number).transpose()?,
+            })
+        }
+        Some(function) => Err(anyhow!("Unsupported payment request function '{}'", function)),
+    }
+}
+
+/// EIP-681 number: an integer, optionally in scientific notation (`2.014e18`)
+fn parse_number(input: &str) -> Result<U256> {
+    let invalid = || anyhow!("Invalid amount '{}' in payment request", input);
+    let (mantissa, exponent) = match input.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.trim_start_matches('+').parse::<usize>().map_err(|_| invalid())?),
+        None => (input, 0),
+    };
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if whole.is_empty() || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > exponent || exponent > 77 {
+        return Err(invalid());
+    }
+    let digits = format!("{}{}{}", whole, fraction, "0".repeat(exponent - fraction.len()));
+    U256::from_dec_str(&digits).map_err(|_| invalid())
+}
+
+/// EIP-681 request for a native payment to `address` on `chain_id`
+pub fn receive_request_uri(address: Address, chain_id: u64, value: Option<U256>) -> String {
+    let mut uri = format!("{}{}@{}", EIP681_SCHEME, ethers::utils::to_checksum(&address, None), chain_id);
+    if let Some(value) = value {
+        uri.push_str(&format!("?value={}", value));
+    }
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0";
+
+    // ==================== EIP-681 tests ====================
+
+    #[test]
+    fn test_parse_native_request() {
+        let target = parse_target(&format!("ethereum:pay-{}@8453?value=2.014e18", ADDRESS)).unwrap();
+        assert_eq!(target.recipient, ADDRESS);
+        assert_eq!(target.chain_id, Some(8453));
+        assert_eq!(target.asset, Some(PaymentAsset::Native));
+        assert_eq!(target.amount, Some(U256::from(2_014_000_000_000_000_000u64)));
+
+        let no_value = parse_target(&format!("ethereum:{}", ADDRESS)).unwrap();
+        assert_eq!((no_value.chain_id, no_value.amount), (None, None));
+        assert!(parse_target(&format!("ethereum:{}?value=1.5", ADDRESS)).is_err());
+    }
+
+    #[test]
+    fn test_parse_transfer_request() {
+        let token = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+        let target = parse_target(&format!("ethereum:{}@1/transfer?address={}&uint256=1e6", token, ADDRESS)).unwrap();
+        assert_eq!(target.recipient, ADDRESS);
+        assert_eq!(target.asset, Some(PaymentAsset::Token(token.parse().unwrap())));
+        assert_eq!(target.amount, Some(U256::from(1_000_000u64)));
+        assert!(parse_target(&format!("ethereum:{}/approve?address={}", token, ADDRESS)).is_err());
+    }
+
+    #[test]
+    fn test_receive_request_round_trip() {
+        let address: Address = ADDRESS.parse().unwrap();
+        let uri = receive_request_uri(address, 10, Some(U256::exp10(17)));
+        assert_eq!(uri, format!("ethereum:{}@10?value=100000000000000000", ethers::utils::to_checksum(&address, None)));
+        let target = parse_target(&uri).unwrap();
+        assert_eq!(target.amount, Some(U256::exp10(17)));
+        assert!(target.check_chain(10).is_ok());
+        assert!(target.check_chain(8453).unwrap_err().to_string().contains("Optimism"));
+    }
+
+    // ==================== EIP-3770 tests ====================
+
+    #[test]
+    fn test_parse_chain_prefixed_address() {
+        let target = parse_target(&format!("base:{}", ADDRESS)).unwrap();
+        assert_eq!(target.recipient, ADDRESS);
+        assert_eq!(target.chain_id, Some(8453));
+        assert_eq!(target.asset, None);
+        assert!(parse_target(&format!("nope:{}", ADDRESS)).is_err());
+        assert_eq!(parse_target(ADDRESS).unwrap().chain_id, None);
+    }
+}
@@ -0,0 +1,318 @@
+//! Recurring payroll distributions.
+//! A payroll is a named recipient list with fixed amounts, paid from one source index on
+//! one network every few days, weeks or months. Beaug reminds when a cycle is due and
+//! loads the list into the bulk disperse form; the transaction hash of each paid cycle is
+//! recorded against the payroll, which gives a payment history per recipient.
+//!
+//! Due dates are counted from the first due date (`first_due + n * period`), so monthly
+//! payrolls on the 31st do not drift after a short month.
+
+use crate::config::Config;
+use crate::operation_log;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Days, Local, Months, TimeZone};
+use ethers::types::{Address, TxHash, U256};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// File in the app data directory holding the payrolls
+const PAYROLL_FILE: &str = "payroll.json";
+
+/// How often a payroll repeats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Recurrence {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+}
+
+impl Default for Recurrence {
+    fn default() -> Self {
+        Recurrence::Months(1)
+    }
+}
+
+impl Recurrence {
+    pub fn describe(&self) -> String {
+        match self {
+            Recurrence::Days(1) => "daily".to_string(),
+            Recurrence::Weeks(1) => "weekly".to_string(),
+            Recurrence::Months(1) => "monthly".to_string(),
+            Recurrence::Days(n) => format!("every {} days", n),
+            Recurrence::Weeks(n) => format!("every {} weeks", n),
+            Recurrence::Months(n) => format!("every {} months", n),
+        }
+    }
+
+    /// `cycles` periods after `start`, keeping the local time of day (months keep the day of
+    /// month where it exists)
+    fn advance(&self, start: DateTime<Local>, cycles: u32) -> Option<DateTime<Local>> {
+        match self {
+            Recurrence::Days(n) => start.checked_add_days(Days::new(u64::from(n * cycles))),
+            Recurrence::Weeks(n) => start.checked_add_days(Days::new(u64::from(7 * n * cycles))),
+            Recurrence::Months(n) => start.checked_add_months(Months::new(n * cycles)),
+        }
+    }
+}
+
+/// One paid cycle of a payroll
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayrollRun {
+    /// Cycle number, counted from 0 at the first due date
+    pub cycle: u32,
+    /// Unix timestamp (seconds) the cycle was due
+    pub due_at: i64,
+    /// Unix timestamp (seconds) the disperse was mined
+    pub paid_at: i64,
+    /// Disperse transactions that paid the cycle, one per batch when it was batched
+    pub tx_hashes: Vec<TxHash>,
+    /// What each recipient received
+    pub payments: Vec<(Address, U256)>,
+}
+
+/// A named recurring distribution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Payroll {
+    pub id: String,
+    pub name: String,
+    pub chain_id: u64,
+    pub network_label: String,
+    pub native_token: String,
+    pub source_index: u32,
+    /// Disperse contract; `None` uses the network default
+    pub contract: Option<String>,
+    pub recipients: Vec<(Address, U256)>,
+    pub recurrence: Recurrence,
+    /// Unix timestamp (seconds) of the first due date
+    pub first_due: i64,
+    /// Cycles paid or skipped so far; the next due date is cycle `cycles_done`
+    pub cycles_done: u32,
+    #[serde(default)]
+    pub runs: Vec<PayrollRun>,
+}
+
+impl Payroll {
+    pub fn new(
+        config: &Config,
+        name: String,
+        source_index: u32,
+        contract: Option<String>,
+        recipients: Vec<(Address, U256)>,
+        recurrence: Recurrence,
+        first_due: i64,
+    ) -> Self {
+        Self {
+            id: format!("{}_{}", Local::now().format("%Y%m%d_%H%M%S%3f"), config.chain_id),
+            name,
+            chain_id: config.chain_id,
+            network_label: config.network_label().to_string(),
+            native_token: config.native_token().to_string(),
+            source_index,
+            contract,
+            recipients,
+            recurrence,
+            first_due,
+            cycles_done: 0,
+            runs: Vec::new(),
+        }
+    }
+
+    /// Sum of one cycle's payments
+    pub fn total(&self) -> U256 {
+        self.recipients.iter().fold(U256::zero(), |acc, (_, amount)| acc + *amount)
+    }
+
+    /// Unix timestamp (seconds) the next unpaid cycle is due
+    pub fn next_due(&self) -> i64 {
+        Local
+            .timestamp_opt(self.first_due, 0)
+            .single()
+            .and_then(|start| self.recurrence.advance(start, self.cycles_done))
+            .map(|due| due.timestamp())
+            .unwrap_or(i64::MAX)
+    }
+
+    pub fn is_due(&self, now: i64) -> bool {
+        now >= self.next_due()
+    }
+
+    /// Record the disperse transactions that paid the next cycle and move on to the following one
+    pub fn record_run(&mut self, tx_hashes: Vec<TxHash>, payments: Vec<(Address, U256)>, paid_at: i64) {
+        self.runs.push(PayrollRun {
+            cycle: self.cycles_done,
+            due_at: self.next_due(),
+            paid_at,
+            tx_hashes,
+            payments,
+        });
+        self.cycles_done += 1;
+    }
+
+    /// Skip the next cycle without paying it, noting it in the operation log
+    pub fn skip_cycle(&mut self) {
+        let due = self.next_due();
+        self.cycles_done += 1;
+        let details = format!(
+            "Payroll '{}' on {} (Chain ID: {})\nSkipped the cycle due {}",
+            self.name,
+            self.network_label,
+            self.chain_id,
+            format_timestamp(due)
+        );
+        if let Err(e) = operation_log::append_log("Beaug Payroll", self.chain_id, details) {
+            tracing::warn!("Failed to log skipped payroll cycle: {}", e);
+        }
+    }
+
+    /// Recipient list in the bulk disperse input format, amounts in whole units
+    pub fn recipient_input(&self) -> String {
+        self.recipients
+            .iter()
+            .map(|(address, amount)| format!("{:?},{}", address, crate::utils::format_ether(*amount)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// One payment received by a recipient, for the per-recipient history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecipientPayment {
+    pub payroll: String,
+    pub native_token: String,
+    pub paid_at: i64,
+    pub amount: U256,
+    /// Transactions of the cycle that paid it
+    pub tx_hashes: Vec<TxHash>,
+}
+
+/// Every recorded payment per recipient, oldest first
+pub fn payments_by_recipient(payrolls: &[Payroll]) -> BTreeMap<Address, Vec<RecipientPayment>> {
+    let mut history: BTreeMap<Address, Vec<RecipientPayment>> = BTreeMap::new();
+    for payroll in payrolls {
+        for run in &payroll.runs {
+            for (address, amount) in &run.payments {
+                history.entry(*address).or_default().push(RecipientPayment {
+                    payroll: payroll.name.clone(),
+                    native_token: payroll.native_token.clone(),
+                    paid_at: run.paid_at,
+                    amount: *amount,
+                    tx_hashes: run.tx_hashes.clone(),
+                });
+            }
+        }
+    }
+    for payments in history.values_mut() {
+        payments.sort_by_key(|payment| payment.paid_at);
+    }
+    history
+}
+
+/// Parse a local date (`YYYY-MM-DD`, optionally with ` HH:MM`) into a unix timestamp
+pub fn parse_due_date(input: &str) -> Result<i64> {
+    let input = input.trim();
+    let naive = chrono::NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M")
+        .ok()
+        .or_else(|| chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d").ok().and_then(|date| date.and_hms_opt(9, 0, 0)))
+        .ok_or_else(|| anyhow!("Invalid date '{}'. Use 2025-01-31 or 2025-01-31 09:00", input))?;
+    naive
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|dt| dt.timestamp())
+        .ok_or_else(|| anyhow!("{} does not exist in the local time zone", input))
+}
+
+/// Local date and time of a unix timestamp
+pub fn format_timestamp(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| format!("unix time {}", timestamp))
+}
+
+/// Comma-separated transaction hashes of a run
+pub fn format_tx_hashes(tx_hashes: &[TxHash]) -> String {
+    tx_hashes.iter().map(|hash| format!("{:?}", hash)).collect::<Vec<_>>().join(", ")
+}
+
+fn payroll_path() -> PathBuf {
+    operation_log::app_data_dir().join(PAYROLL_FILE)
+}
+
+/// Load the saved payrolls; a missing file means none
+pub fn load_payrolls() -> Result<Vec<Payroll>> {
+    let path = payroll_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Persist the payrolls
+pub fn save_payrolls(payrolls: &[Payroll]) -> Result<()> {
+    fs::write(payroll_path(), serde_json::to_string_pretty(payrolls)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payroll(recurrence: Recurrence, first_due: &str) -> Payroll {
+        let config = Config::new("http://localhost:8545".to_string(), 1);
+        let recipients = vec![(Address::repeat_byte(1), U256::exp10(18)), (Address::repeat_byte(2), U256::exp10(17))];
+        Payroll::new(&config, "Team".to_string(), 0, None, recipients, recurrence, parse_due_date(first_due).unwrap())
+    }
+
+    // ==================== Due date tests ====================
+
+    #[test]
+    fn test_monthly_due_dates_do_not_drift() {
+        let mut payroll = payroll(Recurrence::Months(1), "2025-01-31 09:00");
+        let mut due_dates = Vec::new();
+        for _ in 0..3 {
+            due_dates.push(format_timestamp(payroll.next_due()));
+            payroll.record_run(vec![TxHash::zero()], Vec::new(), payroll.next_due());
+        }
+        assert_eq!(due_dates, vec!["2025-01-31 09:00", "2025-02-28 09:00", "2025-03-31 09:00"]);
+        assert_eq!(payroll.runs[1].cycle, 1);
+    }
+
+    #[test]
+    fn test_weekly_due_and_skip() {
+        let mut payroll = payroll(Recurrence::Weeks(2), "2025-03-03");
+        let first = payroll.next_due();
+        assert!(!payroll.is_due(first - 1));
+        assert!(payroll.is_due(first));
+        payroll.cycles_done += 1;
+        assert_eq!(format_timestamp(payroll.next_due()), "2025-03-17 09:00");
+        assert!(parse_due_date("03/03/2025").is_err());
+    }
+
+    // ==================== History tests ====================
+
+    #[test]
+    fn test_payments_by_recipient() {
+        let mut payroll = payroll(Recurrence::Months(1), "2025-01-01");
+        let alice = Address::repeat_byte(1);
+        payroll.record_run(vec![TxHash::repeat_byte(0xaa)], vec![(alice, U256::from(5))], 200);
+        // A batched cycle keeps the hash of every batch
+        let batches = vec![TxHash::repeat_byte(0xbb), TxHash::repeat_byte(0xcc)];
+        payroll.record_run(batches.clone(), vec![(alice, U256::from(7))], 100);
+        assert_eq!(payroll.total(), U256::exp10(18) + U256::exp10(17));
+        assert_eq!(
+            payroll.recipient_input(),
+            "0x0101010101010101010101010101010101010101,1.000000000000000000\n\
+             0x0202020202020202020202020202020202020202,0.100000000000000000"
+        );
+
+        let history = payments_by_recipient(&[payroll]);
+        let amounts: Vec<U256> = history[&alice].iter().map(|payment| payment.amount).collect();
+        assert_eq!(amounts, vec![U256::from(7), U256::from(5)]);
+        assert_eq!(history[&alice][0].tx_hashes, batches);
+        assert_eq!(
+            format_tx_hashes(&batches),
+            format!("{:?}, {:?}", TxHash::repeat_byte(0xbb), TxHash::repeat_byte(0xcc))
+        );
+    }
+}
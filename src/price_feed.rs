@@ -0,0 +1,330 @@
+//! USD valuation of native balances from Chainlink price feeds.
+//! Prices are read straight from the aggregator contracts over the configured
+//! RPC, so no third-party price API is involved. Each network maps to the
+//! Chainlink feed for its native token; custom feeds can be set per chain in
+//! the settings.
+
+use crate::erc20::DECIMALS_SELECTOR;
+use crate::user_settings::UserSettings;
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+use ethers::types::transaction::eip2718::TypedTransaction;
+
+/// Function selector for latestRoundData()
+const LATEST_ROUND_DATA_SELECTOR: [u8; 4] = [0xfe, 0xaf, 0x96, 0x8c];
+
+/// Prices not updated for longer than this are flagged as stale
+pub const STALE_AFTER_SECS: u64 = 6 * 60 * 60;
+
+/// How often the GUI refreshes the price of the selected network
+pub const REFRESH_INTERVAL_SECS: u64 = 5 * 60;
+
+/// A Chainlink native-token/USD feed on a specific chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceFeed {
+    pub chain_id: u64,
+    pub pair: &'static str,
+    pub address: &'static str,
+}
+
+impl PriceFeed {
+    const fn new(chain_id: u64, pair: &'static str, address: &'static str) -> Self {
+        Self {
+            chain_id,
+            pair,
+            address,
+        }
+    }
+}
+
+/// Built-in Chainlink feeds for the native token of each supported network
+pub const PRICE_FEEDS: &[PriceFeed] = &[
+    PriceFeed::new(1, "ETH / USD", "0x5f4eC3Df9cbd43714FE2740f5E3616155c5b8419"),
+    PriceFeed::new(11155111, "ETH / USD", "0x694AA1769357215DE4FAC081bf1f309aDC325306"),
+    PriceFeed::new(10, "ETH / USD", "0x13e3Ee699D1909E989722E753853AE30b17e08c5"),
+    PriceFeed::new(8453, "ETH / USD", "0x71041dddad3595F9CEd3DcCFBe3D1F4b0a16Bb70"),
+    PriceFeed::new(137, "POL / USD", "0xAB594600376Ec9fD91F8e885dADF0CE036862dE0"),
+    PriceFeed::new(56, "BNB / USD", "0x0567F2323251f0Aab15c8dFb1967E4e8A7D42aeE"),
+    PriceFeed::new(43114, "AVAX / USD", "0x0A77230d17318075983913bC2145DB16C7366156"),
+    PriceFeed::new(100, "DAI / USD", "0x678df3415fc31947dA4324eC63212874be5a82f8"),
+];
+
+/// The feed a chain is valued with: a custom feed from the settings, else the built-in one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedSource {
+    pub chain_id: u64,
+    pub pair: String,
+    pub address: Address,
+    pub custom: bool,
+}
+
+/// Find the built-in feed for a chain
+pub fn builtin_feed(chain_id: u64) -> Option<&'static PriceFeed> {
+    PRICE_FEEDS.iter().find(|feed| feed.chain_id == chain_id)
+}
+
+/// Resolve the feed for a chain, preferring a custom feed from the settings
+pub fn feed_for(chain_id: u64, settings: &UserSettings) -> Result<Option<FeedSource>> {
+    if let Some(custom) = settings.custom_price_feeds.get(&chain_id) {
+        let custom = custom.trim();
+        if !custom.is_empty() {
+            let address = custom
+                .parse::<Address>()
+                .map_err(|_| anyhow!("Invalid custom price feed address '{}'", custom))?;
+            return Ok(Some(FeedSource {
+                chain_id,
+                pair: "Custom / USD".to_string(),
+                address,
+                custom: true,
+            }));
+        }
+    }
+
+    Ok(builtin_feed(chain_id).map(|feed| FeedSource {
+        chain_id,
+        pair: feed.pair.to_string(),
+        address: feed
+            .address
+            .parse()
+            .expect("PRICE_FEEDS contains an invalid address - this is a programming error"),
+        custom: false,
+    }))
+}
+
+/// A price read from a feed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceQuote {
+    pub source: FeedSource,
+    /// Raw aggregator answer, scaled by `decimals`
+    pub answer: U256,
+    pub decimals: u8,
+    /// Unix timestamp (seconds) the feed was last updated on-chain
+    pub updated_at: u64,
+}
+
+impl PriceQuote {
+    /// Value of a native amount (18 decimals) in US cents, rounded down
+    pub fn usd_cents(&self, wei: U256) -> U256 {
+        // wei * answer / 10^(18 + decimals - 2)
+        let exponent = 16 + self.decimals as usize;
+        wei.saturating_mul(self.answer) / U256::exp10(exponent)
+    }
+
+    /// Formatted USD value of a native amount, e.g. "$1,234.56"
+    pub fn format_usd(&self, wei: U256) -> String {
+        format_usd_cents(self.usd_cents(wei))
+    }
+
+    /// Formatted price of one native token
+    pub fn format_price(&self) -> String {
+        self.format_usd(U256::exp10(18))
+    }
+
+    /// Seconds since the feed was last updated
+    pub fn age_secs(&self, now: u64) -> u64 {
+        now.saturating_sub(self.updated_at)
+    }
+
+    pub fn is_stale(&self, now: u64) -> bool {
+        self.age_secs(now) > STALE_AFTER_SECS
+    }
+
+    /// Human readable age of the price, e.g. "12m ago"
+    pub fn describe_age(&self, now: u64) -> String {
+        let age = self.age_secs(now);
+        if age < 60 {
+            format!("{}s ago", age)
+        } else if age < 60 * 60 {
+            format!("{}m ago", age / 60)
+        } else if age < 48 * 60 * 60 {
+            format!("{}h ago", age / (60 * 60))
+        } else {
+            format!("{}d ago", age / (24 * 60 * 60))
+        }
+    }
+}
+
+/// Format US cents as "$1,234.56"
+pub fn format_usd_cents(cents: U256) -> String {
+    let dollars = (cents / 100).to_string();
+    let remainder = (cents % 100).as_u64();
+
+    let mut grouped = String::new();
+    for (i, c) in dollars.chars().enumerate() {
+        if i > 0 && (dollars.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    format!("${}.{:02}", grouped, remainder)
+}
+
+/// Read the latest price from a feed
+pub async fn fetch_price(provider: &Provider<Http>, source: FeedSource) -> Result<PriceQuote> {
+    let decimals_call: TypedTransaction = TransactionRequest::new()
+        .to(source.address)
+        .data(DECIMALS_SELECTOR.to_vec())
+        .into();
+    let round_call: TypedTransaction = TransactionRequest::new()
+        .to(source.address)
+        .data(LATEST_ROUND_DATA_SELECTOR.to_vec())
+        .into();
+
+    let (decimals_data, round_data) = tokio::try_join!(
+        provider.call(&decimals_call, None),
+        provider.call(&round_call, None),
+    )
+    .map_err(|e| anyhow!("Failed to read price feed {:?}: {}", source.address, e))?;
+
+    let decimals = decode_decimals(&decimals_data)?;
+    let (answer, updated_at) = decode_latest_round_data(&round_data)?;
+
+    Ok(PriceQuote {
+        source,
+        answer,
+        decimals,
+        updated_at,
+    })
+}
+
+fn decode_decimals(data: &[u8]) -> Result<u8> {
+    if data.len() < 32 {
+        return Err(anyhow!("Price feed returned no decimals (is this an aggregator?)"));
+    }
+    let decimals = U256::from_big_endian(&data[..32]);
+    if decimals > U256::from(36u64) {
+        return Err(anyhow!("Price feed returned invalid decimals {}", decimals));
+    }
+    Ok(decimals.as_u32() as u8)
+}
+
+/// Decode (roundId, answer, startedAt, updatedAt, answeredInRound), returning the answer and updatedAt
+fn decode_latest_round_data(data: &[u8]) -> Result<(U256, u64)> {
+    if data.len() < 5 * 32 {
+        return Err(anyhow!("Price feed returned malformed round data"));
+    }
+    let word = |i: usize| &data[i * 32..(i + 1) * 32];
+
+    // answer is an int256; a negative or zero price is never valid for a USD feed
+    if word(1)[0] & 0x80 != 0 {
+        return Err(anyhow!("Price feed returned a negative price"));
+    }
+    let answer = U256::from_big_endian(word(1));
+    if answer.is_zero() {
+        return Err(anyhow!("Price feed returned a zero price"));
+    }
+
+    let updated_at = U256::from_big_endian(word(3));
+    if updated_at > U256::from(u64::MAX) {
+        return Err(anyhow!("Price feed returned an invalid update time"));
+    }
+    Ok((answer, updated_at.as_u64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote(answer: u64, decimals: u8, updated_at: u64) -> PriceQuote {
+        PriceQuote {
+            source: FeedSource {
+                chain_id: 1,
+                pair: "ETH / USD".to_string(),
+                address: Address::zero(),
+                custom: false,
+            },
+            answer: U256::from(answer),
+            decimals,
+            updated_at,
+        }
+    }
+
+    fn round_data(answer: U256, updated_at: u64) -> Vec<u8> {
+        let mut data = vec![0u8; 5 * 32];
+        answer.to_big_endian(&mut data[32..64]);
+        U256::from(updated_at).to_big_endian(&mut data[96..128]);
+        data
+    }
+
+    // ==================== Registry tests ====================
+
+    #[test]
+    fn test_builtin_feeds_are_valid_addresses() {
+        for feed in PRICE_FEEDS {
+            assert!(feed.address.parse::<Address>().is_ok(), "bad feed for chain {}", feed.chain_id);
+        }
+    }
+
+    #[test]
+    fn test_custom_feed_overrides_builtin() {
+        let mut settings = UserSettings::default();
+        assert!(!feed_for(1, &settings).unwrap().unwrap().custom);
+
+        settings
+            .custom_price_feeds
+            .insert(1, "0x0000000000000000000000000000000000000001".to_string());
+        let feed = feed_for(1, &settings).unwrap().unwrap();
+        assert!(feed.custom);
+        assert_eq!(feed.address, Address::from_low_u64_be(1));
+
+        settings.custom_price_feeds.insert(1, "nope".to_string());
+        assert!(feed_for(1, &settings).is_err());
+    }
+
+    #[test]
+    fn test_unknown_chain_has_no_feed() {
+        assert!(feed_for(999_999, &UserSettings::default()).unwrap().is_none());
+    }
+
+    // ==================== Valuation tests ====================
+
+    #[test]
+    fn test_usd_value() {
+        // $3,000.12345678 with 8 decimals
+        let quote = quote(300_012_345_678, 8, 0);
+        assert_eq!(quote.format_price(), "$3,000.12");
+        // 0.5 ETH
+        assert_eq!(quote.format_usd(U256::exp10(17) * 5), "$1,500.06");
+        assert_eq!(quote.format_usd(U256::zero()), "$0.00");
+    }
+
+    #[test]
+    fn test_format_usd_cents_grouping() {
+        assert_eq!(format_usd_cents(U256::from(5u64)), "$0.05");
+        assert_eq!(format_usd_cents(U256::from(100_000u64)), "$1,000.00");
+        assert_eq!(format_usd_cents(U256::from(123_456_789u64)), "$1,234,567.89");
+    }
+
+    #[test]
+    fn test_staleness() {
+        let quote = quote(1, 8, 1_000);
+        assert_eq!(quote.describe_age(1_030), "30s ago");
+        assert_eq!(quote.describe_age(1_000 + 125 * 60), "2h ago");
+        assert!(!quote.is_stale(1_000 + STALE_AFTER_SECS));
+        assert!(quote.is_stale(1_001 + STALE_AFTER_SECS));
+    }
+
+    // ==================== Decoding tests ====================
+
+    #[test]
+    fn test_decode_latest_round_data() {
+        let (answer, updated_at) = decode_latest_round_data(&round_data(U256::from(42u64), 1_700_000_000)).unwrap();
+        assert_eq!(answer, U256::from(42u64));
+        assert_eq!(updated_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_prices() {
+        assert!(decode_latest_round_data(&round_data(U256::zero(), 1)).is_err());
+        assert!(decode_latest_round_data(&round_data(U256::MAX, 1)).is_err());
+        assert!(decode_latest_round_data(&[0u8; 64]).is_err());
+    }
+
+    #[test]
+    fn test_decode_decimals() {
+        let mut data = [0u8; 32];
+        data[31] = 8;
+        assert_eq!(decode_decimals(&data).unwrap(), 8);
+        assert!(decode_decimals(&[]).is_err());
+    }
+}
@@ -0,0 +1,238 @@
+//! Recipient list checks run before a disperse or split.
+//! The only checks at parse time are address syntax; this pass flags recipients that
+//! parse but are probably not what the user meant:
+//! - the same address listed twice, the zero address, or the sending address itself
+//! - mixed-case addresses whose EIP-55 checksum does not match (likely mistyped)
+//! - look-alikes of address book entries sharing their first and last hex digits,
+//!   as planted by address poisoning
+//! - contracts, which may reject plain transfers or need more than 21000 gas, and
+//!   addresses that already hold a balance (using [`fetch_onchain`])
+
+use crate::config::Config;
+use crate::user_settings::AddressBookEntry;
+use anyhow::Result;
+use ethers::prelude::*;
+use std::collections::HashMap;
+
+/// Leading and trailing hex digits a look-alike shares with an address book entry
+pub const LOOKALIKE_AFFIX_LEN: usize = 4;
+
+/// Most addresses looked up on chain in one round
+pub const ONCHAIN_CHECK_BATCH: usize = 50;
+
+/// One recipient as written in the input, with the address it parsed to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckedRecipient {
+    /// Address column as written (hex address or ENS name)
+    pub text: String,
+    pub address: Address,
+}
+
+/// What the chain says about a recipient
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OnchainInfo {
+    pub is_contract: bool,
+    pub balance: U256,
+}
+
+/// On-chain facts per recipient address
+pub type OnchainInfos = HashMap<Address, OnchainInfo>;
+
+/// Something worth a second look about one recipient
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueKind {
+    /// Listed again; `first` is the index of the first occurrence
+    Duplicate { first: usize },
+    ZeroAddress,
+    /// The address the funds are sent from
+    SelfSend,
+    /// Mixed-case address whose EIP-55 checksum does not match
+    BadChecksum,
+    /// Shares its first and last hex digits with a different address book entry
+    Lookalike { label: String, address: Address },
+    Contract,
+    /// Holds this balance already
+    AlreadyFunded(U256),
+}
+
+impl IssueKind {
+    /// Issues that almost certainly send funds to the wrong place (the rest may be intended)
+    pub fn is_severe(&self) -> bool {
+        matches!(
+            self,
+            IssueKind::ZeroAddress | IssueKind::SelfSend | IssueKind::BadChecksum | IssueKind::Lookalike { .. }
+        )
+    }
+
+    /// One-line explanation, with balances formatted by `format_balance`
+    pub fn describe(&self, format_balance: impl Fn(U256) -> String) -> String {
+        match self {
+            IssueKind::Duplicate { first } => format!("duplicate of recipient {}", first + 1),
+            IssueKind::ZeroAddress => "zero address; funds sent there are burned".to_string(),
+            IssueKind::SelfSend => "is the source address".to_string(),
+            IssueKind::BadChecksum => "checksum does not match; the address may be mistyped".to_string(),
+            IssueKind::Lookalike { label, address } => {
+                format!("looks like address book entry '{}' ({:?}) but differs", label, address)
+            }
+            IssueKind::Contract => "is a contract; it may reject the transfer or need more than 21000 gas".to_string(),
+            IssueKind::AlreadyFunded(balance) => format!("already holds {}", format_balance(*balance)),
+        }
+    }
+}
+
+/// A flagged recipient: its index in the checked list and what is wrong with it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecipientIssue {
+    pub index: usize,
+    pub kind: IssueKind,
+}
+
+/// True when `text` is a mixed-case hex address whose case does not match its EIP-55 checksum.
+/// All-lowercase and all-uppercase addresses carry no checksum and pass.
+pub fn is_checksum_mismatch(text: &str, address: Address) -> bool {
+    let text = text.trim();
+    let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) else {
+        return false;
+    };
+    let has_lower = hex.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = hex.chars().any(|c| c.is_ascii_uppercase());
+    has_lower && has_upper && ethers::utils::to_checksum(&address, None)[2..] != *hex
+}
+
+/// True when two different addresses share their first and last [`LOOKALIKE_AFFIX_LEN`] hex digits
+pub fn is_lookalike(a: Address, b: Address) -> bool {
+    let (a, b) = (hex::encode(a), hex::encode(b));
+    a != b
+        && a[..LOOKALIKE_AFFIX_LEN] == b[..LOOKALIKE_AFFIX_LEN]
+        && a[a.len() - LOOKALIKE_AFFIX_LEN..] == b[b.len() - LOOKALIKE_AFFIX_LEN..]
+}
+
+/// Check `recipients` paid from `sources` against the address book and, for the addresses
+/// in `onchain`, their code and balance. Each recipient is reported at most once per kind.
+pub fn check_recipients(
+    recipients: &[CheckedRecipient],
+    sources: &[Address],
+    book: &[AddressBookEntry],
+    onchain: &OnchainInfos,
+) -> Vec<RecipientIssue> {
+    let book: Vec<(&str, Address)> = book
+        .iter()
+        .filter_map(|entry| entry.address.parse().ok().map(|address| (entry.label.as_str(), address)))
+        .collect();
+    let mut first_seen: HashMap<Address, usize> = HashMap::new();
+    let mut issues = Vec::new();
+
+    for (index, recipient) in recipients.iter().enumerate() {
+        let address = recipient.address;
+        let mut flag = |kind| issues.push(RecipientIssue { index, kind });
+
+        match first_seen.get(&address) {
+            Some(&first) => flag(IssueKind::Duplicate { first }),
+            None => {
+                first_seen.insert(address, index);
+            }
+        }
+        if address.is_zero() {
+            flag(IssueKind::ZeroAddress);
+        }
+        if sources.contains(&address) {
+            flag(IssueKind::SelfSend);
+        }
+        if is_checksum_mismatch(&recipient.text, address) {
+            flag(IssueKind::BadChecksum);
+        }
+        if !book.iter().any(|(_, known)| *known == address) {
+            if let Some((label, known)) = book.iter().find(|(_, known)| is_lookalike(address, *known)) {
+                flag(IssueKind::Lookalike { label: label.to_string(), address: *known });
+            }
+        }
+        if let Some(info) = onchain.get(&address) {
+            if info.is_contract {
+                flag(IssueKind::Contract);
+            }
+            if !info.balance.is_zero() {
+                flag(IssueKind::AlreadyFunded(info.balance));
+            }
+        }
+    }
+    issues
+}
+
+/// Look up the code and balance of each address on the selected network
+pub async fn fetch_onchain(config: Config, addresses: Vec<Address>) -> Result<OnchainInfos> {
+    let provider = config.get_provider().await?;
+    let mut infos = HashMap::with_capacity(addresses.len());
+    for address in addresses {
+        let (code, balance) = tokio::try_join!(provider.get_code(address, None), provider.get_balance(address, None))?;
+        infos.insert(address, OnchainInfo { is_contract: !code.is_empty(), balance });
+    }
+    Ok(infos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOOK_ADDRESS: &str = "0x742d35cc6634c0532925a3b844bc9e7595f0beb0";
+
+    fn recipient(text: &str) -> CheckedRecipient {
+        CheckedRecipient { text: text.to_string(), address: text.parse().unwrap() }
+    }
+
+    // ==================== Address checks ====================
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let address: Address = BOOK_ADDRESS.parse().unwrap();
+        let checksummed = ethers::utils::to_checksum(&address, None);
+        assert!(!is_checksum_mismatch(&checksummed, address));
+        assert!(!is_checksum_mismatch(&checksummed.to_lowercase(), address));
+        assert!(!is_checksum_mismatch(&format!("0x{}", checksummed[2..].to_uppercase()), address));
+        assert!(is_checksum_mismatch(&checksummed.replacen("D35", "d35", 1), address));
+        assert!(!is_checksum_mismatch("alice.eth", address));
+    }
+
+    #[test]
+    fn test_lookalike() {
+        let known: Address = BOOK_ADDRESS.parse().unwrap();
+        let poisoned: Address = "0x742d0000000000000000000000000000000fbeb0".parse().unwrap();
+        let unrelated: Address = "0x1111000000000000000000000000000000002222".parse().unwrap();
+        assert!(is_lookalike(poisoned, known));
+        assert!(!is_lookalike(known, known));
+        assert!(!is_lookalike(unrelated, known));
+    }
+
+    // ==================== Report tests ====================
+
+    #[test]
+    fn test_check_recipients() {
+        let source: Address = "0x00000000000000000000000000000000000000aa".parse().unwrap();
+        let contract: Address = "0x00000000000000000000000000000000000000cc".parse().unwrap();
+        let recipients = vec![
+            recipient("0x00000000000000000000000000000000000000cc"),
+            recipient("0x0000000000000000000000000000000000000000"),
+            recipient("0x00000000000000000000000000000000000000aa"),
+            recipient("0x742d0000000000000000000000000000000fbeb0"),
+            recipient("0x00000000000000000000000000000000000000CC"),
+            recipient(BOOK_ADDRESS),
+        ];
+        let book = vec![AddressBookEntry { label: "Treasury".to_string(), address: BOOK_ADDRESS.to_string() }];
+        let onchain = HashMap::from([(contract, OnchainInfo { is_contract: true, balance: U256::from(5) })]);
+
+        let issues = check_recipients(&recipients, &[source], &book, &onchain);
+        let kinds: Vec<(usize, &IssueKind)> = issues.iter().map(|issue| (issue.index, &issue.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (0, &IssueKind::Contract),
+                (0, &IssueKind::AlreadyFunded(U256::from(5))),
+                (1, &IssueKind::ZeroAddress),
+                (2, &IssueKind::SelfSend),
+                (3, &IssueKind::Lookalike { label: "Treasury".to_string(), address: BOOK_ADDRESS.parse().unwrap() }),
+                (4, &IssueKind::Duplicate { first: 0 }),
+                (4, &IssueKind::Contract),
+                (4, &IssueKind::AlreadyFunded(U256::from(5))),
+            ]
+        );
+    }
+}
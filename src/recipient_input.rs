@@ -0,0 +1,475 @@
+//! Recipient list syntax shared by the bulk disperse parsers.
+//! Each line is `address` or `address,amount` (or separated by whitespace), where the
+//! amount is one of:
+//! - a fixed amount, for native amounts optionally suffixed with a unit (`wei`, `gwei`, `ether`)
+//! - a percentage of the total amount (`25%`, `12.5%`)
+//! - a weight (`w=3`), splitting what is left after fixed amounts and percentages
+//!
+//! The address may be an ENS name once it has been resolved (see [`crate::ens`]), an
+//! EIP-3770 `short:address` or an EIP-681 payment request (see [`crate::payment_uri`]).
+//! `#` starts a comment, and a header row before the first recipient whose first column
+//! is `address`, `recipient` or `to` (e.g. `address,amount`) is skipped.
+
+use crate::ens;
+use crate::payment_uri::{self, PaymentAsset};
+use crate::utils;
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+
+/// Fixed-point scale of percentages and weights (six decimal places)
+pub const SHARE_SCALE: u64 = 1_000_000;
+
+/// 100% in [`SHARE_SCALE`] units
+const FULL_PERCENT: u64 = 100 * SHARE_SCALE;
+
+/// Amount column of one recipient line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecipientAmount {
+    /// Exact amount in base units
+    Fixed(U256),
+    /// Percentage of the total, in millionths of a percent
+    Percent(U256),
+    /// Relative weight, in millionths
+    Weight(U256),
+}
+
+impl RecipientAmount {
+    /// True for percentages and weights, which need a total to resolve
+    pub fn is_share(&self) -> bool {
+        !matches!(self, RecipientAmount::Fixed(_))
+    }
+
+    /// Input-style text (`25%`, `w=3`), with fixed amounts formatted by `format_fixed`
+    pub fn describe(&self, format_fixed: impl Fn(U256) -> String) -> String {
+        match self {
+            RecipientAmount::Fixed(value) => format_fixed(*value),
+            RecipientAmount::Percent(percent) => format!("{}%", format_scaled(*percent)),
+            RecipientAmount::Weight(weight) => format!("w={}", format_scaled(*weight)),
+        }
+    }
+}
+
+/// [`SHARE_SCALE`] units as a decimal without trailing zeros
+fn format_scaled(value: U256) -> String {
+    let text = utils::format_token_amount(value, 6);
+    match text.split_once('.') {
+        Some((whole, fraction)) if fraction.trim_end_matches('0').is_empty() => whole.to_string(),
+        Some((whole, fraction)) => format!("{}.{}", whole, fraction.trim_end_matches('0')),
+        None => text,
+    }
+}
+
+/// One parsed recipient line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecipientLine {
+    /// 1-based line number in the input
+    pub line: usize,
+    /// Recipient as written, without any payment request or chain prefix
+    pub text: String,
+    pub address: Address,
+    pub amount: Option<RecipientAmount>,
+}
+
+/// Parse a native amount with an optional unit suffix: `1.5`, `1.5 ether`, `20 gwei`, `100wei`.
+/// Without a suffix the amount is in ether.
+pub fn parse_native_amount(input: &str) -> Result<U256> {
+    let trimmed = input.trim();
+    let lower = trimmed.to_ascii_lowercase();
+    let (number, decimals) = if let Some(number) = lower.strip_suffix("gwei") {
+        (number, 9)
+    } else if let Some(number) = lower.strip_suffix("wei") {
+        (number, 0)
+    } else if let Some(number) = lower.strip_suffix("ether").or_else(|| lower.strip_suffix("eth")) {
+        (number, 18)
+    } else {
+        return utils::parse_eth_str_to_wei(trimmed);
+    };
+    let number = number.trim();
+    if number.is_empty() || number.starts_with('-') {
+        return Err(anyhow!("Invalid amount '{}'", trimmed));
+    }
+    if number.split_once('.').is_some_and(|(_, fraction)| fraction.len() > decimals) {
+        return Err(anyhow!("Invalid amount '{}': too many decimal places", trimmed));
+    }
+    Ok(ethers::utils::parse_units(number, decimals)
+        .map_err(|e| anyhow!("Invalid amount '{}': {}", trimmed, e))?
+        .into())
+}
+
+/// Parse a decimal with up to six decimal places into [`SHARE_SCALE`] units
+fn parse_scaled(input: &str) -> Result<U256> {
+    let input = input.trim();
+    if input.is_empty() || input.starts_with('-') {
+        return Err(anyhow!("Invalid number '{}'", input));
+    }
+    Ok(ethers::utils::parse_units(input, 6)
+        .map_err(|e| anyhow!("Invalid number '{}': {}", input, e))?
+        .into())
+}
+
+/// Parse the amount column: `25%`, `w=3`, or a fixed amount via `parse_fixed`
+pub fn parse_recipient_amount<F>(input: &str, parse_fixed: F) -> Result<RecipientAmount>
+where
+    F: Fn(&str) -> Result<U256>,
+{
+    let input = input.trim();
+    if let Some(percent) = input.strip_suffix('%') {
+        let percent = parse_scaled(percent)?;
+        if percent.is_zero() || percent > U256::from(FULL_PERCENT) {
+            return Err(anyhow!("Percentage must be above 0% and at most 100%"));
+        }
+        return Ok(RecipientAmount::Percent(percent));
+    }
+    if let Some(weight) = input.strip_prefix("w=").or_else(|| input.strip_prefix("W=")) {
+        let weight = parse_scaled(weight)?;
+        if weight.is_zero() {
+            return Err(anyhow!("Weight must be greater than 0"));
+        }
+        return Ok(RecipientAmount::Weight(weight));
+    }
+    parse_fixed(input).map(RecipientAmount::Fixed)
+}
+
+/// Split a line into its address and optional amount column. Commas separate columns;
+/// without a comma the first whitespace-separated word is the address and the rest the
+/// amount (so `0xabc 20 gwei` works).
+fn split_columns(line: &str) -> Result<(&str, Option<&str>)> {
+    if line.contains(',') {
+        let parts: Vec<&str> = line.split(',').map(str::trim).collect();
+        return match parts.as_slice() {
+            [address] => Ok((address, None)),
+            [address, amount] => Ok((address, Some(amount))),
+            _ => Err(anyhow!("Expected `address,amount`, got {} columns", parts.len())),
+        };
+    }
+    match line.split_once(char::is_whitespace) {
+        Some((address, amount)) => Ok((address, Some(amount.trim()))),
+        None => Ok((line, None)),
+    }
+}
+
+/// Column names a header row may start with
+const HEADER_WORDS: [&str; 5] = ["address", "addresses", "recipient", "recipients", "to"];
+
+/// A first column naming the address column. Anything else that is not an address is a
+/// row error, so a mistyped first recipient is not dropped as a header.
+fn is_header(first_column: &str) -> bool {
+    let word = first_column.trim().trim_matches('"');
+    HEADER_WORDS.iter().any(|header| word.eq_ignore_ascii_case(header))
+}
+
+/// True for text the address column accepts: a hex address or ENS name, bare, chain-prefixed
+/// (EIP-3770) or in a payment request (EIP-681)
+pub fn is_address_column(text: &str) -> bool {
+    payment_uri::parse_target(text).is_ok_and(|target| {
+        let recipient = target.recipient.trim();
+        (recipient.starts_with("0x") && recipient.len() == 42) || ens::is_ens_name(recipient)
+    })
+}
+
+/// ENS names in the address column of a recipient list
+pub fn ens_names(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .map(|raw| raw.split('#').next().unwrap_or_default().trim())
+        .filter_map(|line| split_columns(line).ok().map(|(address, _)| payment_uri::recipient_text(address)))
+        .filter(|address| ens::is_ens_name(address))
+        .map(|address| ens::normalize(&address))
+        .collect()
+}
+
+/// Resolve the address column of one line: the recipient, and the amount a payment request
+/// carries. Requests for another chain or another asset than `asset` are refused.
+fn parse_address_column(column: &str, chain_id: u64, asset: PaymentAsset) -> Result<(String, Address, Option<U256>)> {
+    let target = payment_uri::parse_target(column)?;
+    target.check_chain(chain_id)?;
+    match (target.asset, asset) {
+        (Some(PaymentAsset::Token(_)), PaymentAsset::Native) => {
+            return Err(anyhow!("{} is an ERC-20 transfer request; use token mode for it", target.recipient));
+        }
+        (Some(PaymentAsset::Native), PaymentAsset::Token(_)) => {
+            return Err(anyhow!("{} is a native payment request, not a token transfer", target.recipient));
+        }
+        (Some(PaymentAsset::Token(requested)), PaymentAsset::Token(selected)) if requested != selected => {
+            return Err(anyhow!("{} requests token {:?}, not the selected token", target.recipient, requested));
+        }
+        _ => {}
+    }
+    let address = ens::parse_address_or_name(&target.recipient)?;
+    Ok((target.recipient.trim().to_string(), address, target.amount))
+}
+
+/// Parse every recipient line, skipping blank lines, `#` comments and a header row.
+/// Addresses may be EIP-681 payment requests or EIP-3770 `short:address` forms for
+/// `chain_id`; a request's amount is in base units of `asset`.
+pub fn parse_recipient_lines<F>(input: &str, chain_id: u64, asset: PaymentAsset, parse_fixed: F) -> Result<Vec<RecipientLine>>
+where
+    F: Fn(&str) -> Result<U256>,
+{
+    let mut lines = Vec::new();
+    for (idx, raw) in input.lines().enumerate() {
+        let line_num = idx + 1;
+        let line = raw.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (address_str, amount_str) = split_columns(line).map_err(|e| anyhow!("Line {}: {}", line_num, e))?;
+
+        let (text, address, requested_amount) = match parse_address_column(address_str, chain_id, asset) {
+            Ok(parsed) => parsed,
+            // A header names its columns instead of starting with an address
+            Err(_) if lines.is_empty() && is_header(address_str) => continue,
+            Err(e) => return Err(anyhow!("Line {}: {}", line_num, e)),
+        };
+        let amount = match (amount_str, requested_amount) {
+            (Some(_), Some(_)) => {
+                return Err(anyhow!("Line {}: The payment request already sets the amount", line_num));
+            }
+            (None, requested) => requested.map(RecipientAmount::Fixed),
+            (Some(amount), None) => Some(
+                parse_recipient_amount(amount, &parse_fixed)
+                    .map_err(|e| anyhow!("Line {}: Invalid amount '{}': {}", line_num, amount, e))?,
+            ),
+        };
+        lines.push(RecipientLine { line: line_num, text, address, amount });
+    }
+    Ok(lines)
+}
+
+/// Recipient lines without validating fixed amounts, for checks that only need the addresses
+pub fn parse_recipient_addresses(input: &str, chain_id: u64, asset: PaymentAsset) -> Result<Vec<RecipientLine>> {
+    parse_recipient_lines(input, chain_id, asset, |_| Ok(U256::zero()))
+}
+
+/// `input` without its 1-based line `line`
+pub fn remove_line(input: &str, line: usize) -> String {
+    let mut edited: Vec<&str> = input.lines().enumerate().filter(|(idx, _)| idx + 1 != line).map(|(_, raw)| raw).collect();
+    if input.ends_with('\n') {
+        edited.push("");
+    }
+    edited.join("\n")
+}
+
+/// `input` with the first `from` on 1-based line `line` replaced by `to`
+pub fn replace_in_line(input: &str, line: usize, from: &str, to: &str) -> String {
+    let mut edited: Vec<String> = input
+        .lines()
+        .enumerate()
+        .map(|(idx, raw)| if idx + 1 == line { raw.replacen(from, to, 1) } else { raw.to_string() })
+        .collect();
+    if input.ends_with('\n') {
+        edited.push(String::new());
+    }
+    edited.join("\n")
+}
+
+/// Split `total` in proportion to `weights`, rounding down and handing the leftover wei
+/// to the largest remainders (earlier entries first on ties), so the parts sum to `total`
+pub fn apportion(total: U256, weights: &[U256]) -> Vec<U256> {
+    let weight_sum = weights.iter().fold(U256::zero(), |acc, w| acc + *w);
+    if weight_sum.is_zero() {
+        return vec![U256::zero(); weights.len()];
+    }
+    let mut parts: Vec<U256> = Vec::with_capacity(weights.len());
+    let mut remainders: Vec<(U256, usize)> = Vec::with_capacity(weights.len());
+    for (idx, weight) in weights.iter().enumerate() {
+        let scaled = total * *weight;
+        parts.push(scaled / weight_sum);
+        remainders.push((scaled % weight_sum, idx));
+    }
+    let assigned = parts.iter().fold(U256::zero(), |acc, p| acc + *p);
+    let leftover = (total - assigned).as_usize();
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for (_, idx) in remainders.into_iter().take(leftover) {
+        parts[idx] += U256::one();
+    }
+    parts
+}
+
+/// Resolve fixed amounts, percentages and weights against `total`. Percentages are of the
+/// total; weights share what is left after fixed amounts and percentages. When weights are
+/// present, or the percentages add up to 100%, the result sums to exactly `total`.
+pub fn resolve_amounts(entries: &[(Address, RecipientAmount)], total: U256) -> Result<Vec<(Address, U256)>> {
+    let mut fixed_sum = U256::zero();
+    let mut percent_sum = U256::zero();
+    let mut percents = Vec::new();
+    let mut weights = Vec::new();
+    for (idx, (_, amount)) in entries.iter().enumerate() {
+        match amount {
+            RecipientAmount::Fixed(value) => fixed_sum += *value,
+            RecipientAmount::Percent(percent) => {
+                percent_sum += *percent;
+                percents.push((idx, *percent));
+            }
+            RecipientAmount::Weight(weight) => weights.push((idx, *weight)),
+        }
+    }
+    if percent_sum > U256::from(FULL_PERCENT) {
+        return Err(anyhow!(
+            "Percentages add up to {}%, more than 100%",
+            format_scaled(percent_sum)
+        ));
+    }
+
+    let percent_total = total * percent_sum / U256::from(FULL_PERCENT);
+    if fixed_sum + percent_total > total {
+        return Err(anyhow!(
+            "Fixed amounts ({}) and percentages ({}) exceed the total ({})",
+            utils::format_ether(fixed_sum),
+            utils::format_ether(percent_total),
+            utils::format_ether(total)
+        ));
+    }
+    let weight_total = total - fixed_sum - percent_total;
+
+    let mut resolved: Vec<(Address, U256)> = entries
+        .iter()
+        .map(|(address, amount)| match amount {
+            RecipientAmount::Fixed(value) => (*address, *value),
+            _ => (*address, U256::zero()),
+        })
+        .collect();
+    let percent_parts = apportion(percent_total, &percents.iter().map(|(_, p)| *p).collect::<Vec<_>>());
+    for ((idx, _), part) in percents.iter().zip(percent_parts) {
+        resolved[*idx].1 = part;
+    }
+    let weight_parts = apportion(weight_total, &weights.iter().map(|(_, w)| *w).collect::<Vec<_>>());
+    for ((idx, _), part) in weights.iter().zip(weight_parts) {
+        resolved[*idx].1 = part;
+    }
+
+    if let Some((address, _)) = resolved.iter().find(|(_, amount)| amount.is_zero()) {
+        return Err(anyhow!("{:?} would receive 0; raise the total or its share", address));
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(n: u64) -> Address {
+        Address::from_low_u64_be(n)
+    }
+
+    // ==================== Amount syntax tests ====================
+
+    #[test]
+    fn test_parse_native_amount_units() {
+        assert_eq!(parse_native_amount("1.5").unwrap(), U256::exp10(17) * 15);
+        assert_eq!(parse_native_amount("1.5 ether").unwrap(), U256::exp10(17) * 15);
+        assert_eq!(parse_native_amount("2ETH").unwrap(), U256::exp10(18) * 2);
+        assert_eq!(parse_native_amount("20 gwei").unwrap(), U256::from(20_000_000_000u64));
+        assert_eq!(parse_native_amount("100wei").unwrap(), U256::from(100u64));
+        assert!(parse_native_amount("1.5 wei").is_err());
+        assert!(parse_native_amount("gwei").is_err());
+    }
+
+    #[test]
+    fn test_parse_recipient_amount_shares() {
+        let fixed = |s: &str| parse_native_amount(s);
+        assert_eq!(parse_recipient_amount("25%", fixed).unwrap(), RecipientAmount::Percent(U256::from(25_000_000u64)));
+        assert_eq!(parse_recipient_amount("12.5 %", fixed).unwrap(), RecipientAmount::Percent(U256::from(12_500_000u64)));
+        assert_eq!(parse_recipient_amount("w=3", fixed).unwrap(), RecipientAmount::Weight(U256::from(3_000_000u64)));
+        assert!(parse_recipient_amount("101%", fixed).is_err());
+        assert!(parse_recipient_amount("w=0", fixed).is_err());
+        assert_eq!(RecipientAmount::Percent(U256::from(12_500_000u64)).describe(|v| v.to_string()), "12.5%");
+        assert_eq!(RecipientAmount::Weight(U256::from(3_000_000u64)).describe(|v| v.to_string()), "w=3");
+    }
+
+    #[test]
+    fn test_parse_recipient_lines_comments_and_header() {
+        let input = "# payroll\naddress,amount\n0x0000000000000000000000000000000000000001,1 # alice\n\n0x0000000000000000000000000000000000000002 20 gwei\n";
+        let lines = parse_recipient_lines(input, 1, PaymentAsset::Native, parse_native_amount).unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].line, 3);
+        assert_eq!(lines[1].amount, Some(RecipientAmount::Fixed(U256::from(20_000_000_000u64))));
+
+        // Only a leading header is skipped
+        assert!(parse_recipient_lines("0x0000000000000000000000000000000000000001\naddress", 1, PaymentAsset::Native, parse_native_amount).is_err());
+        assert_eq!(parse_recipient_lines("Recipient,Amount\n", 1, PaymentAsset::Native, parse_native_amount).unwrap().len(), 0);
+
+        // A mistyped first recipient is reported, not skipped as a header
+        let err = parse_recipient_lines("x000000000000000000000000000000000000001,1\n0x0000000000000000000000000000000000000002,1", 1, PaymentAsset::Native, parse_native_amount)
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("Line 1"), "{}", err);
+    }
+
+    #[test]
+    fn test_ens_names_are_not_headers() {
+        let input = "address,amount\nunresolved-beaug-test.eth,1\n0x0000000000000000000000000000000000000001,2 # bob.eth";
+        assert_eq!(ens_names(input), vec!["unresolved-beaug-test.eth".to_string()]);
+        let err = parse_recipient_lines(input, 1, PaymentAsset::Native, parse_native_amount).unwrap_err().to_string();
+        assert!(err.contains("Line 2") && err.contains("not resolved"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_recipient_lines_payment_requests() {
+        let input = "ethereum:0x0000000000000000000000000000000000000001@8453?value=1e18\nbase:0x0000000000000000000000000000000000000002,2";
+        let lines = parse_recipient_lines(input, 8453, PaymentAsset::Native, parse_native_amount).unwrap();
+        assert_eq!(lines[0].amount, Some(RecipientAmount::Fixed(U256::exp10(18))));
+        assert_eq!(lines[1].amount, Some(RecipientAmount::Fixed(U256::exp10(18) * 2)));
+
+        // The selected network must match the requested chain
+        let err = parse_recipient_lines(input, 10, PaymentAsset::Native, parse_native_amount).unwrap_err().to_string();
+        assert!(err.starts_with("Line 1") && err.contains("Base"), "{}", err);
+
+        // A token transfer request is not a native payment
+        let transfer = "ethereum:0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48/transfer?address=0x0000000000000000000000000000000000000001&uint256=5";
+        assert!(parse_recipient_lines(transfer, 1, PaymentAsset::Native, parse_native_amount).is_err());
+        let usdc = PaymentAsset::Token("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".parse().unwrap());
+        let lines = parse_recipient_lines(transfer, 1, usdc, parse_native_amount).unwrap();
+        assert_eq!(lines[0].amount, Some(RecipientAmount::Fixed(U256::from(5u64))));
+    }
+
+    #[test]
+    fn test_line_edits() {
+        let input = "address,amount\n0xaa,1\nbase:0xbb,2\n";
+        assert_eq!(remove_line(input, 2), "address,amount\nbase:0xbb,2\n");
+        assert_eq!(replace_in_line(input, 3, "0xbb", "0xcc"), "address,amount\n0xaa,1\nbase:0xcc,2\n");
+        assert_eq!(remove_line("0xaa\n0xbb", 2), "0xaa");
+    }
+
+    // ==================== Resolution tests ====================
+
+    #[test]
+    fn test_apportion_sums_exactly() {
+        let parts = apportion(U256::from(100u64), &[U256::one(), U256::one(), U256::one()]);
+        assert_eq!(parts, vec![U256::from(34u64), U256::from(33u64), U256::from(33u64)]);
+
+        let parts = apportion(U256::from(10u64), &[U256::from(1u64), U256::from(2u64)]);
+        assert_eq!(parts, vec![U256::from(3u64), U256::from(7u64)]);
+    }
+
+    #[test]
+    fn test_resolve_amounts_mixed_shares() {
+        let entries = vec![
+            (addr(1), RecipientAmount::Fixed(U256::from(10u64))),
+            (addr(2), RecipientAmount::Percent(U256::from(50_000_000u64))),
+            (addr(3), RecipientAmount::Weight(U256::from(1u64))),
+            (addr(4), RecipientAmount::Weight(U256::from(2u64))),
+        ];
+        let resolved = resolve_amounts(&entries, U256::from(101u64)).unwrap();
+        // 10 fixed, 50% of 101 = 50, the remaining 41 split 1:2 = 14 + 27
+        let amounts: Vec<U256> = resolved.iter().map(|(_, a)| *a).collect();
+        assert_eq!(amounts, vec![U256::from(10u64), U256::from(50u64), U256::from(14u64), U256::from(27u64)]);
+        assert_eq!(amounts.iter().fold(U256::zero(), |acc, a| acc + *a), U256::from(101u64));
+    }
+
+    #[test]
+    fn test_resolve_amounts_percentages_total_exactly() {
+        let third = U256::from(33_333_333u64);
+        let entries = vec![
+            (addr(1), RecipientAmount::Percent(third)),
+            (addr(2), RecipientAmount::Percent(third)),
+            (addr(3), RecipientAmount::Percent(U256::from(FULL_PERCENT) - third * 2)),
+        ];
+        let total = U256::exp10(18) + 1;
+        let resolved = resolve_amounts(&entries, total).unwrap();
+        assert_eq!(resolved.iter().fold(U256::zero(), |acc, (_, a)| acc + *a), total);
+
+        let over = vec![(addr(1), RecipientAmount::Percent(U256::from(FULL_PERCENT))), (addr(2), RecipientAmount::Fixed(U256::one()))];
+        assert!(resolve_amounts(&over, U256::from(100u64)).is_err());
+    }
+}
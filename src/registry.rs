@@ -0,0 +1,210 @@
+//! Beaug contract registry.
+//! The main Beaug contract at `MAIN_BEAUG_ADDRESS` keeps a list of registered Beaug-compatible
+//! disperse contracts. This module enumerates that list, validates each entry with
+//! [`disperse::validate_contract`], and lets the registry owner register a new contract
+//! with a transaction signed on the Ledger.
+
+use crate::bulk_disperse;
+use crate::config::Config;
+use crate::disperse::{self, ContractValidationStatus};
+use crate::fee_oracle;
+use crate::ledger_dispatch;
+use crate::operation_log;
+use crate::simulation;
+use crate::utils;
+use anyhow::{anyhow, Result};
+use ethers::abi::{Abi, Token};
+use ethers::prelude::*;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use std::sync::{Arc, OnceLock};
+use tracing::info;
+
+/// Registry functions of the Beaug contract
+const REGISTRY_ABI: &[&str] = &[
+    "function getRegisteredContracts() view returns (address[])",
+    "function isRegistered(address contractAddr) view returns (bool)",
+    "function registerContract(address contractAddr)",
+    "function owner() view returns (address)",
+];
+
+/// Gas limit used when `eth_estimateGas` fails for a registration
+const REGISTER_FALLBACK_GAS_LIMIT: u64 = 120_000;
+
+static REGISTRY: OnceLock<Abi> = OnceLock::new();
+
+fn registry_abi() -> &'static Abi {
+    REGISTRY.get_or_init(|| {
+        ethers::abi::parse_abi(REGISTRY_ABI).expect("REGISTRY_ABI is invalid - this is a programming error")
+    })
+}
+
+fn encode_call(name: &str, args: &[Token]) -> Result<Vec<u8>> {
+    Ok(registry_abi().function(name)?.encode_input(args)?)
+}
+
+async fn call_view(provider: &Provider<Http>, registry: Address, name: &str, args: &[Token]) -> Result<Vec<Token>> {
+    let tx: TypedTransaction = TransactionRequest::new().to(registry).data(encode_call(name, args)?).into();
+    let output = provider.call(&tx, None).await?;
+    Ok(registry_abi().function(name)?.decode_output(&output)?)
+}
+
+/// Calldata for `registerContract(contractAddr)`
+pub fn register_calldata(contract: Address) -> Result<Vec<u8>> {
+    encode_call("registerContract", &[Token::Address(contract)])
+}
+
+/// Every contract in the registry, in registration order
+pub async fn fetch_registered_contracts(provider: &Provider<Http>, registry: Address) -> Result<Vec<Address>> {
+    match call_view(provider, registry, "getRegisteredContracts", &[]).await?.into_iter().next() {
+        Some(Token::Array(tokens)) => Ok(tokens.into_iter().filter_map(|t| t.into_address()).collect()),
+        _ => Err(anyhow!("Unexpected getRegisteredContracts() output from {:?}", registry)),
+    }
+}
+
+/// Current owner of a Beaug contract
+pub async fn fetch_owner(provider: &Provider<Http>, contract: Address) -> Result<Address> {
+    call_view(provider, contract, "owner", &[])
+        .await?
+        .into_iter()
+        .next()
+        .and_then(|t| t.into_address())
+        .ok_or_else(|| anyhow!("Unexpected owner() output from {:?}", contract))
+}
+
+/// A registered contract with its validation result
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegistryEntry {
+    pub address: Address,
+    pub status: ContractValidationStatus,
+}
+
+/// The registry's contents with each entry validated, plus the registry owner
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegistrySnapshot {
+    pub registry: Address,
+    pub owner: Option<Address>,
+    pub entries: Vec<RegistryEntry>,
+}
+
+/// Enumerate and validate every registered contract
+pub async fn fetch_registry(provider: Arc<Provider<Http>>, chain_id: u64) -> Result<RegistrySnapshot> {
+    let registry = disperse::get_beaug_registry_address(chain_id)
+        .ok_or_else(|| anyhow!("No Beaug registry known for chain {}", chain_id))?;
+    if provider.get_code(registry, None).await?.is_empty() {
+        return Err(anyhow!("The Beaug registry {:?} is not deployed on chain {}", registry, chain_id));
+    }
+
+    let owner = fetch_owner(&provider, registry).await.ok();
+    let mut entries = Vec::new();
+    for address in fetch_registered_contracts(&provider, registry).await? {
+        let status = disperse::validate_contract(provider.clone(), chain_id, address).await;
+        entries.push(RegistryEntry { address, status });
+    }
+
+    Ok(RegistrySnapshot { registry, owner, entries })
+}
+
+/// Register `contract` with the Beaug registry, signed by the Ledger address at `source_idx`.
+/// Only the registry owner can register; this is checked before anything is signed, along
+/// with the contract having code and not being registered already.
+pub async fn register_contract(
+    config: Config,
+    contract: Address,
+    source_idx: usize,
+    gas_speed: f32,
+    use_native_ledger: bool,
+) -> Result<TxHash> {
+    let provider = config.get_provider().await?;
+    let chain_id = config.chain_id;
+    let registry = disperse::get_beaug_registry_address(chain_id)
+        .ok_or_else(|| anyhow!("No Beaug registry known for chain {}", chain_id))?;
+    let source = bulk_disperse::load_source_account(&config, &provider, Some(source_idx), use_native_ledger).await?;
+
+    let owner = fetch_owner(&provider, registry).await?;
+    if owner != source.address {
+        return Err(anyhow!(
+            "Only the registry owner {:?} can register contracts; index {} is {:?}",
+            owner,
+            source.index,
+            source.address
+        ));
+    }
+    if provider.get_code(contract, None).await?.is_empty() {
+        return Err(anyhow!("{:?} is not a contract on chain {}", contract, chain_id));
+    }
+    if disperse::check_contract_registered(provider.clone(), registry, contract).await? {
+        return Err(anyhow!("{:?} is already registered", contract));
+    }
+
+    let calldata = register_calldata(contract)?;
+    if let Some(reason) = simulation::simulate_call(&provider, source.address, registry, calldata.clone(), U256::zero()).await? {
+        return Err(anyhow!("Simulation failed, transaction not sent: {}", reason));
+    }
+
+    let tx: TypedTransaction = TransactionRequest::new()
+        .from(source.address)
+        .to(registry)
+        .data(calldata.clone())
+        .into();
+    let gas_limit = match provider.estimate_gas(&tx, None).await {
+        Ok(estimate) => disperse::apply_gas_margin(estimate, disperse::DEFAULT_GAS_MARGIN_PERCENT),
+        Err(_) => REGISTER_FALLBACK_GAS_LIMIT,
+    };
+    let fee_quote = fee_oracle::quote_fees(&provider, config.chain_id, gas_speed).await?;
+    let gas_cost = fee_quote.max_cost(gas_limit);
+    if source.balance < gas_cost {
+        return Err(anyhow!(
+            "Balance too low for the registration gas. Source has {} but needs ~{}.",
+            utils::format_ether(source.balance),
+            utils::format_ether(gas_cost)
+        ));
+    }
+
+    info!("Registering {:?} with the Beaug registry {:?}", contract, registry);
+    let tx_hash = ledger_dispatch::sign_and_send_contract_call(
+        use_native_ledger,
+        provider.clone(),
+        &config.rpc_url,
+        source.index,
+        registry,
+        calldata,
+        U256::zero(),
+        gas_limit,
+        fee_quote.fees,
+        source.nonce,
+        chain_id,
+        config.derivation_mode,
+        config.custom_account,
+        config.custom_address_index,
+        config.coin_type,
+    )
+    .await?;
+
+    let (block_number, gas_used) = bulk_disperse::wait_for_receipt(&provider, tx_hash).await?;
+
+    operation_log::append_log(
+        "Beaug Register Contract",
+        chain_id,
+        format!(
+            "Registered contract with the Beaug registry\nRegistry: {:?}\nContract: {:?}\nOwner: {} → {:?}\nTx hash: {:?}\nBlock: {:?}\nGas used: {:?}",
+            registry, contract, source.derivation_path, source.address, tx_hash, block_number, gas_used
+        ),
+    )?;
+
+    Ok(tx_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Calldata tests ====================
+
+    #[test]
+    fn test_register_calldata() {
+        let contract = Address::from_low_u64_be(0xbeef);
+        let calldata = register_calldata(contract).unwrap();
+        assert_eq!(&calldata[..4], &ethers::utils::keccak256("registerContract(address)")[..4]);
+        assert_eq!(&calldata[16..], contract.as_bytes());
+    }
+}
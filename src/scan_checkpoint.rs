@@ -0,0 +1,246 @@
+//! On-disk checkpoints for long consecutive-empty scans.
+//! The streaming scan periodically writes its progress (next index, the current
+//! run of fresh addresses and every record so far) so a scan interrupted by a
+//! Ledger error, a cancel or an app restart can pick up where it stopped.
+
+use crate::balance::{BalanceScanRecord, BalanceScanResult, BalanceSnapshot};
+use crate::config::Config;
+use crate::scan_history::DerivationSettings;
+use anyhow::Result;
+use ethers::types::Address;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Sub-directory of the app data directory holding scan checkpoints
+const CHECKPOINT_DIR: &str = "scan_checkpoints";
+
+/// Write a checkpoint every this many scanned addresses (and always when the scan stops)
+pub const CHECKPOINT_INTERVAL: usize = 10;
+
+/// Progress of an unfinished consecutive-empty scan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanCheckpoint {
+    pub chain_id: u64,
+    pub derivation: DerivationSettings,
+    /// Consecutive fresh addresses the scan stops at
+    pub empty_target: u32,
+    /// Index the scan originally started from
+    pub start_index: u32,
+    /// Next index to derive
+    pub next_index: u32,
+    /// Current run of consecutive fresh addresses (its length is the consecutive-empty count)
+    pub empty_addresses: Vec<(u32, Address)>,
+    pub records: Vec<BalanceScanRecord>,
+    /// Historical snapshot the scan reads balances at; resumed scans reuse the same block
+    pub snapshot: Option<BalanceSnapshot>,
+    /// Unix timestamp (seconds) of the last write
+    pub updated_at: i64,
+}
+
+impl ScanCheckpoint {
+    pub fn new(config: &Config, empty_target: u32, start_index: u32, snapshot: Option<BalanceSnapshot>) -> Self {
+        Self {
+            chain_id: config.chain_id,
+            derivation: DerivationSettings::from_config(config),
+            empty_target,
+            start_index,
+            next_index: start_index,
+            empty_addresses: Vec::new(),
+            records: Vec::new(),
+            snapshot,
+            updated_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// Record a scanned address and advance to the next index
+    pub fn push_record(&mut self, record: BalanceScanRecord) {
+        if record.activity.is_fresh() {
+            self.empty_addresses.push((record.index, record.address));
+        } else {
+            self.empty_addresses.clear();
+        }
+        self.next_index = record.index + 1;
+        self.records.push(record);
+    }
+
+    pub fn consecutive_empty(&self) -> u32 {
+        self.empty_addresses.len() as u32
+    }
+
+    pub fn met_target(&self) -> bool {
+        self.consecutive_empty() >= self.empty_target
+    }
+
+    pub fn last_scanned_index(&self) -> u32 {
+        self.next_index.saturating_sub(1)
+    }
+
+    /// Whether the checkpoint was made on the same chain with the same derivation settings
+    pub fn matches_config(&self, config: &Config) -> bool {
+        self.chain_id == config.chain_id && self.derivation == DerivationSettings::from_config(config)
+    }
+
+    /// Build the scan result for the progress so far
+    pub fn to_result(&self, cancelled: bool) -> BalanceScanResult {
+        BalanceScanResult {
+            records: self.records.clone(),
+            empty_addresses: self.empty_addresses.clone(),
+            last_scanned_index: self.last_scanned_index(),
+            met_target: self.met_target(),
+            cancelled,
+            snapshot: self.snapshot,
+        }
+    }
+
+    pub fn updated_at_display(&self) -> String {
+        chrono::DateTime::from_timestamp(self.updated_at, 0)
+            .map(|dt| {
+                dt.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_else(|| self.updated_at.to_string())
+    }
+
+    /// One-line description for the resume prompt
+    pub fn describe(&self) -> String {
+        let mut text = format!(
+            "{} addresses scanned (index {} to {}), {} of {} consecutive fresh",
+            self.records.len(),
+            self.start_index,
+            self.last_scanned_index(),
+            self.consecutive_empty(),
+            self.empty_target
+        );
+        if let Some(snapshot) = &self.snapshot {
+            text.push_str(&format!(", balances at {}", snapshot.describe()));
+        }
+        text
+    }
+}
+
+fn checkpoint_path(chain_id: u64) -> PathBuf {
+    crate::operation_log::app_data_dir()
+        .join(CHECKPOINT_DIR)
+        .join(format!("{}.json", chain_id))
+}
+
+/// Persist a checkpoint, replacing any previous one for the same chain
+pub fn save_checkpoint(checkpoint: &ScanCheckpoint) -> Result<()> {
+    let path = checkpoint_path(checkpoint.chain_id);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut checkpoint = checkpoint.clone();
+    checkpoint.updated_at = chrono::Utc::now().timestamp();
+
+    // Write to a temporary file first so a crash mid-write never corrupts the checkpoint
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_string(&checkpoint)?)?;
+    fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+/// Load the checkpoint for this chain if it matches the current derivation settings
+pub fn load_checkpoint(config: &Config) -> Option<ScanCheckpoint> {
+    let content = fs::read_to_string(checkpoint_path(config.chain_id)).ok()?;
+    match serde_json::from_str::<ScanCheckpoint>(&content) {
+        Ok(checkpoint) if checkpoint.matches_config(config) && !checkpoint.records.is_empty() => Some(checkpoint),
+        Ok(_) => None,
+        Err(e) => {
+            tracing::warn!("Ignoring unreadable scan checkpoint: {}", e);
+            None
+        }
+    }
+}
+
+/// Remove the checkpoint for a chain (finished or discarded scans)
+pub fn clear_checkpoint(chain_id: u64) -> Result<()> {
+    let path = checkpoint_path(chain_id);
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance::AddressActivity;
+    use crate::config::DerivationMode;
+    use ethers::types::U256;
+
+    fn record(index: u32, balance: u64) -> BalanceScanRecord {
+        let balance = U256::from(balance);
+        BalanceScanRecord {
+            index,
+            address: Address::from_low_u64_be(index as u64 + 1),
+            balance,
+            nonce: 0,
+            activity: AddressActivity::classify(balance, 0, false),
+            derivation_path: format!("m/44'/60'/{}'/0/0", index),
+        }
+    }
+
+    fn config() -> Config {
+        Config::new("http://localhost:8545".to_string(), 1)
+    }
+
+    // ==================== ScanCheckpoint tests ====================
+
+    #[test]
+    fn test_push_record_tracks_consecutive_empty() {
+        let mut checkpoint = ScanCheckpoint::new(&config(), 3, 5, None);
+        checkpoint.push_record(record(5, 0));
+        checkpoint.push_record(record(6, 0));
+        assert_eq!(checkpoint.consecutive_empty(), 2);
+
+        checkpoint.push_record(record(7, 100));
+        assert_eq!(checkpoint.consecutive_empty(), 0);
+
+        checkpoint.push_record(record(8, 0));
+        assert_eq!(checkpoint.consecutive_empty(), 1);
+        assert_eq!(checkpoint.next_index, 9);
+        assert_eq!(checkpoint.last_scanned_index(), 8);
+        assert_eq!(checkpoint.records.len(), 4);
+        assert!(!checkpoint.met_target());
+    }
+
+    #[test]
+    fn test_to_result() {
+        let mut checkpoint = ScanCheckpoint::new(&config(), 2, 0, None);
+        checkpoint.push_record(record(0, 100));
+        checkpoint.push_record(record(1, 0));
+        checkpoint.push_record(record(2, 0));
+
+        let result = checkpoint.to_result(false);
+        assert!(result.met_target);
+        assert_eq!(result.last_scanned_index, 2);
+        assert_eq!(result.empty_addresses.len(), 2);
+        assert_eq!(result.records.len(), 3);
+    }
+
+    #[test]
+    fn test_empty_checkpoint_last_index() {
+        let checkpoint = ScanCheckpoint::new(&config(), 5, 0, None);
+        assert_eq!(checkpoint.last_scanned_index(), 0);
+        assert_eq!(checkpoint.consecutive_empty(), 0);
+    }
+
+    #[test]
+    fn test_checkpoint_roundtrip_and_matching() {
+        let mut checkpoint = ScanCheckpoint::new(&config(), 5, 0, None);
+        checkpoint.push_record(record(0, 0));
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let loaded: ScanCheckpoint = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.next_index, 1);
+        assert_eq!(loaded.consecutive_empty(), 1);
+        assert!(loaded.matches_config(&config()));
+
+        let mut other = config();
+        other.derivation_mode = DerivationMode::AddressIndex;
+        assert!(!loaded.matches_config(&other));
+    }
+}
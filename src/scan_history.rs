@@ -0,0 +1,395 @@
+//! Persistent history of completed balance scans.
+//! Each scan is stored as a JSON file in the app data directory so it can be
+//! reopened, compared against later scans, or reused as a source of funded and
+//! fresh addresses without touching the Ledger again.
+
+use crate::balance::{BalanceScanRecord, BalanceScanResult};
+use crate::config::{Config, DerivationMode};
+use anyhow::{anyhow, Result};
+use ethers::types::{Address, U256};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+/// Sub-directory of the app data directory holding stored scans
+const SCAN_HISTORY_DIR: &str = "scans";
+
+/// Stored scans older than this are not offered for reuse by split / disperse
+pub const RECENT_SCAN_MAX_AGE_SECS: i64 = 24 * 60 * 60;
+
+/// Derivation settings a scan was made with. Scans are only comparable when these match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DerivationSettings {
+    pub mode: DerivationMode,
+    pub custom_account: u32,
+    pub custom_address_index: u32,
+    pub coin_type: u32,
+}
+
+impl DerivationSettings {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            mode: config.derivation_mode,
+            custom_account: config.custom_account,
+            custom_address_index: config.custom_address_index,
+            coin_type: config.coin_type,
+        }
+    }
+}
+
+/// A completed scan together with the context it was made in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredScan {
+    pub id: String,
+    pub chain_id: u64,
+    pub network_label: String,
+    pub native_token: String,
+    pub derivation: DerivationSettings,
+    /// Unix timestamp (seconds) of when the scan completed
+    pub scanned_at: i64,
+    pub result: BalanceScanResult,
+}
+
+impl StoredScan {
+    pub fn new(config: &Config, result: BalanceScanResult) -> Self {
+        let now = chrono::Local::now();
+        Self {
+            id: format!("{}_{}", now.format("%Y%m%d_%H%M%S"), config.chain_id),
+            chain_id: config.chain_id,
+            network_label: config.network_label().to_string(),
+            native_token: config.native_token().to_string(),
+            derivation: DerivationSettings::from_config(config),
+            scanned_at: now.timestamp(),
+            result,
+        }
+    }
+
+    pub fn total_balance(&self) -> U256 {
+        self.result
+            .records
+            .iter()
+            .fold(U256::zero(), |acc, r| acc + r.balance)
+    }
+
+    pub fn funded_records(&self) -> Vec<BalanceScanRecord> {
+        self.result
+            .records
+            .iter()
+            .filter(|r| !r.balance.is_zero())
+            .cloned()
+            .collect()
+    }
+
+    pub fn fresh_records(&self) -> Vec<BalanceScanRecord> {
+        self.result
+            .records
+            .iter()
+            .filter(|r| r.activity.is_fresh())
+            .cloned()
+            .collect()
+    }
+
+    pub fn scanned_at_display(&self) -> String {
+        chrono::DateTime::from_timestamp(self.scanned_at, 0)
+            .map(|dt| {
+                dt.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_else(|| self.scanned_at.to_string())
+    }
+
+    /// Age of the scan in seconds
+    pub fn age_secs(&self) -> i64 {
+        chrono::Utc::now().timestamp() - self.scanned_at
+    }
+
+    /// Whether the scan was made on the same chain with the same derivation settings
+    pub fn matches_config(&self, config: &Config) -> bool {
+        self.chain_id == config.chain_id && self.derivation == DerivationSettings::from_config(config)
+    }
+}
+
+fn history_dir() -> PathBuf {
+    crate::operation_log::app_data_dir().join(SCAN_HISTORY_DIR)
+}
+
+fn scan_path(id: &str) -> PathBuf {
+    history_dir().join(format!("{}.json", id))
+}
+
+/// Persist a completed scan, returning its id
+pub fn save_scan(scan: &StoredScan) -> Result<String> {
+    let dir = history_dir();
+    fs::create_dir_all(&dir)?;
+
+    // Two scans completing within the same second on the same chain get a suffix
+    let mut id = scan.id.clone();
+    let mut suffix = 1;
+    while scan_path(&id).exists() {
+        suffix += 1;
+        id = format!("{}_{}", scan.id, suffix);
+    }
+
+    let mut scan = scan.clone();
+    scan.id = id.clone();
+    fs::write(scan_path(&id), serde_json::to_string_pretty(&scan)?)?;
+    tracing::info!("Saved balance scan {} to {:?}", id, dir);
+    Ok(id)
+}
+
+/// Load every stored scan, newest first. Unreadable files are skipped.
+pub fn load_scans() -> Result<Vec<StoredScan>> {
+    let dir = history_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut scans = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        match fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| serde_json::from_str::<StoredScan>(&content).map_err(anyhow::Error::from))
+        {
+            Ok(scan) => scans.push(scan),
+            Err(e) => tracing::warn!("Skipping unreadable scan file {:?}: {}", path, e),
+        }
+    }
+
+    scans.sort_by_key(|scan| std::cmp::Reverse(scan.scanned_at));
+    Ok(scans)
+}
+
+/// Delete a stored scan
+pub fn delete_scan(id: &str) -> Result<()> {
+    let path = scan_path(id);
+    if !path.exists() {
+        return Err(anyhow!("Scan {} not found", id));
+    }
+    fs::remove_file(path)?;
+    Ok(())
+}
+
+/// Most recent stored scan for this chain and derivation settings, if younger than
+/// [`RECENT_SCAN_MAX_AGE_SECS`]. Historical snapshots are never offered for reuse.
+pub fn recent_scan_for(config: &Config) -> Option<StoredScan> {
+    load_scans().ok()?.into_iter().find(|scan| {
+        scan.matches_config(config)
+            && scan.result.snapshot.is_none()
+            && scan.age_secs() <= RECENT_SCAN_MAX_AGE_SECS
+    })
+}
+
+/// Balance change of a single address between two scans
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceDelta {
+    pub index: u32,
+    pub address: Address,
+    pub derivation_path: String,
+    pub old_balance: U256,
+    pub new_balance: U256,
+}
+
+impl BalanceDelta {
+    /// True when the balance went up
+    pub fn is_increase(&self) -> bool {
+        self.new_balance > self.old_balance
+    }
+
+    /// Absolute size of the change
+    pub fn magnitude(&self) -> U256 {
+        if self.is_increase() {
+            self.new_balance - self.old_balance
+        } else {
+            self.old_balance - self.new_balance
+        }
+    }
+}
+
+/// Differences between an older and a newer scan
+#[derive(Debug, Clone, Default)]
+pub struct ScanDiff {
+    /// Addresses that were empty (or not scanned) before and now hold funds
+    pub new_funds: Vec<BalanceDelta>,
+    /// Addresses that held funds before and are now empty
+    pub drained: Vec<BalanceDelta>,
+    /// Addresses funded in both scans whose balance changed
+    pub changed: Vec<BalanceDelta>,
+    /// Funded records of the older scan whose address the newer scan did not reach; their
+    /// current balance is unknown
+    pub removed: Vec<BalanceScanRecord>,
+    pub old_total: U256,
+    pub new_total: U256,
+}
+
+impl ScanDiff {
+    pub fn is_empty(&self) -> bool {
+        self.new_funds.is_empty() && self.drained.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Compare two scans address by address
+pub fn diff_scans(old: &BalanceScanResult, new: &BalanceScanResult) -> ScanDiff {
+    let old_balances: HashMap<Address, U256> =
+        old.records.iter().map(|r| (r.address, r.balance)).collect();
+
+    let mut diff = ScanDiff {
+        old_total: old.records.iter().fold(U256::zero(), |acc, r| acc + r.balance),
+        new_total: new.records.iter().fold(U256::zero(), |acc, r| acc + r.balance),
+        ..Default::default()
+    };
+
+    for record in &new.records {
+        let old_balance = old_balances.get(&record.address).copied().unwrap_or_default();
+        if old_balance == record.balance {
+            continue;
+        }
+
+        let delta = BalanceDelta {
+            index: record.index,
+            address: record.address,
+            derivation_path: record.derivation_path.clone(),
+            old_balance,
+            new_balance: record.balance,
+        };
+
+        if old_balance.is_zero() {
+            diff.new_funds.push(delta);
+        } else if record.balance.is_zero() {
+            diff.drained.push(delta);
+        } else {
+            diff.changed.push(delta);
+        }
+    }
+
+    let new_addresses: HashSet<Address> = new.records.iter().map(|r| r.address).collect();
+    diff.removed = old
+        .records
+        .iter()
+        .filter(|r| !r.balance.is_zero() && !new_addresses.contains(&r.address))
+        .cloned()
+        .collect();
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance::AddressActivity;
+
+    fn record(index: u32, balance: u64) -> BalanceScanRecord {
+        let balance = U256::from(balance);
+        BalanceScanRecord {
+            index,
+            address: Address::from_low_u64_be(index as u64 + 1),
+            balance,
+            nonce: 0,
+            activity: AddressActivity::classify(balance, 0, false),
+            derivation_path: format!("m/44'/60'/{}'/0/0", index),
+        }
+    }
+
+    fn result(records: Vec<BalanceScanRecord>) -> BalanceScanResult {
+        BalanceScanResult {
+            records,
+            empty_addresses: Vec::new(),
+            last_scanned_index: 0,
+            met_target: true,
+            cancelled: false,
+            snapshot: None,
+        }
+    }
+
+    // ==================== diff_scans tests ====================
+
+    #[test]
+    fn test_diff_identical_scans() {
+        let scan = result(vec![record(0, 100), record(1, 0)]);
+        let diff = diff_scans(&scan, &scan);
+        assert!(diff.is_empty());
+        assert_eq!(diff.old_total, diff.new_total);
+    }
+
+    #[test]
+    fn test_diff_categories() {
+        let old = result(vec![record(0, 100), record(1, 0), record(2, 50)]);
+        let new = result(vec![record(0, 0), record(1, 25), record(2, 80), record(3, 10)]);
+        let diff = diff_scans(&old, &new);
+
+        assert_eq!(diff.drained.len(), 1);
+        assert_eq!(diff.drained[0].index, 0);
+
+        // Index 3 was not in the old scan, so it counts as new funds
+        let new_indexes: Vec<u32> = diff.new_funds.iter().map(|d| d.index).collect();
+        assert_eq!(new_indexes, vec![1, 3]);
+
+        assert_eq!(diff.changed.len(), 1);
+        assert!(diff.changed[0].is_increase());
+        assert_eq!(diff.changed[0].magnitude(), U256::from(30u64));
+
+        assert_eq!(diff.old_total, U256::from(150u64));
+        assert_eq!(diff.new_total, U256::from(115u64));
+    }
+
+    #[test]
+    fn test_diff_reports_addresses_missing_from_new_scan() {
+        let old = result(vec![record(0, 100), record(1, 40), record(2, 0)]);
+        let new = result(vec![record(0, 100)]);
+        let diff = diff_scans(&old, &new);
+
+        assert!(!diff.is_empty());
+        assert!(diff.drained.is_empty());
+        // Index 2 was already empty, so only index 1 went missing with funds
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].index, 1);
+        assert_eq!(diff.removed[0].balance, U256::from(40u64));
+    }
+
+    #[test]
+    fn test_delta_magnitude_decrease() {
+        let delta = BalanceDelta {
+            index: 0,
+            address: Address::zero(),
+            derivation_path: String::new(),
+            old_balance: U256::from(80u64),
+            new_balance: U256::from(30u64),
+        };
+        assert!(!delta.is_increase());
+        assert_eq!(delta.magnitude(), U256::from(50u64));
+    }
+
+    // ==================== StoredScan tests ====================
+
+    #[test]
+    fn test_stored_scan_roundtrip() {
+        let config = Config::new("http://localhost:8545".to_string(), 1);
+        let scan = StoredScan::new(&config, result(vec![record(0, 100), record(1, 0)]));
+        let json = serde_json::to_string(&scan).unwrap();
+        let loaded: StoredScan = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.result.records.len(), 2);
+        assert_eq!(loaded.result.records[0].balance, U256::from(100u64));
+        assert_eq!(loaded.result.records[1].activity, AddressActivity::Fresh);
+        assert_eq!(loaded.total_balance(), U256::from(100u64));
+        assert!(loaded.matches_config(&config));
+    }
+
+    #[test]
+    fn test_matches_config_requires_same_derivation() {
+        let config = Config::new("http://localhost:8545".to_string(), 1);
+        let scan = StoredScan::new(&config, result(Vec::new()));
+
+        let mut other = config.clone();
+        other.derivation_mode = DerivationMode::AddressIndex;
+        assert!(!scan.matches_config(&other));
+
+        let other_chain = Config::new("http://localhost:8545".to_string(), 8453);
+        assert!(!scan.matches_config(&other_chain));
+    }
+}
@@ -0,0 +1,476 @@
+//! Scheduled dispersals.
+//! A split or native bulk disperse can be queued to run at a wall-clock time or at a
+//! block height. The plan keeps the inputs of the form it was scheduled from (ENS names
+//! already replaced by their addresses); when the run is due, the source balance and the
+//! network fee are checked again before the Ledger is asked to sign.
+//!
+//! Runs only fire while the app is open on the run's network. A run that cannot start
+//! within [`MISSED_GRACE_SECS`] / [`MISSED_GRACE_BLOCKS`] of its trigger is marked missed;
+//! missed, cancelled and failed runs are written to the operation log.
+
+use crate::config::Config;
+use crate::fee_oracle::{self, FeeSnapshot};
+use crate::operation_log;
+use crate::recipient_input::{RecipientAmount, RecipientLine};
+use crate::utils;
+use anyhow::{anyhow, Result};
+use ethers::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// File in the app data directory holding the schedule
+const SCHEDULE_FILE: &str = "schedules.json";
+
+/// A time-triggered run is missed when it could not start this long after its time
+pub const MISSED_GRACE_SECS: i64 = 15 * 60;
+
+/// A block-triggered run is missed when it could not start this many blocks after its block
+pub const MISSED_GRACE_BLOCKS: u64 = 100;
+
+/// Seconds between scheduler checks of the pending runs
+pub const TICK_SECS: u64 = 5;
+
+/// Gas of one plain transfer, used to estimate split fees
+const TRANSFER_GAS: u64 = 21_000;
+
+/// When a scheduled run is due
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScheduleTrigger {
+    /// Unix timestamp (seconds)
+    At(i64),
+    /// Block height on the run's network
+    Block(u64),
+}
+
+impl ScheduleTrigger {
+    /// Parse user input: `#<block>` or `block <n>` for a block height, `+30m` / `+2h` / `+1d`
+    /// relative to `now`, or a local date/time (`YYYY-MM-DD HH:MM[:SS]` or RFC 3339)
+    pub fn parse(input: &str, now: i64) -> Result<Self> {
+        let input = input.trim();
+        let lower = input.to_ascii_lowercase();
+        if let Some(block) = lower.strip_prefix('#').or_else(|| lower.strip_prefix("block")) {
+            let block = block.trim().parse::<u64>().map_err(|_| anyhow!("Invalid block number '{}'", input))?;
+            return Ok(ScheduleTrigger::Block(block));
+        }
+        if let Some(offset) = lower.strip_prefix('+') {
+            let (number, unit_secs) = match offset.chars().last() {
+                Some('m') => (&offset[..offset.len() - 1], 60),
+                Some('h') => (&offset[..offset.len() - 1], 60 * 60),
+                Some('d') => (&offset[..offset.len() - 1], 24 * 60 * 60),
+                _ => return Err(anyhow!("Use +<n>m, +<n>h or +<n>d for a relative time")),
+            };
+            let number = number.trim().parse::<i64>().map_err(|_| anyhow!("Invalid relative time '{}'", input))?;
+            return Ok(ScheduleTrigger::At(now + number * unit_secs));
+        }
+
+        let timestamp = if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(input) {
+            dt.timestamp()
+        } else if let Some(dt) = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+            .iter()
+            .find_map(|fmt| chrono::NaiveDateTime::parse_from_str(input, fmt).ok())
+        {
+            dt.and_local_timezone(chrono::Local)
+                .earliest()
+                .ok_or_else(|| anyhow!("{} does not exist in the local time zone", input))?
+                .timestamp()
+        } else {
+            return Err(anyhow!(
+                "Invalid time '{}'. Use a local date like 2025-01-31 12:00, +30m, or #<block>",
+                input
+            ));
+        };
+        Ok(ScheduleTrigger::At(timestamp))
+    }
+
+    /// True once the trigger time or block has been reached. Block triggers are never due
+    /// before the current block is known.
+    pub fn is_due(&self, now: i64, block: Option<u64>) -> bool {
+        match self {
+            ScheduleTrigger::At(at) => now >= *at,
+            ScheduleTrigger::Block(target) => block.is_some_and(|block| block >= *target),
+        }
+    }
+
+    /// True when the run is too late to start
+    pub fn is_missed(&self, now: i64, block: Option<u64>) -> bool {
+        match self {
+            ScheduleTrigger::At(at) => now > at + MISSED_GRACE_SECS,
+            ScheduleTrigger::Block(target) => block.is_some_and(|block| block > target + MISSED_GRACE_BLOCKS),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            ScheduleTrigger::At(at) => chrono::DateTime::from_timestamp(*at, 0)
+                .map(|dt| dt.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_else(|| format!("unix time {}", at)),
+            ScheduleTrigger::Block(block) => format!("block #{}", block),
+        }
+    }
+}
+
+/// What a scheduled run sends, as entered in the form it was scheduled from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SchedulePlan {
+    BulkDisperse {
+        /// Recipient list with addresses only (see [`canonical_recipient_list`])
+        recipients: String,
+        recipient_count: usize,
+        amount: U256,
+        contract: Option<String>,
+        source_index: u32,
+        source_address: Address,
+        tip: Option<U256>,
+        /// Balance to leave on the source
+        keep_on_source: U256,
+        gas_speed: f32,
+        /// Gas limit previewed when the run was scheduled; the run signs with it
+        gas_limit: u64,
+    },
+    Split {
+        /// Equal amounts, otherwise random
+        equal: bool,
+        outputs: u32,
+        source_index: usize,
+        /// Source address, when known from a scan
+        source_address: Option<Address>,
+        recipients: Option<Vec<String>>,
+        /// Balance to leave on the source, in wei
+        remaining_balance: Option<u64>,
+        gas_speed: Option<f32>,
+    },
+}
+
+impl SchedulePlan {
+    /// Short description for lists and the log
+    pub fn describe(&self, native_token: &str) -> String {
+        match self {
+            SchedulePlan::BulkDisperse { recipient_count, amount, source_index, .. } => format!(
+                "Bulk disperse of {} {} to {} recipients from index {}",
+                utils::format_ether(*amount),
+                native_token,
+                recipient_count,
+                source_index
+            ),
+            SchedulePlan::Split { equal, outputs, source_index, .. } => format!(
+                "{} split into {} outputs from index {}",
+                if *equal { "Equal" } else { "Random" },
+                outputs,
+                source_index
+            ),
+        }
+    }
+
+    fn source_address(&self) -> Option<Address> {
+        match self {
+            SchedulePlan::BulkDisperse { source_address, .. } => Some(*source_address),
+            SchedulePlan::Split { source_address, .. } => *source_address,
+        }
+    }
+
+    /// Gas of the whole run, and its speed multiplier
+    fn gas(&self, default_gas_speed: f32) -> (u64, f32) {
+        match self {
+            SchedulePlan::BulkDisperse { gas_limit, gas_speed, .. } => (*gas_limit, *gas_speed),
+            SchedulePlan::Split { outputs, gas_speed, .. } => {
+                (TRANSFER_GAS * u64::from(*outputs), gas_speed.unwrap_or(default_gas_speed))
+            }
+        }
+    }
+
+    /// Value that has to stay on or leave the source besides the fee
+    fn committed(&self) -> U256 {
+        match self {
+            SchedulePlan::BulkDisperse { amount, keep_on_source, .. } => *amount + *keep_on_source,
+            SchedulePlan::Split { remaining_balance, .. } => U256::from(remaining_balance.unwrap_or(0)),
+        }
+    }
+}
+
+/// Where a scheduled run stands
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ScheduleStatus {
+    Pending,
+    /// Checked and handed to the signer
+    Running,
+    Done(String),
+    Failed(String),
+    Missed(String),
+    Cancelled,
+}
+
+impl ScheduleStatus {
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, ScheduleStatus::Pending | ScheduleStatus::Running)
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            ScheduleStatus::Pending => "Pending".to_string(),
+            ScheduleStatus::Running => "Running".to_string(),
+            ScheduleStatus::Done(summary) => format!("Done: {}", summary),
+            ScheduleStatus::Failed(error) => format!("Failed: {}", error),
+            ScheduleStatus::Missed(reason) => format!("Missed: {}", reason),
+            ScheduleStatus::Cancelled => "Cancelled".to_string(),
+        }
+    }
+}
+
+/// A run queued for later
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledRun {
+    pub id: String,
+    pub label: String,
+    pub chain_id: u64,
+    pub network_label: String,
+    pub native_token: String,
+    pub trigger: ScheduleTrigger,
+    pub plan: SchedulePlan,
+    /// Highest gas price the run may start at; above it the run waits (and may be missed)
+    #[serde(default)]
+    pub max_gas_price: Option<U256>,
+    /// Unix timestamp (seconds) of when the run was scheduled
+    pub created_at: i64,
+    pub status: ScheduleStatus,
+}
+
+impl ScheduledRun {
+    pub fn new(
+        config: &Config,
+        label: String,
+        trigger: ScheduleTrigger,
+        plan: SchedulePlan,
+        max_gas_price: Option<U256>,
+    ) -> Self {
+        let now = chrono::Local::now();
+        Self {
+            id: format!("{}_{}", now.format("%Y%m%d_%H%M%S%3f"), config.chain_id),
+            label,
+            chain_id: config.chain_id,
+            network_label: config.network_label().to_string(),
+            native_token: config.native_token().to_string(),
+            trigger,
+            plan,
+            max_gas_price,
+            created_at: now.timestamp(),
+            status: ScheduleStatus::Pending,
+        }
+    }
+
+    /// Write a missed, cancelled or failed run (or any other status change) to the operation log
+    pub fn log_status(&self) {
+        let details = format!(
+            "Scheduled run '{}' on {} (Chain ID: {})\n{}\nTrigger: {}\nStatus: {}",
+            self.label,
+            self.network_label,
+            self.chain_id,
+            self.plan.describe(&self.native_token),
+            self.trigger.describe(),
+            self.status.label()
+        );
+        if let Err(e) = operation_log::append_log("Beaug Scheduled Run", self.chain_id, details) {
+            tracing::warn!("Failed to log scheduled run: {}", e);
+        }
+    }
+}
+
+/// Outcome of the checks made when a run is due
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TriggerCheck {
+    /// Balance and fees are fine; the summary is shown while signing
+    Ready(String),
+    /// Try again on the next tick (fees above the cap)
+    Wait(String),
+    /// The run cannot go ahead
+    Fail(String),
+}
+
+/// Decide whether a due run can start given the source `balance` (if known) and the network `fees`.
+/// The cap applies to the expected fee per gas; the balance has to cover the max fee.
+pub fn assess(run: &ScheduledRun, balance: Option<U256>, fees: &FeeSnapshot, default_gas_speed: f32) -> TriggerCheck {
+    let gwei = |wei: U256| ethers::utils::format_units(wei, "gwei").unwrap_or_default();
+    let (gas_limit, gas_speed) = run.plan.gas(default_gas_speed);
+    let quote = fees.quote(gas_speed);
+    if let Some(cap) = run.max_gas_price.filter(|cap| quote.expected_fee_per_gas > *cap) {
+        return TriggerCheck::Wait(format!(
+            "gas price {} gwei is above the {} gwei cap",
+            gwei(quote.expected_fee_per_gas),
+            gwei(cap)
+        ));
+    }
+
+    let fee = quote.max_cost(gas_limit);
+    let required = run.plan.committed() + fee;
+    match balance {
+        Some(balance) if balance < required => TriggerCheck::Fail(format!(
+            "source holds {} {} but needs {} {} (max fee {})",
+            utils::format_ether(balance),
+            run.native_token,
+            utils::format_ether(required),
+            run.native_token,
+            utils::format_ether(fee)
+        )),
+        Some(balance) => TriggerCheck::Ready(format!(
+            "balance {} {}, fee ~{} {} ({})",
+            utils::format_ether(balance),
+            run.native_token,
+            utils::format_ether(quote.expected_cost(gas_limit)),
+            run.native_token,
+            quote.describe()
+        )),
+        None => TriggerCheck::Ready(format!(
+            "fee ~{} {} ({}); source balance is checked while preparing",
+            utils::format_ether(quote.expected_cost(gas_limit)),
+            run.native_token,
+            quote.describe()
+        )),
+    }
+}
+
+/// Fetch the source balance and network fees a due run starts with, and assess them
+pub async fn check_trigger(config: Config, run: ScheduledRun, default_gas_speed: f32) -> Result<TriggerCheck> {
+    let provider = config.get_provider().await?;
+    let fees = fee_oracle::fetch_snapshot(&provider, config.chain_id).await?;
+    let balance = match run.plan.source_address() {
+        Some(address) => Some(provider.get_balance(address, None).await?),
+        None => None,
+    };
+    Ok(assess(&run, balance, &fees, default_gas_speed))
+}
+
+/// Recipient lines as `address[,amount]`, with fixed amounts in wei, so the list parses the
+/// same way later without ENS lookups or payment links
+pub fn canonical_recipient_list(lines: &[RecipientLine]) -> String {
+    lines
+        .iter()
+        .map(|line| match &line.amount {
+            None => format!("{:?}", line.address),
+            Some(amount) => format!("{:?},{}", line.address, amount_text(amount)),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn amount_text(amount: &RecipientAmount) -> String {
+    amount.describe(|value| format!("{}wei", value))
+}
+
+fn schedule_path() -> PathBuf {
+    operation_log::app_data_dir().join(SCHEDULE_FILE)
+}
+
+/// Load the schedule, oldest first; a missing file is an empty schedule
+pub fn load_runs() -> Result<Vec<ScheduledRun>> {
+    let path = schedule_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Persist the schedule
+pub fn save_runs(runs: &[ScheduledRun]) -> Result<()> {
+    fs::write(schedule_path(), serde_json::to_string_pretty(runs)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fee_oracle::FeeMarket;
+
+    fn bulk_run(max_gas_price: Option<U256>) -> ScheduledRun {
+        let config = Config::new("http://localhost:8545".to_string(), 1);
+        let plan = SchedulePlan::BulkDisperse {
+            recipients: String::new(),
+            recipient_count: 2,
+            amount: U256::exp10(18),
+            contract: None,
+            source_index: 0,
+            source_address: Address::zero(),
+            tip: None,
+            keep_on_source: U256::zero(),
+            gas_speed: 1.0,
+            gas_limit: 100_000,
+        };
+        ScheduledRun::new(&config, "Test".to_string(), ScheduleTrigger::Block(10), plan, max_gas_price)
+    }
+
+    // ==================== Trigger tests ====================
+
+    #[test]
+    fn test_parse_trigger() {
+        let now = 1_700_000_000;
+        assert_eq!(ScheduleTrigger::parse("#19000000", now).unwrap(), ScheduleTrigger::Block(19_000_000));
+        assert_eq!(ScheduleTrigger::parse("block 42", now).unwrap(), ScheduleTrigger::Block(42));
+        assert_eq!(ScheduleTrigger::parse("+30m", now).unwrap(), ScheduleTrigger::At(now + 1800));
+        assert_eq!(ScheduleTrigger::parse("+2h", now).unwrap(), ScheduleTrigger::At(now + 7200));
+        assert_eq!(
+            ScheduleTrigger::parse("2024-01-31T12:00:00Z", now).unwrap(),
+            ScheduleTrigger::At(1_706_702_400)
+        );
+        assert!(matches!(ScheduleTrigger::parse("2024-01-31 12:00", now), Ok(ScheduleTrigger::At(_))));
+        assert!(ScheduleTrigger::parse("tomorrow", now).is_err());
+        assert!(ScheduleTrigger::parse("+5x", now).is_err());
+    }
+
+    #[test]
+    fn test_trigger_due_and_missed() {
+        let at = ScheduleTrigger::At(1_000);
+        assert!(!at.is_due(999, None));
+        assert!(at.is_due(1_000, None));
+        assert!(!at.is_missed(1_000 + MISSED_GRACE_SECS, None));
+        assert!(at.is_missed(1_001 + MISSED_GRACE_SECS, None));
+
+        let block = ScheduleTrigger::Block(50);
+        assert!(!block.is_due(i64::MAX, None));
+        assert!(block.is_due(0, Some(50)));
+        assert!(block.is_missed(0, Some(51 + MISSED_GRACE_BLOCKS)));
+    }
+
+    // ==================== Trigger-time check tests ====================
+
+    #[test]
+    fn test_assess_balance_and_fee_cap() {
+        let gwei = U256::exp10(9);
+        let legacy = |gas_price: U256| FeeSnapshot { gas_price, market: None };
+        let run = bulk_run(Some(gwei * 50));
+        assert!(matches!(assess(&run, Some(U256::exp10(19)), &legacy(gwei * 60), 1.0), TriggerCheck::Wait(_)));
+        assert!(matches!(assess(&run, Some(U256::exp10(19)), &legacy(gwei * 20), 1.0), TriggerCheck::Ready(_)));
+        // 1 ETH plus 100k gas at 20 gwei does not fit in exactly 1 ETH
+        assert!(matches!(assess(&run, Some(U256::exp10(18)), &legacy(gwei * 20), 1.0), TriggerCheck::Fail(_)));
+
+        // The cap is checked against the expected fee (base 40 + tip 2), not the max fee (82)
+        let market = FeeSnapshot {
+            gas_price: gwei * 45,
+            market: Some(FeeMarket { base_fee: gwei * 40, priority_fees: [gwei, gwei * 2, gwei * 3] }),
+        };
+        assert!(matches!(assess(&run, Some(U256::exp10(19)), &market, 1.0), TriggerCheck::Ready(_)));
+    }
+
+    #[test]
+    fn test_canonical_recipient_list() {
+        let lines = vec![
+            RecipientLine { line: 1, text: "alice.eth".to_string(), address: Address::repeat_byte(1), amount: None },
+            RecipientLine {
+                line: 2,
+                text: "base:0x…".to_string(),
+                address: Address::repeat_byte(2),
+                amount: Some(RecipientAmount::Fixed(U256::from(5))),
+            },
+            RecipientLine {
+                line: 3,
+                text: String::new(),
+                address: Address::repeat_byte(3),
+                amount: Some(RecipientAmount::Percent(U256::from(25_000_000u64))),
+            },
+        ];
+        assert_eq!(
+            canonical_recipient_list(&lines),
+            "0x0101010101010101010101010101010101010101\n\
+             0x0202020202020202020202020202020202020202,5wei\n\
+             0x0303030303030303030303030303030303030303,25%"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Pre-flight simulation of transactions before they are signed.
+//! Each call is run with `eth_call` from the real sender with the real value and calldata,
+//! and revert data is decoded into a readable reason: `Error(string)`, `Panic(uint256)`
+//! or one of the custom errors of the Beaug contract.
+
+use crate::ledger_transaction_manager::PendingTransaction;
+use anyhow::{anyhow, Result};
+use ethers::abi::{Abi, Token};
+use ethers::prelude::*;
+use ethers::providers::RpcError;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Selector of `Error(string)`, used by `require(cond, "reason")` and `revert("reason")`
+pub const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of `Panic(uint256)`, used for failed asserts, overflows and similar
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Custom errors declared by the Beaug contract
+const BEAUG_ERRORS: &[&str] = &[
+    "error ArrayLengthMismatch()",
+    "error NoRecipients()",
+    "error IncorrectValue(uint256 sent, uint256 required)",
+    "error ZeroAddressRecipient(uint256 index)",
+    "error TransferFailed(address recipient, uint256 amount)",
+    "error NotOwner()",
+    "error NotPendingOwner()",
+    "error NoStuckFunds()",
+    "error AlreadyRegistered(address contractAddr)",
+    "error NotAContract(address contractAddr)",
+];
+
+static BEAUG_ERRORS_ABI: OnceLock<Abi> = OnceLock::new();
+
+fn beaug_errors_abi() -> &'static Abi {
+    BEAUG_ERRORS_ABI.get_or_init(|| {
+        ethers::abi::parse_abi(BEAUG_ERRORS)
+            .expect("BEAUG_ERRORS signatures are invalid - this is a programming error")
+    })
+}
+
+/// Why a simulated call reverted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevertReason {
+    /// `Error(string)` from `require` / `revert`
+    Message(String),
+    /// `Panic(uint256)` with its code
+    Panic(U256),
+    /// A custom error from the Beaug ABI, with its arguments formatted
+    Custom { name: String, args: Vec<String> },
+    /// Revert data that matches no known error
+    Unknown(Bytes),
+    /// The call reverted without data (e.g. a recipient that rejects ETH)
+    Empty,
+    /// The node rejected the call outright (e.g. insufficient funds)
+    Rejected(String),
+}
+
+impl fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RevertReason::Message(message) => write!(f, "reverted: {}", message),
+            RevertReason::Panic(code) => write!(f, "panic 0x{:02x}: {}", code, panic_description(*code)),
+            RevertReason::Custom { name, args } => write!(f, "{}({})", name, args.join(", ")),
+            RevertReason::Unknown(data) => write!(f, "reverted with unknown error data {}", data),
+            RevertReason::Empty => write!(f, "reverted without a reason (a recipient may reject the transfer)"),
+            RevertReason::Rejected(message) => write!(f, "rejected by the node: {}", message),
+        }
+    }
+}
+
+/// Meaning of a Solidity panic code
+pub fn panic_description(code: U256) -> &'static str {
+    if code > U256::from(u8::MAX) {
+        return "unknown panic code";
+    }
+    match code.as_u64() {
+        0x00 => "generic compiler panic",
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "invalid storage byte array",
+        0x31 => "pop on an empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory",
+        0x51 => "call to an uninitialized function",
+        _ => "unknown panic code",
+    }
+}
+
+fn format_token(token: &Token) -> String {
+    match token {
+        Token::Uint(value) | Token::Int(value) => value.to_string(),
+        Token::Address(address) => format!("{:?}", address),
+        Token::Bool(value) => value.to_string(),
+        Token::String(value) => value.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Decode revert data returned by `eth_call`
+pub fn decode_revert_data(data: &[u8]) -> RevertReason {
+    if data.is_empty() {
+        return RevertReason::Empty;
+    }
+    if data.len() < 4 {
+        return RevertReason::Unknown(Bytes::from(data.to_vec()));
+    }
+
+    let (selector, payload) = data.split_at(4);
+    if selector == ERROR_STRING_SELECTOR {
+        if let Ok(tokens) = ethers::abi::decode(&[ethers::abi::ParamType::String], payload) {
+            if let Some(Token::String(message)) = tokens.into_iter().next() {
+                return RevertReason::Message(message);
+            }
+        }
+    } else if selector == PANIC_SELECTOR {
+        if let Ok(tokens) = ethers::abi::decode(&[ethers::abi::ParamType::Uint(256)], payload) {
+            if let Some(Token::Uint(code)) = tokens.into_iter().next() {
+                return RevertReason::Panic(code);
+            }
+        }
+    } else {
+        for error in beaug_errors_abi().errors() {
+            if error.signature()[..4] != *selector {
+                continue;
+            }
+            if let Ok(tokens) = error.decode(payload) {
+                let args = error
+                    .inputs
+                    .iter()
+                    .zip(tokens.iter())
+                    .map(|(param, token)| {
+                        if param.name.is_empty() {
+                            format_token(token)
+                        } else {
+                            format!("{}: {}", param.name, format_token(token))
+                        }
+                    })
+                    .collect();
+                return RevertReason::Custom { name: error.name.clone(), args };
+            }
+        }
+    }
+
+    RevertReason::Unknown(Bytes::from(data.to_vec()))
+}
+
+/// Simulate a call with `eth_call`. Returns `None` if it would succeed and the decoded reason
+/// if it would revert; errors only when the node could not be reached.
+pub async fn simulate_call(
+    provider: &Provider<Http>,
+    from: Address,
+    to: Address,
+    calldata: Vec<u8>,
+    value: U256,
+) -> Result<Option<RevertReason>> {
+    let tx: TypedTransaction = TransactionRequest::new()
+        .from(from)
+        .to(to)
+        .data(calldata)
+        .value(value)
+        .into();
+
+    match provider.call(&tx, None).await {
+        Ok(_) => Ok(None),
+        Err(e) => match RpcError::as_error_response(&e) {
+            Some(response) => match response.as_revert_data() {
+                Some(data) => Ok(Some(decode_revert_data(data.as_ref()))),
+                None => Ok(Some(RevertReason::Rejected(response.message.clone()))),
+            },
+            None => Err(anyhow!("Simulation failed: {}", e)),
+        },
+    }
+}
+
+/// Simulate every prepared transaction in order, failing with the first readable revert reason
+pub async fn simulate_pending_transactions(
+    provider: &Provider<Http>,
+    from: Address,
+    transactions: &[(PendingTransaction, String, String)],
+) -> Result<()> {
+    for (tx, description, _) in transactions {
+        if let Some(reason) = simulate_call(provider, from, tx.to, tx.data.clone(), tx.value).await? {
+            return Err(anyhow!("Simulation of {} to {:?} failed: {}", description, tx.to, reason));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Revert decoding tests ====================
+
+    #[test]
+    fn test_decode_error_string() {
+        let mut data = ERROR_STRING_SELECTOR.to_vec();
+        data.extend(ethers::abi::encode(&[Token::String("Insufficient value".to_string())]));
+        assert_eq!(decode_revert_data(&data), RevertReason::Message("Insufficient value".to_string()));
+    }
+
+    #[test]
+    fn test_decode_panic() {
+        let mut data = PANIC_SELECTOR.to_vec();
+        data.extend(ethers::abi::encode(&[Token::Uint(U256::from(0x11))]));
+        let reason = decode_revert_data(&data);
+        assert_eq!(reason, RevertReason::Panic(U256::from(0x11)));
+        assert_eq!(reason.to_string(), "panic 0x11: arithmetic overflow or underflow");
+    }
+
+    #[test]
+    fn test_decode_beaug_custom_error() {
+        let selector = &ethers::utils::keccak256("IncorrectValue(uint256,uint256)")[..4];
+        let mut data = selector.to_vec();
+        data.extend(ethers::abi::encode(&[Token::Uint(U256::from(5)), Token::Uint(U256::from(7))]));
+        assert_eq!(
+            decode_revert_data(&data).to_string(),
+            "IncorrectValue(sent: 5, required: 7)"
+        );
+    }
+
+    #[test]
+    fn test_decode_empty_and_unknown() {
+        assert_eq!(decode_revert_data(&[]), RevertReason::Empty);
+        assert!(matches!(decode_revert_data(&[0xde, 0xad, 0xbe, 0xef]), RevertReason::Unknown(_)));
+    }
+}
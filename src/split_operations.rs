@@ -2,8 +2,10 @@
 //! Supports equal and random distribution modes with transaction queue management.
 
 use crate::config::Config;
+use crate::fee_oracle::{self, TxFees};
 use crate::ledger_dispatch;
 use crate::ledger_transaction_manager::{LedgerTransactionManager, PendingTransaction, TransactionManagerConfig};
+use crate::simulation;
 use crate::types::AccountInfo;
 use crate::utils;
 use ethers::prelude::*;
@@ -166,7 +168,7 @@ fn prepare_random_transactions(
     tx_fee: U256,
     _gas_reserve: U256, // Unused, we calculate dynamically
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     operation_name: &str,
     remaining_balance_wei: U256,
 ) -> Result<Vec<PendingTransaction>> {
@@ -222,8 +224,9 @@ fn prepare_random_transactions(
         transactions.push(PendingTransaction {
             to: receiver.address,
             value: amount,
+            data: Vec::new(),
             gas_limit,
-            gas_price,
+            fees,
             operation_name: format!("{}_to_{}", operation_name, receiver.index),
         });
 
@@ -241,7 +244,7 @@ fn prepare_equal_transactions(
     min_transfer_amount: U256,
     tx_fee: U256,
     gas_limit: u64,
-    gas_price: U256,
+    fees: TxFees,
     operation_name: &str,
     remaining_balance_wei: U256,
 ) -> Result<Vec<PendingTransaction>> {
@@ -277,8 +280,9 @@ fn prepare_equal_transactions(
         .map(|(idx, receiver)| PendingTransaction {
             to: receiver.address,
             value: amount_per_receiver,
-                    gas_limit,
-                    gas_price,
+            data: Vec::new(),
+            gas_limit,
+            fees,
             operation_name: format!("{}_equal_{}", operation_name, idx),
         })
         .collect();
@@ -353,7 +357,7 @@ pub async fn prepare_split_transactions(
         // Use provided addresses
         let mut receivers = Vec::new();
         for addr_str in addresses {
-            let addr: Address = addr_str.parse().map_err(|_| anyhow!("Invalid address: {}", addr_str))?;
+            let addr = crate::ens::parse_address_or_name(addr_str)?;
             let balance = provider.get_balance(addr, None).await?;
             let nonce = provider.get_transaction_count(addr, None).await?.as_u64();
 
@@ -381,30 +385,21 @@ pub async fn prepare_split_transactions(
         return Err(anyhow!(error_msg));
     }
 
-    // Get gas price
-    let gas_price = provider.get_gas_price().await?;
-    info!(
-        "Current Gas Price: {} Gwei",
-        ethers::utils::format_units(gas_price, "gwei")?
-    );
-
     // Calculate transaction parameters
     let gas_limit = 21000u64;
-    
-    // Apply gas speed multiplier to gas price for faster/more reliable transactions
+
+    // Price the transfers from the fee history (or the gas price on legacy chains) at the chosen speed
     let gas_speed = gas_speed_override.unwrap_or(config.gas_speed_multiplier);
-    // Convert f32 multiplier to integer basis points for U256 math (e.g., 1.5 -> 150)
-    let gas_speed_bp = (gas_speed * 100.0) as u64;
-    let effective_gas_price = gas_price * U256::from(gas_speed_bp) / U256::from(100u64);
-    let tx_fee = effective_gas_price * U256::from(gas_limit);
+    let fee_quote = fee_oracle::quote_fees(&provider, chain_id, gas_speed).await?;
+    // Reserve the max fee per transfer: the source must cover it when each one is sent
+    let tx_fee = fee_quote.max_cost(gas_limit);
 
-    // Minimum transfer amount is 5x the base transaction fee (to prevent dust)
-    let base_tx_fee = gas_price * U256::from(gas_limit);
-    let min_transfer_amount = base_tx_fee * U256::from(5u64);
+    // Minimum transfer amount is 5x the expected transaction fee (to prevent dust)
+    let min_transfer_amount = fee_quote.expected_cost(gas_limit) * U256::from(5u64);
 
     info!(
-        "Gas price: {} Gwei ({:.1}x speed), Min transfer: {} ETH",
-        ethers::utils::format_units(effective_gas_price, "gwei").unwrap_or_default(),
+        "Fees: {} ({:.1}x speed), Min transfer: {} ETH",
+        fee_quote.describe(),
         gas_speed,
         utils::format_ether(min_transfer_amount)
     );
@@ -472,7 +467,7 @@ pub async fn prepare_split_transactions(
                 tx_fee,
                 gas_reserve,
                 gas_limit,
-                effective_gas_price,
+                fee_quote.fees,
                 operation_name,
                 remaining_balance_wei,
             )?
@@ -484,7 +479,7 @@ pub async fn prepare_split_transactions(
                 min_transfer_amount,
                 tx_fee,
                 gas_limit,
-                effective_gas_price,
+                fee_quote.fees,
                 operation_name,
                 remaining_balance_wei,
             )?
@@ -500,14 +495,19 @@ pub async fn prepare_split_transactions(
     for (idx, tx) in transactions.into_iter().enumerate() {
         let receiver = &receivers[idx];
         let description = format!(
-            "{} #{}: {} ETH",
+            "{} #{}: {} ETH (fee ~{}, max {})",
             operation_name,
             idx + 1,
-            utils::format_ether(tx.value)
+            utils::format_ether(tx.value),
+            utils::format_ether(fee_quote.expected_cost(gas_limit)),
+            utils::format_ether(tx_fee)
         );
         let dest_label = format!("{} → {:?}", receiver.derivation_path, receiver.address);
         tx_list.push((tx, description, dest_label));
     }
 
+    // Simulate every transfer so a recipient that rejects funds is caught before signing
+    simulation::simulate_pending_transactions(&provider, source.address, &tx_list).await?;
+
     Ok((tx_list, manager))
 }
\ No newline at end of file
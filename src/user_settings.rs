@@ -30,6 +30,29 @@ impl CustomNetwork {
     }
 }
 
+/// A labelled address saved by the user
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AddressBookEntry {
+    pub label: String,
+    /// Checksummed or lowercase 0x address
+    pub address: String,
+}
+
+/// An address polled by the background balance watcher
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WatchedAddress {
+    pub label: String,
+    pub address: String,
+    /// Networks to poll this address on
+    pub chain_ids: Vec<u64>,
+    /// Minimum balance change (in native units) that triggers a notification; empty = any change
+    #[serde(default)]
+    pub threshold: String,
+    /// Ledger derivation index, when the address was derived from the device
+    #[serde(default)]
+    pub derivation_index: Option<u32>,
+}
+
 fn default_custom_networks() -> Vec<CustomNetwork> {
     Vec::new()
 }
@@ -83,6 +106,38 @@ pub struct UserSettings {
     /// Use native ethers-rs Ledger support instead of Foundry cast
     #[serde(default = "default_use_native_ledger")]
     pub use_native_ledger: bool,
+    /// Saved labelled addresses
+    #[serde(default)]
+    pub address_book: Vec<AddressBookEntry>,
+    /// Addresses polled by the background balance watcher
+    #[serde(default)]
+    pub watched_addresses: Vec<WatchedAddress>,
+    /// Whether the background balance watcher is running
+    #[serde(default)]
+    pub watcher_enabled: bool,
+    /// Balance watcher polling interval (seconds)
+    #[serde(default = "default_watcher_interval")]
+    pub watcher_interval_secs: u64,
+    /// Show USD values read from Chainlink price feeds
+    #[serde(default)]
+    pub show_fiat_values: bool,
+    /// Custom Chainlink price feed per chain ID, overriding the built-in feed
+    #[serde(default)]
+    pub custom_price_feeds: HashMap<u64, String>,
+    /// Headroom added on top of `eth_estimateGas` for disperse gas limits, in percent
+    #[serde(default = "default_gas_margin_percent")]
+    pub gas_margin_percent: u32,
+    /// Look up ENS reverse records and show primary names next to addresses
+    #[serde(default)]
+    pub show_ens_names: bool,
+}
+
+fn default_gas_margin_percent() -> u32 {
+    crate::disperse::DEFAULT_GAS_MARGIN_PERCENT
+}
+
+fn default_watcher_interval() -> u64 {
+    60
 }
 
 fn default_gas_speed() -> f32 {
@@ -133,6 +188,14 @@ impl Default for UserSettings {
             coin_type_override: default_coin_type(),
             default_remaining_balance: default_remaining_balance(),
             use_native_ledger: default_use_native_ledger(),
+            address_book: Vec::new(),
+            watched_addresses: Vec::new(),
+            watcher_enabled: false,
+            watcher_interval_secs: default_watcher_interval(),
+            show_fiat_values: false,
+            custom_price_feeds: HashMap::new(),
+            gas_margin_percent: default_gas_margin_percent(),
+            show_ens_names: false,
         }
     }
 }
@@ -239,6 +302,49 @@ impl UserSettings {
     pub fn effective_coin_type(&self) -> u32 {
         self.coin_type_override.unwrap_or(crate::config::DEFAULT_COIN_TYPE)
     }
+
+    /// RPC URL to use for a chain: custom override, then built-in default, then custom network
+    pub fn rpc_url_for(&self, chain_id: u64) -> Option<String> {
+        if let Some(rpc) = self.get_custom_rpc(chain_id) {
+            return Some(rpc.clone());
+        }
+        if let Some(network) = crate::config::find_network_by_chain_id(chain_id) {
+            return Some(network.default_rpc.to_string());
+        }
+        self.get_custom_network(chain_id).map(|n| n.rpc_url.clone())
+    }
+
+    /// Add an address book entry (returns false if the address is already saved)
+    pub fn add_address_book_entry(&mut self, entry: AddressBookEntry) -> bool {
+        if self.address_book.iter().any(|e| e.address.eq_ignore_ascii_case(&entry.address)) {
+            return false;
+        }
+        self.address_book.push(entry);
+        true
+    }
+
+    /// Remove an address book entry by address
+    pub fn remove_address_book_entry(&mut self, address: &str) -> bool {
+        let initial_len = self.address_book.len();
+        self.address_book.retain(|e| !e.address.eq_ignore_ascii_case(address));
+        self.address_book.len() < initial_len
+    }
+
+    /// Add an address to the balance watcher (returns false if it is already watched)
+    pub fn add_watched_address(&mut self, watched: WatchedAddress) -> bool {
+        if self.watched_addresses.iter().any(|w| w.address.eq_ignore_ascii_case(&watched.address)) {
+            return false;
+        }
+        self.watched_addresses.push(watched);
+        true
+    }
+
+    /// Stop watching an address
+    pub fn remove_watched_address(&mut self, address: &str) -> bool {
+        let initial_len = self.watched_addresses.len();
+        self.watched_addresses.retain(|w| !w.address.eq_ignore_ascii_case(address));
+        self.watched_addresses.len() < initial_len
+    }
 }
 
 #[cfg(test)]
@@ -461,4 +567,79 @@ mod tests {
         
         assert_eq!(settings.effective_coin_type(), 714);
     }
+
+    // ==================== rpc_url_for tests ====================
+
+    #[test]
+    fn test_rpc_url_for_builtin_and_override() {
+        let mut settings = UserSettings::default();
+        let builtin = crate::config::find_network_by_chain_id(1).unwrap();
+        assert_eq!(settings.rpc_url_for(1), Some(builtin.default_rpc.to_string()));
+
+        settings.set_custom_rpc(1, "https://my-rpc.example".to_string());
+        assert_eq!(settings.rpc_url_for(1), Some("https://my-rpc.example".to_string()));
+    }
+
+    #[test]
+    fn test_rpc_url_for_custom_network() {
+        let mut settings = UserSettings::default();
+        settings.add_custom_network(CustomNetwork::new("Test".to_string(), 99999, "TST".to_string(), "https://test.com".to_string()));
+        assert_eq!(settings.rpc_url_for(99999), Some("https://test.com".to_string()));
+        assert_eq!(settings.rpc_url_for(424242), None);
+    }
+
+    // ==================== address book tests ====================
+
+    #[test]
+    fn test_address_book_add_and_remove() {
+        let mut settings = UserSettings::default();
+        let entry = AddressBookEntry {
+            label: "Treasury".to_string(),
+            address: "0xAbC0000000000000000000000000000000000001".to_string(),
+        };
+        assert!(settings.add_address_book_entry(entry.clone()));
+        // Duplicate addresses are rejected regardless of case
+        let duplicate = AddressBookEntry {
+            label: "Other".to_string(),
+            address: entry.address.to_lowercase(),
+        };
+        assert!(!settings.add_address_book_entry(duplicate));
+        assert_eq!(settings.address_book.len(), 1);
+
+        assert!(settings.remove_address_book_entry(&entry.address.to_uppercase().replace("0X", "0x")));
+        assert!(settings.address_book.is_empty());
+    }
+
+    // ==================== watched address tests ====================
+
+    #[test]
+    fn test_watched_address_add_and_remove() {
+        let mut settings = UserSettings::default();
+        let watched = WatchedAddress {
+            label: "Index 3".to_string(),
+            address: "0x0000000000000000000000000000000000000003".to_string(),
+            chain_ids: vec![1, 8453],
+            threshold: "0.01".to_string(),
+            derivation_index: Some(3),
+        };
+        assert!(settings.add_watched_address(watched.clone()));
+        assert!(!settings.add_watched_address(watched.clone()));
+        assert!(settings.remove_watched_address(&watched.address));
+        assert!(!settings.remove_watched_address(&watched.address));
+    }
+
+    #[test]
+    fn test_watcher_settings_default_when_missing() {
+        let settings: UserSettings = serde_json::from_str(r#"{"selected_chain_id": 1}"#).unwrap();
+        assert!(settings.address_book.is_empty());
+        assert!(settings.watched_addresses.is_empty());
+        assert!(!settings.watcher_enabled);
+        assert_eq!(settings.watcher_interval_secs, 60);
+    }
+
+    #[test]
+    fn test_gas_margin_default_when_missing() {
+        let settings: UserSettings = serde_json::from_str(r#"{"selected_chain_id": 1}"#).unwrap();
+        assert_eq!(settings.gas_margin_percent, crate::disperse::DEFAULT_GAS_MARGIN_PERCENT);
+    }
 }
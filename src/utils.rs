@@ -40,6 +40,40 @@ pub fn parse_eth_str_to_wei(input: &str) -> Result<U256> {
         .map_err(|e| anyhow!("Invalid ETH amount '{}': {}", trimmed, e))
 }
 
+/// Format raw token units with the token's decimals
+pub fn format_token_amount(amount: U256, decimals: u8) -> String {
+    ethers::utils::format_units(amount, decimals as u32).unwrap_or_else(|_| amount.to_string())
+}
+
+/// Parse a decimal amount string into raw token units using the token's decimals
+///
+/// Like `parse_eth_str_to_wei`, but for tokens that do not use 18 decimals (e.g. USDC uses 6).
+///
+/// # Errors
+/// Returns an error for empty, negative or malformed input, or more fractional digits than the token has
+pub fn parse_token_amount(input: &str, decimals: u8) -> Result<U256> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(anyhow!("Amount cannot be empty"));
+    }
+    if trimmed.starts_with('-') {
+        return Err(anyhow!("Amount cannot be negative: {}", trimmed));
+    }
+    if let Some((_, fraction)) = trimmed.split_once('.') {
+        if fraction.len() > decimals as usize {
+            return Err(anyhow!(
+                "Invalid amount '{}': at most {} decimal places allowed",
+                trimmed,
+                decimals
+            ));
+        }
+    }
+
+    ethers::utils::parse_units(trimmed, decimals as u32)
+        .map(U256::from)
+        .map_err(|e| anyhow!("Invalid amount '{}': {}", trimmed, e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,4 +187,32 @@ mod tests {
         let expected = U256::from(123456789012345678u64);
         assert_eq!(result, expected);
     }
+
+    // ==================== token amount tests ====================
+
+    #[test]
+    fn test_parse_token_amount_six_decimals() {
+        assert_eq!(parse_token_amount("1", 6).unwrap(), U256::from(1_000_000u64));
+        assert_eq!(parse_token_amount(" 0.25 ", 6).unwrap(), U256::from(250_000u64));
+    }
+
+    #[test]
+    fn test_parse_token_amount_rejects_excess_precision() {
+        assert!(parse_token_amount("0.1234567", 6).is_err());
+        assert!(parse_token_amount("1.5", 0).is_err());
+        assert_eq!(parse_token_amount("7", 0).unwrap(), U256::from(7u64));
+    }
+
+    #[test]
+    fn test_parse_token_amount_invalid() {
+        assert!(parse_token_amount("", 6).is_err());
+        assert!(parse_token_amount("-1", 6).is_err());
+        assert!(parse_token_amount("abc", 6).is_err());
+    }
+
+    #[test]
+    fn test_format_token_amount() {
+        assert_eq!(format_token_amount(U256::from(1_500_000u64), 6), "1.500000");
+        assert_eq!(format_token_amount(U256::from(42u64), 0), "42.0");
+    }
 }